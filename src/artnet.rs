@@ -0,0 +1,540 @@
+use crate::consts::{RDM_MAX_PACKAGE_SIZE, SC_RDM};
+use crate::dmx_controller::{DmxController, RdmResponseError};
+use crate::dmx_driver::{DmxControllerDriver, DmxError, RdmControllerDriver};
+use crate::dmx_universe::{DmxUniverse, DMX_UNIVERSE_SIZE};
+use crate::rdm_data::RdmData;
+use crate::rdm_types::DeserializationError;
+use std::net::{Ipv4Addr, SocketAddr, UdpSocket};
+
+/// The udp port Art-Net is sent to.
+pub const ARTNET_PORT: u16 = 6454;
+/// The Art-Net protocol version that is implemented.
+pub const ARTNET_PROTOCOL_VERSION: u16 = 14;
+
+const ARTNET_ID: [u8; 8] = *b"Art-Net\0";
+const OP_POLL: u16 = 0x2000;
+const OP_POLL_REPLY: u16 = 0x2100;
+const OP_DMX: u16 = 0x5000;
+const OP_RDM: u16 = 0x8300;
+const POLL_SIZE: usize = 14;
+const POLL_REPLY_SIZE: usize = 239;
+const DMX_HEADER_SIZE: usize = 18;
+const RDM_HEADER_SIZE: usize = 24;
+const MAX_PACKET_SIZE: usize = DMX_HEADER_SIZE + DMX_UNIVERSE_SIZE;
+const RDM_VERSION_STANDARD: u8 = 0x01;
+const RDM_COMMAND_PROCESS: u8 = 0x00;
+const PORT_TYPE_OUTPUT_DMX512: u8 = 0x80;
+const GOOD_OUTPUT_DATA_TRANSMITTED: u8 = 0x80;
+/// The node supports 15 bit port-addresses (Art-Net 3 and later).
+const STATUS2_PORT_ADDRESS_15_BIT: u8 = 0x08;
+
+/// Splits a 15 bit port-address into the net and the sub-net/universe byte.
+fn split_port_address(port_address: u16) -> (u8, u8) {
+    (((port_address >> 8) & 0x7F) as u8, port_address as u8)
+}
+
+/// An ArtDmx packet.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ArtDmxPacket {
+    /// The sequence number used for reordering packets. Zero disables reordering.
+    pub sequence: u8,
+    /// The physical input port the data was received on by the sender.
+    pub physical: u8,
+    /// The 15 bit port-address consisting of the net, sub-net and universe.
+    pub port_address: u16,
+    /// The dmx slots after the start code.
+    pub data: heapless::Vec<u8, DMX_UNIVERSE_SIZE>,
+}
+
+/// An ArtRdm packet.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ArtRdmPacket {
+    /// The 15 bit port-address consisting of the net, sub-net and universe.
+    pub port_address: u16,
+    pub command: u8,
+    /// The rdm package without the start code.
+    pub rdm_package: heapless::Vec<u8, RDM_MAX_PACKAGE_SIZE>,
+}
+
+impl ArtRdmPacket {
+    /// Serializes the ArtRdm packet.
+    pub fn serialize(&self) -> heapless::Vec<u8, { RDM_HEADER_SIZE + RDM_MAX_PACKAGE_SIZE }> {
+        let (net, address) = split_port_address(self.port_address);
+
+        let mut packet = heapless::Vec::new();
+        packet.extend_from_slice(&ARTNET_ID).unwrap();
+        packet.extend_from_slice(&OP_RDM.to_le_bytes()).unwrap();
+        packet
+            .extend_from_slice(&ARTNET_PROTOCOL_VERSION.to_be_bytes())
+            .unwrap();
+        packet.push(RDM_VERSION_STANDARD).unwrap();
+        // filler and spare bytes
+        packet.extend_from_slice(&[0x00; 8]).unwrap();
+        packet.push(net).unwrap();
+        packet.push(self.command).unwrap();
+        packet.push(address).unwrap();
+        packet.extend_from_slice(&self.rdm_package).unwrap();
+
+        packet
+    }
+}
+
+/// An Art-Net packet that is processed by the [ArtNetNode].
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[allow(clippy::large_enum_variant)]
+pub enum ArtNetPacket {
+    /// An ArtPoll packet. It gets answered with an ArtPollReply.
+    Poll,
+    Dmx(ArtDmxPacket),
+    Rdm(ArtRdmPacket),
+    /// A packet with an opcode that isn't processed by the [ArtNetNode].
+    Unsupported(u16),
+}
+
+impl ArtNetPacket {
+    /// Deserializes an Art-Net packet.
+    pub fn deserialize(buffer: &[u8]) -> Result<Self, DeserializationError> {
+        if buffer.len() < 12 {
+            return Err(DeserializationError::WrongLength);
+        }
+        if buffer[..8] != ARTNET_ID {
+            return Err(DeserializationError::InvalidFormat);
+        }
+
+        let op_code = u16::from_le_bytes([buffer[8], buffer[9]]);
+        match op_code {
+            OP_POLL if buffer.len() >= POLL_SIZE => Ok(Self::Poll),
+            OP_DMX if buffer.len() >= DMX_HEADER_SIZE => {
+                let length = u16::from_be_bytes([buffer[16], buffer[17]]) as usize;
+                if length > DMX_UNIVERSE_SIZE || buffer.len() != DMX_HEADER_SIZE + length {
+                    return Err(DeserializationError::WrongLength);
+                }
+
+                Ok(Self::Dmx(ArtDmxPacket {
+                    sequence: buffer[12],
+                    physical: buffer[13],
+                    port_address: u16::from_be_bytes([buffer[15] & 0x7F, buffer[14]]),
+                    data: heapless::Vec::from_slice(&buffer[DMX_HEADER_SIZE..]).unwrap(),
+                }))
+            },
+            OP_RDM if buffer.len() >= RDM_HEADER_SIZE => Ok(Self::Rdm(ArtRdmPacket {
+                port_address: u16::from_be_bytes([buffer[21] & 0x7F, buffer[23]]),
+                command: buffer[22],
+                rdm_package: heapless::Vec::from_slice(&buffer[RDM_HEADER_SIZE..])
+                    .map_err(|_| DeserializationError::WrongLength)?,
+            })),
+            OP_POLL | OP_DMX | OP_RDM => Err(DeserializationError::WrongLength),
+            op_code => Ok(Self::Unsupported(op_code)),
+        }
+    }
+}
+
+/// Raised by [ArtNetNode::receive_and_forward] and [ArtNetNode::receive_and_forward_with_rdm].
+#[derive(Debug)]
+pub enum ArtNetError<E> {
+    /// The socket raised an error.
+    Io(std::io::Error),
+    /// The received datagram isn't a valid Art-Net packet or contains an invalid rdm package.
+    DeserializationError(DeserializationError),
+    /// The controller couldn't send the universe or the rdm request.
+    ControllerError(RdmResponseError<E>),
+}
+
+impl<E: core::fmt::Debug> core::fmt::Display for ArtNetError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ArtNetError::Io(error) => error.fmt(f),
+            ArtNetError::DeserializationError(error) => error.fmt(f),
+            ArtNetError::ControllerError(error) => error.fmt(f),
+        }
+    }
+}
+
+impl<E: core::fmt::Debug + core::fmt::Display> std::error::Error for ArtNetError<E> {}
+
+impl<E> From<std::io::Error> for ArtNetError<E> {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl<E> From<DeserializationError> for ArtNetError<E> {
+    fn from(value: DeserializationError) -> Self {
+        Self::DeserializationError(value)
+    }
+}
+
+/// The configuration of an [ArtNetNode].
+#[derive(Debug, Clone)]
+pub struct ArtNetNodeConfig {
+    /// The name shown by Art-Net controllers. Only the first 17 bytes are sent.
+    pub short_name: String,
+    /// The description shown by Art-Net controllers. Only the first 63 bytes are sent.
+    pub long_name: String,
+    /// The ip address that is advertised in the ArtPollReply.
+    pub ip_address: Ipv4Addr,
+    pub mac_address: [u8; 6],
+    /// The 15 bit port-addresses of the universes the node outputs.
+    /// Each universe is forwarded to the controller with the same index.
+    pub universes: Vec<u16>,
+}
+
+impl Default for ArtNetNodeConfig {
+    fn default() -> Self {
+        Self {
+            short_name: String::from("dmx-rdm"),
+            long_name: String::from("dmx-rdm Art-Net node"),
+            ip_address: Ipv4Addr::UNSPECIFIED,
+            mac_address: [0x00; 6],
+            universes: vec![0],
+        }
+    }
+}
+
+/// Receives universes via Art-Net and forwards them to [DmxController]s.
+///
+/// ArtPoll packets are answered with one ArtPollReply per configured universe.
+/// Packets for universes that aren't configured are ignored.
+pub struct ArtNetNode {
+    socket: UdpSocket,
+    config: ArtNetNodeConfig,
+    universes: Vec<DmxUniverse>,
+}
+
+impl ArtNetNode {
+    /// Binds to the Art-Net port on all interfaces.
+    pub fn bind(config: ArtNetNodeConfig) -> std::io::Result<Self> {
+        let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, ARTNET_PORT))?;
+        socket.set_broadcast(true)?;
+
+        Ok(Self::from_socket(socket, config))
+    }
+
+    /// Creates a node from an already bound socket.
+    pub fn from_socket(socket: UdpSocket, config: ArtNetNodeConfig) -> Self {
+        Self {
+            socket,
+            universes: vec![DmxUniverse::new(); config.universes.len()],
+            config,
+        }
+    }
+
+    /// Get a reference to the underlying socket.
+    pub fn get_socket(&self) -> &UdpSocket {
+        &self.socket
+    }
+
+    /// Get the last received values of a universe by its port-address.
+    pub fn get_universe(&self, port_address: u16) -> Option<&DmxUniverse> {
+        self.universe_index(port_address)
+            .map(|universe_index| &self.universes[universe_index])
+    }
+
+    fn universe_index(&self, port_address: u16) -> Option<usize> {
+        self.config
+            .universes
+            .iter()
+            .position(|&universe| universe == port_address)
+    }
+
+    /// Serializes the ArtPollReply that advertises the configured universe with the given index.
+    pub fn serialize_poll_reply(&self, universe_index: usize) -> [u8; POLL_REPLY_SIZE] {
+        let (net, address) = split_port_address(self.config.universes[universe_index]);
+        let copy_name = |destination: &mut [u8], name: &str| {
+            // the last byte has to stay null
+            let length = name.len().min(destination.len() - 1);
+            destination[..length].copy_from_slice(&name.as_bytes()[..length]);
+        };
+
+        let mut reply = [0u8; POLL_REPLY_SIZE];
+        reply[..8].copy_from_slice(&ARTNET_ID);
+        reply[8..10].copy_from_slice(&OP_POLL_REPLY.to_le_bytes());
+        reply[10..14].copy_from_slice(&self.config.ip_address.octets());
+        reply[14..16].copy_from_slice(&ARTNET_PORT.to_le_bytes());
+        reply[18] = net;
+        reply[19] = address >> 4;
+        copy_name(&mut reply[26..44], &self.config.short_name);
+        copy_name(&mut reply[44..108], &self.config.long_name);
+        reply[172..174].copy_from_slice(&1u16.to_be_bytes());
+        reply[174] = PORT_TYPE_OUTPUT_DMX512;
+        reply[182] = GOOD_OUTPUT_DATA_TRANSMITTED;
+        reply[190] = address & 0x0F;
+        reply[201..207].copy_from_slice(&self.config.mac_address);
+        reply[207..211].copy_from_slice(&self.config.ip_address.octets());
+        // the bind index starts at 1
+        reply[211] = (universe_index + 1) as u8;
+        reply[212] = STATUS2_PORT_ADDRESS_15_BIT;
+
+        reply
+    }
+
+    /// Handles a received datagram.
+    ///
+    /// Only ArtDmx packets for configured universes are processed.
+    /// Returns the index of the universe that was updated.
+    pub fn handle_packet(&mut self, packet: &[u8]) -> Result<Option<usize>, DeserializationError> {
+        match ArtNetPacket::deserialize(packet)? {
+            ArtNetPacket::Dmx(packet) => Ok(self.handle_dmx(&packet)),
+            _ => Ok(None),
+        }
+    }
+
+    fn handle_dmx(&mut self, packet: &ArtDmxPacket) -> Option<usize> {
+        let universe_index = self.universe_index(packet.port_address)?;
+
+        let mut universe = DmxUniverse::new();
+        universe.as_mut_slice()[..packet.data.len()].copy_from_slice(&packet.data);
+        self.universes[universe_index] = universe;
+
+        Some(universe_index)
+    }
+
+    /// Receives one datagram and answers it if it's an ArtPoll.
+    fn receive_packet<E>(&mut self) -> Result<Option<(ArtNetPacket, SocketAddr)>, ArtNetError<E>> {
+        let mut buffer = [0u8; MAX_PACKET_SIZE];
+        let (received_bytes, source) = self.socket.recv_from(&mut buffer)?;
+
+        let packet = ArtNetPacket::deserialize(&buffer[..received_bytes])?;
+        if packet == ArtNetPacket::Poll {
+            for universe_index in 0..self.config.universes.len() {
+                self.socket
+                    .send_to(&self.serialize_poll_reply(universe_index), source)?;
+            }
+
+            return Ok(None);
+        }
+
+        Ok(Some((packet, source)))
+    }
+
+    fn forward_dmx<D: DmxControllerDriver>(
+        &mut self,
+        packet: &ArtDmxPacket,
+        controllers: &mut [DmxController<D>],
+    ) -> Result<bool, ArtNetError<D::DriverError>> {
+        let universe_index = match self.handle_dmx(packet) {
+            Some(universe_index) => universe_index,
+            None => return Ok(false),
+        };
+
+        let controller = match controllers.get_mut(universe_index) {
+            Some(controller) => controller,
+            None => return Ok(false),
+        };
+
+        controller
+            .send_dmx_package(self.universes[universe_index].as_slice())
+            .map_err(ArtNetError::ControllerError)?;
+
+        Ok(true)
+    }
+
+    /// Receives one datagram and sends the universe using the controller with the same index
+    /// as the universe in [ArtNetNodeConfig::universes].
+    /// ArtPoll packets are answered and ArtRdm packets are ignored.
+    /// Returns true if a universe was sent.
+    pub fn receive_and_forward<D: DmxControllerDriver>(
+        &mut self,
+        controllers: &mut [DmxController<D>],
+    ) -> Result<bool, ArtNetError<D::DriverError>> {
+        match self.receive_packet()? {
+            Some((ArtNetPacket::Dmx(packet), _)) => self.forward_dmx(&packet, controllers),
+            _ => Ok(false),
+        }
+    }
+
+    /// Like [ArtNetNode::receive_and_forward], but ArtRdm requests are relayed unaltered to the
+    /// controller of the universe using [DmxController::rdm_relay_request].
+    /// The response is sent back to the Art-Net controller as an ArtRdm packet.
+    pub fn receive_and_forward_with_rdm<D: DmxControllerDriver + RdmControllerDriver>(
+        &mut self,
+        controllers: &mut [DmxController<D>],
+    ) -> Result<bool, ArtNetError<D::DriverError>> {
+        match self.receive_packet()? {
+            Some((ArtNetPacket::Dmx(packet), _)) => self.forward_dmx(&packet, controllers),
+            Some((ArtNetPacket::Rdm(packet), source)) => {
+                self.relay_rdm(&packet, source, controllers)?;
+                Ok(false)
+            },
+            _ => Ok(false),
+        }
+    }
+
+    fn relay_rdm<D: RdmControllerDriver>(
+        &mut self,
+        packet: &ArtRdmPacket,
+        source: SocketAddr,
+        controllers: &mut [DmxController<D>],
+    ) -> Result<(), ArtNetError<D::DriverError>> {
+        let controller = match self
+            .universe_index(packet.port_address)
+            .and_then(|universe_index| controllers.get_mut(universe_index))
+        {
+            Some(controller) if packet.command == RDM_COMMAND_PROCESS => controller,
+            _ => return Ok(()),
+        };
+
+        let mut rdm_package: heapless::Vec<u8, { RDM_MAX_PACKAGE_SIZE + 1 }> = heapless::Vec::new();
+        rdm_package.push(SC_RDM).unwrap();
+        rdm_package.extend_from_slice(&packet.rdm_package).unwrap();
+        let request = match RdmData::deserialize(&rdm_package) {
+            Ok(RdmData::Request(request)) => request,
+            _ => {
+                return Err(ArtNetError::DeserializationError(
+                    DeserializationError::InvalidFormat,
+                ))
+            },
+        };
+
+        let response = match controller.rdm_relay_request(request) {
+            Ok(Some(response)) => response,
+            // there is nothing to reply if the request was a broadcast or timed out
+            Ok(None) | Err(RdmResponseError::DmxError(DmxError::TimeoutError)) => return Ok(()),
+            Err(error) => return Err(ArtNetError::ControllerError(error)),
+        };
+
+        let response_package = RdmData::Response(response).serialize();
+        let reply = ArtRdmPacket {
+            port_address: packet.port_address,
+            command: RDM_COMMAND_PROCESS,
+            rdm_package: heapless::Vec::from_slice(&response_package[1..]).unwrap(),
+        };
+        self.socket.send_to(&reply.serialize(), source)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::artnet::{
+        ArtNetNode, ArtNetNodeConfig, ArtNetPacket, ArtRdmPacket, OP_POLL_REPLY, POLL_REPLY_SIZE,
+    };
+    use crate::dmx_controller::{DmxController, DmxControllerConfig};
+    use crate::dmx_driver::{ControllerDriverErrorDef, DmxControllerDriver, DmxError};
+    use std::net::UdpSocket;
+    use std::time::Duration;
+
+    fn build_dmx_packet(port_address: u16, data: &[u8]) -> Vec<u8> {
+        let mut packet = Vec::new();
+        packet.extend_from_slice(b"Art-Net\0");
+        packet.extend_from_slice(&0x5000u16.to_le_bytes());
+        packet.extend_from_slice(&14u16.to_be_bytes());
+        packet.extend_from_slice(&[0x01, 0x00]);
+        packet.push(port_address as u8);
+        packet.push((port_address >> 8) as u8);
+        packet.extend_from_slice(&(data.len() as u16).to_be_bytes());
+        packet.extend_from_slice(data);
+
+        packet
+    }
+
+    fn build_node(universes: Vec<u16>) -> ArtNetNode {
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        socket
+            .set_read_timeout(Some(Duration::from_secs(1)))
+            .unwrap();
+
+        ArtNetNode::from_socket(
+            socket,
+            ArtNetNodeConfig {
+                universes,
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn test_deserialize_packets() {
+        match ArtNetPacket::deserialize(&build_dmx_packet(0x0123, &[1, 2])).unwrap() {
+            ArtNetPacket::Dmx(packet) => {
+                assert_eq!(packet.port_address, 0x0123);
+                assert_eq!(packet.sequence, 0x01);
+                assert_eq!(&packet.data[..], &[1, 2]);
+            },
+            packet => panic!("expected an ArtDmx packet, got {packet:?}"),
+        }
+
+        let rdm_packet = ArtRdmPacket {
+            port_address: 0x0123,
+            command: 0x00,
+            rdm_package: heapless::Vec::from_slice(&[0x01, 0x18]).unwrap(),
+        };
+        assert_eq!(
+            ArtNetPacket::deserialize(&rdm_packet.serialize()).unwrap(),
+            ArtNetPacket::Rdm(rdm_packet)
+        );
+
+        let mut poll_packet = b"Art-Net\0".to_vec();
+        poll_packet.extend_from_slice(&[0x00, 0x20, 0x00, 0x0E, 0x00, 0x00]);
+        assert_eq!(
+            ArtNetPacket::deserialize(&poll_packet).unwrap(),
+            ArtNetPacket::Poll
+        );
+
+        let mut truncated_packet = build_dmx_packet(0, &[1, 2]);
+        truncated_packet.pop();
+        ArtNetPacket::deserialize(&truncated_packet).unwrap_err();
+    }
+
+    #[test]
+    fn test_poll_reply() {
+        let node = build_node(vec![0x0123]);
+        let reply = node.serialize_poll_reply(0);
+
+        assert_eq!(reply.len(), POLL_REPLY_SIZE);
+        assert_eq!(&reply[..8], b"Art-Net\0");
+        assert_eq!(&reply[8..10], &OP_POLL_REPLY.to_le_bytes());
+        assert_eq!(reply[18], 0x01);
+        assert_eq!(reply[19], 0x02);
+        assert_eq!(reply[190], 0x03);
+        assert_eq!(&reply[26..34], b"dmx-rdm\0");
+    }
+
+    #[test]
+    fn test_loopback_forwarding() {
+        struct DmxDriver {
+            sent_package: Vec<u8>,
+        }
+
+        impl ControllerDriverErrorDef for DmxDriver {
+            type DriverError = ();
+        }
+
+        impl DmxControllerDriver for DmxDriver {
+            fn send_dmx_package(&mut self, package: &[u8]) -> Result<(), DmxError<()>> {
+                self.sent_package = package.to_vec();
+                Ok(())
+            }
+        }
+
+        let mut node = build_node(vec![0x0001, 0x0002]);
+        let node_address = node.get_socket().local_addr().unwrap();
+        let mut controllers = [0, 1].map(|_| {
+            DmxController::new(
+                DmxDriver {
+                    sent_package: Vec::new(),
+                },
+                &DmxControllerConfig::default(),
+            )
+        });
+
+        let sender = UdpSocket::bind("127.0.0.1:0").unwrap();
+        sender
+            .send_to(&build_dmx_packet(0x0002, &[1, 2, 3]), node_address)
+            .unwrap();
+        assert!(node.receive_and_forward(&mut controllers).unwrap());
+
+        assert!(controllers[0].get_driver().sent_package.is_empty());
+        let sent_package = &controllers[1].get_driver().sent_package;
+        assert_eq!(sent_package.len(), 512);
+        assert_eq!(&sent_package[..4], &[1, 2, 3, 0]);
+        assert_eq!(node.get_universe(0x0002).unwrap().get_channel(2), Some(3));
+
+        // universes that aren't configured are ignored
+        sender
+            .send_to(&build_dmx_packet(0x0003, &[1]), node_address)
+            .unwrap();
+        assert!(!node.receive_and_forward(&mut controllers).unwrap());
+    }
+}
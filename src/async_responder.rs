@@ -0,0 +1,567 @@
+use crate::consts::{DMX_MAX_PACKAGE_SIZE, SC_RDM};
+use crate::dmx_driver::{expected_package_size, DmxError};
+use crate::dmx_receiver::{DmxFrame, PollingError};
+use crate::dmx_uart_driver::{AsyncDmxRecvUartDriver, AsyncDmxRespUartDriver, DmxUartDriverError};
+use crate::rdm_data::{serialize_discovery_response, RdmData, RdmDeserializationError};
+use crate::rdm_responder::{
+    RdmAnswer, RdmResponderConfig, RdmResponderHandlerFunc, RdmResponderPackageHandler,
+};
+
+const READ_TIMEOUT_US: u32 = 1800;
+
+/// The async counterpart of [RdmResponder](crate::dmx_receiver::RdmResponder).
+/// Instead of blocking on the uart it awaits the futures of the driver, so it doesn't stall
+/// the executor while waiting for packages.
+/// MQ_SIZE specifies the size of the message queue and the status vector. MQ_SIZE cannot be greater
+/// than 255.
+/// SUB_DEVICE_COUNT specifies the amount of sub-devices. SUB_DEVICE_COUNT cannot be greater than 512.
+/// SENSOR_COUNT specifies the maximum amount of sensors. SENSOR_COUNT cannot be greater than 255.
+pub struct AsyncRdmResponder<
+    D: AsyncDmxRecvUartDriver + AsyncDmxRespUartDriver,
+    const MQ_SIZE: usize,
+    const SUB_DEVICE_COUNT: usize = 0,
+    const SENSOR_COUNT: usize = 0,
+> {
+    driver: D,
+    rdm_receiver_handler: RdmResponderPackageHandler<MQ_SIZE, SUB_DEVICE_COUNT, SENSOR_COUNT>,
+    foreign_response_count: u32,
+    ignore_foreign_responses: bool,
+}
+
+impl<
+        D: AsyncDmxRecvUartDriver + AsyncDmxRespUartDriver,
+        const MQ_SIZE: usize,
+        const SUB_DEVICE_COUNT: usize,
+        const SENSOR_COUNT: usize,
+    > AsyncRdmResponder<D, MQ_SIZE, SUB_DEVICE_COUNT, SENSOR_COUNT>
+{
+    /// Creates a new [AsyncRdmResponder].
+    pub fn new(driver: D, config: RdmResponderConfig) -> Self {
+        Self {
+            driver,
+            rdm_receiver_handler: RdmResponderPackageHandler::new(config),
+            foreign_response_count: 0,
+            ignore_foreign_responses: true,
+        }
+    }
+
+    /// Call this function in a loop. It will await a package and handle it.
+    ///
+    /// RDM requests are answered using the handler. DMX packages and packages with a custom
+    /// start code are returned, the first byte of the frame is the start code.
+    ///
+    /// Returns None if no package was received or the package was an RDM request.
+    pub async fn poll<HandlerError>(
+        &mut self,
+        handler: &mut dyn RdmResponderHandlerFunc<Error = HandlerError>,
+    ) -> Result<Option<DmxFrame>, PollingError<D::DriverError, HandlerError>> {
+        let package = match self.receive_package().await {
+            Err(DmxError::TimeoutError) => return Ok(None),
+            result => result?,
+        };
+
+        if package[0] != SC_RDM {
+            return Ok(Some(package));
+        }
+
+        let request = match RdmData::deserialize(&package) {
+            Ok(RdmData::Request(request)) => request,
+            Ok(RdmData::Response(_)) => {
+                self.foreign_response_count = self.foreign_response_count.wrapping_add(1);
+                if self.ignore_foreign_responses {
+                    return Ok(None);
+                }
+
+                return Err(PollingError::NotMatching);
+            },
+            Err(error) => {
+                self.rdm_receiver_handler
+                    .record_deserialization_error(&error);
+                return Err(PollingError::DeserializationError(error));
+            },
+        };
+
+        let answer = self
+            .rdm_receiver_handler
+            .handle_rdm_request_with_raw(request, Some(&package), handler)
+            .map_err(PollingError::HandlerError)?;
+
+        match answer {
+            RdmAnswer::Response(response_data) => {
+                let response_delay_us = self.rdm_receiver_handler.get_response_delay_us();
+                if response_delay_us > 0 {
+                    self.driver.delay_response(response_delay_us).await;
+                }
+
+                let serialized_package = RdmData::Response(response_data).serialize();
+                let written_bytes = self
+                    .driver
+                    .write_frames(&serialized_package)
+                    .await
+                    .map_err(DmxError::from)?;
+
+                if written_bytes != serialized_package.len() {
+                    return Err(PollingError::UartOverflow);
+                }
+            },
+            RdmAnswer::DiscoveryResponse(uid) => {
+                let preamble_length = self.rdm_receiver_handler.get_discovery_preamble_len();
+                let frame_buffer = serialize_discovery_response(uid, preamble_length);
+                let written_bytes = self
+                    .driver
+                    .write_frames_no_break(&frame_buffer)
+                    .await
+                    .map_err(DmxError::from)?;
+
+                if written_bytes != frame_buffer.len() {
+                    return Err(PollingError::UartOverflow);
+                }
+            },
+            RdmAnswer::NoResponse => {},
+        }
+
+        Ok(None)
+    }
+
+    async fn receive_package(&mut self) -> Result<DmxFrame, DmxError<D::DriverError>> {
+        let mut buffer = [0u8; DMX_MAX_PACKAGE_SIZE];
+        let mut bytes_read = self
+            .driver
+            .read_frames(&mut buffer[0..3], READ_TIMEOUT_US)
+            .await?;
+        if bytes_read < 2 {
+            return Err(DmxError::DeserializationError(
+                RdmDeserializationError::WrongMessageLength(bytes_read),
+            ));
+        }
+
+        let message_size = expected_package_size(&buffer[..bytes_read])?;
+        bytes_read += match self
+            .driver
+            .read_frames_no_break(&mut buffer[3..message_size], READ_TIMEOUT_US)
+            .await
+        {
+            Err(DmxUartDriverError::TimeoutError) => 0,
+            result => result?,
+        };
+
+        Ok(DmxFrame::from_slice(&buffer[..bytes_read]).unwrap())
+    }
+
+    /// Get the package handler that keeps the state of the responder.
+    pub fn get_package_handler(
+        &self,
+    ) -> &RdmResponderPackageHandler<MQ_SIZE, SUB_DEVICE_COUNT, SENSOR_COUNT> {
+        &self.rdm_receiver_handler
+    }
+
+    /// Get the package handler that keeps the state of the responder mutably.
+    pub fn get_package_handler_mut(
+        &mut self,
+    ) -> &mut RdmResponderPackageHandler<MQ_SIZE, SUB_DEVICE_COUNT, SENSOR_COUNT> {
+        &mut self.rdm_receiver_handler
+    }
+
+    /// Get the amount of RDM responses that have been received. Responses are sent by
+    /// other responders on the bus and are usually ignored.
+    pub fn get_foreign_response_count(&self) -> u32 {
+        self.foreign_response_count
+    }
+
+    /// Set whether [AsyncRdmResponder::poll] ignores received RDM responses instead of returning
+    /// [PollingError::NotMatching]. Responses are ignored by default.
+    pub fn set_ignore_foreign_responses(&mut self, ignore_foreign_responses: bool) {
+        self.ignore_foreign_responses = ignore_foreign_responses;
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(not(feature = "std"), allow(unused_imports))]
+mod tests {
+    use crate::async_responder::AsyncRdmResponder;
+    use crate::command_class::{RequestCommandClass, ResponseCommandClass};
+    use crate::consts::{RDM_MAX_DISCOVERY_RESPONSE_SIZE, SC_RDM, SC_SUB_MESSAGE};
+    use crate::dmx_receiver::PollingError;
+    use crate::dmx_uart_driver::{
+        AsyncDmxRecvUartDriver, AsyncDmxRespUartDriver, DmxUartDriver, DmxUartDriverError,
+    };
+    use crate::pids;
+    use crate::rdm_data::{
+        deserialize_discovery_response, RdmData, RdmDeserializationError, RdmRequestData,
+        RdmResponseData,
+    };
+    use crate::rdm_responder::{
+        DmxReceiverContext, RdmResponderConfig, RdmResponderHandlerFunc, RdmResult,
+    };
+    use crate::rdm_types::CommsStatus;
+    use crate::types::{DataPack, ResponseType};
+    use crate::unique_identifier::{PackageAddress, UniqueIdentifier};
+    use core::future::Future;
+    use core::pin::pin;
+    use core::task::{Context, Poll, Waker};
+
+    /// Polls a future that never has to wait, which is the case for [AsyncFrameDriver].
+    #[cfg(feature = "std")]
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        match future
+            .as_mut()
+            .poll(&mut Context::from_waker(Waker::noop()))
+        {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("the future is not ready"),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    struct AsyncFrameDriver {
+        frame: Vec<u8>,
+        position: usize,
+        written_frames: Vec<(bool, Vec<u8>)>,
+        time_us: u32,
+        write_times_us: Vec<u32>,
+    }
+
+    #[cfg(feature = "std")]
+    impl AsyncFrameDriver {
+        fn read(&mut self, buffer: &mut [u8]) -> Result<usize, DmxUartDriverError<()>> {
+            let bytes_left = self.frame.len() - self.position;
+            if bytes_left == 0 {
+                return Err(DmxUartDriverError::TimeoutError);
+            }
+
+            let bytes_read = bytes_left.min(buffer.len());
+            buffer[..bytes_read]
+                .copy_from_slice(&self.frame[self.position..self.position + bytes_read]);
+            self.position += bytes_read;
+
+            Ok(bytes_read)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl DmxUartDriver for AsyncFrameDriver {
+        type DriverError = ();
+    }
+
+    #[cfg(feature = "std")]
+    impl AsyncDmxRecvUartDriver for AsyncFrameDriver {
+        async fn read_frames(
+            &mut self,
+            buffer: &mut [u8],
+            _: u32,
+        ) -> Result<usize, DmxUartDriverError<()>> {
+            self.read(buffer)
+        }
+
+        async fn read_frames_no_break(
+            &mut self,
+            buffer: &mut [u8],
+            _: u32,
+        ) -> Result<usize, DmxUartDriverError<()>> {
+            self.read(buffer)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl AsyncDmxRespUartDriver for AsyncFrameDriver {
+        async fn write_frames(&mut self, buffer: &[u8]) -> Result<usize, DmxUartDriverError<()>> {
+            self.written_frames.push((true, buffer.to_vec()));
+            self.write_times_us.push(self.time_us);
+            Ok(buffer.len())
+        }
+
+        async fn write_frames_no_break(
+            &mut self,
+            buffer: &[u8],
+        ) -> Result<usize, DmxUartDriverError<()>> {
+            self.written_frames.push((false, buffer.to_vec()));
+            self.write_times_us.push(self.time_us);
+            Ok(buffer.len())
+        }
+
+        async fn delay_response(&mut self, delay_us: u32) {
+            self.time_us += delay_us;
+        }
+    }
+
+    #[cfg(feature = "std")]
+    struct EchoHandler;
+
+    #[cfg(feature = "std")]
+    impl RdmResponderHandlerFunc for EchoHandler {
+        type Error = ();
+
+        fn handle_rdm(
+            &mut self,
+            request: &RdmRequestData,
+            _: &mut DmxReceiverContext,
+        ) -> Result<RdmResult, Self::Error> {
+            Ok(RdmResult::Acknowledged(request.parameter_data.clone()))
+        }
+    }
+
+    #[cfg(feature = "std")]
+    const RESPONDER_UID: UniqueIdentifier = match UniqueIdentifier::new(0x7FF0, 1) {
+        Ok(uid) => uid,
+        Err(_) => panic!(),
+    };
+
+    #[cfg(feature = "std")]
+    fn build_responder(frame: &[u8]) -> AsyncRdmResponder<AsyncFrameDriver, 4> {
+        build_responder_with_config(frame, build_config())
+    }
+
+    #[cfg(feature = "std")]
+    fn build_responder_with_config(
+        frame: &[u8],
+        config: RdmResponderConfig,
+    ) -> AsyncRdmResponder<AsyncFrameDriver, 4> {
+        AsyncRdmResponder::new(
+            AsyncFrameDriver {
+                frame: frame.to_vec(),
+                position: 0,
+                written_frames: vec![],
+                time_us: 0,
+                write_times_us: vec![],
+            },
+            config,
+        )
+    }
+
+    #[cfg(feature = "std")]
+    fn build_config() -> RdmResponderConfig {
+        RdmResponderConfig {
+            uid: RESPONDER_UID,
+            supported_pids: &[0x8000],
+            ..Default::default()
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn build_request(
+        command_class: RequestCommandClass,
+        parameter_id: u16,
+        data: &[u8],
+    ) -> RdmData {
+        RdmData::Request(RdmRequestData {
+            destination_uid: PackageAddress::Device(RESPONDER_UID),
+            source_uid: UniqueIdentifier::new(0x7FF0, 0).unwrap(),
+            transaction_number: 5,
+            port_id: 1,
+            message_count: 0,
+            sub_device: 0,
+            command_class,
+            parameter_id,
+            parameter_data: DataPack::from_slice(data).unwrap(),
+        })
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_request_response_cycle() {
+        let request = build_request(RequestCommandClass::GetCommand, 0x8000, &[1, 2, 3]);
+        let mut responder = build_responder(&request.serialize());
+
+        assert!(block_on(responder.poll(&mut EchoHandler))
+            .unwrap()
+            .is_none());
+
+        let (with_break, frame) = &responder.driver.written_frames[0];
+        assert!(with_break);
+        let response = match RdmData::deserialize(frame).unwrap() {
+            RdmData::Response(response) => response,
+            _ => panic!("expected rdm response"),
+        };
+        assert_eq!(
+            response.command_class,
+            ResponseCommandClass::GetCommandResponse
+        );
+        assert_eq!(response.response_type, ResponseType::ResponseTypeAck);
+        assert_eq!(response.transaction_number, 5);
+        assert_eq!(&response.parameter_data[..], &[1, 2, 3]);
+
+        // nothing left to receive
+        assert!(block_on(responder.poll(&mut EchoHandler))
+            .unwrap()
+            .is_none());
+        assert_eq!(responder.driver.written_frames.len(), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_discovery_response_without_break() {
+        let mut data = [0u8; 12];
+        data[6..].copy_from_slice(&[0xFF; 6]);
+        let request = build_request(RequestCommandClass::DiscoveryCommand, 0x0001, &data);
+        let request = match request {
+            RdmData::Request(mut request) => {
+                request.destination_uid = PackageAddress::Broadcast;
+                RdmData::Request(request)
+            },
+            _ => unreachable!(),
+        };
+        let mut responder = build_responder(&request.serialize());
+
+        assert!(block_on(responder.poll(&mut EchoHandler))
+            .unwrap()
+            .is_none());
+
+        let (with_break, frame) = &responder.driver.written_frames[0];
+        assert!(!with_break);
+        assert_eq!(frame.len(), RDM_MAX_DISCOVERY_RESPONSE_SIZE);
+        assert_eq!(
+            deserialize_discovery_response(frame).unwrap(),
+            RESPONDER_UID
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_response_delay() {
+        let frame = build_request(RequestCommandClass::GetCommand, 0x8000, &[1])
+            .serialize()
+            .to_vec();
+        let mut data = [0u8; 12];
+        data[6..].copy_from_slice(&[0xFF; 6]);
+        let discovery_request = match build_request(
+            RequestCommandClass::DiscoveryCommand,
+            pids::DISC_UNIQUE_BRANCH,
+            &data,
+        ) {
+            RdmData::Request(mut request) => {
+                request.destination_uid = PackageAddress::Broadcast;
+                RdmData::Request(request)
+            },
+            _ => unreachable!(),
+        };
+        let frames = [frame.clone(), frame, discovery_request.serialize().to_vec()].concat();
+
+        let mut responder = build_responder_with_config(
+            &frames,
+            RdmResponderConfig {
+                response_delay_us: 500,
+                ..build_config()
+            },
+        );
+        for _ in 0..3 {
+            assert!(block_on(responder.poll(&mut EchoHandler))
+                .unwrap()
+                .is_none());
+        }
+
+        // every response is sent after its delay has passed, discovery responses aren't delayed
+        assert_eq!(responder.driver.write_times_us, [500, 1000, 1000]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_dmx_frame_is_returned() {
+        let mut responder = build_responder(&[0x00, 1, 2, 3]);
+
+        let frame = block_on(responder.poll(&mut EchoHandler)).unwrap().unwrap();
+        assert_eq!(&frame[..], &[0x00, 1, 2, 3]);
+        assert!(responder.driver.written_frames.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_invalid_message_length() {
+        let mut responder = build_responder(&[SC_RDM, SC_SUB_MESSAGE, 0]);
+
+        assert!(matches!(
+            block_on(responder.poll(&mut EchoHandler)),
+            Err(PollingError::DeserializationError(
+                RdmDeserializationError::WrongMessageLength(2)
+            ))
+        ));
+        assert!(responder.driver.written_frames.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_corrupted_frame_counts_comms_status() {
+        let mut frame = build_request(RequestCommandClass::GetCommand, 0x8000, &[])
+            .serialize()
+            .to_vec();
+        let checksum_index = frame.len() - 1;
+        frame[checksum_index] ^= 0xFF;
+        let mut responder = build_responder(&frame);
+
+        assert!(matches!(
+            block_on(responder.poll(&mut EchoHandler)),
+            Err(PollingError::DeserializationError(
+                RdmDeserializationError::WrongChecksum { .. }
+            ))
+        ));
+        assert_eq!(
+            responder.get_package_handler().get_comms_status(),
+            CommsStatus {
+                short_message: 0,
+                length_mismatch: 0,
+                checksum_fail: 1,
+            }
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_foreign_responses_are_ignored() {
+        let response = RdmData::Response(RdmResponseData {
+            destination_uid: PackageAddress::Device(UniqueIdentifier::new(0x7FF0, 0).unwrap()),
+            source_uid: UniqueIdentifier::new(0x7FF0, 2).unwrap(),
+            transaction_number: 0,
+            response_type: ResponseType::ResponseTypeAck,
+            message_count: 0,
+            sub_device: 0,
+            command_class: ResponseCommandClass::GetCommandResponse,
+            parameter_id: 0x8000,
+            parameter_data: DataPack::from_slice(&[0]).unwrap(),
+        });
+        let frame = response.serialize().to_vec();
+        let mut responder = build_responder(&[frame.clone(), frame].concat());
+
+        assert!(block_on(responder.poll(&mut EchoHandler))
+            .unwrap()
+            .is_none());
+        assert_eq!(responder.get_foreign_response_count(), 1);
+
+        responder.set_ignore_foreign_responses(false);
+        assert!(matches!(
+            block_on(responder.poll(&mut EchoHandler)),
+            Err(PollingError::NotMatching)
+        ));
+        assert_eq!(responder.get_foreign_response_count(), 2);
+        assert!(responder.driver.written_frames.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_raw_request_in_context() {
+        struct RawRequestHandler {
+            raw_request: Option<Vec<u8>>,
+        }
+
+        impl RdmResponderHandlerFunc for RawRequestHandler {
+            type Error = ();
+
+            fn handle_rdm(
+                &mut self,
+                _: &RdmRequestData,
+                context: &mut DmxReceiverContext,
+            ) -> Result<RdmResult, Self::Error> {
+                self.raw_request = context.raw_request.map(|raw_request| raw_request.to_vec());
+                Ok(RdmResult::Acknowledged(DataPack::new()))
+            }
+        }
+
+        let frame = build_request(RequestCommandClass::GetCommand, 0x8000, &[1, 2])
+            .serialize()
+            .to_vec();
+        let mut responder = build_responder(&frame);
+        let mut handler = RawRequestHandler { raw_request: None };
+
+        assert!(block_on(responder.poll(&mut handler)).unwrap().is_none());
+        assert_eq!(handler.raw_request, Some(frame));
+    }
+}
@@ -0,0 +1,194 @@
+use crate::rdm_data::{RdmData, RdmDeserializationError};
+use std::io::BufRead;
+
+/// The character that starts a comment in a capture.
+const COMMENT_PREFIX: char = '#';
+
+/// The reason a line of a capture couldn't be parsed.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum CaptureError {
+    /// The line couldn't be read. The rest of the capture gets skipped.
+    Io(std::io::ErrorKind),
+    /// The line contains characters that aren't hex digits or an odd number of digits.
+    InvalidHex,
+    /// The frame isn't a valid RDM package.
+    DeserializationError(RdmDeserializationError),
+}
+
+impl core::fmt::Display for CaptureError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CaptureError::Io(kind) => write!(f, "couldn't read the line: {kind}"),
+            CaptureError::InvalidHex => write!(f, "the line isn't valid hex"),
+            CaptureError::DeserializationError(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for CaptureError {}
+
+impl From<RdmDeserializationError> for CaptureError {
+    fn from(value: RdmDeserializationError) -> Self {
+        Self::DeserializationError(value)
+    }
+}
+
+/// A single frame of a capture.
+#[derive(Debug)]
+pub struct CaptureFrame {
+    /// The line number of the frame starting at 1.
+    pub line: usize,
+    pub result: Result<RdmData, CaptureError>,
+}
+
+fn parse_hex(line: &str) -> Result<Vec<u8>, CaptureError> {
+    let digits: Vec<u8> = line
+        .bytes()
+        .filter(|digit| !digit.is_ascii_whitespace())
+        .collect();
+
+    if !digits.len().is_multiple_of(2) {
+        return Err(CaptureError::InvalidHex);
+    }
+
+    digits
+        .chunks(2)
+        .map(|byte| {
+            let byte = core::str::from_utf8(byte).or(Err(CaptureError::InvalidHex))?;
+            u8::from_str_radix(byte, 16).or(Err(CaptureError::InvalidHex))
+        })
+        .collect()
+}
+
+/// Parses a line based hex capture, like the export of a logic analyzer or sniffer.
+///
+/// Every line contains one frame starting with the start code. The bytes may be separated by
+/// whitespace (`CC 01 18 ...` or `CC0118...`). Blank lines and everything after a `#` get
+/// ignored.
+/// Every frame is parsed on its own, so a malformed line doesn't affect the other frames.
+pub fn parse_capture(reader: impl BufRead) -> Vec<CaptureFrame> {
+    let mut frames = Vec::new();
+
+    for (index, line) in reader.lines().enumerate() {
+        let line_number = index + 1;
+
+        let line = match line {
+            Ok(line) => line,
+            Err(error) => {
+                frames.push(CaptureFrame {
+                    line: line_number,
+                    result: Err(CaptureError::Io(error.kind())),
+                });
+                break;
+            },
+        };
+
+        let content = match line.split_once(COMMENT_PREFIX) {
+            Some((content, _)) => content,
+            None => &line,
+        };
+
+        if content.trim().is_empty() {
+            continue;
+        }
+
+        frames.push(CaptureFrame {
+            line: line_number,
+            result: parse_hex(content)
+                .and_then(|frame| RdmData::deserialize(&frame).map_err(CaptureError::from)),
+        });
+    }
+
+    frames
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::capture::{parse_capture, CaptureError};
+    use crate::command_class::RequestCommandClass;
+    use crate::pids;
+    use crate::rdm_data::{RdmData, RdmDeserializationError, RdmRequestData};
+    use crate::types::{DataPack, ResponseType};
+    use crate::unique_identifier::{PackageAddress, UniqueIdentifier};
+
+    fn to_hex(data: &[u8], separator: &str) -> String {
+        data.iter()
+            .map(|byte| format!("{byte:02X}"))
+            .collect::<Vec<_>>()
+            .join(separator)
+    }
+
+    #[test]
+    fn test_parse_capture() {
+        let request = RdmRequestData {
+            destination_uid: PackageAddress::Device(UniqueIdentifier::new(0x7FF0, 1).unwrap()),
+            source_uid: UniqueIdentifier::new(0x7FF0, 2).unwrap(),
+            transaction_number: 7,
+            port_id: 1,
+            message_count: 0,
+            sub_device: 0,
+            command_class: RequestCommandClass::GetCommand,
+            parameter_id: pids::IDENTIFY_DEVICE,
+            parameter_data: DataPack::new(),
+        };
+        let response = request
+            .build_response(
+                ResponseType::ResponseTypeAck,
+                DataPack::from_slice(&[0x01]).unwrap(),
+                0,
+            )
+            .unwrap();
+
+        let request_frame = RdmData::Request(request).serialize();
+        let response_frame = RdmData::Response(response).serialize();
+        let mut broken_frame = request_frame.clone();
+        *broken_frame.last_mut().unwrap() ^= 0xFF;
+
+        let capture = format!(
+            "# exported by a sniffer\n\
+             {}\n\
+             \n\
+             {} # the response\n\
+             {}\n\
+             CC 01 XY\n",
+            to_hex(&request_frame, " "),
+            to_hex(&response_frame, ""),
+            to_hex(&broken_frame, " "),
+        );
+
+        let frames = parse_capture(capture.as_bytes());
+        assert_eq!(frames.len(), 4);
+
+        assert_eq!(frames[0].line, 2);
+        match &frames[0].result {
+            Ok(RdmData::Request(request)) => {
+                assert_eq!(request.transaction_number, 7);
+                assert_eq!(request.parameter_id, pids::IDENTIFY_DEVICE);
+            },
+            result => panic!("expected a request, got {result:?}"),
+        }
+
+        assert_eq!(frames[1].line, 4);
+        match &frames[1].result {
+            Ok(RdmData::Response(response)) => {
+                assert_eq!(response.transaction_number, 7);
+                assert_eq!(&response.parameter_data[..], &[0x01]);
+            },
+            result => panic!("expected a response, got {result:?}"),
+        }
+
+        assert_eq!(frames[2].line, 5);
+        assert!(matches!(
+            frames[2].result,
+            Err(CaptureError::DeserializationError(
+                RdmDeserializationError::WrongChecksum { .. }
+            ))
+        ));
+
+        assert_eq!(frames[3].line, 6);
+        assert_eq!(
+            frames[3].result.as_ref().unwrap_err(),
+            &CaptureError::InvalidHex
+        );
+    }
+}
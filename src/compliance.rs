@@ -0,0 +1,351 @@
+use crate::command_class::RequestCommandClass;
+use crate::dmx_controller::DmxControllerConfig;
+use crate::dmx_receiver::{DmxResponderHandler, RdmResponder};
+use crate::mock::MockBus;
+use crate::pids;
+use crate::rdm_data::{RdmData, RdmRequestData};
+use crate::rdm_packages::{
+    deserialize_identify, deserialize_software_version_label, deserialize_supported_parameters,
+};
+use crate::rdm_types::{DeserializationError, DeviceInfo, DiscoveryMuteResponse, DmxStartAddress};
+use crate::types::{DataPack, ResponseType};
+use crate::unique_identifier::{PackageAddress, UniqueIdentifier};
+
+/// The RDM protocol version every responder has to report in DEVICE_INFO.
+const RDM_PROTOCOL_VERSION: [u8; 2] = [0x01, 0x00];
+
+/// The reason a pid failed the compliance check.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ComplianceError {
+    /// The responder didn't answer the request.
+    NoResponse,
+    /// The responder raised an error while handling the request.
+    PollingError,
+    /// The response doesn't match the request (uids, transaction number, pid or command class).
+    NotMatching,
+    /// The responder didn't acknowledge the request. The [ResponseType] is the received type.
+    UnexpectedResponseType(ResponseType),
+    /// The parameter data of the response couldn't be deserialized.
+    InvalidParameterData,
+}
+
+impl core::fmt::Display for ComplianceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(self, f)
+    }
+}
+
+impl std::error::Error for ComplianceError {}
+
+impl From<DeserializationError> for ComplianceError {
+    fn from(_: DeserializationError) -> Self {
+        Self::InvalidParameterData
+    }
+}
+
+/// The compliance check result of a single pid.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PidComplianceResult {
+    pub pid: u16,
+    pub result: Result<(), ComplianceError>,
+}
+
+/// The result of [check_responder_compliance].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ComplianceReport {
+    /// The results in the order of [pids::required_pids].
+    pub results: Vec<PidComplianceResult>,
+}
+
+impl ComplianceReport {
+    /// Returns true if all required pids passed.
+    pub fn is_compliant(&self) -> bool {
+        self.results.iter().all(|result| result.result.is_ok())
+    }
+
+    /// Returns the pids that failed the compliance check.
+    pub fn failures(&self) -> impl Iterator<Item = &PidComplianceResult> {
+        self.results.iter().filter(|result| result.result.is_err())
+    }
+}
+
+struct ComplianceChecker<
+    'a,
+    HandlerError,
+    const MQ_SIZE: usize,
+    const SUB: usize,
+    const SENS: usize,
+> {
+    responder: &'a mut RdmResponder<MockBus, MQ_SIZE, SUB, SENS>,
+    handler: &'a mut dyn DmxResponderHandler<Error = HandlerError>,
+    controller_uid: UniqueIdentifier,
+    responder_uid: UniqueIdentifier,
+    transaction_number: u8,
+}
+
+impl<HandlerError, const MQ_SIZE: usize, const SUB: usize, const SENS: usize>
+    ComplianceChecker<'_, HandlerError, MQ_SIZE, SUB, SENS>
+{
+    fn send_request(
+        &mut self,
+        destination_uid: PackageAddress,
+        command_class: RequestCommandClass,
+        parameter_id: u16,
+        parameter_data: &[u8],
+    ) -> Result<(), ComplianceError> {
+        self.transaction_number = self.transaction_number.wrapping_add(1);
+
+        self.responder
+            .get_driver()
+            .push_rdm(&RdmData::Request(RdmRequestData {
+                destination_uid,
+                source_uid: self.controller_uid,
+                transaction_number: self.transaction_number,
+                port_id: 1,
+                message_count: 0,
+                sub_device: 0,
+                command_class,
+                parameter_id,
+                parameter_data: DataPack::from_slice(parameter_data).unwrap(),
+            }));
+
+        self.responder
+            .poll(self.handler)
+            .map_err(|_| ComplianceError::PollingError)?;
+
+        Ok(())
+    }
+
+    fn request(
+        &mut self,
+        command_class: RequestCommandClass,
+        parameter_id: u16,
+    ) -> Result<DataPack, ComplianceError> {
+        self.send_request(
+            PackageAddress::Device(self.responder_uid),
+            command_class,
+            parameter_id,
+            &[],
+        )?;
+
+        let response = match self.responder.get_driver().pop_sent_package() {
+            Some(RdmData::Response(response)) => response,
+            Some(RdmData::Request(_)) => return Err(ComplianceError::NotMatching),
+            None => return Err(ComplianceError::NoResponse),
+        };
+
+        if response.destination_uid != PackageAddress::Device(self.controller_uid)
+            || response.source_uid != self.responder_uid
+            || response.transaction_number != self.transaction_number
+            || response.parameter_id != parameter_id
+            || response.command_class != command_class.get_response_class()
+        {
+            return Err(ComplianceError::NotMatching);
+        }
+
+        if response.response_type != ResponseType::ResponseTypeAck {
+            return Err(ComplianceError::UnexpectedResponseType(
+                response.response_type,
+            ));
+        }
+
+        Ok(response.parameter_data)
+    }
+
+    fn check_disc_unique_branch(&mut self) -> Result<(), ComplianceError> {
+        // make sure the responder takes part in the discovery
+        self.send_request(
+            PackageAddress::Broadcast,
+            RequestCommandClass::DiscoveryCommand,
+            pids::DISC_UN_MUTE,
+            &[],
+        )?;
+
+        let mut full_range = [0u8; 12];
+        full_range[6..].copy_from_slice(&[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE]);
+        self.send_request(
+            PackageAddress::Broadcast,
+            RequestCommandClass::DiscoveryCommand,
+            pids::DISC_UNIQUE_BRANCH,
+            &full_range,
+        )?;
+
+        match self.responder.get_driver().pop_sent_discovery_response() {
+            Some(uid) if uid == self.responder_uid => Ok(()),
+            Some(_) => Err(ComplianceError::NotMatching),
+            None => Err(ComplianceError::NoResponse),
+        }
+    }
+
+    fn check_pid(&mut self, pid: u16) -> Result<(), ComplianceError> {
+        match pid {
+            pids::DISC_UNIQUE_BRANCH => self.check_disc_unique_branch()?,
+            pids::DISC_MUTE | pids::DISC_UN_MUTE => {
+                let data = self.request(RequestCommandClass::DiscoveryCommand, pid)?;
+                DiscoveryMuteResponse::deserialize(&data)?;
+            },
+            pids::SUPPORTED_PARAMETERS => {
+                let data = self.request(RequestCommandClass::GetCommand, pid)?;
+                let supported_parameters = deserialize_supported_parameters(&data)?;
+                if supported_parameters.len() != self.responder.supported_pid_count() {
+                    return Err(ComplianceError::InvalidParameterData);
+                }
+            },
+            pids::DEVICE_INFO => {
+                let data = self.request(RequestCommandClass::GetCommand, pid)?;
+                DeviceInfo::deserialize(&data)?;
+                if data[..2] != RDM_PROTOCOL_VERSION {
+                    return Err(ComplianceError::InvalidParameterData);
+                }
+            },
+            pids::SOFTWARE_VERSION_LABEL => {
+                let data = self.request(RequestCommandClass::GetCommand, pid)?;
+                deserialize_software_version_label(&data)?;
+            },
+            pids::DMX_START_ADDRESS => {
+                let data = self.request(RequestCommandClass::GetCommand, pid)?;
+                DmxStartAddress::deserialize(&data).map_err(DeserializationError::from)?;
+            },
+            pids::IDENTIFY_DEVICE => {
+                let data = self.request(RequestCommandClass::GetCommand, pid)?;
+                deserialize_identify(&data)?;
+            },
+            _ => {
+                self.request(RequestCommandClass::GetCommand, pid)?;
+            },
+        }
+
+        Ok(())
+    }
+}
+
+/// Sends a request for every pid in [pids::required_pids] to the responder over a [MockBus]
+/// and checks whether the responses are well-formed.
+///
+/// The discovery pids are checked using discovery requests, all others using get requests.
+/// Frames that are still queued on the bus get discarded.
+pub fn check_responder_compliance<
+    HandlerError,
+    const MQ_SIZE: usize,
+    const SUB_DEVICE_COUNT: usize,
+    const SENSOR_COUNT: usize,
+>(
+    responder: &mut RdmResponder<MockBus, MQ_SIZE, SUB_DEVICE_COUNT, SENSOR_COUNT>,
+    handler: &mut dyn DmxResponderHandler<Error = HandlerError>,
+) -> ComplianceReport {
+    *responder.get_driver() = MockBus::new();
+
+    let mut checker = ComplianceChecker {
+        responder_uid: responder.get_uid(),
+        responder,
+        handler,
+        controller_uid: DmxControllerConfig::default().rdm_uid,
+        transaction_number: 0,
+    };
+
+    ComplianceReport {
+        results: pids::required_pids()
+            .iter()
+            .map(|&pid| PidComplianceResult {
+                pid,
+                result: checker.check_pid(pid),
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::compliance::{check_responder_compliance, ComplianceError};
+    use crate::dmx_receiver::{DmxResponderHandler, RdmResponder};
+    use crate::mock::MockBus;
+    use crate::pids;
+    use crate::rdm_data::RdmRequestData;
+    use crate::rdm_responder::{DmxReceiverContext, RdmResponderConfig, RdmResult};
+    use crate::types::{NackReason, ResponseType};
+    use crate::unique_identifier::UniqueIdentifier;
+
+    /// The responder handler of the README.
+    struct RdmHandler;
+
+    impl DmxResponderHandler for RdmHandler {
+        type Error = std::fmt::Error;
+
+        fn handle_rdm(
+            &mut self,
+            _: &RdmRequestData,
+            _: &mut DmxReceiverContext,
+        ) -> Result<RdmResult, Self::Error> {
+            Ok(RdmResult::NotAcknowledged(u16::from(
+                NackReason::UnknownPid,
+            )))
+        }
+
+        fn on_identify_changed(
+            &mut self,
+            identify: bool,
+            _: &mut DmxReceiverContext,
+        ) -> Result<(), Self::Error> {
+            println!("Current identify is {identify}");
+
+            Ok(())
+        }
+    }
+
+    fn build_responder() -> RdmResponder<MockBus, 32> {
+        RdmResponder::new(
+            MockBus::new(),
+            RdmResponderConfig {
+                uid: UniqueIdentifier::new(0x7FF0, 1).unwrap(),
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn test_readme_responder_is_compliant() {
+        let mut responder = build_responder();
+
+        let report = check_responder_compliance(&mut responder, &mut RdmHandler);
+
+        assert_eq!(report.results.len(), pids::required_pids().len());
+        assert!(
+            report.is_compliant(),
+            "{:?}",
+            report.failures().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_intercepted_identify_is_reported() {
+        struct BrokenHandler;
+
+        impl DmxResponderHandler for BrokenHandler {
+            type Error = ();
+
+            fn handle_identify_device(
+                &mut self,
+                _: &RdmRequestData,
+                _: &mut DmxReceiverContext,
+            ) -> Result<Option<RdmResult>, Self::Error> {
+                Ok(Some(RdmResult::NotAcknowledged(u16::from(
+                    NackReason::HardwareFault,
+                ))))
+            }
+        }
+
+        let mut responder = build_responder();
+
+        let report = check_responder_compliance(&mut responder, &mut BrokenHandler);
+
+        let failures: Vec<_> = report.failures().collect();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].pid, pids::IDENTIFY_DEVICE);
+        assert_eq!(
+            failures[0].result,
+            Err(ComplianceError::UnexpectedResponseType(
+                ResponseType::ResponseTypeNackReason
+            ))
+        );
+    }
+}
@@ -15,17 +15,69 @@ pub const BREAK_MICROS: u64 = 200;
 pub const MAB_MICROS: u64 = 48;
 pub const MAXIMUM_DMX512_MILLIS: usize = 1250;
 pub const INTER_SLOT_TIME_MILLIS: usize = 2;
+/// The time it takes to transmit one slot (start bit, 8 data bits and 2 stop bits)
+pub const SLOT_MICROS: u32 = 11 * 1_000_000 / DMX_BAUD;
+/// The maximum allowed time between two slots of an RDM response
+pub const RDM_MAX_INTER_SLOT_MICROS: u32 = 2_000;
 
 pub const RDM_MIN_PACKAGE_SIZE: usize = 22;
 pub const RDM_MAX_PACKAGE_SIZE: usize = 257;
 /// Excluding preamble and separator
 pub const RDM_DISCOVERY_RESPONSE_SIZE: usize = 16;
+/// Maximum amount of preamble bytes before the separator of a discovery response
+pub const RDM_MAX_DISCOVERY_PREAMBLE_SIZE: usize = 7;
 /// Including 7 bytes preamble + 1 byte separator
-pub const RDM_MAX_DISCOVERY_RESPONSE_SIZE: usize = RDM_DISCOVERY_RESPONSE_SIZE + 8;
+pub const RDM_MAX_DISCOVERY_RESPONSE_SIZE: usize =
+    RDM_DISCOVERY_RESPONSE_SIZE + RDM_MAX_DISCOVERY_PREAMBLE_SIZE + 1;
 
 pub const RDM_MAX_PARAMETER_DATA_LENGTH: usize = 231;
+/// The maximum amount of bytes of a response that is streamed over multiple packages
+pub const RDM_MAX_OVERFLOW_BUFFER_SIZE: usize = 1024;
 pub const RDM_MAX_STATUS_PACKAGES_PER_REQUEST: usize = 25;
 pub const RDM_STATUS_MESSAGE_SIZE: usize = 9;
 pub const RDM_DEVICE_INFO_SIZE: usize = 0x13;
+/// Excluding the description
+pub const RDM_PARAMETER_DESCRIPTION_FIXED_SIZE: usize = 0x14;
+/// Including a description of 32 bytes
+pub const RDM_MAX_PARAMETER_DESCRIPTION_SIZE: usize = 0x34;
+/// Excluding the description
+pub const RDM_SENSOR_DEFINITION_FIXED_SIZE: usize = 0x0D;
+/// Including a description of 32 bytes
+pub const RDM_MAX_SENSOR_DEFINITION_SIZE: usize = 0x2D;
+pub const RDM_SENSOR_VALUE_SIZE: usize = 0x09;
+pub const RDM_DMX_PERSONALITY_SIZE: usize = 0x02;
+/// Excluding the description
+pub const RDM_DMX_PERSONALITY_DESCRIPTION_FIXED_SIZE: usize = 0x03;
+/// Including a description of 32 bytes
+pub const RDM_MAX_DMX_PERSONALITY_DESCRIPTION_SIZE: usize = 0x23;
+/// Current curve and curve count
+pub const RDM_CURVE_SIZE: usize = 0x02;
+/// The curve number, excluding the description
+pub const RDM_CURVE_DESCRIPTION_FIXED_SIZE: usize = 0x01;
+/// Including a description of 32 bytes
+pub const RDM_MAX_CURVE_DESCRIPTION_SIZE: usize = 0x21;
+/// Scene, up fade time, down fade time and wait time
+pub const RDM_CAPTURE_PRESET_SIZE: usize = 0x08;
+pub const RDM_PRESET_PLAYBACK_SIZE: usize = 0x03;
+/// Short message, length mismatch and checksum fail counters
+pub const RDM_COMMS_STATUS_SIZE: usize = 0x06;
+/// Sub-device footprint sum and base dmx address
+pub const RDM_DMX_BLOCK_ADDRESS_SIZE: usize = 0x04;
+/// Year, month, day, hour, minute and second
+pub const RDM_REAL_TIME_CLOCK_SIZE: usize = 0x07;
+/// Slot offset and default value
+pub const RDM_DEFAULT_SLOT_VALUE_SIZE: usize = 0x03;
+/// The sensor number that addresses all sensors in SENSOR_VALUE and RECORD_SENSORS set requests.
+pub const RDM_ALL_SENSORS: u8 = 0xFF;
+/// The self test number that stops all self tests in PERFORM_SELFTEST.
+pub const RDM_SELF_TEST_OFF: u8 = 0x00;
+/// The self test number that performs all self tests in PERFORM_SELFTEST.
+pub const RDM_SELF_TEST_ALL: u8 = 0xFF;
+/// The self test number, excluding the description
+pub const RDM_SELF_TEST_DESCRIPTION_FIXED_SIZE: usize = 0x01;
+/// Including a description of 32 bytes
+pub const RDM_MAX_SELF_TEST_DESCRIPTION_SIZE: usize = 0x21;
 
 pub const RDM_MAX_SUPPORTED_PARAMETERS_PER_REQUEST: usize = 128;
+pub const RDM_MAX_DEFAULT_SLOT_VALUES_PER_REQUEST: usize =
+    RDM_MAX_PARAMETER_DATA_LENGTH / RDM_DEFAULT_SLOT_VALUE_SIZE;
@@ -1,35 +1,59 @@
 use crate::command_class::RequestCommandClass;
+use crate::consts::{RDM_CAPTURE_PRESET_SIZE, RDM_SELF_TEST_OFF};
 use crate::dmx_driver::{
     ControllerDriverErrorDef, CustomStartCodeControllerDriver, DiscoveryOption,
     DmxControllerDriver, DmxError, RdmControllerDriver,
 };
-use crate::rdm_data::{RdmData, RdmRequestData};
+use crate::dmx_universe::DmxUniverse;
+use crate::rdm_data::{BinaryRdmPackage, RdmData, RdmRequestData, RdmResponseData};
 use crate::rdm_packages::{
-    deserialize_identify, deserialize_status_messages, deserialize_supported_parameters,
-    RdmResponseInfo, RdmResponsePackage,
+    deserialize_default_slot_values, deserialize_identify, deserialize_identify_mode,
+    deserialize_power_state, deserialize_statistics_counter, deserialize_status_messages,
+    deserialize_supported_parameters, RdmResponseInfo, RdmResponsePackage,
 };
 use crate::rdm_types::{
-    DeviceInfo, DiscoveryMuteResponse, DmxStartAddress, OverflowMessageResp, StatusMessages,
-    StatusType, SupportedParameters,
+    CommsStatus, Curve, CurveDescription, DefaultSlotValues, DeviceInfo, DiscoveryMuteResponse,
+    DmxBlockAddress, DmxPersonality, DmxPersonalityDescription, DmxStartAddress, IdentifyMode,
+    OverflowMessageResp, ParameterDescription, PowerState, PresetPlayback, RealTimeClock,
+    ResetType, SelfTestDescription, SensorDefinition, SensorValue, StatisticsCounter,
+    StatusMessages, StatusType, SupportedParameters,
 };
 use crate::types::{DataPack, NackReason, ResponseType};
-use crate::unique_identifier::{PackageAddress, UniqueIdentifier};
+use crate::unique_identifier::{PackageAddress, UnicastAddress, UniqueIdentifier};
+use crate::utils::pid_response_matches;
 use crate::{pids, rdm_packages, rdm_types};
 
 #[derive(Debug)]
 pub struct DmxControllerConfig {
     pub rdm_uid: UniqueIdentifier,
+    /// The amount of times a get or set request gets resent if the responder doesn't answer in time.
+    pub request_retries: u8,
+    /// If true, [DmxController::rdm_discover] returns [DiscoveryOption::FoundWithErrors] for
+    /// discovery responses with a wrong checksum, which the discovery functions verify using a
+    /// DISC_MUTE request. If false, these responses are treated as a [DiscoveryOption::Collision].
+    pub accept_discovery_responses_with_errors: bool,
+    /// The amount of times the DISC_MUTE request for a found device gets resent by the discovery
+    /// functions if the device doesn't answer in time.
+    pub discovery_mute_retries: u8,
+    /// If true, the serialized bytes of the last request and response are stored and can be
+    /// inspected using [DmxController::last_request_bytes] and
+    /// [DmxController::last_response_bytes]. Useful for debugging failed transactions.
+    pub capture_last_transaction: bool,
 }
 
 impl Default for DmxControllerConfig {
     fn default() -> Self {
         Self {
             rdm_uid: UniqueIdentifier::new(0x7FF0, 0).unwrap(), // prototyping id
+            request_retries: 0,
+            accept_discovery_responses_with_errors: false,
+            discovery_mute_retries: 0,
+            capture_last_transaction: false,
         }
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct RdmRequest {
     /// The unique id of the recipient of the request.
     pub destination_uid: PackageAddress,
@@ -39,15 +63,55 @@ pub struct RdmRequest {
     pub data: DataPack,
 }
 
+/// Raised if the parameter data of an [RdmRequest] would be longer than 231 bytes.
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ParameterDataTooLongError;
+
+impl core::fmt::Display for ParameterDataTooLongError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "The parameter data can't be longer than 231 bytes.")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParameterDataTooLongError {}
+
 impl RdmRequest {
     /// Creates an RdmRequest with empty parameter data.
-    pub fn empty(uid: PackageAddress, pid: u16) -> Self {
+    /// Parameter data can be appended using [RdmRequest::with_bytes], [RdmRequest::with_u8]
+    /// and [RdmRequest::with_u16_be].
+    pub fn new(uid: PackageAddress, pid: u16) -> Self {
         Self {
             destination_uid: uid,
             parameter_id: pid,
             data: heapless::Vec::new(),
         }
     }
+
+    /// Creates an RdmRequest with empty parameter data.
+    pub fn empty(uid: PackageAddress, pid: u16) -> Self {
+        Self::new(uid, pid)
+    }
+
+    /// Appends bytes to the parameter data.
+    pub fn with_bytes(mut self, bytes: &[u8]) -> Result<Self, ParameterDataTooLongError> {
+        self.data
+            .extend_from_slice(bytes)
+            .or(Err(ParameterDataTooLongError))?;
+
+        Ok(self)
+    }
+
+    /// Appends a byte to the parameter data.
+    pub fn with_u8(self, value: u8) -> Result<Self, ParameterDataTooLongError> {
+        self.with_bytes(&[value])
+    }
+
+    /// Appends a big endian u16 to the parameter data.
+    pub fn with_u16_be(self, value: u16) -> Result<Self, ParameterDataTooLongError> {
+        self.with_bytes(&value.to_be_bytes())
+    }
 }
 
 #[derive(Debug)]
@@ -61,12 +125,53 @@ pub enum RdmResponse {
     RequestWasBroadcast,
 }
 
+/// The parameter data of a get request reassembled from all parts of an overflowing response.
+#[derive(Debug, Clone)]
+pub struct ReassembledResponse<const N: usize> {
+    pub parameter_id: u16,
+    /// The parameter data of all responses in the order they were received.
+    pub data: heapless::Vec<u8, N>,
+    /// The amount of responses the parameter data was split into.
+    /// 1 if the response didn't overflow.
+    pub segment_count: usize,
+}
+
+/// The direction of a package passed to a packet observer.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PacketDirection {
+    /// The package was sent by the controller.
+    Outgoing,
+    /// The package was received by the controller.
+    Incoming,
+}
+
+#[cfg(feature = "std")]
+type PacketObserver = Box<dyn FnMut(PacketDirection, &[u8]) + Send>;
+
 /// An RDM controller
+///
+/// The available methods depend on the traits the driver implements:
+/// - [DmxControllerDriver] provides [DmxController::send_dmx_package] and
+///   [DmxController::send_dmx_delta].
+/// - [CustomStartCodeControllerDriver] provides [DmxController::send_custom_package].
+/// - [RdmControllerDriver] provides all RDM requests and the discovery.
+///
+/// A transmit-only driver, that can't receive RDM responses, only has to implement
+/// [DmxControllerDriver] or [DmxRespUartDriver](crate::dmx_uart_driver::DmxRespUartDriver).
 pub struct DmxController<C: ControllerDriverErrorDef> {
     driver: C,
     uid: UniqueIdentifier,
     current_transaction_id: u8,
     last_message_count: u8,
+    request_retries: u8,
+    accept_discovery_responses_with_errors: bool,
+    discovery_mute_retries: u8,
+    capture_last_transaction: bool,
+    last_request_bytes: Option<BinaryRdmPackage>,
+    last_response_bytes: Option<BinaryRdmPackage>,
+    #[cfg(feature = "std")]
+    packet_observer: Option<PacketObserver>,
 }
 
 #[derive(Debug)]
@@ -82,6 +187,13 @@ pub enum RdmResponseError<E> {
     NotReady(u16),
     /// The responder didn't acknowledge the request.
     NotAcknowledged(NackReason),
+    /// The responder answered a set request with an overflow response.
+    /// Only responses to get requests are allowed to overflow.
+    UnexpectedOverflow,
+    /// The request wasn't sent since its parameter data is invalid.
+    InvalidRequestData,
+    /// The parameter data of an overflowing response doesn't fit into the buffer.
+    ResponseTooLong,
     /// The underlying dmx controller raised an error.
     DmxError(DmxError<E>),
 }
@@ -107,6 +219,41 @@ impl<E> From<rdm_types::DeserializationError> for RdmResponseError<E> {
     }
 }
 
+impl<E> From<rdm_types::DmxStartAddressError> for RdmResponseError<E> {
+    fn from(_: rdm_types::DmxStartAddressError) -> Self {
+        Self::ParameterDataNotDeserializable
+    }
+}
+
+/// Returned by [DmxController::rdm_validate_patch] if a device can't be patched to an address.
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PatchError<E> {
+    /// The start address is not in the range of 1-512.
+    InvalidStartAddress,
+    /// The device doesn't have a dmx footprint.
+    NoFootprint,
+    /// The footprint of the device doesn't fit into the universe at the start address.
+    FootprintExceedsUniverse,
+    /// The device info couldn't be requested.
+    RdmError(RdmResponseError<E>),
+}
+
+impl<E: core::fmt::Debug> core::fmt::Display for PatchError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(self, f)
+    }
+}
+
+impl<E> From<RdmResponseError<E>> for PatchError<E> {
+    fn from(value: RdmResponseError<E>) -> Self {
+        Self::RdmError(value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: core::fmt::Debug + core::fmt::Display> std::error::Error for PatchError<E> {}
+
 impl<D: ControllerDriverErrorDef> DmxController<D> {
     /// Creates a new DmxManager instance.
     pub fn new(driver: D, config: &DmxControllerConfig) -> Self {
@@ -115,9 +262,119 @@ impl<D: ControllerDriverErrorDef> DmxController<D> {
             uid: config.rdm_uid,
             current_transaction_id: 0,
             last_message_count: 0,
+            request_retries: config.request_retries,
+            accept_discovery_responses_with_errors: config.accept_discovery_responses_with_errors,
+            discovery_mute_retries: config.discovery_mute_retries,
+            capture_last_transaction: config.capture_last_transaction,
+            last_request_bytes: None,
+            last_response_bytes: None,
+            #[cfg(feature = "std")]
+            packet_observer: None,
+        }
+    }
+
+    /// Creates a new DmxManager instance using the [DmxControllerConfig::default] config.
+    ///
+    /// The default config uses a prototyping uid, which shouldn't be used in production.
+    pub fn with_default_config(driver: D) -> Self {
+        Self::new(driver, &DmxControllerConfig::default())
+    }
+
+    /// Creates a new DmxManager instance using the [DmxControllerConfig::default] config
+    /// with the uid of the controller replaced.
+    ///
+    /// ```
+    /// # #[cfg(feature = "mock")]
+    /// # {
+    /// use dmx_rdm::dmx_controller::DmxController;
+    /// use dmx_rdm::mock::MockBus;
+    /// use dmx_rdm::rdm_data::RdmData;
+    /// use dmx_rdm::unique_identifier::{PackageAddress, UniqueIdentifier};
+    ///
+    /// let uid = UniqueIdentifier::new(0x7FF0, 0x1234).unwrap();
+    /// let mut dmx_controller = DmxController::with_uid(MockBus::new(), uid);
+    ///
+    /// dmx_controller
+    ///     .rdm_set_identify(PackageAddress::Broadcast, true)
+    ///     .unwrap();
+    ///
+    /// match dmx_controller.get_driver().pop_sent_package() {
+    ///     Some(RdmData::Request(request)) => assert_eq!(request.source_uid, uid),
+    ///     _ => panic!("expected a request"),
+    /// }
+    /// # }
+    /// ```
+    pub fn with_uid(driver: D, uid: UniqueIdentifier) -> Self {
+        Self::new(
+            driver,
+            &DmxControllerConfig {
+                rdm_uid: uid,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Set an observer that gets called with every serialized RDM package that is sent or received
+    /// by a get, set or discovery mute request. Useful for logging the traffic on the bus.
+    ///
+    /// Since drivers deserialize received packages themselves, incoming packages are
+    /// serialized again before being passed to the observer.
+    #[cfg(feature = "std")]
+    pub fn set_packet_observer(
+        &mut self,
+        observer: impl FnMut(PacketDirection, &[u8]) + Send + 'static,
+    ) {
+        self.packet_observer = Some(Box::new(observer));
+    }
+
+    /// Remove the packet observer.
+    #[cfg(feature = "std")]
+    pub fn clear_packet_observer(&mut self) {
+        self.packet_observer = None;
+    }
+
+    /// Get the serialized bytes of the last request that has been sent by a get, set or
+    /// discovery mute request.
+    ///
+    /// Returns None if [DmxControllerConfig::capture_last_transaction] is disabled or no request
+    /// has been sent yet.
+    pub fn last_request_bytes(&self) -> Option<&[u8]> {
+        self.last_request_bytes.as_deref()
+    }
+
+    /// Get the serialized bytes of the last response that has been received after
+    /// [DmxController::last_request_bytes] was sent.
+    ///
+    /// Returns None if [DmxControllerConfig::capture_last_transaction] is disabled or no response
+    /// has been received for the last request. Since drivers deserialize received packages
+    /// themselves, the response is serialized again.
+    pub fn last_response_bytes(&self) -> Option<&[u8]> {
+        self.last_response_bytes.as_deref()
+    }
+
+    fn observe_package(&mut self, direction: PacketDirection, package: &RdmData) {
+        if self.capture_last_transaction {
+            let serialized_package = Some(package.serialize());
+            match direction {
+                PacketDirection::Outgoing => {
+                    self.last_request_bytes = serialized_package;
+                    self.last_response_bytes = None;
+                },
+                PacketDirection::Incoming => self.last_response_bytes = serialized_package,
+            }
+        }
+
+        #[cfg(feature = "std")]
+        if let Some(observer) = &mut self.packet_observer {
+            observer(direction, &package.serialize());
         }
     }
 
+    /// Get the amount of times the discovery functions resend a DISC_MUTE request.
+    pub fn get_discovery_mute_retries(&self) -> u8 {
+        self.discovery_mute_retries
+    }
+
     /// Get a reference to the underlying driver.
     pub fn get_driver(&mut self) -> &mut D {
         &mut self.driver
@@ -147,49 +404,132 @@ impl<D: DmxControllerDriver> DmxController<D> {
             .send_dmx_package(package)
             .map_err(RdmResponseError::DmxError)
     }
+
+    /// Sends the universe only if it differs from the last sent universe.
+    /// If the universe was sent, `last_sent` gets updated to it.
+    ///
+    /// DMX512 does not support partial updates, so the whole universe will be sent
+    /// on a change. This avoids redundant sends while the scene is static.
+    /// Keep in mind that receivers might treat a missing refresh as a loss of signal,
+    /// so the universe should still be resent periodically using
+    /// [DmxController::send_dmx_package].
+    ///
+    /// Returns true if the universe was sent.
+    pub fn send_dmx_delta(
+        &mut self,
+        universe: &DmxUniverse,
+        last_sent: &mut Option<DmxUniverse>,
+    ) -> Result<bool, RdmResponseError<D::DriverError>> {
+        if let Some(last_sent_universe) = last_sent {
+            if universe.changed_since(last_sent_universe).is_none() {
+                return Ok(false);
+            }
+        }
+
+        self.send_dmx_package(universe.as_slice())?;
+        *last_sent = Some(universe.clone());
+
+        Ok(true)
+    }
 }
 
 impl<D: RdmControllerDriver> DmxController<D> {
-    fn rdm_request(
+    /// Sends the request and receives the response with the matching transaction number.
+    /// Returns None if the request was a broadcast.
+    fn send_and_receive(
         &mut self,
-        command_class: RequestCommandClass,
-        request: RdmRequest,
-    ) -> Result<RdmResponse, RdmResponseError<D::DriverError>> {
-        self.current_transaction_id = self.current_transaction_id.wrapping_add(1);
+        request: RdmRequestData,
+    ) -> Result<Option<RdmResponseData>, RdmResponseError<D::DriverError>> {
+        let destination_uid = request.destination_uid;
+        let source_uid = request.source_uid;
+        let transaction_number = request.transaction_number;
+        let request_pid = request.parameter_id;
 
-        self.driver.send_rdm(RdmData::Request(RdmRequestData {
-            destination_uid: request.destination_uid,
-            source_uid: self.uid,
-            transaction_number: self.current_transaction_id,
-            port_id: 0,
-            message_count: 0,
-            sub_device: 0,
-            command_class,
-            parameter_id: request.parameter_id,
-            parameter_data: request.data,
-        }))?;
+        let request_package = RdmData::Request(request);
+        self.observe_package(PacketDirection::Outgoing, &request_package);
+        self.driver.send_rdm(request_package)?;
 
-        if request.destination_uid.is_broadcast() {
-            return Ok(RdmResponse::RequestWasBroadcast);
+        if destination_uid.is_broadcast() {
+            return Ok(None);
         }
 
         let response = loop {
-            let response = match self.driver.receive_rdm()? {
+            let response_package = self.driver.receive_rdm()?;
+            self.observe_package(PacketDirection::Incoming, &response_package);
+
+            let response = match response_package {
                 RdmData::Request(_) => {
                     return Err(RdmResponseError::NotMatching);
                 },
                 RdmData::Response(response) => response,
             };
 
-            if self.current_transaction_id == response.transaction_number {
+            if transaction_number == response.transaction_number {
                 break response;
             }
         };
 
-        if response.destination_uid != PackageAddress::Device(self.uid) {
+        if response.destination_uid != PackageAddress::Device(source_uid)
+            || !pid_response_matches(request_pid, response.parameter_id)
+        {
             return Err(RdmResponseError::NotMatching);
         }
 
+        Ok(Some(response))
+    }
+
+    fn rdm_request(
+        &mut self,
+        command_class: RequestCommandClass,
+        request: RdmRequest,
+    ) -> Result<RdmResponse, RdmResponseError<D::DriverError>> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!(
+            "rdm_request",
+            uid = ?request.destination_uid,
+            pid = request.parameter_id,
+            ?command_class
+        )
+        .entered();
+
+        let result = self.send_rdm_request(command_class, request);
+
+        #[cfg(feature = "tracing")]
+        match &result {
+            Err(RdmResponseError::NotAcknowledged(nack_reason)) => {
+                tracing::warn!(?nack_reason, "request was not acknowledged")
+            },
+            Err(RdmResponseError::DmxError(DmxError::TimeoutError)) => {
+                tracing::warn!("request timed out")
+            },
+            _ => {},
+        }
+
+        result
+    }
+
+    fn send_rdm_request(
+        &mut self,
+        command_class: RequestCommandClass,
+        request: RdmRequest,
+    ) -> Result<RdmResponse, RdmResponseError<D::DriverError>> {
+        self.current_transaction_id = self.current_transaction_id.wrapping_add(1);
+
+        let response = match self.send_and_receive(RdmRequestData {
+            destination_uid: request.destination_uid,
+            source_uid: self.uid,
+            transaction_number: self.current_transaction_id,
+            port_id: 0,
+            message_count: 0,
+            sub_device: 0,
+            command_class,
+            parameter_id: request.parameter_id,
+            parameter_data: request.data,
+        })? {
+            Some(response) => response,
+            None => return Ok(RdmResponse::RequestWasBroadcast),
+        };
+
         let response_info = RdmResponseInfo {
             parameter_id: response.parameter_id,
             message_count: response.message_count,
@@ -221,29 +561,84 @@ impl<D: RdmControllerDriver> DmxController<D> {
                 Err(RdmResponseError::NotAcknowledged(nack_reason))
             },
             ResponseType::ResponseTypeAckOverflow => {
+                if command_class == RequestCommandClass::SetCommand {
+                    return Err(RdmResponseError::UnexpectedOverflow);
+                }
+
                 Ok(RdmResponse::IncompleteResponse(response_info))
             },
         }
     }
 
+    /// Sends a request without altering it and returns the raw response.
+    ///
+    /// Unlike the other request methods, the source uid, transaction number, port id,
+    /// message count and sub-device of the request are sent as they are. This allows proxies to
+    /// relay requests of other controllers faithfully.
+    /// The response has to be addressed to the source uid of the request.
+    /// Returns None if the request was a broadcast.
+    pub fn rdm_relay_request(
+        &mut self,
+        request: RdmRequestData,
+    ) -> Result<Option<RdmResponseData>, RdmResponseError<D::DriverError>> {
+        self.send_and_receive(request)
+    }
+
+    /// Sends a request and resends it up to `retries` times if the responder doesn't answer in time.
+    ///
+    /// Every attempt uses a new transaction number, so late responses to previous attempts
+    /// get discarded.
+    pub fn rdm_request_with_retries(
+        &mut self,
+        command_class: RequestCommandClass,
+        request: RdmRequest,
+        retries: u8,
+    ) -> Result<RdmResponse, RdmResponseError<D::DriverError>> {
+        for _ in 0..retries {
+            match self.rdm_request(command_class, request.clone()) {
+                Err(RdmResponseError::DmxError(DmxError::TimeoutError)) => continue,
+                result => return result,
+            }
+        }
+
+        self.rdm_request(command_class, request)
+    }
+
     /// Sends a get request.
+    ///
+    /// The request is retried as configured in [DmxControllerConfig::request_retries].
     pub fn rdm_get(
         &mut self,
         request: RdmRequest,
     ) -> Result<RdmResponse, RdmResponseError<D::DriverError>> {
-        self.rdm_request(RequestCommandClass::GetCommand, request)
+        self.rdm_request_with_retries(
+            RequestCommandClass::GetCommand,
+            request,
+            self.request_retries,
+        )
     }
 
     /// Sends a set request.
+    ///
+    /// Since responses to set requests aren't allowed to overflow, an overflow response raises
+    /// [RdmResponseError::UnexpectedOverflow].
+    /// The request is retried as configured in [DmxControllerConfig::request_retries].
     pub fn rdm_set(
         &mut self,
         request: RdmRequest,
     ) -> Result<RdmResponse, RdmResponseError<D::DriverError>> {
-        self.rdm_request(RequestCommandClass::SetCommand, request)
+        self.rdm_request_with_retries(
+            RequestCommandClass::SetCommand,
+            request,
+            self.request_retries,
+        )
     }
 
     /// Sends a discovery request to a range of device ids and returns the found uid
     /// if there is no collision and the device does not have its discovery muted.
+    ///
+    /// Responses with a wrong checksum are only returned as [DiscoveryOption::FoundWithErrors]
+    /// if [DmxControllerConfig::accept_discovery_responses_with_errors] is set.
     pub fn rdm_discover(
         &mut self,
         first_uid: u64,
@@ -270,7 +665,12 @@ impl<D: RdmControllerDriver> DmxController<D> {
             parameter_data,
         }))?;
 
-        Ok(self.driver.receive_rdm_discovery_response()?)
+        match self.driver.receive_rdm_discovery_response()? {
+            DiscoveryOption::FoundWithErrors(_) if !self.accept_discovery_responses_with_errors => {
+                Ok(DiscoveryOption::Collision)
+            },
+            discovery_option => Ok(discovery_option),
+        }
     }
 
     /// Mute device from discovery. It will not respond to discovery requests anymore.
@@ -301,13 +701,24 @@ impl<D: RdmControllerDriver> DmxController<D> {
         deserialize_discovery_mute_response::<D>(&response)
     }
 
+    /// Releases the bus before the application stops.
+    ///
+    /// Unmutes all devices with a DISC_UN_MUTE broadcast, so the next controller can discover
+    /// them, and flushes the driver afterward.
+    pub fn shutdown(&mut self) -> Result<(), RdmResponseError<D::DriverError>> {
+        self.rdm_disc_un_mute(PackageAddress::Broadcast)?;
+        self.driver.flush()?;
+
+        Ok(())
+    }
+
     /// Get the identify state in the rdm device (led for searching)
     pub fn rdm_get_identify(
         &mut self,
-        uid: UniqueIdentifier,
+        uid: impl Into<UnicastAddress>,
     ) -> Result<bool, RdmResponseError<D::DriverError>> {
         let response = self.rdm_get(RdmRequest::empty(
-            PackageAddress::Device(uid),
+            PackageAddress::from(uid.into()),
             pids::IDENTIFY_DEVICE,
         ))?;
 
@@ -332,180 +743,2397 @@ impl<D: RdmControllerDriver> DmxController<D> {
         Ok(())
     }
 
-    /// Get the software version label.
-    pub fn rdm_get_software_version_label(
+    /// Reset the device.
+    ///
+    /// The request isn't retried since that could reset the device twice.
+    /// Since a device might power cycle before responding to a cold reset, a timeout
+    /// is treated as success for [ResetType::Cold].
+    pub fn rdm_reset_device(
         &mut self,
-        uid: UniqueIdentifier,
-    ) -> Result<heapless::String<32>, RdmResponseError<D::DriverError>> {
-        let response_info = match self.rdm_get(RdmRequest::empty(
-            PackageAddress::Device(uid),
-            pids::SOFTWARE_VERSION_LABEL,
+        uid: PackageAddress,
+        reset_type: ResetType,
+    ) -> Result<(), RdmResponseError<D::DriverError>> {
+        let result = self.rdm_request(
+            RequestCommandClass::SetCommand,
+            RdmRequest {
+                destination_uid: uid,
+                parameter_id: pids::RESET_DEVICE,
+                data: heapless::Vec::from_slice(&[reset_type as u8]).unwrap(),
+            },
+        );
+
+        match result {
+            Ok(_) => Ok(()),
+            Err(RdmResponseError::DmxError(DmxError::TimeoutError))
+                if reset_type == ResetType::Cold =>
+            {
+                Ok(())
+            },
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Store the current state of the device as a scene. The scene has to be between 1 and 0xFFFE.
+    /// The fade and wait times are set to 0.
+    pub fn rdm_capture_preset(
+        &mut self,
+        uid: PackageAddress,
+        scene: u16,
+    ) -> Result<(), RdmResponseError<D::DriverError>> {
+        if scene == PresetPlayback::OFF || scene == PresetPlayback::ALL {
+            return Err(RdmResponseError::InvalidRequestData);
+        }
+
+        let mut data = [0u8; RDM_CAPTURE_PRESET_SIZE];
+        data[..2].copy_from_slice(&scene.to_be_bytes());
+        self.rdm_set(RdmRequest {
+            destination_uid: uid,
+            parameter_id: pids::CAPTURE_PRESET,
+            data: DataPack::from_slice(&data).unwrap(),
+        })?;
+
+        Ok(())
+    }
+
+    /// Get how the device identifies itself while IDENTIFY_DEVICE is active.
+    pub fn rdm_get_identify_mode(
+        &mut self,
+        uid: impl Into<UnicastAddress>,
+    ) -> Result<IdentifyMode, RdmResponseError<D::DriverError>> {
+        let response = match self.rdm_get(RdmRequest::empty(
+            PackageAddress::from(uid.into()),
+            pids::IDENTIFY_MODE,
         ))? {
-            RdmResponse::Response(response_info) => response_info,
+            RdmResponse::Response(response) => response,
             _ => return Err(RdmResponseError::ParameterDataNotDeserializable),
         };
 
-        Ok(rdm_packages::deserialize_software_version_label(
-            &response_info.data,
-        )?)
+        Ok(deserialize_identify_mode(&response.data)?)
     }
 
-    /// Get the current start address of the dmx slave.
-    pub fn rdm_get_dmx_start_address(
+    /// Set how the device identifies itself. This doesn't start the identification,
+    /// use [DmxController::rdm_set_identify] for that.
+    pub fn rdm_set_identify_mode(
         &mut self,
-        uid: UniqueIdentifier,
-    ) -> Result<DmxStartAddress, RdmResponseError<D::DriverError>> {
+        uid: PackageAddress,
+        identify_mode: IdentifyMode,
+    ) -> Result<(), RdmResponseError<D::DriverError>> {
+        self.rdm_set(RdmRequest {
+            destination_uid: uid,
+            parameter_id: pids::IDENTIFY_MODE,
+            data: DataPack::from_slice(&[identify_mode as u8]).unwrap(),
+        })?;
+
+        Ok(())
+    }
+
+    /// Get the power state of the device.
+    pub fn rdm_get_power_state(
+        &mut self,
+        uid: impl Into<UnicastAddress>,
+    ) -> Result<PowerState, RdmResponseError<D::DriverError>> {
         let response = match self.rdm_get(RdmRequest::empty(
-            PackageAddress::Device(uid),
-            pids::DMX_START_ADDRESS,
+            PackageAddress::from(uid.into()),
+            pids::POWER_STATE,
         ))? {
             RdmResponse::Response(response) => response,
             _ => return Err(RdmResponseError::ParameterDataNotDeserializable),
         };
 
-        Ok(DmxStartAddress::deserialize(&response.data)?)
+        Ok(deserialize_power_state(&response.data)?)
     }
 
-    /// Set the current start address of the dmx slave. The address has to be between 1 and 512.
-    pub fn rdm_set_dmx_start_address(
+    /// Set the power state of the device.
+    ///
+    /// A device that is set to [PowerState::FullOff] or [PowerState::Shutdown] might stop
+    /// responding, even before acknowledging the request. Treat a
+    /// [DmxError::TimeoutError] as expected in that case.
+    pub fn rdm_set_power_state(
         &mut self,
         uid: PackageAddress,
-        start_address: u16,
+        power_state: PowerState,
     ) -> Result<(), RdmResponseError<D::DriverError>> {
-        assert!(
-            (1..=512).contains(&start_address),
-            "The requested start address is not valid."
-        );
-
         self.rdm_set(RdmRequest {
             destination_uid: uid,
-            parameter_id: pids::DMX_START_ADDRESS,
-            data: DataPack::from_slice(&start_address.to_be_bytes()).unwrap(),
+            parameter_id: pids::POWER_STATE,
+            data: DataPack::from_slice(&[power_state as u8]).unwrap(),
         })?;
 
         Ok(())
     }
 
-    /// Get the last queued message.
-    ///
-    /// Use [DmxController::rdm_get_last_message_count]
-    /// to receive the message count from the last request.
-    ///
-    /// If no messages are queued this will return the current [StatusMessages].
-    /// You can use the [StatusType] to filter these [StatusMessages].
-    ///
-    /// Note that you can only use [StatusType::StatusAdvisory], [StatusType::StatusWarning],
-    /// [StatusType::StatusError] and [StatusType::StatusGetLastMessage].
-    ///
-    /// If you want to receive the previous response use [StatusType::StatusGetLastMessage].
-    pub fn rdm_get_queued_message(
+    /// Get the current dimming curve and the amount of available curves.
+    pub fn rdm_get_curve(
         &mut self,
-        uid: UniqueIdentifier,
-        status_requested: StatusType,
-    ) -> Result<RdmResponsePackage, RdmResponseError<D::DriverError>> {
-        let response = self.rdm_get(RdmRequest {
-            destination_uid: PackageAddress::Device(uid),
-            parameter_id: pids::QUEUED_MESSAGE,
-            data: DataPack::from_slice(&[status_requested as u8]).unwrap(),
-        })?;
+        uid: impl Into<UnicastAddress>,
+    ) -> Result<Curve, RdmResponseError<D::DriverError>> {
+        let response = match self.rdm_get(RdmRequest::empty(
+            PackageAddress::from(uid.into()),
+            pids::CURVE,
+        ))? {
+            RdmResponse::Response(response) => response,
+            _ => return Err(RdmResponseError::ParameterDataNotDeserializable),
+        };
 
-        match response {
-            RdmResponse::Response(response_info) => {
-                Ok(RdmResponsePackage::from_response_info(response_info)?)
-            },
-            _ => Err(RdmResponseError::ParameterDataNotDeserializable),
-        }
+        Ok(Curve::deserialize(&response.data)?)
     }
 
-    /// Get status messages. Filter severity by using the [StatusType::StatusAdvisory], [StatusType::StatusWarning]
-    /// and [StatusType::StatusError].
-    ///
-    /// If you want to receive the previously set of status messages again use [StatusType::StatusGetLastMessage].
-    /// To perform an availability test use [StatusType::StatusNone].
-    ///
-    /// If this parameter message is properly implemented on the slave you
-    /// should never get a [OverflowMessageResp::Incomplete] back, since STATUS_MESSAGE uses
-    /// its own queuing.
-    pub fn rdm_get_status_messages(
+    /// Set the dimming curve. Curves are numbered starting at 1.
+    pub fn rdm_set_curve(
         &mut self,
-        uid: UniqueIdentifier,
-        status_requested: StatusType,
-    ) -> Result<OverflowMessageResp<StatusMessages>, RdmResponseError<D::DriverError>> {
-        let response = self.rdm_get(RdmRequest {
-            destination_uid: PackageAddress::Device(uid),
-            parameter_id: pids::STATUS_MESSAGES,
-            data: DataPack::from_slice(&[status_requested as u8]).unwrap(),
+        uid: PackageAddress,
+        curve: u8,
+    ) -> Result<(), RdmResponseError<D::DriverError>> {
+        self.rdm_set(RdmRequest {
+            destination_uid: uid,
+            parameter_id: pids::CURVE,
+            data: DataPack::from_slice(&[curve]).unwrap(),
         })?;
 
-        match response {
-            RdmResponse::Response(response_info) => Ok(OverflowMessageResp::Complete(
-                deserialize_status_messages(&response_info.data)?,
-            )),
-            RdmResponse::IncompleteResponse(response_info) => Ok(OverflowMessageResp::Incomplete(
-                deserialize_status_messages(&response_info.data)?,
-            )),
-            _ => Err(RdmResponseError::ParameterDataNotDeserializable),
-        }
+        Ok(())
     }
 
-    /// Get the parameter ids that are supported by the responder.
-    ///
-    /// <div class="warning">Note that this only includes optional parameter ids that are not
-    /// required to be compliant with ANSI E1.20.</div>
-    pub fn rdm_get_supported_parameters(
+    /// Get the description of a dimming curve.
+    pub fn rdm_get_curve_description(
         &mut self,
-        uid: UniqueIdentifier,
-    ) -> Result<OverflowMessageResp<SupportedParameters>, RdmResponseError<D::DriverError>> {
-        let response = self.rdm_get(RdmRequest::empty(
-            PackageAddress::Device(uid),
-            pids::SUPPORTED_PARAMETERS,
-        ))?;
+        uid: impl Into<UnicastAddress>,
+        curve: u8,
+    ) -> Result<CurveDescription, RdmResponseError<D::DriverError>> {
+        let response = match self.rdm_get(RdmRequest {
+            destination_uid: PackageAddress::from(uid.into()),
+            parameter_id: pids::CURVE_DESCRIPTION,
+            data: DataPack::from_slice(&[curve]).unwrap(),
+        })? {
+            RdmResponse::Response(response) => response,
+            _ => return Err(RdmResponseError::ParameterDataNotDeserializable),
+        };
 
-        match response {
-            RdmResponse::Response(response_info) => Ok(OverflowMessageResp::Complete(
-                deserialize_supported_parameters(&response_info.data)?,
-            )),
-            RdmResponse::IncompleteResponse(response_info) => Ok(OverflowMessageResp::Incomplete(
-                deserialize_supported_parameters(&response_info.data)?,
-            )),
-            _ => Err(RdmResponseError::ParameterDataNotDeserializable),
-        }
+        Ok(CurveDescription::deserialize(&response.data)?)
     }
 
-    /// Get the device info from the rdm device.
-    pub fn rdm_get_device_info(
+    /// Get the self test the device is currently performing.
+    /// [RDM_SELF_TEST_OFF] if no self test is running.
+    pub fn rdm_get_self_test(
         &mut self,
-        uid: UniqueIdentifier,
-    ) -> Result<DeviceInfo, RdmResponseError<D::DriverError>> {
-        let response = self.rdm_get(RdmRequest::empty(
-            PackageAddress::Device(uid),
-            pids::DEVICE_INFO,
-        ))?;
-        match response {
-            RdmResponse::Response(response_info) => {
-                Ok(DeviceInfo::deserialize(&response_info.data)?)
-            },
+        uid: impl Into<UnicastAddress>,
+    ) -> Result<u8, RdmResponseError<D::DriverError>> {
+        let response = match self.rdm_get(RdmRequest::empty(
+            PackageAddress::from(uid.into()),
+            pids::PERFORM_SELFTEST,
+        ))? {
+            RdmResponse::Response(response) => response,
+            _ => return Err(RdmResponseError::ParameterDataNotDeserializable),
+        };
+
+        match response.data[..] {
+            [self_test] => Ok(self_test),
             _ => Err(RdmResponseError::ParameterDataNotDeserializable),
         }
     }
 
-    /// Returns the message count that was received on the last request using this instance.
-    pub fn rdm_get_last_message_count(&self) -> u8 {
-        self.last_message_count
+    /// Start a self test. Self tests are numbered starting at 1,
+    /// [RDM_SELF_TEST_ALL](crate::consts::RDM_SELF_TEST_ALL) performs all self tests.
+    pub fn rdm_perform_self_test(
+        &mut self,
+        uid: PackageAddress,
+        self_test: u8,
+    ) -> Result<(), RdmResponseError<D::DriverError>> {
+        self.rdm_set(RdmRequest {
+            destination_uid: uid,
+            parameter_id: pids::PERFORM_SELFTEST,
+            data: DataPack::from_slice(&[self_test]).unwrap(),
+        })?;
+
+        Ok(())
     }
-}
 
-fn deserialize_discovery_mute_response<D: RdmControllerDriver>(
-    response: &RdmResponse,
-) -> Result<Option<DiscoveryMuteResponse>, RdmResponseError<D::DriverError>> {
-    Ok(match response {
-        RdmResponse::Response(response_info) => Some(
-            DiscoveryMuteResponse::deserialize(&response_info.data)
-                .map_err(|_| RdmResponseError::ParameterDataNotDeserializable)?,
-        ),
-        RdmResponse::RequestWasBroadcast => None,
-        RdmResponse::IncompleteResponse(_) => {
-            return Err(RdmResponseError::ParameterDataNotDeserializable)
-        },
-    })
+    /// Stop all running self tests.
+    pub fn rdm_stop_self_test(
+        &mut self,
+        uid: PackageAddress,
+    ) -> Result<(), RdmResponseError<D::DriverError>> {
+        self.rdm_perform_self_test(uid, RDM_SELF_TEST_OFF)
+    }
+
+    /// Get the description of a self test.
+    pub fn rdm_get_self_test_description(
+        &mut self,
+        uid: impl Into<UnicastAddress>,
+        self_test: u8,
+    ) -> Result<SelfTestDescription, RdmResponseError<D::DriverError>> {
+        let response = match self.rdm_get(RdmRequest {
+            destination_uid: PackageAddress::from(uid.into()),
+            parameter_id: pids::SELF_TEST_DESCRIPTION,
+            data: DataPack::from_slice(&[self_test]).unwrap(),
+        })? {
+            RdmResponse::Response(response) => response,
+            _ => return Err(RdmResponseError::ParameterDataNotDeserializable),
+        };
+
+        Ok(SelfTestDescription::deserialize(&response.data)?)
+    }
+
+    /// Get the communication error counters of the device.
+    pub fn rdm_get_comms_status(
+        &mut self,
+        uid: impl Into<UnicastAddress>,
+    ) -> Result<CommsStatus, RdmResponseError<D::DriverError>> {
+        let response = match self.rdm_get(RdmRequest::empty(
+            PackageAddress::from(uid.into()),
+            pids::COMMS_STATUS,
+        ))? {
+            RdmResponse::Response(response) => response,
+            _ => return Err(RdmResponseError::ParameterDataNotDeserializable),
+        };
+
+        Ok(CommsStatus::deserialize(&response.data)?)
+    }
+
+    /// Reset the communication error counters of the device.
+    pub fn rdm_clear_comms_status(
+        &mut self,
+        uid: PackageAddress,
+    ) -> Result<(), RdmResponseError<D::DriverError>> {
+        self.rdm_set(RdmRequest::empty(uid, pids::COMMS_STATUS))?;
+
+        Ok(())
+    }
+
+    /// Get the date and time of the real time clock of the device.
+    pub fn rdm_get_real_time_clock(
+        &mut self,
+        uid: impl Into<UnicastAddress>,
+    ) -> Result<RealTimeClock, RdmResponseError<D::DriverError>> {
+        let response = match self.rdm_get(RdmRequest::empty(
+            PackageAddress::from(uid.into()),
+            pids::REAL_TIME_CLOCK,
+        ))? {
+            RdmResponse::Response(response) => response,
+            _ => return Err(RdmResponseError::ParameterDataNotDeserializable),
+        };
+
+        Ok(RealTimeClock::deserialize(&response.data)?)
+    }
+
+    /// Set the real time clock of the device.
+    /// Devices nack the request with [NackReason::DataOutOfRange] if the date is invalid.
+    pub fn rdm_set_real_time_clock(
+        &mut self,
+        uid: PackageAddress,
+        clock: RealTimeClock,
+    ) -> Result<(), RdmResponseError<D::DriverError>> {
+        self.rdm_set(RdmRequest {
+            destination_uid: uid,
+            parameter_id: pids::REAL_TIME_CLOCK,
+            data: DataPack::from_slice(&clock.serialize()).unwrap(),
+        })?;
+
+        Ok(())
+    }
+
+    /// Get the value of a statistics counter of the device (e.g. the lamp hours).
+    pub fn rdm_get_statistics_counter(
+        &mut self,
+        uid: impl Into<UnicastAddress>,
+        counter: StatisticsCounter,
+    ) -> Result<u32, RdmResponseError<D::DriverError>> {
+        let response = match self.rdm_get(RdmRequest::empty(
+            PackageAddress::from(uid.into()),
+            counter.parameter_id(),
+        ))? {
+            RdmResponse::Response(response) => response,
+            _ => return Err(RdmResponseError::ParameterDataNotDeserializable),
+        };
+
+        Ok(deserialize_statistics_counter(&response.data)?)
+    }
+
+    /// Reset a statistics counter of the device to a value (e.g. after replacing the lamp).
+    /// Devices nack the request with [NackReason::WriteProtect] if the counter is read-only.
+    pub fn rdm_set_statistics_counter(
+        &mut self,
+        uid: PackageAddress,
+        counter: StatisticsCounter,
+        value: u32,
+    ) -> Result<(), RdmResponseError<D::DriverError>> {
+        self.rdm_set(RdmRequest {
+            destination_uid: uid,
+            parameter_id: counter.parameter_id(),
+            data: DataPack::from_slice(&value.to_be_bytes()).unwrap(),
+        })?;
+
+        Ok(())
+    }
+
+    /// Get the scene that is currently played back and its level.
+    pub fn rdm_get_preset_playback(
+        &mut self,
+        uid: impl Into<UnicastAddress>,
+    ) -> Result<PresetPlayback, RdmResponseError<D::DriverError>> {
+        let response = match self.rdm_get(RdmRequest::empty(
+            PackageAddress::from(uid.into()),
+            pids::PRESET_PLAYBACK,
+        ))? {
+            RdmResponse::Response(response) => response,
+            _ => return Err(RdmResponseError::ParameterDataNotDeserializable),
+        };
+
+        Ok(PresetPlayback::deserialize(&response.data)?)
+    }
+
+    /// Start the playback of a scene. See [PresetPlayback] for the special scene values.
+    pub fn rdm_set_preset_playback(
+        &mut self,
+        uid: PackageAddress,
+        preset_playback: PresetPlayback,
+    ) -> Result<(), RdmResponseError<D::DriverError>> {
+        self.rdm_set(RdmRequest {
+            destination_uid: uid,
+            parameter_id: pids::PRESET_PLAYBACK,
+            data: DataPack::from_slice(&preset_playback.serialize()).unwrap(),
+        })?;
+
+        Ok(())
+    }
+
+    /// Get the software version label.
+    pub fn rdm_get_software_version_label(
+        &mut self,
+        uid: impl Into<UnicastAddress>,
+    ) -> Result<heapless::String<32>, RdmResponseError<D::DriverError>> {
+        let response_info = match self.rdm_get(RdmRequest::empty(
+            PackageAddress::from(uid.into()),
+            pids::SOFTWARE_VERSION_LABEL,
+        ))? {
+            RdmResponse::Response(response_info) => response_info,
+            _ => return Err(RdmResponseError::ParameterDataNotDeserializable),
+        };
+
+        Ok(rdm_packages::deserialize_software_version_label(
+            &response_info.data,
+        )?)
+    }
+
+    /// Get the version id of the boot software.
+    pub fn rdm_get_boot_software_version_id(
+        &mut self,
+        uid: impl Into<UnicastAddress>,
+    ) -> Result<u32, RdmResponseError<D::DriverError>> {
+        let response_info = match self.rdm_get(RdmRequest::empty(
+            PackageAddress::from(uid.into()),
+            pids::BOOT_SOFTWARE_VERSION_ID,
+        ))? {
+            RdmResponse::Response(response_info) => response_info,
+            _ => return Err(RdmResponseError::ParameterDataNotDeserializable),
+        };
+
+        Ok(rdm_packages::deserialize_boot_software_version_id(
+            &response_info.data,
+        )?)
+    }
+
+    /// Get the version label of the boot software.
+    pub fn rdm_get_boot_software_version_label(
+        &mut self,
+        uid: impl Into<UnicastAddress>,
+    ) -> Result<heapless::String<32>, RdmResponseError<D::DriverError>> {
+        let response_info = match self.rdm_get(RdmRequest::empty(
+            PackageAddress::from(uid.into()),
+            pids::BOOT_SOFTWARE_VERSION_LABEL,
+        ))? {
+            RdmResponse::Response(response_info) => response_info,
+            _ => return Err(RdmResponseError::ParameterDataNotDeserializable),
+        };
+
+        Ok(rdm_packages::deserialize_boot_software_version_label(
+            &response_info.data,
+        )?)
+    }
+
+    /// Get the description of the device model.
+    pub fn rdm_get_device_model_description(
+        &mut self,
+        uid: impl Into<UnicastAddress>,
+    ) -> Result<heapless::String<32>, RdmResponseError<D::DriverError>> {
+        let response_info = match self.rdm_get(RdmRequest::empty(
+            PackageAddress::from(uid.into()),
+            pids::DEVICE_MODEL_DESCRIPTION,
+        ))? {
+            RdmResponse::Response(response_info) => response_info,
+            _ => return Err(RdmResponseError::ParameterDataNotDeserializable),
+        };
+
+        Ok(rdm_packages::deserialize_device_model_description(
+            &response_info.data,
+        )?)
+    }
+
+    /// Get the name of the manufacturer.
+    pub fn rdm_get_manufacturer_label(
+        &mut self,
+        uid: impl Into<UnicastAddress>,
+    ) -> Result<heapless::String<32>, RdmResponseError<D::DriverError>> {
+        let response_info = match self.rdm_get(RdmRequest::empty(
+            PackageAddress::from(uid.into()),
+            pids::MANUFACTURER_LABEL,
+        ))? {
+            RdmResponse::Response(response_info) => response_info,
+            _ => return Err(RdmResponseError::ParameterDataNotDeserializable),
+        };
+
+        Ok(rdm_packages::deserialize_manufacturer_label(
+            &response_info.data,
+        )?)
+    }
+
+    /// Get the device label.
+    pub fn rdm_get_device_label(
+        &mut self,
+        uid: impl Into<UnicastAddress>,
+    ) -> Result<heapless::String<32>, RdmResponseError<D::DriverError>> {
+        let response_info = match self.rdm_get(RdmRequest::empty(
+            PackageAddress::from(uid.into()),
+            pids::DEVICE_LABEL,
+        ))? {
+            RdmResponse::Response(response_info) => response_info,
+            _ => return Err(RdmResponseError::ParameterDataNotDeserializable),
+        };
+
+        Ok(rdm_packages::deserialize_device_label(&response_info.data)?)
+    }
+
+    /// Set the device label.
+    ///
+    /// The label has to be ASCII and can't be longer than 32 bytes, otherwise
+    /// [RdmResponseError::InvalidRequestData] is returned without sending the request.
+    pub fn rdm_set_device_label(
+        &mut self,
+        uid: PackageAddress,
+        device_label: &str,
+    ) -> Result<(), RdmResponseError<D::DriverError>> {
+        if device_label.len() > 32 || !device_label.is_ascii() {
+            return Err(RdmResponseError::InvalidRequestData);
+        }
+
+        self.rdm_set(RdmRequest {
+            destination_uid: uid,
+            parameter_id: pids::DEVICE_LABEL,
+            data: DataPack::from_slice(device_label.as_bytes()).unwrap(),
+        })?;
+
+        Ok(())
+    }
+
+    /// Get whether the device is at its factory defaults.
+    ///
+    /// A device reports true after [DmxController::rdm_set_factory_defaults] until any
+    /// of its parameters has been changed.
+    pub fn rdm_get_factory_defaults(
+        &mut self,
+        uid: impl Into<UnicastAddress>,
+    ) -> Result<bool, RdmResponseError<D::DriverError>> {
+        let response_info = match self.rdm_get(RdmRequest::empty(
+            PackageAddress::from(uid.into()),
+            pids::FACTORY_DEFAULTS,
+        ))? {
+            RdmResponse::Response(response_info) => response_info,
+            _ => return Err(RdmResponseError::ParameterDataNotDeserializable),
+        };
+
+        Ok(rdm_packages::deserialize_factory_defaults(
+            &response_info.data,
+        )?)
+    }
+
+    /// Reset the device to its factory defaults.
+    pub fn rdm_set_factory_defaults(
+        &mut self,
+        uid: PackageAddress,
+    ) -> Result<(), RdmResponseError<D::DriverError>> {
+        self.rdm_set(RdmRequest::empty(uid, pids::FACTORY_DEFAULTS))?;
+
+        Ok(())
+    }
+
+    /// Get the current start address of the dmx slave.
+    pub fn rdm_get_dmx_start_address(
+        &mut self,
+        uid: impl Into<UnicastAddress>,
+    ) -> Result<DmxStartAddress, RdmResponseError<D::DriverError>> {
+        let response = match self.rdm_get(RdmRequest::empty(
+            PackageAddress::from(uid.into()),
+            pids::DMX_START_ADDRESS,
+        ))? {
+            RdmResponse::Response(response) => response,
+            _ => return Err(RdmResponseError::ParameterDataNotDeserializable),
+        };
+
+        Ok(DmxStartAddress::deserialize(&response.data)?)
+    }
+
+    /// Set the current start address of the dmx slave. The address has to be between 1 and 512.
+    pub fn rdm_set_dmx_start_address(
+        &mut self,
+        uid: PackageAddress,
+        start_address: u16,
+    ) -> Result<(), RdmResponseError<D::DriverError>> {
+        assert!(
+            (1..=512).contains(&start_address),
+            "The requested start address is not valid."
+        );
+
+        self.rdm_set(RdmRequest {
+            destination_uid: uid,
+            parameter_id: pids::DMX_START_ADDRESS,
+            data: DataPack::from_slice(&start_address.to_be_bytes()).unwrap(),
+        })?;
+
+        Ok(())
+    }
+
+    /// Get the total footprint and the base dmx address of all sub-devices of the device.
+    /// The base address is [DmxBlockAddress::NOT_CONTIGUOUS] if the sub-devices aren't
+    /// addressed contiguously.
+    pub fn rdm_get_dmx_block_address(
+        &mut self,
+        uid: impl Into<UnicastAddress>,
+    ) -> Result<DmxBlockAddress, RdmResponseError<D::DriverError>> {
+        let response = match self.rdm_get(RdmRequest::empty(
+            PackageAddress::from(uid.into()),
+            pids::DMX_BLOCK_ADDRESS,
+        ))? {
+            RdmResponse::Response(response) => response,
+            _ => return Err(RdmResponseError::ParameterDataNotDeserializable),
+        };
+
+        Ok(DmxBlockAddress::deserialize(&response.data)?)
+    }
+
+    /// Assign consecutive dmx start addresses to all sub-devices of the device,
+    /// starting at base_address.
+    pub fn rdm_set_dmx_block_address(
+        &mut self,
+        uid: PackageAddress,
+        base_address: u16,
+    ) -> Result<(), RdmResponseError<D::DriverError>> {
+        assert!(
+            (1..=512).contains(&base_address),
+            "The requested base address is not valid."
+        );
+
+        self.rdm_set(RdmRequest {
+            destination_uid: uid,
+            parameter_id: pids::DMX_BLOCK_ADDRESS,
+            data: DataPack::from_slice(&base_address.to_be_bytes()).unwrap(),
+        })?;
+
+        Ok(())
+    }
+
+    /// Get the current personality and the amount of personalities.
+    pub fn rdm_get_dmx_personality(
+        &mut self,
+        uid: impl Into<UnicastAddress>,
+    ) -> Result<DmxPersonality, RdmResponseError<D::DriverError>> {
+        let response = match self.rdm_get(RdmRequest::empty(
+            PackageAddress::from(uid.into()),
+            pids::DMX_PERSONALITY,
+        ))? {
+            RdmResponse::Response(response) => response,
+            _ => return Err(RdmResponseError::ParameterDataNotDeserializable),
+        };
+
+        Ok(DmxPersonality::deserialize(&response.data)?)
+    }
+
+    /// Set the personality. Personalities are numbered starting at 1.
+    pub fn rdm_set_dmx_personality(
+        &mut self,
+        uid: PackageAddress,
+        personality: u8,
+    ) -> Result<(), RdmResponseError<D::DriverError>> {
+        self.rdm_set(RdmRequest {
+            destination_uid: uid,
+            parameter_id: pids::DMX_PERSONALITY,
+            data: DataPack::from_slice(&[personality]).unwrap(),
+        })?;
+
+        Ok(())
+    }
+
+    /// Get the description and the dmx footprint of a personality.
+    pub fn rdm_get_dmx_personality_description(
+        &mut self,
+        uid: impl Into<UnicastAddress>,
+        personality: u8,
+    ) -> Result<DmxPersonalityDescription, RdmResponseError<D::DriverError>> {
+        let response = match self.rdm_get(RdmRequest {
+            destination_uid: PackageAddress::from(uid.into()),
+            parameter_id: pids::DMX_PERSONALITY_DESCRIPTION,
+            data: DataPack::from_slice(&[personality]).unwrap(),
+        })? {
+            RdmResponse::Response(response) => response,
+            _ => return Err(RdmResponseError::ParameterDataNotDeserializable),
+        };
+
+        Ok(DmxPersonalityDescription::deserialize(&response.data)?)
+    }
+
+    /// Get the dmx footprint of a personality without switching to it.
+    /// Useful to check if a start address fits before changing the personality.
+    pub fn rdm_get_personality_footprint(
+        &mut self,
+        uid: impl Into<UnicastAddress>,
+        personality: u8,
+    ) -> Result<u16, RdmResponseError<D::DriverError>> {
+        Ok(self
+            .rdm_get_dmx_personality_description(uid, personality)?
+            .dmx_footprint)
+    }
+
+    /// Get the last queued message.
+    ///
+    /// Use [DmxController::rdm_get_last_message_count]
+    /// to receive the message count from the last request.
+    ///
+    /// If no messages are queued this will return the current [StatusMessages].
+    /// You can use the [StatusType] to filter these [StatusMessages].
+    ///
+    /// Note that you can only use [StatusType::StatusAdvisory], [StatusType::StatusWarning],
+    /// [StatusType::StatusError] and [StatusType::StatusGetLastMessage].
+    ///
+    /// If you want to receive the previous response use [StatusType::StatusGetLastMessage].
+    pub fn rdm_get_queued_message(
+        &mut self,
+        uid: impl Into<UnicastAddress>,
+        status_requested: StatusType,
+    ) -> Result<RdmResponsePackage, RdmResponseError<D::DriverError>> {
+        let response = self.rdm_get(RdmRequest {
+            destination_uid: PackageAddress::from(uid.into()),
+            parameter_id: pids::QUEUED_MESSAGE,
+            data: DataPack::from_slice(&[status_requested as u8]).unwrap(),
+        })?;
+
+        match response {
+            RdmResponse::Response(response_info) => {
+                Ok(RdmResponsePackage::from_response_info(response_info)?)
+            },
+            _ => Err(RdmResponseError::ParameterDataNotDeserializable),
+        }
+    }
+
+    /// Get status messages. Filter severity by using the [StatusType::StatusAdvisory], [StatusType::StatusWarning]
+    /// and [StatusType::StatusError].
+    ///
+    /// If you want to receive the previously set of status messages again use [StatusType::StatusGetLastMessage].
+    /// To perform an availability test use [StatusType::StatusNone].
+    ///
+    /// The requested [StatusType] is the lowest severity that will be reported. The `*Cleared`
+    /// variants can't be requested. They are reported alongside the messages of the same severity
+    /// and signal that a previously reported condition has been resolved. Use
+    /// [DmxController::rdm_get_active_errors] or [DmxController::rdm_get_all_status_events]
+    /// if you don't want to deal with this.
+    ///
+    /// If this parameter message is properly implemented on the slave you
+    /// should never get a [OverflowMessageResp::Incomplete] back, since STATUS_MESSAGE uses
+    /// its own queuing.
+    pub fn rdm_get_status_messages(
+        &mut self,
+        uid: impl Into<UnicastAddress>,
+        status_requested: StatusType,
+    ) -> Result<OverflowMessageResp<StatusMessages>, RdmResponseError<D::DriverError>> {
+        let response = self.rdm_get(RdmRequest {
+            destination_uid: PackageAddress::from(uid.into()),
+            parameter_id: pids::STATUS_MESSAGES,
+            data: DataPack::from_slice(&[status_requested as u8]).unwrap(),
+        })?;
+
+        match response {
+            RdmResponse::Response(response_info) => Ok(OverflowMessageResp::Complete(
+                deserialize_status_messages(&response_info.data)?,
+            )),
+            RdmResponse::IncompleteResponse(response_info) => Ok(OverflowMessageResp::Incomplete(
+                deserialize_status_messages(&response_info.data)?,
+            )),
+            _ => Err(RdmResponseError::ParameterDataNotDeserializable),
+        }
+    }
+
+    /// Get the errors that are currently active.
+    ///
+    /// This requests [StatusType::StatusError] and removes the [StatusType::StatusErrorCleared]
+    /// messages from the response since they refer to errors that aren't active anymore.
+    pub fn rdm_get_active_errors(
+        &mut self,
+        uid: impl Into<UnicastAddress>,
+    ) -> Result<OverflowMessageResp<StatusMessages>, RdmResponseError<D::DriverError>> {
+        let mut response = self.rdm_get_status_messages(uid, StatusType::StatusError)?;
+
+        match response {
+            OverflowMessageResp::Complete(ref mut status_messages)
+            | OverflowMessageResp::Incomplete(ref mut status_messages) => {
+                status_messages.retain(|status_message| !status_message.status_type.is_cleared())
+            },
+        }
+
+        Ok(response)
+    }
+
+    /// Get the status messages of all severities including the ones that have been cleared.
+    ///
+    /// This requests [StatusType::StatusAdvisory], the lowest severity, so advisories, warnings
+    /// and errors are reported as well as their `*Cleared` counterparts.
+    /// Use [StatusType::is_cleared] to tell them apart.
+    pub fn rdm_get_all_status_events(
+        &mut self,
+        uid: impl Into<UnicastAddress>,
+    ) -> Result<OverflowMessageResp<StatusMessages>, RdmResponseError<D::DriverError>> {
+        self.rdm_get_status_messages(uid, StatusType::StatusAdvisory)
+    }
+
+    /// Get the parameter ids that are supported by the responder.
+    ///
+    /// <div class="warning">Note that this only includes optional parameter ids that are not
+    /// required to be compliant with ANSI E1.20.</div>
+    pub fn rdm_get_supported_parameters(
+        &mut self,
+        uid: impl Into<UnicastAddress>,
+    ) -> Result<OverflowMessageResp<SupportedParameters>, RdmResponseError<D::DriverError>> {
+        let response = self.rdm_get(RdmRequest::empty(
+            PackageAddress::from(uid.into()),
+            pids::SUPPORTED_PARAMETERS,
+        ))?;
+
+        match response {
+            RdmResponse::Response(response_info) => Ok(OverflowMessageResp::Complete(
+                deserialize_supported_parameters(&response_info.data)?,
+            )),
+            RdmResponse::IncompleteResponse(response_info) => Ok(OverflowMessageResp::Incomplete(
+                deserialize_supported_parameters(&response_info.data)?,
+            )),
+            _ => Err(RdmResponseError::ParameterDataNotDeserializable),
+        }
+    }
+
+    /// Get the power-up default values of the dmx slots.
+    ///
+    /// If the response is [OverflowMessageResp::Incomplete], request it again to receive the
+    /// remaining slots.
+    pub fn rdm_get_default_slot_values(
+        &mut self,
+        uid: impl Into<UnicastAddress>,
+    ) -> Result<OverflowMessageResp<DefaultSlotValues>, RdmResponseError<D::DriverError>> {
+        let response = self.rdm_get(RdmRequest::empty(
+            PackageAddress::from(uid.into()),
+            pids::DEFAULT_SLOT_VALUE,
+        ))?;
+
+        match response {
+            RdmResponse::Response(response_info) => Ok(OverflowMessageResp::Complete(
+                deserialize_default_slot_values(&response_info.data)?,
+            )),
+            RdmResponse::IncompleteResponse(response_info) => Ok(OverflowMessageResp::Incomplete(
+                deserialize_default_slot_values(&response_info.data)?,
+            )),
+            _ => Err(RdmResponseError::ParameterDataNotDeserializable),
+        }
+    }
+
+    /// Get the description of a manufacturer specific pid.
+    pub fn rdm_get_parameter_description(
+        &mut self,
+        uid: impl Into<UnicastAddress>,
+        pid: u16,
+    ) -> Result<ParameterDescription, RdmResponseError<D::DriverError>> {
+        let response = self.rdm_get(RdmRequest {
+            destination_uid: PackageAddress::from(uid.into()),
+            parameter_id: pids::PARAMETER_DESCRIPTION,
+            data: DataPack::from_slice(&pid.to_be_bytes()).unwrap(),
+        })?;
+
+        match response {
+            RdmResponse::Response(response_info) => {
+                Ok(ParameterDescription::deserialize(&response_info.data)?)
+            },
+            _ => Err(RdmResponseError::ParameterDataNotDeserializable),
+        }
+    }
+
+    /// Get the definition of a sensor. The amount of sensors is reported in the [DeviceInfo].
+    pub fn rdm_get_sensor_definition(
+        &mut self,
+        uid: impl Into<UnicastAddress>,
+        sensor_number: u8,
+    ) -> Result<SensorDefinition, RdmResponseError<D::DriverError>> {
+        let response = self.rdm_get(RdmRequest {
+            destination_uid: PackageAddress::from(uid.into()),
+            parameter_id: pids::SENSOR_DEFINITION,
+            data: DataPack::from_slice(&[sensor_number]).unwrap(),
+        })?;
+
+        match response {
+            RdmResponse::Response(response_info) => {
+                Ok(SensorDefinition::deserialize(&response_info.data)?)
+            },
+            _ => Err(RdmResponseError::ParameterDataNotDeserializable),
+        }
+    }
+
+    /// Get the current value of a sensor.
+    pub fn rdm_get_sensor_value(
+        &mut self,
+        uid: impl Into<UnicastAddress>,
+        sensor_number: u8,
+    ) -> Result<SensorValue, RdmResponseError<D::DriverError>> {
+        let response = self.rdm_get(RdmRequest {
+            destination_uid: PackageAddress::from(uid.into()),
+            parameter_id: pids::SENSOR_VALUE,
+            data: DataPack::from_slice(&[sensor_number]).unwrap(),
+        })?;
+
+        match response {
+            RdmResponse::Response(response_info) => {
+                Ok(SensorValue::deserialize(&response_info.data)?)
+            },
+            _ => Err(RdmResponseError::ParameterDataNotDeserializable),
+        }
+    }
+
+    /// Reset the lowest, highest and recorded values of a sensor.
+    /// Use [RDM_ALL_SENSORS](crate::consts::RDM_ALL_SENSORS) to reset all sensors.
+    pub fn rdm_reset_sensor_value(
+        &mut self,
+        uid: PackageAddress,
+        sensor_number: u8,
+    ) -> Result<(), RdmResponseError<D::DriverError>> {
+        self.rdm_set(RdmRequest {
+            destination_uid: uid,
+            parameter_id: pids::SENSOR_VALUE,
+            data: DataPack::from_slice(&[sensor_number]).unwrap(),
+        })?;
+
+        Ok(())
+    }
+
+    /// Record the present value of a sensor.
+    /// Use [RDM_ALL_SENSORS](crate::consts::RDM_ALL_SENSORS) to record all sensors.
+    pub fn rdm_record_sensors(
+        &mut self,
+        uid: PackageAddress,
+        sensor_number: u8,
+    ) -> Result<(), RdmResponseError<D::DriverError>> {
+        self.rdm_set(RdmRequest {
+            destination_uid: uid,
+            parameter_id: pids::RECORD_SENSORS,
+            data: DataPack::from_slice(&[sensor_number]).unwrap(),
+        })?;
+
+        Ok(())
+    }
+
+    /// Get the device info from the rdm device.
+    pub fn rdm_get_device_info(
+        &mut self,
+        uid: impl Into<UnicastAddress>,
+    ) -> Result<DeviceInfo, RdmResponseError<D::DriverError>> {
+        let response = self.rdm_get(RdmRequest::empty(
+            PackageAddress::from(uid.into()),
+            pids::DEVICE_INFO,
+        ))?;
+        match response {
+            RdmResponse::Response(response_info) => {
+                Ok(DeviceInfo::deserialize(&response_info.data)?)
+            },
+            _ => Err(RdmResponseError::ParameterDataNotDeserializable),
+        }
+    }
+
+    /// Sends a get request without parameter data and decodes the response according to the pid.
+    ///
+    /// Useful for generically displaying the values of the pids in the supported parameters.
+    /// Pids that aren't decoded by [RdmResponsePackage::from_response_info] are returned as
+    /// [RdmResponsePackage::Custom]. Pids that require parameter data in the get request, like
+    /// SENSOR_VALUE, have to be requested using their dedicated method.
+    pub fn rdm_get_typed(
+        &mut self,
+        uid: impl Into<UnicastAddress>,
+        parameter_id: u16,
+    ) -> Result<OverflowMessageResp<RdmResponsePackage>, RdmResponseError<D::DriverError>> {
+        let response = self.rdm_get(RdmRequest::empty(
+            PackageAddress::from(uid.into()),
+            parameter_id,
+        ))?;
+
+        match response {
+            RdmResponse::Response(response_info) => Ok(OverflowMessageResp::Complete(
+                RdmResponsePackage::from_response_info(response_info)?,
+            )),
+            RdmResponse::IncompleteResponse(response_info) => Ok(OverflowMessageResp::Incomplete(
+                RdmResponsePackage::from_response_info(response_info)?,
+            )),
+            RdmResponse::RequestWasBroadcast => {
+                Err(RdmResponseError::ParameterDataNotDeserializable)
+            },
+        }
+    }
+
+    /// Sends a get request and repeats it until the complete parameter data of an overflowing
+    /// response has been received. The parameter data of all responses is concatenated into a
+    /// buffer that holds up to N bytes.
+    ///
+    /// The segment count of the result can be used to spot devices that split their responses
+    /// into an unusual amount of parts.
+    pub fn rdm_get_reassembled<const N: usize>(
+        &mut self,
+        request: RdmRequest,
+    ) -> Result<ReassembledResponse<N>, RdmResponseError<D::DriverError>> {
+        let mut data = heapless::Vec::new();
+        let mut segment_count = 0;
+
+        loop {
+            let (response_info, complete) = match self.rdm_get(request.clone())? {
+                RdmResponse::Response(response_info) => (response_info, true),
+                RdmResponse::IncompleteResponse(response_info) => (response_info, false),
+                RdmResponse::RequestWasBroadcast => {
+                    return Err(RdmResponseError::ParameterDataNotDeserializable)
+                },
+            };
+
+            segment_count += 1;
+            data.extend_from_slice(&response_info.data)
+                .or(Err(RdmResponseError::ResponseTooLong))?;
+
+            if complete {
+                return Ok(ReassembledResponse {
+                    parameter_id: response_info.parameter_id,
+                    data,
+                    segment_count,
+                });
+            }
+        }
+    }
+
+    /// Patches a device by setting its personality and its start address.
+    ///
+    /// The personality is set first, since it can change the dmx footprint. Afterward the
+    /// footprint is read again and the start address is only set if the whole footprint fits
+    /// into the universe. Otherwise [RdmResponseError::InvalidRequestData] is returned.
+    pub fn rdm_patch(
+        &mut self,
+        uid: impl Into<UnicastAddress>,
+        personality: Option<u8>,
+        start_address: Option<u16>,
+    ) -> Result<(), RdmResponseError<D::DriverError>> {
+        let uid = uid.into();
+
+        if let Some(start_address) = start_address {
+            if !(1..=512).contains(&start_address) {
+                return Err(RdmResponseError::InvalidRequestData);
+            }
+        }
+
+        if let Some(personality) = personality {
+            self.rdm_set_dmx_personality(PackageAddress::from(uid), personality)?;
+        }
+
+        if let Some(start_address) = start_address {
+            let dmx_footprint = self.rdm_get_device_info(uid)?.dmx_footprint;
+            if start_address as u32 + dmx_footprint as u32 > 513 {
+                return Err(RdmResponseError::InvalidRequestData);
+            }
+
+            self.rdm_set_dmx_start_address(PackageAddress::from(uid), start_address)?;
+        }
+
+        Ok(())
+    }
+
+    /// Checks if a device can be patched to a start address without changing it.
+    ///
+    /// Reads the device info and fails if the device has no dmx footprint or if the
+    /// footprint doesn't fit into the universe at the start address.
+    pub fn rdm_validate_patch(
+        &mut self,
+        uid: impl Into<UnicastAddress>,
+        start_address: u16,
+    ) -> Result<(), PatchError<D::DriverError>> {
+        if !(1..=512).contains(&start_address) {
+            return Err(PatchError::InvalidStartAddress);
+        }
+
+        let device_info = self.rdm_get_device_info(uid)?;
+        if device_info.dmx_start_address == DmxStartAddress::NoAddress
+            || device_info.dmx_footprint == 0
+        {
+            return Err(PatchError::NoFootprint);
+        }
+
+        if start_address as u32 + device_info.dmx_footprint as u32 - 1 > 512 {
+            return Err(PatchError::FootprintExceedsUniverse);
+        }
+
+        Ok(())
+    }
+
+    /// Get all queued messages of a responder.
+    ///
+    /// Requests QUEUED_MESSAGE with [StatusType::StatusError] until the message count of the
+    /// responder drops to zero and returns the received responses in order.
+    /// Since a responder can queue new messages while it is being drained, no more than `N`
+    /// messages are requested.
+    pub fn rdm_drain_queued_messages<const N: usize>(
+        &mut self,
+        uid: impl Into<UnicastAddress>,
+    ) -> Result<heapless::Vec<RdmResponsePackage, N>, RdmResponseError<D::DriverError>> {
+        let uid = uid.into();
+        let mut messages = heapless::Vec::new();
+
+        while !messages.is_full() {
+            let message = self.rdm_get_queued_message(uid, StatusType::StatusError)?;
+            // can't fail since the vector isn't full
+            let _ = messages.push(message);
+
+            if self.rdm_get_last_message_count() == 0 {
+                break;
+            }
+        }
+
+        Ok(messages)
+    }
+
+    /// Returns the message count that was received on the last request using this instance.
+    pub fn rdm_get_last_message_count(&self) -> u8 {
+        self.last_message_count
+    }
+}
+
+fn deserialize_discovery_mute_response<D: RdmControllerDriver>(
+    response: &RdmResponse,
+) -> Result<Option<DiscoveryMuteResponse>, RdmResponseError<D::DriverError>> {
+    Ok(match response {
+        RdmResponse::Response(response_info) => Some(
+            DiscoveryMuteResponse::deserialize(&response_info.data)
+                .map_err(|_| RdmResponseError::ParameterDataNotDeserializable)?,
+        ),
+        RdmResponse::RequestWasBroadcast => None,
+        RdmResponse::IncompleteResponse(_) => {
+            return Err(RdmResponseError::ParameterDataNotDeserializable)
+        },
+    })
+}
+
+#[cfg(test)]
+#[cfg_attr(not(feature = "std"), allow(unused_imports))]
+mod tests {
+    use crate::command_class::RequestCommandClass;
+    use crate::consts::RDM_SELF_TEST_OFF;
+    use crate::dmx_controller::{
+        DmxController, DmxControllerConfig, PacketDirection, ParameterDataTooLongError, PatchError,
+        RdmRequest, RdmResponse, RdmResponseError, ReassembledResponse,
+    };
+    use crate::dmx_driver::{
+        ControllerDriverErrorDef, DiscoveryOption, DmxControllerDriver, DmxError,
+        RdmControllerDriver,
+    };
+    use crate::dmx_uart_driver::{DmxRespUartDriver, DmxUartDriver, DmxUartDriverError};
+    use crate::dmx_universe::DmxUniverse;
+    use crate::pids;
+    use crate::rdm_data::{RdmData, RdmRequestData, RdmResponseData};
+    use crate::rdm_packages::{deserialize_supported_parameters, RdmResponsePackage};
+    use crate::rdm_responder::{
+        QueuedMessage, RdmAnswer, RdmResponderConfig, RdmResponderHandlerFunc,
+        RdmResponderPackageHandler,
+    };
+    use crate::rdm_types::{
+        Curve, CurveDescription, DefaultSlotValue, DeviceInfo, DmxBlockAddress,
+        DmxPersonalityDescription, DmxStartAddress, IdentifyMode, OverflowMessageResp,
+        RealTimeClock, ResetType, SelfTestDescription, StatisticsCounter, StatusMessage,
+        StatusType,
+    };
+    use crate::types::{DataPack, NackReason, ResponseType};
+    use crate::unique_identifier::{PackageAddress, UniqueIdentifier};
+    use crate::utils::run_full_discovery;
+    #[cfg(feature = "std")]
+    use std::sync::{Arc, Mutex};
+
+    #[cfg(feature = "std")]
+    const RESPONDER_UID: UniqueIdentifier = match UniqueIdentifier::new(0x7FF0, 1) {
+        Ok(uid) => uid,
+        Err(_) => panic!(),
+    };
+
+    /// Answers every request with the configured response type and parameter data.
+    #[cfg(feature = "std")]
+    struct AckDriver {
+        requests: Vec<RdmRequestData>,
+        response_type: ResponseType,
+        response_data: DataPack,
+        timeouts: usize,
+        discovery_options: Vec<DiscoveryOption>,
+        /// The amount of sent requests at the time of the last flush.
+        flushed_requests: Option<usize>,
+    }
+
+    #[cfg(feature = "std")]
+    impl AckDriver {
+        fn new(response_data: &[u8]) -> Self {
+            Self {
+                requests: Vec::new(),
+                response_type: ResponseType::ResponseTypeAck,
+                response_data: DataPack::from_slice(response_data).unwrap(),
+                timeouts: 0,
+                discovery_options: Vec::new(),
+                flushed_requests: None,
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl ControllerDriverErrorDef for AckDriver {
+        type DriverError = ();
+    }
+
+    #[cfg(feature = "std")]
+    impl RdmControllerDriver for AckDriver {
+        fn send_rdm(&mut self, package: RdmData) -> Result<(), DmxError<()>> {
+            match package {
+                RdmData::Request(request) => self.requests.push(request),
+                RdmData::Response(_) => panic!("controller sent a response"),
+            }
+
+            Ok(())
+        }
+
+        fn receive_rdm(&mut self) -> Result<RdmData, DmxError<()>> {
+            if self.timeouts > 0 {
+                self.timeouts -= 1;
+                return Err(DmxError::TimeoutError);
+            }
+
+            let request = self.requests.last().ok_or(DmxError::TimeoutError)?;
+
+            Ok(RdmData::Response(
+                request
+                    .build_response(self.response_type, self.response_data.clone(), 0)
+                    .unwrap(),
+            ))
+        }
+
+        fn receive_rdm_discovery_response(&mut self) -> Result<DiscoveryOption, DmxError<()>> {
+            if self.discovery_options.is_empty() {
+                return Ok(DiscoveryOption::NoDevice);
+            }
+
+            Ok(self.discovery_options.remove(0))
+        }
+
+        fn send_rdm_discovery_response(&mut self, _: UniqueIdentifier) -> Result<(), DmxError<()>> {
+            Ok(())
+        }
+
+        fn flush(&mut self) -> Result<(), DmxError<()>> {
+            self.flushed_requests = Some(self.requests.len());
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn serialize_status_messages(status_types: &[StatusType]) -> Vec<u8> {
+        status_types
+            .iter()
+            .flat_map(|status_type| {
+                StatusMessage {
+                    sub_device_id: 0,
+                    status_type: *status_type,
+                    status_message_id: 0x0001,
+                    data_value_1: 0,
+                    data_value_2: 0,
+                }
+                .serialize()
+            })
+            .collect()
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_status_message_helpers_request_status_type() {
+        let response_data =
+            serialize_status_messages(&[StatusType::StatusError, StatusType::StatusErrorCleared]);
+        let mut controller = DmxController::new(
+            AckDriver::new(&response_data),
+            &DmxControllerConfig::default(),
+        );
+
+        let active_errors = match controller.rdm_get_active_errors(RESPONDER_UID).unwrap() {
+            OverflowMessageResp::Complete(status_messages) => status_messages,
+            OverflowMessageResp::Incomplete(_) => panic!("expected complete response"),
+        };
+        assert_eq!(active_errors.len(), 1);
+        assert_eq!(active_errors[0].status_type, StatusType::StatusError);
+
+        let all_events = match controller.rdm_get_all_status_events(RESPONDER_UID).unwrap() {
+            OverflowMessageResp::Complete(status_messages) => status_messages,
+            OverflowMessageResp::Incomplete(_) => panic!("expected complete response"),
+        };
+        assert_eq!(all_events.len(), 2);
+
+        let requests = &controller.get_driver().requests;
+        assert_eq!(
+            &requests[0].parameter_data[..],
+            &[StatusType::StatusError as u8]
+        );
+        assert_eq!(
+            &requests[1].parameter_data[..],
+            &[StatusType::StatusAdvisory as u8]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_set_overflow_is_unexpected() {
+        let mut driver = AckDriver::new(&[]);
+        driver.response_type = ResponseType::ResponseTypeAckOverflow;
+        let mut controller = DmxController::new(driver, &DmxControllerConfig::default());
+
+        match controller.rdm_set_identify(PackageAddress::Device(RESPONDER_UID), true) {
+            Err(RdmResponseError::UnexpectedOverflow) => {},
+            result => panic!("expected unexpected overflow error, got {result:?}"),
+        }
+
+        match controller.rdm_get(RdmRequest::empty(
+            PackageAddress::Device(RESPONDER_UID),
+            pids::IDENTIFY_DEVICE,
+        )) {
+            Ok(RdmResponse::IncompleteResponse(_)) => {},
+            result => panic!("expected incomplete response, got {result:?}"),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_request_retries() {
+        let mut driver = AckDriver::new(&[0x01]);
+        driver.timeouts = 2;
+        let mut controller = DmxController::new(
+            driver,
+            &DmxControllerConfig {
+                request_retries: 2,
+                ..Default::default()
+            },
+        );
+
+        assert!(controller.rdm_get_identify(RESPONDER_UID).unwrap());
+
+        let transaction_numbers: Vec<u8> = controller
+            .get_driver()
+            .requests
+            .iter()
+            .map(|request| request.transaction_number)
+            .collect();
+        assert_eq!(transaction_numbers, [1, 2, 3]);
+
+        controller.get_driver().timeouts = 2;
+        match controller.rdm_request_with_retries(
+            RequestCommandClass::GetCommand,
+            RdmRequest::empty(PackageAddress::Device(RESPONDER_UID), pids::IDENTIFY_DEVICE),
+            1,
+        ) {
+            Err(RdmResponseError::DmxError(DmxError::TimeoutError)) => {},
+            result => panic!("expected timeout error, got {result:?}"),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_reset_device() {
+        let mut driver = AckDriver::new(&[]);
+        driver.timeouts = 1;
+        let mut controller = DmxController::new(
+            driver,
+            &DmxControllerConfig {
+                request_retries: 2,
+                ..Default::default()
+            },
+        );
+
+        controller
+            .rdm_reset_device(PackageAddress::Device(RESPONDER_UID), ResetType::Cold)
+            .unwrap();
+        assert_eq!(controller.get_driver().requests.len(), 1);
+        assert_eq!(
+            &controller.get_driver().requests[0].parameter_data[..],
+            &[0xFF]
+        );
+
+        controller.get_driver().timeouts = 1;
+        match controller.rdm_reset_device(PackageAddress::Device(RESPONDER_UID), ResetType::Warm) {
+            Err(RdmResponseError::DmxError(DmxError::TimeoutError)) => {},
+            result => panic!("expected timeout error, got {result:?}"),
+        }
+
+        controller
+            .rdm_reset_device(PackageAddress::Device(RESPONDER_UID), ResetType::Warm)
+            .unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_packet_observer() {
+        let observed_packages = Arc::new(Mutex::new(Vec::new()));
+        let mut controller =
+            DmxController::new(AckDriver::new(&[0x01]), &DmxControllerConfig::default());
+
+        let observer_packages = observed_packages.clone();
+        controller.set_packet_observer(move |direction, package| {
+            observer_packages
+                .lock()
+                .unwrap()
+                .push((direction, package.to_vec()));
+        });
+        controller.rdm_get_identify(RESPONDER_UID).unwrap();
+
+        let observed_packages = observed_packages.lock().unwrap();
+        assert_eq!(observed_packages.len(), 2);
+
+        let request = RdmData::Request(controller.get_driver().requests[0].clone());
+        assert_eq!(observed_packages[0].0, PacketDirection::Outgoing);
+        assert_eq!(observed_packages[0].1, &request.serialize()[..]);
+
+        assert_eq!(observed_packages[1].0, PacketDirection::Incoming);
+        match RdmData::deserialize(&observed_packages[1].1).unwrap() {
+            RdmData::Response(response) => {
+                assert_eq!(response.parameter_id, pids::IDENTIFY_DEVICE);
+                assert_eq!(&response.parameter_data[..], &[0x01]);
+            },
+            RdmData::Request(_) => panic!("expected a response"),
+        }
+
+        controller.clear_packet_observer();
+        controller.rdm_get_identify(RESPONDER_UID).unwrap();
+        assert_eq!(observed_packages.len(), 2);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_manufacturer_specific_nack() {
+        let mut driver = AckDriver::new(&0x8001u16.to_be_bytes());
+        driver.response_type = ResponseType::ResponseTypeNackReason;
+        let mut controller = DmxController::new(driver, &DmxControllerConfig::default());
+
+        match controller.rdm_get_identify(RESPONDER_UID) {
+            Err(RdmResponseError::NotAcknowledged(NackReason::ManufacturerSpecific(0x8001))) => {},
+            result => panic!("expected a manufacturer specific nack, got {result:?}"),
+        }
+        assert_eq!(u16::from(NackReason::ManufacturerSpecific(0x8001)), 0x8001);
+
+        // reserved nack reasons can't be decoded
+        let mut driver = AckDriver::new(&0x0100u16.to_be_bytes());
+        driver.response_type = ResponseType::ResponseTypeNackReason;
+        let mut controller = DmxController::new(driver, &DmxControllerConfig::default());
+        assert!(matches!(
+            controller.rdm_get_identify(RESPONDER_UID),
+            Err(RdmResponseError::ErrorNotDeserializable)
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_capture_last_transaction() {
+        let mut controller =
+            DmxController::new(AckDriver::new(&[0x01]), &DmxControllerConfig::default());
+        controller.rdm_get_identify(RESPONDER_UID).unwrap();
+        assert_eq!(controller.last_request_bytes(), None);
+        assert_eq!(controller.last_response_bytes(), None);
+
+        let mut controller = DmxController::new(
+            AckDriver::new(&[0x01]),
+            &DmxControllerConfig {
+                capture_last_transaction: true,
+                ..Default::default()
+            },
+        );
+        controller.rdm_get_identify(RESPONDER_UID).unwrap();
+
+        let request = RdmData::Request(controller.get_driver().requests[0].clone());
+        assert_eq!(
+            controller.last_request_bytes(),
+            Some(&request.serialize()[..])
+        );
+        match RdmData::deserialize(controller.last_response_bytes().unwrap()).unwrap() {
+            RdmData::Response(response) => {
+                assert_eq!(response.parameter_id, pids::IDENTIFY_DEVICE);
+                assert_eq!(&response.parameter_data[..], &[0x01]);
+            },
+            RdmData::Request(_) => panic!("expected a response"),
+        }
+
+        // a broadcast isn't answered, so the previous response is discarded
+        controller
+            .rdm_set_identify(PackageAddress::Broadcast, true)
+            .unwrap();
+        let request = RdmData::Request(controller.get_driver().requests[1].clone());
+        assert_eq!(
+            controller.last_request_bytes(),
+            Some(&request.serialize()[..])
+        );
+        assert_eq!(controller.last_response_bytes(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_discovery_responses_with_errors() {
+        let mut driver = AckDriver::new(&[0x00, 0x00]);
+        driver.discovery_options = vec![DiscoveryOption::FoundWithErrors(RESPONDER_UID)];
+        let mut controller = DmxController::new(driver, &DmxControllerConfig::default());
+        assert_eq!(
+            controller
+                .rdm_discover(0x000000000001, 0xFFFFFFFFFFFE)
+                .unwrap(),
+            DiscoveryOption::Collision
+        );
+
+        let config = DmxControllerConfig {
+            accept_discovery_responses_with_errors: true,
+            ..Default::default()
+        };
+
+        // the device answers the mute, so the uid is valid
+        let mut driver = AckDriver::new(&[0x00, 0x00]);
+        driver.discovery_options = vec![DiscoveryOption::FoundWithErrors(RESPONDER_UID)];
+        let mut controller = DmxController::new(driver, &config);
+        let mut uids = [RESPONDER_UID; 4];
+        assert_eq!(run_full_discovery(&mut controller, &mut uids).unwrap(), 1);
+        assert_eq!(uids[0], RESPONDER_UID);
+        assert_eq!(
+            controller
+                .get_driver()
+                .requests
+                .last()
+                .unwrap()
+                .parameter_id,
+            pids::DISC_MUTE
+        );
+
+        // nobody answers the mute, so the branch gets split like a collision
+        let mut driver = AckDriver::new(&[0x00, 0x00]);
+        driver.discovery_options = vec![DiscoveryOption::FoundWithErrors(RESPONDER_UID)];
+        driver.timeouts = 1;
+        let mut controller = DmxController::new(driver, &config);
+        assert_eq!(run_full_discovery(&mut controller, &mut uids).unwrap(), 0);
+        let branch_count = controller
+            .get_driver()
+            .requests
+            .iter()
+            .filter(|request| request.parameter_id == pids::DISC_UNIQUE_BRANCH)
+            .count();
+        assert_eq!(branch_count, 3);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_discovery_mute_retries() {
+        let count_mutes = |controller: &mut DmxController<AckDriver>| {
+            controller
+                .get_driver()
+                .requests
+                .iter()
+                .filter(|request| request.parameter_id == pids::DISC_MUTE)
+                .count()
+        };
+
+        // without retries a single timeout drops the device
+        let mut driver = AckDriver::new(&[0x00, 0x00]);
+        driver.discovery_options = vec![DiscoveryOption::Found(RESPONDER_UID)];
+        driver.timeouts = 1;
+        let mut controller = DmxController::new(driver, &DmxControllerConfig::default());
+        let mut uids = [RESPONDER_UID; 4];
+        assert_eq!(run_full_discovery(&mut controller, &mut uids).unwrap(), 0);
+        assert_eq!(count_mutes(&mut controller), 1);
+
+        let config = DmxControllerConfig {
+            discovery_mute_retries: 2,
+            ..Default::default()
+        };
+
+        // the mute times out once and succeeds on the retry
+        let mut driver = AckDriver::new(&[0x00, 0x00]);
+        driver.discovery_options = vec![DiscoveryOption::Found(RESPONDER_UID)];
+        driver.timeouts = 1;
+        let mut controller = DmxController::new(driver, &config);
+        assert_eq!(run_full_discovery(&mut controller, &mut uids).unwrap(), 1);
+        assert_eq!(uids[0], RESPONDER_UID);
+        assert_eq!(count_mutes(&mut controller), 2);
+
+        // the device is given up on after the retries are exhausted
+        let mut driver = AckDriver::new(&[0x00, 0x00]);
+        driver.discovery_options = vec![DiscoveryOption::Found(RESPONDER_UID)];
+        driver.timeouts = 3;
+        let mut controller = DmxController::new(driver, &config);
+        assert_eq!(run_full_discovery(&mut controller, &mut uids).unwrap(), 0);
+        assert_eq!(count_mutes(&mut controller), 3);
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_tracing() {
+        use std::sync::atomic::{AtomicU64, Ordering};
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata, Subscriber};
+
+        /// Records the names and fields of all spans and events.
+        struct RecordingSubscriber {
+            records: Arc<Mutex<Vec<String>>>,
+            next_id: AtomicU64,
+        }
+
+        struct FieldRecorder(String);
+
+        impl Visit for FieldRecorder {
+            fn record_debug(&mut self, field: &Field, value: &dyn core::fmt::Debug) {
+                self.0.push_str(&format!(" {}={:?}", field.name(), value));
+            }
+        }
+
+        impl Subscriber for RecordingSubscriber {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                let mut recorder = FieldRecorder(format!("span {}", span.metadata().name()));
+                span.record(&mut recorder);
+                self.records.lock().unwrap().push(recorder.0);
+
+                Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed))
+            }
+
+            fn record(&self, _: &Id, _: &Record<'_>) {}
+
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+            fn event(&self, event: &Event<'_>) {
+                let mut recorder = FieldRecorder(String::from("event"));
+                event.record(&mut recorder);
+                self.records.lock().unwrap().push(recorder.0);
+            }
+
+            fn enter(&self, _: &Id) {}
+
+            fn exit(&self, _: &Id) {}
+        }
+
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let subscriber = RecordingSubscriber {
+            records: records.clone(),
+            next_id: AtomicU64::new(1),
+        };
+
+        tracing::subscriber::with_default(subscriber, || {
+            let mut driver =
+                AckDriver::new(&u16::from(crate::types::NackReason::UnknownPid).to_be_bytes());
+            driver.response_type = ResponseType::ResponseTypeNackReason;
+            driver.discovery_options = vec![DiscoveryOption::Found(RESPONDER_UID)];
+            let mut controller = DmxController::new(driver, &DmxControllerConfig::default());
+
+            controller.rdm_get_identify(RESPONDER_UID).unwrap_err();
+
+            controller.get_driver().timeouts = 1;
+            controller.rdm_get_identify(RESPONDER_UID).unwrap_err();
+
+            controller.get_driver().response_type = ResponseType::ResponseTypeAck;
+
+            let mut uids = [RESPONDER_UID; 1];
+            run_full_discovery(&mut controller, &mut uids).unwrap();
+        });
+
+        let records = records.lock().unwrap();
+        let position = |prefix: &str| {
+            records
+                .iter()
+                .position(|record| record.starts_with(prefix))
+                .unwrap_or_else(|| panic!("{prefix} wasn't recorded in {records:?}"))
+        };
+
+        let request_span = position("span rdm_request uid=Device");
+        assert!(records[request_span].contains(&format!("pid={}", pids::IDENTIFY_DEVICE)));
+        assert!(records[request_span].contains("command_class=GetCommand"));
+        position("event message=request was not acknowledged nack_reason=UnknownPid");
+        position("event message=request timed out");
+        let discovery_span = position("span run_full_discovery");
+        let range_span = position("span discover_range lower_bound=1 upper_bound=281474976710654");
+        let found_event = position(&format!("event message=found device uid={RESPONDER_UID}"));
+        assert!(discovery_span < range_span && range_span < found_event);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_real_time_clock() {
+        let mut controller = DmxController::new(
+            AckDriver::new(&[0x07, 0xE8, 0x02, 0x1D, 0x17, 0x3B, 0x3C]),
+            &DmxControllerConfig::default(),
+        );
+        assert_eq!(
+            controller.rdm_get_real_time_clock(RESPONDER_UID).unwrap(),
+            RealTimeClock {
+                year: 2024,
+                month: 2,
+                day: 29,
+                hour: 23,
+                minute: 59,
+                second: 60,
+            }
+        );
+
+        let mut controller =
+            DmxController::new(AckDriver::new(&[]), &DmxControllerConfig::default());
+        controller
+            .rdm_set_real_time_clock(
+                PackageAddress::Device(RESPONDER_UID),
+                RealTimeClock {
+                    year: 2025,
+                    month: 12,
+                    day: 31,
+                    hour: 12,
+                    minute: 30,
+                    second: 0,
+                },
+            )
+            .unwrap();
+        let request = &controller.get_driver().requests[0];
+        assert_eq!(request.parameter_id, pids::REAL_TIME_CLOCK);
+        assert_eq!(
+            &request.parameter_data[..],
+            &[0x07, 0xE9, 0x0C, 0x1F, 0x0C, 0x1E, 0x00]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_dmx_block_address() {
+        let mut controller = DmxController::new(
+            AckDriver::new(&[0x00, 0x09, 0x00, 0x64]),
+            &DmxControllerConfig::default(),
+        );
+        assert_eq!(
+            controller.rdm_get_dmx_block_address(RESPONDER_UID).unwrap(),
+            DmxBlockAddress {
+                total_footprint: 9,
+                base_address: 100,
+            }
+        );
+
+        let mut controller =
+            DmxController::new(AckDriver::new(&[]), &DmxControllerConfig::default());
+        controller
+            .rdm_set_dmx_block_address(PackageAddress::Device(RESPONDER_UID), 100)
+            .unwrap();
+        let request = &controller.get_driver().requests[0];
+        assert_eq!(request.parameter_id, pids::DMX_BLOCK_ADDRESS);
+        assert_eq!(&request.parameter_data[..], &[0x00, 0x64]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_statistics_counter() {
+        let mut controller = DmxController::new(
+            AckDriver::new(&[0x00, 0x00, 0x04, 0xB0]),
+            &DmxControllerConfig::default(),
+        );
+        assert_eq!(
+            controller
+                .rdm_get_statistics_counter(RESPONDER_UID, StatisticsCounter::LampHours)
+                .unwrap(),
+            1200
+        );
+
+        let mut controller =
+            DmxController::new(AckDriver::new(&[]), &DmxControllerConfig::default());
+        controller
+            .rdm_set_statistics_counter(
+                PackageAddress::Device(RESPONDER_UID),
+                StatisticsCounter::DeviceHours,
+                5,
+            )
+            .unwrap();
+        let request = &controller.get_driver().requests[0];
+        assert_eq!(request.parameter_id, pids::DEVICE_HOURS);
+        assert_eq!(&request.parameter_data[..], &[0x00, 0x00, 0x00, 0x05]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_get_typed() {
+        let device_info = DeviceInfo {
+            device_model_id: 1,
+            product_category: 0x0101,
+            software_version: 2,
+            dmx_footprint: 4,
+            dmx_personality: 0x0101,
+            dmx_start_address: DmxStartAddress::Address(1),
+            sub_device_count: 0,
+            sensor_count: 0,
+        };
+
+        let get_typed = |parameter_id: u16, data: &[u8]| {
+            let mut controller =
+                DmxController::new(AckDriver::new(data), &DmxControllerConfig::default());
+            match controller
+                .rdm_get_typed(RESPONDER_UID, parameter_id)
+                .unwrap()
+            {
+                OverflowMessageResp::Complete(package) => package,
+                OverflowMessageResp::Incomplete(_) => panic!("expected a complete response"),
+            }
+        };
+
+        assert!(matches!(
+            get_typed(pids::DEVICE_INFO, &device_info.serialize()),
+            RdmResponsePackage::DeviceInfo(DeviceInfo {
+                dmx_footprint: 4,
+                ..
+            })
+        ));
+        match get_typed(pids::SUPPORTED_PARAMETERS, &[0x80, 0x00, 0x10, 0x40]) {
+            RdmResponsePackage::SupportedParameters(supported_parameters) => {
+                assert_eq!(&supported_parameters[..], &[0x8000, pids::IDENTIFY_MODE])
+            },
+            package => panic!("expected supported parameters, got {package:?}"),
+        }
+        assert!(matches!(
+            get_typed(pids::IDENTIFY_MODE, &[0x00]),
+            RdmResponsePackage::IdentifyMode(IdentifyMode::Quiet)
+        ));
+        assert!(matches!(
+            get_typed(pids::IDENTIFY_DEVICE, &[0x01]),
+            RdmResponsePackage::IdentifyDevice(true)
+        ));
+        match get_typed(0x8000, &[0x01, 0x02]) {
+            RdmResponsePackage::Custom(response_info) => {
+                assert_eq!(response_info.parameter_id, 0x8000);
+                assert_eq!(&response_info.data[..], &[0x01, 0x02]);
+            },
+            package => panic!("expected a custom package, got {package:?}"),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_get_default_slot_values() {
+        let mut controller = DmxController::new(
+            AckDriver::new(&[0x00, 0x00, 0x7F, 0x00, 0x01, 0xFF]),
+            &DmxControllerConfig::default(),
+        );
+
+        match controller
+            .rdm_get_default_slot_values(RESPONDER_UID)
+            .unwrap()
+        {
+            OverflowMessageResp::Complete(default_slot_values) => assert_eq!(
+                &default_slot_values[..],
+                &[
+                    DefaultSlotValue {
+                        slot_offset: 0,
+                        default_value: 0x7F,
+                    },
+                    DefaultSlotValue {
+                        slot_offset: 1,
+                        default_value: 0xFF,
+                    },
+                ]
+            ),
+            OverflowMessageResp::Incomplete(_) => panic!("expected a complete response"),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_relay_request() {
+        let mut controller =
+            DmxController::new(AckDriver::new(&[0x01]), &DmxControllerConfig::default());
+        let original_controller_uid = UniqueIdentifier::new(0x7FF0, 0x0042).unwrap();
+
+        let request = RdmRequestData {
+            destination_uid: PackageAddress::Device(RESPONDER_UID),
+            source_uid: original_controller_uid,
+            transaction_number: 0x23,
+            port_id: 2,
+            message_count: 5,
+            sub_device: 3,
+            command_class: RequestCommandClass::GetCommand,
+            parameter_id: pids::IDENTIFY_DEVICE,
+            parameter_data: DataPack::new(),
+        };
+        let response = controller.rdm_relay_request(request).unwrap().unwrap();
+
+        let sent_request = &controller.get_driver().requests[0];
+        assert_eq!(sent_request.source_uid, original_controller_uid);
+        assert_eq!(sent_request.transaction_number, 0x23);
+        assert_eq!(sent_request.port_id, 2);
+        assert_eq!(sent_request.message_count, 5);
+        assert_eq!(sent_request.sub_device, 3);
+        assert_eq!(
+            response.destination_uid,
+            PackageAddress::Device(original_controller_uid)
+        );
+        assert_eq!(response.transaction_number, 0x23);
+        assert_eq!(&response.parameter_data[..], &[0x01]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_request_builder() {
+        let request = RdmRequest::new(PackageAddress::Device(RESPONDER_UID), 0x8000)
+            .with_u8(0x01)
+            .unwrap()
+            .with_u16_be(0x0203)
+            .unwrap()
+            .with_bytes(&[0x04, 0x05])
+            .unwrap();
+        assert_eq!(&request.data[..], &[0x01, 0x02, 0x03, 0x04, 0x05]);
+
+        let request = RdmRequest::new(PackageAddress::Device(RESPONDER_UID), 0x8000)
+            .with_bytes(&[0; 230])
+            .unwrap();
+        assert_eq!(
+            request.clone().with_u16_be(0x0102).unwrap_err(),
+            ParameterDataTooLongError
+        );
+        let request = request.with_u8(0xFF).unwrap();
+        assert_eq!(request.data.len(), 231);
+        assert_eq!(
+            request.with_u8(0x00).unwrap_err(),
+            ParameterDataTooLongError
+        );
+
+        RdmRequest::new(PackageAddress::Device(RESPONDER_UID), 0x8000)
+            .with_bytes(&[0; 232])
+            .unwrap_err();
+    }
+
+    #[cfg(feature = "std")]
+    struct EmptyHandler;
+
+    #[cfg(feature = "std")]
+    impl RdmResponderHandlerFunc for EmptyHandler {
+        type Error = ();
+    }
+
+    /// Answers the requests using an actual responder.
+    #[cfg(feature = "std")]
+    struct ResponderDriver {
+        package_handler: RdmResponderPackageHandler<4>,
+        response: Option<RdmResponseData>,
+        request_pids: Vec<u16>,
+    }
+
+    #[cfg(feature = "std")]
+    impl ControllerDriverErrorDef for ResponderDriver {
+        type DriverError = ();
+    }
+
+    #[cfg(feature = "std")]
+    impl RdmControllerDriver for ResponderDriver {
+        fn send_rdm(&mut self, package: RdmData) -> Result<(), DmxError<()>> {
+            let request = match package {
+                RdmData::Request(request) => request,
+                RdmData::Response(_) => panic!("controller sent a response"),
+            };
+
+            self.request_pids.push(request.parameter_id);
+            self.response = match self
+                .package_handler
+                .handle_rdm_request(request, &mut EmptyHandler)
+            {
+                Ok(RdmAnswer::Response(response)) => Some(response),
+                _ => None,
+            };
+
+            Ok(())
+        }
+
+        fn receive_rdm(&mut self) -> Result<RdmData, DmxError<()>> {
+            self.response
+                .take()
+                .map(RdmData::Response)
+                .ok_or(DmxError::TimeoutError)
+        }
+
+        fn receive_rdm_discovery_response(&mut self) -> Result<DiscoveryOption, DmxError<()>> {
+            Ok(DiscoveryOption::NoDevice)
+        }
+
+        fn send_rdm_discovery_response(&mut self, _: UniqueIdentifier) -> Result<(), DmxError<()>> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_get_reassembled() {
+        let supported_pids = Vec::leak((0x8000..0x800A).collect());
+        let mut controller = DmxController::new(
+            ResponderDriver {
+                package_handler: RdmResponderPackageHandler::new(RdmResponderConfig {
+                    uid: RESPONDER_UID,
+                    supported_pids,
+                    max_response_parameter_len: Some(8),
+                    advertise_internal_pids: false,
+                    ..Default::default()
+                }),
+                response: None,
+                request_pids: Vec::new(),
+            },
+            &DmxControllerConfig::default(),
+        );
+
+        let request = RdmRequest::empty(
+            PackageAddress::Device(RESPONDER_UID),
+            pids::SUPPORTED_PARAMETERS,
+        );
+        let response: ReassembledResponse<64> =
+            controller.rdm_get_reassembled(request.clone()).unwrap();
+        assert_eq!(response.parameter_id, pids::SUPPORTED_PARAMETERS);
+        assert_eq!(response.segment_count, 3);
+        assert_eq!(
+            &deserialize_supported_parameters(&response.data).unwrap()[..],
+            &supported_pids[..]
+        );
+
+        // the buffer can't hold the complete response
+        match controller.rdm_get_reassembled::<16>(request) {
+            Err(RdmResponseError::ResponseTooLong) => {},
+            result => panic!("expected response too long error, got {result:?}"),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_get_personality_footprint() {
+        let dmx_personalities = Vec::leak(vec![
+            DmxPersonalityDescription {
+                personality: 1,
+                dmx_footprint: 4,
+                description: "4 channel",
+            },
+            DmxPersonalityDescription {
+                personality: 2,
+                dmx_footprint: 16,
+                description: "16 channel",
+            },
+        ]);
+        let mut controller = DmxController::new(
+            ResponderDriver {
+                package_handler: RdmResponderPackageHandler::new(RdmResponderConfig {
+                    uid: RESPONDER_UID,
+                    dmx_personalities,
+                    ..Default::default()
+                }),
+                response: None,
+                request_pids: Vec::new(),
+            },
+            &DmxControllerConfig::default(),
+        );
+
+        assert_eq!(
+            controller
+                .rdm_get_personality_footprint(RESPONDER_UID, 2)
+                .unwrap(),
+            16
+        );
+        // the active personality stays the same
+        assert_eq!(
+            controller
+                .get_driver()
+                .package_handler
+                .get_dmx_personality(),
+            1
+        );
+
+        match controller.rdm_get_personality_footprint(RESPONDER_UID, 3) {
+            Err(RdmResponseError::NotAcknowledged(_)) => {},
+            result => panic!("expected a nack, got {result:?}"),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_curve() {
+        let mut controller = DmxController::new(
+            ResponderDriver {
+                package_handler: RdmResponderPackageHandler::new(RdmResponderConfig {
+                    uid: RESPONDER_UID,
+                    curve_descriptions: &["linear", "square law"],
+                    ..Default::default()
+                }),
+                response: None,
+                request_pids: Vec::new(),
+            },
+            &DmxControllerConfig::default(),
+        );
+
+        controller
+            .rdm_set_curve(PackageAddress::Device(RESPONDER_UID), 2)
+            .unwrap();
+        assert_eq!(
+            controller.rdm_get_curve(RESPONDER_UID).unwrap(),
+            Curve {
+                current_curve: 2,
+                curve_count: 2,
+            }
+        );
+        assert_eq!(
+            controller
+                .rdm_get_curve_description(RESPONDER_UID, 1)
+                .unwrap(),
+            CurveDescription {
+                curve: 1,
+                description: heapless::String::try_from("linear").unwrap(),
+            }
+        );
+
+        match controller.rdm_set_curve(PackageAddress::Device(RESPONDER_UID), 3) {
+            Err(RdmResponseError::NotAcknowledged(NackReason::DataOutOfRange)) => {},
+            result => panic!("expected a nack, got {result:?}"),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_self_test() {
+        let mut controller = DmxController::new(
+            ResponderDriver {
+                package_handler: RdmResponderPackageHandler::new(RdmResponderConfig {
+                    uid: RESPONDER_UID,
+                    self_test_descriptions: &["lamp test", "motor test"],
+                    ..Default::default()
+                }),
+                response: None,
+                request_pids: Vec::new(),
+            },
+            &DmxControllerConfig::default(),
+        );
+
+        assert_eq!(
+            controller
+                .rdm_get_self_test_description(RESPONDER_UID, 2)
+                .unwrap(),
+            SelfTestDescription {
+                self_test: 2,
+                description: heapless::String::try_from("motor test").unwrap(),
+            }
+        );
+
+        controller
+            .rdm_perform_self_test(PackageAddress::Device(RESPONDER_UID), 1)
+            .unwrap();
+        assert_eq!(controller.rdm_get_self_test(RESPONDER_UID).unwrap(), 1);
+
+        controller
+            .rdm_stop_self_test(PackageAddress::Device(RESPONDER_UID))
+            .unwrap();
+        assert_eq!(
+            controller.rdm_get_self_test(RESPONDER_UID).unwrap(),
+            RDM_SELF_TEST_OFF
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_patch() {
+        let dmx_personalities = Vec::leak(vec![
+            DmxPersonalityDescription {
+                personality: 1,
+                dmx_footprint: 4,
+                description: "4 channel",
+            },
+            DmxPersonalityDescription {
+                personality: 2,
+                dmx_footprint: 16,
+                description: "16 channel",
+            },
+        ]);
+        let mut controller = DmxController::new(
+            ResponderDriver {
+                package_handler: RdmResponderPackageHandler::new(RdmResponderConfig {
+                    uid: RESPONDER_UID,
+                    dmx_personalities,
+                    ..Default::default()
+                }),
+                response: None,
+                request_pids: Vec::new(),
+            },
+            &DmxControllerConfig::default(),
+        );
+
+        // the address fits the current footprint but not the one of the new personality
+        match controller.rdm_patch(RESPONDER_UID, Some(2), Some(500)) {
+            Err(RdmResponseError::InvalidRequestData) => {},
+            result => panic!("expected invalid request data error, got {result:?}"),
+        }
+        assert_eq!(
+            controller.get_driver().request_pids,
+            [pids::DMX_PERSONALITY, pids::DEVICE_INFO]
+        );
+
+        controller.get_driver().request_pids.clear();
+        controller
+            .rdm_patch(RESPONDER_UID, Some(2), Some(497))
+            .unwrap();
+        assert_eq!(
+            controller.get_driver().request_pids,
+            [
+                pids::DMX_PERSONALITY,
+                pids::DEVICE_INFO,
+                pids::DMX_START_ADDRESS
+            ]
+        );
+
+        let device_info = controller.rdm_get_device_info(RESPONDER_UID).unwrap();
+        assert_eq!(device_info.dmx_footprint, 16);
+        assert_eq!(device_info.dmx_start_address, DmxStartAddress::Address(497));
+
+        controller.get_driver().request_pids.clear();
+        match controller.rdm_patch(RESPONDER_UID, None, Some(0)) {
+            Err(RdmResponseError::InvalidRequestData) => {},
+            result => panic!("expected invalid request data error, got {result:?}"),
+        }
+        assert!(controller.get_driver().request_pids.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_validate_patch() {
+        let build_controller = |dmx_start_address: DmxStartAddress, dmx_footprint: u16| {
+            let device_info = DeviceInfo {
+                device_model_id: 1,
+                product_category: 0x0101,
+                software_version: 2,
+                dmx_footprint,
+                dmx_personality: 0x0101,
+                dmx_start_address,
+                sub_device_count: 0,
+                sensor_count: 0,
+            };
+
+            DmxController::new(
+                AckDriver::new(&device_info.serialize()),
+                &DmxControllerConfig::default(),
+            )
+        };
+
+        let mut controller = build_controller(DmxStartAddress::Address(1), 20);
+        match controller.rdm_validate_patch(RESPONDER_UID, 500) {
+            Err(PatchError::FootprintExceedsUniverse) => {},
+            result => panic!("expected the footprint to exceed the universe, got {result:?}"),
+        }
+        controller.rdm_validate_patch(RESPONDER_UID, 480).unwrap();
+        // the last channel is 512
+        controller.rdm_validate_patch(RESPONDER_UID, 493).unwrap();
+        assert!(controller
+            .get_driver()
+            .requests
+            .iter()
+            .all(|request| request.parameter_id == pids::DEVICE_INFO));
+
+        match controller.rdm_validate_patch(RESPONDER_UID, 0) {
+            Err(PatchError::InvalidStartAddress) => {},
+            result => panic!("expected an invalid start address, got {result:?}"),
+        }
+
+        let mut controller = build_controller(DmxStartAddress::NoAddress, 0);
+        match controller.rdm_validate_patch(RESPONDER_UID, 1) {
+            Err(PatchError::NoFootprint) => {},
+            result => panic!("expected no footprint, got {result:?}"),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_shutdown() {
+        let mut controller =
+            DmxController::new(AckDriver::new(&[]), &DmxControllerConfig::default());
+
+        controller.shutdown().unwrap();
+
+        let driver = controller.get_driver();
+        assert_eq!(driver.requests.len(), 1);
+        assert_eq!(
+            driver.requests[0].destination_uid,
+            PackageAddress::Broadcast
+        );
+        assert_eq!(
+            driver.requests[0].command_class,
+            RequestCommandClass::DiscoveryCommand
+        );
+        assert_eq!(driver.requests[0].parameter_id, pids::DISC_UN_MUTE);
+        assert_eq!(driver.flushed_requests, Some(1));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_drain_queued_messages() {
+        let mut package_handler = RdmResponderPackageHandler::new(RdmResponderConfig {
+            uid: RESPONDER_UID,
+            ..Default::default()
+        });
+        // the messages are sent from the back of the queue
+        let message_queue = package_handler.get_message_queue_mut();
+        message_queue
+            .push(QueuedMessage::new(
+                pids::DEVICE_LABEL,
+                DataPack::from_slice(b"Stage Left").unwrap(),
+            ))
+            .unwrap();
+        message_queue
+            .push(QueuedMessage::new(
+                pids::DMX_START_ADDRESS,
+                DataPack::from_slice(&[0x00, 0x2A]).unwrap(),
+            ))
+            .unwrap();
+        message_queue
+            .push(QueuedMessage::new(
+                pids::IDENTIFY_DEVICE,
+                DataPack::from_slice(&[0x01]).unwrap(),
+            ))
+            .unwrap();
+
+        let mut controller = DmxController::new(
+            ResponderDriver {
+                package_handler,
+                response: None,
+                request_pids: Vec::new(),
+            },
+            &DmxControllerConfig::default(),
+        );
+
+        let messages: heapless::Vec<RdmResponsePackage, 8> =
+            controller.rdm_drain_queued_messages(RESPONDER_UID).unwrap();
+        assert_eq!(messages.len(), 3);
+        assert!(matches!(
+            messages[0],
+            RdmResponsePackage::IdentifyDevice(true)
+        ));
+        assert!(matches!(
+            messages[1],
+            RdmResponsePackage::DmxStartAddress(DmxStartAddress::Address(42))
+        ));
+        match &messages[2] {
+            RdmResponsePackage::DeviceLabel(label) => assert_eq!(label, "Stage Left"),
+            message => panic!("expected a device label, got {message:?}"),
+        }
+        assert_eq!(controller.rdm_get_last_message_count(), 0);
+        assert_eq!(
+            controller.get_driver().request_pids,
+            [pids::QUEUED_MESSAGE; 3]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_set_device_label() {
+        let mut controller =
+            DmxController::new(AckDriver::new(&[]), &DmxControllerConfig::default());
+
+        controller
+            .rdm_set_device_label(PackageAddress::Device(RESPONDER_UID), "Stage Left")
+            .unwrap();
+        assert_eq!(
+            &controller.get_driver().requests[0].parameter_data[..],
+            b"Stage Left"
+        );
+
+        match controller.rdm_set_device_label(
+            PackageAddress::Device(RESPONDER_UID),
+            "This label is definitely too long!",
+        ) {
+            Err(RdmResponseError::InvalidRequestData) => {},
+            result => panic!("expected invalid request data error, got {result:?}"),
+        }
+
+        match controller.rdm_set_device_label(PackageAddress::Device(RESPONDER_UID), "Bühne") {
+            Err(RdmResponseError::InvalidRequestData) => {},
+            result => panic!("expected invalid request data error, got {result:?}"),
+        }
+
+        assert_eq!(controller.get_driver().requests.len(), 1);
+    }
+
+    #[test]
+    fn test_send_dmx_delta() {
+        struct DmxDriver {
+            sent_packages: usize,
+        }
+
+        impl ControllerDriverErrorDef for DmxDriver {
+            type DriverError = ();
+        }
+
+        impl DmxControllerDriver for DmxDriver {
+            fn send_dmx_package(&mut self, _: &[u8]) -> Result<(), DmxError<()>> {
+                self.sent_packages += 1;
+                Ok(())
+            }
+        }
+
+        let mut controller = DmxController::new(
+            DmxDriver { sent_packages: 0 },
+            &DmxControllerConfig::default(),
+        );
+        let mut universe = DmxUniverse::new();
+        let mut last_sent = None;
+
+        assert!(controller
+            .send_dmx_delta(&universe, &mut last_sent)
+            .unwrap());
+        assert!(!controller
+            .send_dmx_delta(&universe, &mut last_sent)
+            .unwrap());
+        assert_eq!(controller.get_driver().sent_packages, 1);
+
+        universe.set_channel(0, 255);
+        assert!(controller
+            .send_dmx_delta(&universe, &mut last_sent)
+            .unwrap());
+        assert_eq!(controller.get_driver().sent_packages, 2);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_send_only_uart_driver() {
+        /// A uart that can only transmit, so it doesn't implement [DmxRecvUartDriver].
+        struct SendOnlyUartDriver {
+            frames: Vec<Vec<u8>>,
+        }
+
+        impl DmxUartDriver for SendOnlyUartDriver {
+            type DriverError = ();
+        }
+
+        impl DmxRespUartDriver for SendOnlyUartDriver {
+            fn write_frames(&mut self, buffer: &[u8]) -> Result<usize, DmxUartDriverError<()>> {
+                self.frames.push(buffer.to_vec());
+                Ok(buffer.len())
+            }
+
+            fn write_frames_no_break(
+                &mut self,
+                buffer: &[u8],
+            ) -> Result<usize, DmxUartDriverError<()>> {
+                self.write_frames(buffer)
+            }
+        }
+
+        let mut controller = DmxController::new(
+            SendOnlyUartDriver { frames: Vec::new() },
+            &DmxControllerConfig::default(),
+        );
+
+        controller.send_dmx_package(&[0x01, 0x02, 0x03]).unwrap();
+        controller.send_custom_package(0x17, &[0xFF]).unwrap();
+        assert_eq!(
+            controller.get_driver().frames,
+            [vec![0x00, 0x01, 0x02, 0x03], vec![0x17, 0xFF]]
+        );
+    }
 }
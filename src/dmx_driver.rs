@@ -1,14 +1,17 @@
 use crate::consts::{
-    DMX_MAX_PACKAGE_SIZE, DMX_NULL_START, PREAMBLE_BYTE, RDM_DISCOVERY_RESPONSE_SIZE,
-    RDM_MAX_PACKAGE_SIZE, SC_RDM, SEPARATOR_BYTE,
+    DMX_MAX_PACKAGE_SIZE, DMX_NULL_START, RDM_DISCOVERY_RESPONSE_SIZE,
+    RDM_MAX_DISCOVERY_PREAMBLE_SIZE, RDM_MAX_INTER_SLOT_MICROS, RDM_MAX_PACKAGE_SIZE, SC_RDM,
+    SLOT_MICROS,
 };
 use crate::dmx_receiver::DmxFrame;
 use crate::dmx_uart_driver::{
     DmxRecvUartDriver, DmxRespUartDriver, DmxUartDriver, DmxUartDriverError,
 };
-use crate::rdm_data::{deserialize_discovery_response, RdmData, RdmDeserializationError};
+use crate::rdm_data::{
+    deserialize_discovery_response_unchecked, serialize_discovery_response, RdmData,
+    RdmDeserializationError, SerializationError,
+};
 use crate::unique_identifier::UniqueIdentifier;
-use crate::utils::{calculate_checksum, encode_disc_unique};
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
@@ -21,6 +24,8 @@ pub enum DmxError<E> {
     TimeoutError,
     /// Raised when an RDM package could not be deserialized.
     DeserializationError(RdmDeserializationError),
+    /// Raised when an RDM package could not be serialized.
+    SerializationError(SerializationError),
     /// An error raised by the uart driver.
     DriverError(E),
 }
@@ -31,6 +36,7 @@ impl<E: core::fmt::Display> core::fmt::Display for DmxError<E> {
             DmxError::UartOverflow => write!(f, "uart overflowed"),
             DmxError::TimeoutError => write!(f, "request timed out"),
             DmxError::DeserializationError(error) => error.fmt(f),
+            DmxError::SerializationError(error) => error.fmt(f),
             DmxError::DriverError(error) => error.fmt(f),
         }
     }
@@ -56,6 +62,10 @@ pub enum DiscoveryOption {
     /// The discovery response was successfully deserialized.
     /// There is only one device in the specified unique id range.
     Found(UniqueIdentifier),
+    /// The device id of the discovery response could be decoded, but the checksum is wrong.
+    /// This is either a single device on a marginal bus or a collision of multiple devices.
+    /// The device id should be verified using a DISC_MUTE request.
+    FoundWithErrors(UniqueIdentifier),
 }
 
 impl<E> From<DmxUartDriverError<E>> for DmxError<E> {
@@ -74,6 +84,9 @@ pub trait ControllerDriverErrorDef {
 }
 
 /// Trait for controlling DMX fixtures.
+///
+/// It's implemented for every [DmxRespUartDriver], so transmit-only hardware doesn't have to
+/// implement [DmxRecvUartDriver] or [RdmControllerDriver].
 pub trait DmxControllerDriver: ControllerDriverErrorDef {
     /// Send a DMX512 package. It shouldn't be bigger than 512 bytes.
     fn send_dmx_package(&mut self, package: &[u8]) -> Result<(), DmxError<Self::DriverError>>;
@@ -96,6 +109,24 @@ pub trait RdmControllerDriver: ControllerDriverErrorDef {
         &mut self,
         uid: UniqueIdentifier,
     ) -> Result<(), DmxError<Self::DriverError>>;
+    /// Send a dmx discovery response with a custom amount of preamble bytes (0-7).
+    /// Drivers that can't configure the preamble length should keep the default implementation,
+    /// which ignores the preamble length and calls [RdmControllerDriver::send_rdm_discovery_response].
+    fn send_rdm_discovery_response_with_preamble(
+        &mut self,
+        uid: UniqueIdentifier,
+        _preamble_length: usize,
+    ) -> Result<(), DmxError<Self::DriverError>> {
+        self.send_rdm_discovery_response(uid)
+    }
+    /// Blocks for the given amount of microseconds before an RDM response is sent.
+    /// Drivers that can't wait should keep the default implementation, which returns immediately.
+    fn delay_response(&mut self, _delay_us: u32) {}
+    /// Blocks until all pending packages have been sent.
+    /// Drivers that don't buffer should keep the default implementation, which returns immediately.
+    fn flush(&mut self) -> Result<(), DmxError<Self::DriverError>> {
+        Ok(())
+    }
 }
 
 /// Trait for implementing packages with custom start codes.
@@ -124,7 +155,7 @@ impl<D: DmxRespUartDriver> CustomStartCodeControllerDriver for D {
             .extend_from_slice(package)
             .or(Err(DmxError::UartOverflow))?;
 
-        if self.write_frames(&frame_buffer)? != package.len() {
+        if self.write_frames(&frame_buffer)? != frame_buffer.len() {
             return Err(DmxError::UartOverflow);
         }
 
@@ -138,10 +169,13 @@ impl<D: DmxRespUartDriver> DmxControllerDriver for D {
     }
 }
 
-const READ_TIMEOUT_US: u32 = 2800;
+/// A larger gap between two bytes of an RDM response means the frame is corrupted.
+const INTER_BYTE_TIMEOUT_US: u32 = SLOT_MICROS + RDM_MAX_INTER_SLOT_MICROS;
 impl<D: DmxRespUartDriver + DmxRecvUartDriver> RdmControllerDriver for D {
     fn send_rdm(&mut self, rdm_package: RdmData) -> Result<(), DmxError<Self::DriverError>> {
-        let serialized_package = rdm_package.serialize();
+        let serialized_package = rdm_package
+            .try_serialize()
+            .map_err(DmxError::SerializationError)?;
         let written_bytes = self.write_frames(&serialized_package)?;
 
         if serialized_package.len() != written_bytes {
@@ -154,7 +188,14 @@ impl<D: DmxRespUartDriver + DmxRecvUartDriver> RdmControllerDriver for D {
     fn receive_rdm(&mut self) -> Result<RdmData, DmxError<Self::DriverError>> {
         // Very imprecise value for testing
         let mut receive_buffer = [0u8; RDM_MAX_PACKAGE_SIZE];
-        let mut bytes_read = self.read_frames(&mut receive_buffer[0..3], READ_TIMEOUT_US)?;
+        let read_timeout_us = self.get_timing().get_rdm_read_timeout_us();
+        let mut bytes_read = self.read_frames(&mut receive_buffer[0..3], read_timeout_us)?;
+        // the message length is only valid if the header has been read completely
+        if bytes_read != 3 {
+            return Err(DmxError::DeserializationError(
+                RdmDeserializationError::WrongMessageLength(bytes_read),
+            ));
+        }
 
         // plus two checksum bytes
         let message_length = receive_buffer[2] as usize + 2;
@@ -169,8 +210,17 @@ impl<D: DmxRespUartDriver + DmxRecvUartDriver> RdmControllerDriver for D {
             ));
         }
 
-        bytes_read +=
-            self.read_frames_no_break(&mut receive_buffer[3..message_length], READ_TIMEOUT_US)?;
+        bytes_read += self.read_frames_no_break_with_inter_byte_timeout(
+            &mut receive_buffer[3..message_length],
+            read_timeout_us,
+            INTER_BYTE_TIMEOUT_US,
+        )?;
+        if bytes_read != message_length {
+            return Err(DmxError::DeserializationError(
+                RdmDeserializationError::WrongMessageLength(bytes_read),
+            ));
+        }
+
         let response = RdmData::deserialize(&receive_buffer[..bytes_read])
             .map_err(DmxError::DeserializationError)?;
 
@@ -181,7 +231,8 @@ impl<D: DmxRespUartDriver + DmxRecvUartDriver> RdmControllerDriver for D {
         &mut self,
     ) -> Result<DiscoveryOption, DmxError<Self::DriverError>> {
         let mut receive_buffer = [0u8; 32]; // the actual package is 24 bytes
-        let bytes_read = match self.read_frames_no_break(&mut receive_buffer, READ_TIMEOUT_US) {
+        let read_timeout_us = self.get_timing().get_rdm_read_timeout_us();
+        let bytes_read = match self.read_frames_no_break(&mut receive_buffer, read_timeout_us) {
             Err(DmxUartDriverError::TimeoutError) => return Ok(DiscoveryOption::NoDevice),
             result => result,
         }?;
@@ -191,8 +242,11 @@ impl<D: DmxRespUartDriver + DmxRecvUartDriver> RdmControllerDriver for D {
         }
 
         Ok(
-            deserialize_discovery_response(&receive_buffer[..bytes_read])
-                .map_or(DiscoveryOption::Collision, DiscoveryOption::Found),
+            match deserialize_discovery_response_unchecked(&receive_buffer[..bytes_read]) {
+                Ok((uid, true)) => DiscoveryOption::Found(uid),
+                Ok((uid, false)) => DiscoveryOption::FoundWithErrors(uid),
+                Err(_) => DiscoveryOption::Collision,
+            },
         )
     }
 
@@ -200,14 +254,15 @@ impl<D: DmxRespUartDriver + DmxRecvUartDriver> RdmControllerDriver for D {
         &mut self,
         uid: UniqueIdentifier,
     ) -> Result<(), DmxError<Self::DriverError>> {
-        let mut frame_buffer = [PREAMBLE_BYTE; 24];
-        frame_buffer[7] = SEPARATOR_BYTE;
-
-        let uid_buffer = uid.to_bytes();
-        encode_disc_unique(&uid_buffer, &mut frame_buffer[8..20]);
+        self.send_rdm_discovery_response_with_preamble(uid, RDM_MAX_DISCOVERY_PREAMBLE_SIZE)
+    }
 
-        let checksum = calculate_checksum(&frame_buffer[8..20]);
-        encode_disc_unique(&checksum.to_be_bytes(), &mut frame_buffer[20..24]);
+    fn send_rdm_discovery_response_with_preamble(
+        &mut self,
+        uid: UniqueIdentifier,
+        preamble_length: usize,
+    ) -> Result<(), DmxError<Self::DriverError>> {
+        let frame_buffer = serialize_discovery_response(uid, preamble_length);
 
         if self.write_frames_no_break(&frame_buffer)? != frame_buffer.len() {
             return Err(DmxError::UartOverflow);
@@ -215,6 +270,16 @@ impl<D: DmxRespUartDriver + DmxRecvUartDriver> RdmControllerDriver for D {
 
         Ok(())
     }
+
+    fn delay_response(&mut self, delay_us: u32) {
+        self.delay_us(delay_us)
+    }
+
+    fn flush(&mut self) -> Result<(), DmxError<Self::DriverError>> {
+        DmxUartDriver::flush(self)?;
+
+        Ok(())
+    }
 }
 
 pub trait DmxReceiver: ControllerDriverErrorDef {
@@ -222,6 +287,28 @@ pub trait DmxReceiver: ControllerDriverErrorDef {
     fn receive_package(&mut self) -> Result<DmxFrame, DmxError<Self::DriverError>>;
 }
 
+/// Returns the size of the package whose first bytes have been read into the header.
+/// RDM packages are only read up to their message length so they can be answered without
+/// waiting for the read timeout, every other package is read up to the maximum dmx package size.
+pub(crate) fn expected_package_size<DriverError>(
+    header: &[u8],
+) -> Result<usize, DmxError<DriverError>> {
+    // the message length is only valid if the header has been read completely
+    if header.len() != 3 || header[0] != SC_RDM {
+        return Ok(DMX_MAX_PACKAGE_SIZE);
+    }
+
+    // plus two checksum bytes
+    let message_size = header[2] as usize + 2;
+    if !(3..=RDM_MAX_PACKAGE_SIZE).contains(&message_size) {
+        return Err(DmxError::DeserializationError(
+            RdmDeserializationError::WrongMessageLength(message_size),
+        ));
+    }
+
+    Ok(message_size)
+}
+
 impl<D: DmxRecvUartDriver> DmxReceiver for D {
     fn receive_package(&mut self) -> Result<DmxFrame, DmxError<D::DriverError>> {
         const READ_TIMEOUT_US: u32 = 1800;
@@ -234,16 +321,264 @@ impl<D: DmxRecvUartDriver> DmxReceiver for D {
             ));
         }
 
-        // workaround for rdm packages to have better receive times
-        let start_code = buffer[0];
-        let message_size = if start_code == SC_RDM && bytes_read == 3 {
-            // message size plus two checksum bytes
-            buffer[2] as usize + 2
-        } else {
-            DMX_MAX_PACKAGE_SIZE
-        };
-
+        let message_size = expected_package_size(&buffer[..bytes_read])?;
         bytes_read += self.read_frames_no_break(&mut buffer[3..message_size], READ_TIMEOUT_US)?;
         Ok(DmxFrame::from_slice(&buffer[..bytes_read]).unwrap())
     }
 }
+
+#[cfg(test)]
+#[cfg_attr(not(feature = "std"), allow(unused_imports))]
+mod tests {
+    use crate::command_class::RequestCommandClass;
+    use crate::dmx_driver::{
+        CustomStartCodeControllerDriver, DmxError, RdmControllerDriver, INTER_BYTE_TIMEOUT_US,
+    };
+    use crate::dmx_uart_driver::{
+        DmxRecvUartDriver, DmxRespUartDriver, DmxUartDriver, DmxUartDriverError, TimedUartDriver,
+    };
+    use crate::rdm_data::{RdmData, RdmDeserializationError, RdmRequestData};
+    use crate::timing::DmxTiming;
+    use crate::types::{DataPack, ResponseType};
+    use crate::unique_identifier::{PackageAddress, UniqueIdentifier};
+
+    /// Replays a frame where every byte arrives after the configured gap.
+    #[cfg(feature = "std")]
+    struct GapUartDriver {
+        frame: Vec<u8>,
+        gaps_us: Vec<u32>,
+        position: usize,
+        timeouts_us: Vec<u32>,
+    }
+
+    #[cfg(feature = "std")]
+    impl GapUartDriver {
+        fn read(&mut self, buffer: &mut [u8], inter_byte_timeout_us: Option<u32>) -> usize {
+            let mut bytes_read = 0;
+
+            for byte in buffer.iter_mut() {
+                if self.position >= self.frame.len() {
+                    break;
+                }
+
+                if bytes_read > 0
+                    && inter_byte_timeout_us
+                        .is_some_and(|timeout_us| self.gaps_us[self.position] > timeout_us)
+                {
+                    break;
+                }
+
+                *byte = self.frame[self.position];
+                self.position += 1;
+                bytes_read += 1;
+            }
+
+            bytes_read
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl DmxUartDriver for GapUartDriver {
+        type DriverError = ();
+    }
+
+    #[cfg(feature = "std")]
+    impl DmxRecvUartDriver for GapUartDriver {
+        fn read_frames(
+            &mut self,
+            buffer: &mut [u8],
+            timeout_us: u32,
+        ) -> Result<usize, DmxUartDriverError<()>> {
+            self.timeouts_us.push(timeout_us);
+            Ok(self.read(buffer, None))
+        }
+
+        fn read_frames_no_break(
+            &mut self,
+            buffer: &mut [u8],
+            _: u32,
+        ) -> Result<usize, DmxUartDriverError<()>> {
+            Ok(self.read(buffer, None))
+        }
+
+        fn read_frames_no_break_with_inter_byte_timeout(
+            &mut self,
+            buffer: &mut [u8],
+            timeout_us: u32,
+            inter_byte_timeout_us: u32,
+        ) -> Result<usize, DmxUartDriverError<()>> {
+            self.timeouts_us.push(timeout_us);
+            Ok(self.read(buffer, Some(inter_byte_timeout_us)))
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl DmxRespUartDriver for GapUartDriver {
+        fn write_frames(&mut self, buffer: &[u8]) -> Result<usize, DmxUartDriverError<()>> {
+            Ok(buffer.len())
+        }
+
+        fn write_frames_no_break(
+            &mut self,
+            buffer: &[u8],
+        ) -> Result<usize, DmxUartDriverError<()>> {
+            Ok(buffer.len())
+        }
+    }
+
+    /// Records the written frames and writes at most max_write_len bytes of each frame.
+    #[cfg(feature = "std")]
+    struct RecordingUartDriver {
+        frames: Vec<Vec<u8>>,
+        max_write_len: usize,
+    }
+
+    #[cfg(feature = "std")]
+    impl DmxUartDriver for RecordingUartDriver {
+        type DriverError = ();
+    }
+
+    #[cfg(feature = "std")]
+    impl DmxRespUartDriver for RecordingUartDriver {
+        fn write_frames(&mut self, buffer: &[u8]) -> Result<usize, DmxUartDriverError<()>> {
+            let written_len = buffer.len().min(self.max_write_len);
+            self.frames.push(buffer[..written_len].to_vec());
+            Ok(written_len)
+        }
+
+        fn write_frames_no_break(
+            &mut self,
+            buffer: &[u8],
+        ) -> Result<usize, DmxUartDriverError<()>> {
+            self.write_frames(buffer)
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_send_custom_package() {
+        let mut driver = RecordingUartDriver {
+            frames: Vec::new(),
+            max_write_len: usize::MAX,
+        };
+        driver
+            .send_custom_package(0x91, &[0x01, 0x02, 0x03])
+            .unwrap();
+        assert_eq!(driver.frames, [vec![0x91, 0x01, 0x02, 0x03]]);
+
+        // the start code counts towards the written bytes
+        let mut driver = RecordingUartDriver {
+            frames: Vec::new(),
+            max_write_len: 3,
+        };
+        assert!(matches!(
+            driver.send_custom_package(0x91, &[0x01, 0x02, 0x03]),
+            Err(DmxError::UartOverflow)
+        ));
+    }
+
+    #[cfg(feature = "std")]
+    fn build_response_frame() -> Vec<u8> {
+        let request = RdmRequestData {
+            destination_uid: PackageAddress::Device(UniqueIdentifier::new(0x7FF0, 1).unwrap()),
+            source_uid: UniqueIdentifier::new(0x7FF0, 0).unwrap(),
+            transaction_number: 1,
+            port_id: 1,
+            message_count: 0,
+            sub_device: 0,
+            command_class: RequestCommandClass::GetCommand,
+            parameter_id: 0x1000,
+            parameter_data: DataPack::new(),
+        };
+
+        RdmData::Response(
+            request
+                .build_response(
+                    ResponseType::ResponseTypeAck,
+                    DataPack::from_slice(&[0x01]).unwrap(),
+                    0,
+                )
+                .unwrap(),
+        )
+        .serialize()
+        .to_vec()
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_receive_rdm_inter_byte_gap() {
+        let frame = build_response_frame();
+
+        let mut driver = GapUartDriver {
+            gaps_us: vec![INTER_BYTE_TIMEOUT_US; frame.len()],
+            frame: frame.clone(),
+            position: 0,
+            timeouts_us: Vec::new(),
+        };
+        assert!(matches!(driver.receive_rdm(), Ok(RdmData::Response(_))));
+
+        let mut gaps_us = vec![0; frame.len()];
+        gaps_us[10] = INTER_BYTE_TIMEOUT_US + 1;
+        let mut driver = GapUartDriver {
+            frame,
+            gaps_us,
+            position: 0,
+            timeouts_us: Vec::new(),
+        };
+        match driver.receive_rdm() {
+            Err(DmxError::DeserializationError(RdmDeserializationError::WrongMessageLength(
+                10,
+            ))) => {},
+            result => panic!("expected wrong message length, got {result:?}"),
+        }
+    }
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_receive_rdm_truncated_frame() {
+        let frame = build_response_frame();
+
+        for truncated_length in [1, 2, 3, frame.len() - 1] {
+            let mut driver = GapUartDriver {
+                gaps_us: vec![0; truncated_length],
+                frame: frame[..truncated_length].to_vec(),
+                position: 0,
+                timeouts_us: Vec::new(),
+            };
+            match driver.receive_rdm() {
+                Err(DmxError::DeserializationError(
+                    RdmDeserializationError::WrongMessageLength(bytes_read),
+                )) => assert_eq!(bytes_read, truncated_length),
+                result => panic!("expected wrong message length, got {result:?}"),
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_receive_rdm_timing() {
+        let frame = build_response_frame();
+
+        let mut driver = GapUartDriver {
+            gaps_us: vec![0; frame.len()],
+            frame: frame.clone(),
+            position: 0,
+            timeouts_us: Vec::new(),
+        };
+        driver.receive_rdm().unwrap();
+        assert_eq!(driver.timeouts_us, [2800, 2800]);
+
+        let timing = DmxTiming::new(176, 16, 5000).unwrap();
+        let mut driver = TimedUartDriver::new(
+            GapUartDriver {
+                gaps_us: vec![0; frame.len()],
+                frame,
+                position: 0,
+                timeouts_us: Vec::new(),
+            },
+            timing,
+        );
+        assert_eq!(driver.get_timing(), timing);
+        driver.receive_rdm().unwrap();
+        assert_eq!(driver.get_driver().timeouts_us, [5000, 5000]);
+    }
+}
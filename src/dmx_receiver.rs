@@ -1,16 +1,74 @@
-use crate::consts::SC_RDM;
+use crate::consts::{DMX_NULL_START, SC_RDM};
 use crate::dmx_driver::{DmxError, DmxReceiver, RdmControllerDriver};
-use crate::rdm_data::{RdmData, RdmDeserializationError, RdmRequestData, RdmResponseData};
+use crate::rdm_data::{RdmData, RdmDeserializationError, RdmRequestData, SerializationError};
 use crate::rdm_responder::{
-    DmxReceiverContext, RdmAnswer, RdmResponderConfig, RdmResponderHandlerFunc,
-    RdmResponderPackageHandler, RdmResult,
+    DmxReceiverContext, MessageQueueFullError, QueuedMessage, RdmAnswer, RdmResponderConfig,
+    RdmResponderHandlerFunc, RdmResponderPackageHandler, RdmResult, SubDevice,
+};
+use crate::rdm_types::{
+    CommsStatus, IdentifyMode, PowerState, PresetPlayback, RealTimeClock, ResetType, SensorValue,
+    StatisticsCounter, StatusMessage, StatusType,
 };
-use crate::rdm_types::StatusMessage;
 use crate::types::NackReason;
+use crate::unique_identifier::UniqueIdentifier;
+use core::convert::Infallible;
 
 /// A vector that contains one DmxFrame. The first byte is the start code. 0x00 is the dmx start code.
 pub type DmxFrame = heapless::Vec<u8, 513>;
 
+/// Accessors for the slots of a [DmxFrame] that take care of the start code.
+pub trait DmxFrameExt {
+    /// Returns the start code of the frame or [DMX_NULL_START] if the frame is empty.
+    fn start_code(&self) -> u8;
+    /// Returns the slots after the start code.
+    fn channels(&self) -> &[u8];
+    /// Returns the value of a channel starting at 1.
+    /// Returns None for channel 0 or if the frame is too short.
+    fn channel(&self, channel: u16) -> Option<u8>;
+}
+
+impl DmxFrameExt for DmxFrame {
+    fn start_code(&self) -> u8 {
+        self.first().copied().unwrap_or(DMX_NULL_START)
+    }
+
+    fn channels(&self) -> &[u8] {
+        self.get(1..).unwrap_or(&[])
+    }
+
+    fn channel(&self, channel: u16) -> Option<u8> {
+        self.channels()
+            .get((channel as usize).checked_sub(1)?)
+            .copied()
+    }
+}
+
+/// A received frame classified by its start code.
+#[derive(Debug)]
+pub enum ReceivedFrame {
+    /// A DMX512 frame with the null start code. The first byte is the start code.
+    Dmx(DmxFrame),
+    /// A deserialized RDM request.
+    Rdm(RdmRequestData),
+    /// A frame with a custom start code. The [u8] is the start code, the [DmxFrame]
+    /// still contains the start code as its first byte.
+    Custom(u8, DmxFrame),
+}
+
+/// The status message that gets queued by [RdmResponder::poll] on recoverable errors
+/// like uart overflows or packages that couldn't be deserialized.
+///
+/// Only one status message per status message id is queued. Further errors increase its
+/// `data_value_1` until the status message was collected by the controller.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PollingErrorStatus {
+    /// The severity of the status message.
+    pub status_type: StatusType,
+    /// The status message id. Use a manufacturer specific id (0x8000-0xFFDF).
+    pub status_message_id: u16,
+}
+
 pub enum ResponseOption {
     NoResponse,
     Response(DmxFrame),
@@ -52,6 +110,8 @@ pub enum PollingError<DriverError, HandlerError> {
     HandlerError(HandlerError),
     /// Raised when an RDM package could not be deserialized.
     DeserializationError(RdmDeserializationError),
+    /// Raised when an RDM package could not be serialized.
+    SerializationError(SerializationError),
 }
 
 impl<DriverError, HandlerError> From<DmxError<DriverError>>
@@ -64,11 +124,30 @@ impl<DriverError, HandlerError> From<DmxError<DriverError>>
             DmxError::DeserializationError(deserialization_error) => {
                 Self::DeserializationError(deserialization_error)
             },
+            DmxError::SerializationError(serialization_error) => {
+                Self::SerializationError(serialization_error)
+            },
             DmxError::DriverError(driver_error) => Self::DriverError(driver_error),
         }
     }
 }
 
+impl<DriverError> PollingError<DriverError, Infallible> {
+    fn map_handler_error<HandlerError>(self) -> PollingError<DriverError, HandlerError> {
+        match self {
+            PollingError::UartOverflow => PollingError::UartOverflow,
+            PollingError::TimeoutError => PollingError::TimeoutError,
+            PollingError::UnknownStartCode => PollingError::UnknownStartCode,
+            PollingError::WrongPackageSize => PollingError::WrongPackageSize,
+            PollingError::NotMatching => PollingError::NotMatching,
+            PollingError::DriverError(error) => PollingError::DriverError(error),
+            PollingError::HandlerError(error) => match error {},
+            PollingError::DeserializationError(error) => PollingError::DeserializationError(error),
+            PollingError::SerializationError(error) => PollingError::SerializationError(error),
+        }
+    }
+}
+
 impl<DriverError: core::fmt::Display, HandlerError: core::fmt::Display> core::fmt::Display
     for PollingError<DriverError, HandlerError>
 {
@@ -77,6 +156,7 @@ impl<DriverError: core::fmt::Display, HandlerError: core::fmt::Display> core::fm
             PollingError::UartOverflow => "Uart overflow.",
             PollingError::TimeoutError => "Timeout error.",
             PollingError::DeserializationError(_) => "Deserialization error.",
+            PollingError::SerializationError(_) => "Serialization error.",
             PollingError::NotMatching => "Received response and not request.",
             PollingError::UnknownStartCode => "The start code is unknown.",
             PollingError::WrongPackageSize => "The package size is insufficient.",
@@ -110,9 +190,165 @@ pub trait DmxResponderHandler {
         _request: &RdmRequestData,
         _context: &mut DmxReceiverContext,
     ) -> Result<RdmResult, Self::Error> {
-        Ok(RdmResult::NotAcknowledged(
-            NackReason::UnsupportedCommandClass as u16,
-        ))
+        Ok(RdmResult::NotAcknowledged(u16::from(
+            NackReason::UnsupportedCommandClass,
+        )))
+    }
+
+    /// Handle rdm requests to a sub-device that aren't handled by the [RdmResponder] itself.
+    /// The sub_device is between 1 and the configured sub-device count. Set requests to all
+    /// sub-devices (0xFFFF) are passed to every sub-device and their responses are discarded.
+    fn handle_rdm_sub_device(
+        &mut self,
+        _sub_device: u16,
+        _request: &RdmRequestData,
+        _context: &mut DmxReceiverContext,
+    ) -> Result<RdmResult, Self::Error> {
+        Ok(RdmResult::NotAcknowledged(u16::from(
+            NackReason::UnknownPid,
+        )))
+    }
+
+    /// Called on a FACTORY_DEFAULTS set request after the [RdmResponder] reset its own state
+    /// (device label, dmx start addresses, personality, identify, curve and preset playback).
+    /// Reset the remaining state of the device here.
+    /// Only called if FACTORY_DEFAULTS is listed in the supported pids of the config.
+    fn on_factory_reset(&mut self, _context: &mut DmxReceiverContext) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Intercept IDENTIFY_DEVICE requests to implement custom behavior.
+    /// Return None to let the [RdmResponder] handle the request itself.
+    fn handle_identify_device(
+        &mut self,
+        _request: &RdmRequestData,
+        _context: &mut DmxReceiverContext,
+    ) -> Result<Option<RdmResult>, Self::Error> {
+        Ok(None)
+    }
+
+    /// Called when the identify state was changed by an IDENTIFY_DEVICE or FACTORY_DEFAULTS
+    /// set request.
+    /// Use this to start or stop the identification (e.g. flashing an LED).
+    /// This is also called for broadcast requests, even though they don't get a response.
+    /// Firmwares that poll the state instead can use [RdmResponder::is_identifying].
+    fn on_identify_changed(
+        &mut self,
+        _identify: bool,
+        _context: &mut DmxReceiverContext,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Called on a RESET_DEVICE set request after the response has been built.
+    /// Reset the device once the response has been sent.
+    /// Only called if RESET_DEVICE is listed in the supported pids of the config.
+    fn on_reset(
+        &mut self,
+        _reset_type: ResetType,
+        _context: &mut DmxReceiverContext,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Called on a POWER_STATE set request after the response has been built.
+    /// Change the power state once the response has been sent.
+    /// Only called if [POWER_STATE](crate::pids::POWER_STATE) is in the supported pids.
+    fn on_power_state(
+        &mut self,
+        _power_state: PowerState,
+        _context: &mut DmxReceiverContext,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Called on a PERFORM_SELFTEST set request after the response has been built.
+    /// Start the self test, or all self tests if it is
+    /// [RDM_SELF_TEST_ALL](crate::consts::RDM_SELF_TEST_ALL). Stop the running self tests if it is
+    /// [RDM_SELF_TEST_OFF](crate::consts::RDM_SELF_TEST_OFF). Use [RdmResponder::set_self_test]
+    /// once the self test has finished.
+    /// Only called if the self test descriptions are configured.
+    fn on_self_test(
+        &mut self,
+        _self_test: u8,
+        _context: &mut DmxReceiverContext,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Called on a get request of a statistics counter. Return the current value of the counter.
+    /// Only called if the pid of the counter is in the supported pids.
+    fn get_statistics_counter(
+        &mut self,
+        _counter: StatisticsCounter,
+        _context: &mut DmxReceiverContext,
+    ) -> Result<u32, Self::Error> {
+        Ok(0)
+    }
+
+    /// Called on a set request of a statistics counter. Reset the counter to the value.
+    /// Return false if the counter is read-only, which nacks the request with a write protect.
+    /// Only called if the pid of the counter is in the supported pids.
+    fn on_set_statistics_counter(
+        &mut self,
+        _counter: StatisticsCounter,
+        _value: u32,
+        _context: &mut DmxReceiverContext,
+    ) -> Result<bool, Self::Error> {
+        Ok(false)
+    }
+
+    /// Called on a REAL_TIME_CLOCK get request. Return the current date and time of the device.
+    /// Only called if [REAL_TIME_CLOCK](pids::REAL_TIME_CLOCK) is in the supported pids.
+    fn get_clock(
+        &mut self,
+        _context: &mut DmxReceiverContext,
+    ) -> Result<RealTimeClock, Self::Error> {
+        Ok(RealTimeClock::default())
+    }
+
+    /// Called on a REAL_TIME_CLOCK set request. Set the real time clock of the device.
+    /// The date is already validated.
+    /// Only called if [REAL_TIME_CLOCK](pids::REAL_TIME_CLOCK) is in the supported pids.
+    fn on_set_clock(
+        &mut self,
+        _clock: RealTimeClock,
+        _context: &mut DmxReceiverContext,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Called on a CAPTURE_PRESET set request. Store the current state as the scene.
+    /// The scene is between 1 and 0xFFFE. Return false if the scene doesn't exist.
+    /// Only called if [CAPTURE_PRESET](crate::pids::CAPTURE_PRESET) is in the supported pids.
+    fn on_capture_preset(
+        &mut self,
+        _scene: u16,
+        _context: &mut DmxReceiverContext,
+    ) -> Result<bool, Self::Error> {
+        Ok(true)
+    }
+
+    /// Called on a PRESET_PLAYBACK set request. Start the playback of the scene at the level.
+    /// See [PresetPlayback] for the special scene values. Return false if the scene doesn't exist.
+    /// Only called if [PRESET_PLAYBACK](crate::pids::PRESET_PLAYBACK) is in the supported pids.
+    fn on_preset_playback(
+        &mut self,
+        _scene: u16,
+        _level: u8,
+        _context: &mut DmxReceiverContext,
+    ) -> Result<bool, Self::Error> {
+        Ok(true)
+    }
+
+    /// Called when an RDM package with a reserved or unknown command class was received.
+    /// The [u8] is the raw command class. Use this for diagnostics of non-standard bus traffic.
+    fn handle_unknown_command_class(
+        &mut self,
+        _command_class: u8,
+        _context: &mut DmxReceiverContext,
+    ) -> Result<(), Self::Error> {
+        Ok(())
     }
 
     /// Handle all received frames that have a different start code than `0xCC` (the rdm start code).
@@ -126,23 +362,172 @@ pub trait DmxResponderHandler {
     }
 }
 
+/// Passes the rdm callbacks of the [RdmResponderPackageHandler] to a [DmxResponderHandler].
+struct DmxRdmHandlerWrapper<'a, HandlerError> {
+    dmx: &'a mut dyn DmxResponderHandler<Error = HandlerError>,
+}
+
+impl<HandlerError> RdmResponderHandlerFunc for DmxRdmHandlerWrapper<'_, HandlerError> {
+    type Error = HandlerError;
+    fn handle_rdm(
+        &mut self,
+        request: &RdmRequestData,
+        context: &mut DmxReceiverContext,
+    ) -> Result<RdmResult, Self::Error> {
+        self.dmx.handle_rdm(request, context)
+    }
+
+    fn handle_rdm_sub_device(
+        &mut self,
+        sub_device: u16,
+        request: &RdmRequestData,
+        context: &mut DmxReceiverContext,
+    ) -> Result<RdmResult, Self::Error> {
+        self.dmx.handle_rdm_sub_device(sub_device, request, context)
+    }
+
+    fn on_factory_reset(&mut self, context: &mut DmxReceiverContext) -> Result<(), Self::Error> {
+        self.dmx.on_factory_reset(context)
+    }
+
+    fn handle_identify_device(
+        &mut self,
+        request: &RdmRequestData,
+        context: &mut DmxReceiverContext,
+    ) -> Result<Option<RdmResult>, Self::Error> {
+        self.dmx.handle_identify_device(request, context)
+    }
+
+    fn on_identify_changed(
+        &mut self,
+        identify: bool,
+        context: &mut DmxReceiverContext,
+    ) -> Result<(), Self::Error> {
+        self.dmx.on_identify_changed(identify, context)
+    }
+
+    fn on_reset(
+        &mut self,
+        reset_type: ResetType,
+        context: &mut DmxReceiverContext,
+    ) -> Result<(), Self::Error> {
+        self.dmx.on_reset(reset_type, context)
+    }
+
+    fn on_power_state(
+        &mut self,
+        power_state: PowerState,
+        context: &mut DmxReceiverContext,
+    ) -> Result<(), Self::Error> {
+        self.dmx.on_power_state(power_state, context)
+    }
+
+    fn on_self_test(
+        &mut self,
+        self_test: u8,
+        context: &mut DmxReceiverContext,
+    ) -> Result<(), Self::Error> {
+        self.dmx.on_self_test(self_test, context)
+    }
+
+    fn get_statistics_counter(
+        &mut self,
+        counter: StatisticsCounter,
+        context: &mut DmxReceiverContext,
+    ) -> Result<u32, Self::Error> {
+        self.dmx.get_statistics_counter(counter, context)
+    }
+
+    fn on_set_statistics_counter(
+        &mut self,
+        counter: StatisticsCounter,
+        value: u32,
+        context: &mut DmxReceiverContext,
+    ) -> Result<bool, Self::Error> {
+        self.dmx.on_set_statistics_counter(counter, value, context)
+    }
+
+    fn get_clock(
+        &mut self,
+        context: &mut DmxReceiverContext,
+    ) -> Result<RealTimeClock, Self::Error> {
+        self.dmx.get_clock(context)
+    }
+
+    fn on_set_clock(
+        &mut self,
+        clock: RealTimeClock,
+        context: &mut DmxReceiverContext,
+    ) -> Result<(), Self::Error> {
+        self.dmx.on_set_clock(clock, context)
+    }
+
+    fn on_capture_preset(
+        &mut self,
+        scene: u16,
+        context: &mut DmxReceiverContext,
+    ) -> Result<bool, Self::Error> {
+        self.dmx.on_capture_preset(scene, context)
+    }
+
+    fn on_preset_playback(
+        &mut self,
+        scene: u16,
+        level: u8,
+        context: &mut DmxReceiverContext,
+    ) -> Result<bool, Self::Error> {
+        self.dmx.on_preset_playback(scene, level, context)
+    }
+}
+
 /// The structure to build an RDM Receiver.
 /// MQ_SIZE specifies the size of the message queue and the status vector. MQ_SIZE cannot be greater
 /// than 255.
-pub struct RdmResponder<D: DmxReceiver + RdmControllerDriver, const MQ_SIZE: usize> {
+/// SUB_DEVICE_COUNT specifies the amount of sub-devices. SUB_DEVICE_COUNT cannot be greater than 512.
+/// SENSOR_COUNT specifies the maximum amount of sensors. SENSOR_COUNT cannot be greater than 255.
+pub struct RdmResponder<
+    D: DmxReceiver + RdmControllerDriver,
+    const MQ_SIZE: usize,
+    const SUB_DEVICE_COUNT: usize = 0,
+    const SENSOR_COUNT: usize = 0,
+> {
     driver: D,
-    rdm_receiver_handler: RdmResponderPackageHandler<MQ_SIZE>,
+    rdm_receiver_handler: RdmResponderPackageHandler<MQ_SIZE, SUB_DEVICE_COUNT, SENSOR_COUNT>,
+    unknown_command_class_count: u32,
+    foreign_response_count: u32,
+    ignore_foreign_responses: bool,
+    polling_error_status: Option<PollingErrorStatus>,
 }
 
-impl<D: DmxReceiver + RdmControllerDriver, const MQ_SIZE: usize> RdmResponder<D, MQ_SIZE> {
+impl<
+        D: DmxReceiver + RdmControllerDriver,
+        const MQ_SIZE: usize,
+        const SUB_DEVICE_COUNT: usize,
+        const SENSOR_COUNT: usize,
+    > RdmResponder<D, MQ_SIZE, SUB_DEVICE_COUNT, SENSOR_COUNT>
+{
     /// Creates a new [RdmResponder].
     pub fn new(driver: D, config: RdmResponderConfig) -> Self {
         Self {
             driver,
             rdm_receiver_handler: RdmResponderPackageHandler::new(config),
+            unknown_command_class_count: 0,
+            foreign_response_count: 0,
+            ignore_foreign_responses: true,
+            polling_error_status: None,
         }
     }
 
+    /// Get a reference to the underlying driver.
+    pub fn get_driver(&mut self) -> &mut D {
+        &mut self.driver
+    }
+
+    /// Get the uid of the rdm responder.
+    pub fn get_uid(&self) -> UniqueIdentifier {
+        self.rdm_receiver_handler.get_uid()
+    }
+
     /// Call this function as often as you can or on a serial interrupt. It will
     /// receive a package and handle it.
     ///
@@ -150,22 +535,55 @@ impl<D: DmxReceiver + RdmControllerDriver, const MQ_SIZE: usize> RdmResponder<D,
     pub fn poll<HandlerError>(
         &mut self,
         handler: &mut dyn DmxResponderHandler<Error = HandlerError>,
+    ) -> Result<bool, PollingError<D::DriverError, HandlerError>> {
+        let result = self.poll_package(handler);
+
+        if let Err(error) = &result {
+            self.queue_polling_error_status(error);
+        }
+
+        result
+    }
+
+    fn poll_package<HandlerError>(
+        &mut self,
+        handler: &mut dyn DmxResponderHandler<Error = HandlerError>,
     ) -> Result<bool, PollingError<D::DriverError, HandlerError>> {
         let package = match self.driver.receive_package() {
             Err(DmxError::TimeoutError) => return Ok(false),
-            result => result?,
+            result => result.map_err(|error| PollingError::from(error).map_handler_error())?,
+        };
+        let raw_request = if package.first() == Some(&SC_RDM) {
+            Some(package.clone())
+        } else {
+            None
         };
 
-        if package.is_empty() {
-            return Err(PollingError::WrongPackageSize);
-        }
+        let frame = match self.classify_package(package) {
+            Ok(frame) => frame,
+            Err(PollingError::NotMatching) if self.ignore_foreign_responses => return Ok(true),
+            Err(PollingError::DeserializationError(
+                RdmDeserializationError::CommandClassNotFound(command_class),
+            )) => {
+                handler
+                    .handle_unknown_command_class(
+                        command_class,
+                        &mut self.rdm_receiver_handler.get_context(),
+                    )
+                    .map_err(PollingError::HandlerError)?;
 
-        let start_code = package[0];
-        match start_code {
-            SC_RDM => {
-                self.handle_rdm(package, handler)?;
+                return Err(PollingError::DeserializationError(
+                    RdmDeserializationError::CommandClassNotFound(command_class),
+                ));
+            },
+            Err(error) => return Err(error.map_handler_error()),
+        };
+
+        match frame {
+            ReceivedFrame::Rdm(request) => {
+                self.handle_rdm(request, raw_request.as_deref(), handler)?;
             },
-            _ => {
+            ReceivedFrame::Dmx(package) | ReceivedFrame::Custom(_, package) => {
                 handler
                     .handle_dmx(package, &mut self.rdm_receiver_handler.get_context())
                     .map_err(|error| PollingError::HandlerError(error))?;
@@ -175,41 +593,84 @@ impl<D: DmxReceiver + RdmControllerDriver, const MQ_SIZE: usize> RdmResponder<D,
         Ok(true)
     }
 
-    fn handle_rdm<HandlerError>(
+    /// Receives a package and classifies it by its start code without handling it.
+    ///
+    /// This is an alternative to [RdmResponder::poll] for applications that want to handle
+    /// frames imperatively instead of implementing a [DmxResponderHandler].
+    /// RDM requests received this way won't be answered.
+    ///
+    /// Returns None if no package was received.
+    pub fn receive_classified(
+        &mut self,
+    ) -> Result<Option<ReceivedFrame>, PollingError<D::DriverError, Infallible>> {
+        let package = match self.driver.receive_package() {
+            Err(DmxError::TimeoutError) => return Ok(None),
+            result => result?,
+        };
+
+        self.classify_package(package).map(Some)
+    }
+
+    fn classify_package(
         &mut self,
         package: DmxFrame,
-        handler: &mut dyn DmxResponderHandler<Error = HandlerError>,
-    ) -> Result<(), PollingError<D::DriverError, HandlerError>> {
-        struct DmxRdmHandlerWrapper<'a, HandlerError> {
-            dmx: &'a mut dyn DmxResponderHandler<Error = HandlerError>,
+    ) -> Result<ReceivedFrame, PollingError<D::DriverError, Infallible>> {
+        if package.is_empty() {
+            return Err(PollingError::WrongPackageSize);
         }
 
-        impl<HandlerError> RdmResponderHandlerFunc for DmxRdmHandlerWrapper<'_, HandlerError> {
-            type Error = HandlerError;
-            fn handle_rdm(
-                &mut self,
-                request: &RdmRequestData,
-                context: &mut DmxReceiverContext,
-            ) -> Result<RdmResult, Self::Error> {
-                self.dmx.handle_rdm(request, context)
-            }
-        }
+        let start_code = package[0];
+        let frame = match start_code {
+            SC_RDM => {
+                let rdm_data = RdmData::deserialize(&package).map_err(|error| {
+                    self.rdm_receiver_handler
+                        .record_deserialization_error(&error);
+
+                    if let RdmDeserializationError::CommandClassNotFound(_) = error {
+                        self.unknown_command_class_count =
+                            self.unknown_command_class_count.wrapping_add(1);
+                    }
 
-        let rdm_data =
-            RdmData::deserialize(&package).map_err(PollingError::DeserializationError)?;
+                    PollingError::DeserializationError(error)
+                })?;
 
-        let request = match rdm_data {
-            RdmData::Request(request) => request,
-            _ => return Err(PollingError::NotMatching),
+                match rdm_data {
+                    RdmData::Request(request) => ReceivedFrame::Rdm(request),
+                    RdmData::Response(_) => {
+                        self.foreign_response_count = self.foreign_response_count.wrapping_add(1);
+                        return Err(PollingError::NotMatching);
+                    },
+                }
+            },
+            DMX_NULL_START => ReceivedFrame::Dmx(package),
+            _ => ReceivedFrame::Custom(start_code, package),
         };
 
+        Ok(frame)
+    }
+
+    fn handle_rdm<HandlerError>(
+        &mut self,
+        request: RdmRequestData,
+        raw_request: Option<&[u8]>,
+        handler: &mut dyn DmxResponderHandler<Error = HandlerError>,
+    ) -> Result<(), PollingError<D::DriverError, HandlerError>> {
         let response = self
             .rdm_receiver_handler
-            .handle_rdm_request(request, &mut DmxRdmHandlerWrapper { dmx: handler })
+            .handle_rdm_request_with_raw(
+                request,
+                raw_request,
+                &mut DmxRdmHandlerWrapper { dmx: handler },
+            )
             .map_err(PollingError::HandlerError)?;
 
         match response {
             RdmAnswer::Response(response_data) => {
+                let response_delay_us = self.rdm_receiver_handler.get_response_delay_us();
+                if response_delay_us > 0 {
+                    self.driver.delay_response(response_delay_us);
+                }
+
                 self.driver
                     .send_rdm(RdmData::Response(response_data))
                     .map_err(|error| match error {
@@ -218,13 +679,18 @@ impl<D: DmxReceiver + RdmControllerDriver, const MQ_SIZE: usize> RdmResponder<D,
                         DmxError::DeserializationError(deserialization_error) => {
                             PollingError::DeserializationError(deserialization_error)
                         },
+                        DmxError::SerializationError(serialization_error) => {
+                            PollingError::SerializationError(serialization_error)
+                        },
                         DmxError::DriverError(driver_error) => {
                             PollingError::DriverError(driver_error)
                         },
                     })?;
             },
             RdmAnswer::DiscoveryResponse(uid) => {
-                self.driver.send_rdm_discovery_response(uid)?;
+                let preamble_length = self.rdm_receiver_handler.get_discovery_preamble_len();
+                self.driver
+                    .send_rdm_discovery_response_with_preamble(uid, preamble_length)?;
             },
             RdmAnswer::NoResponse => {},
         }
@@ -232,16 +698,253 @@ impl<D: DmxReceiver + RdmControllerDriver, const MQ_SIZE: usize> RdmResponder<D,
         Ok(())
     }
 
+    fn queue_polling_error_status<HandlerError>(
+        &mut self,
+        error: &PollingError<D::DriverError, HandlerError>,
+    ) {
+        let polling_error_status = match self.polling_error_status {
+            Some(polling_error_status) => polling_error_status,
+            None => return,
+        };
+
+        if !matches!(
+            error,
+            PollingError::UartOverflow
+                | PollingError::WrongPackageSize
+                | PollingError::DeserializationError(_)
+        ) {
+            return;
+        }
+
+        let status_vec = self.rdm_receiver_handler.get_status_vec_mut();
+        match status_vec.iter_mut().find(|status_message| {
+            status_message.sub_device_id == 0
+                && status_message.status_message_id == polling_error_status.status_message_id
+        }) {
+            Some(status_message) => {
+                status_message.data_value_1 = status_message.data_value_1.saturating_add(1)
+            },
+            None => {
+                // If the status vector is full the configured policy decides what to report.
+                let _ = self
+                    .rdm_receiver_handler
+                    .queue_status_message(StatusMessage {
+                        sub_device_id: 0,
+                        status_type: polling_error_status.status_type,
+                        status_message_id: polling_error_status.status_message_id,
+                        data_value_1: 1,
+                        data_value_2: 0,
+                    });
+            },
+        }
+    }
+
+    /// Returns the amount of pids that are reported in SUPPORTED_PARAMETERS.
+    pub fn supported_pid_count(&self) -> usize {
+        self.rdm_receiver_handler.supported_pid_count()
+    }
+
+    /// Sends a get request for each of the supported pids to the handler and returns the pids
+    /// that are nacked with [NackReason::UnknownPid] or [NackReason::UnsupportedCommandClass].
+    /// See [RdmResponderPackageHandler::verify_advertised_pids].
+    #[cfg(feature = "std")]
+    pub fn verify_advertised_pids<HandlerError>(
+        &mut self,
+        handler: &mut dyn DmxResponderHandler<Error = HandlerError>,
+    ) -> Result<Vec<crate::rdm_responder::UnhandledPid>, HandlerError> {
+        self.rdm_receiver_handler
+            .verify_advertised_pids(&mut DmxRdmHandlerWrapper { dmx: handler })
+    }
+
+    /// Get the status message that is queued on recoverable polling errors.
+    pub fn get_polling_error_status(&self) -> Option<PollingErrorStatus> {
+        self.polling_error_status
+    }
+
+    /// Set the status message that is queued on recoverable polling errors.
+    /// This is disabled (None) by default.
+    pub fn set_polling_error_status(&mut self, polling_error_status: Option<PollingErrorStatus>) {
+        self.polling_error_status = polling_error_status;
+    }
+
+    /// Get the amount of RDM packages with a reserved or unknown command class
+    /// that have been received.
+    pub fn get_unknown_command_class_count(&self) -> u32 {
+        self.unknown_command_class_count
+    }
+
+    /// Get the amount of RDM responses that have been received. Responses are sent by
+    /// other responders on the bus and are usually ignored.
+    pub fn get_foreign_response_count(&self) -> u32 {
+        self.foreign_response_count
+    }
+
+    /// Set whether [RdmResponder::poll] ignores received RDM responses instead of returning
+    /// [PollingError::NotMatching]. Responses are ignored by default.
+    pub fn set_ignore_foreign_responses(&mut self, ignore_foreign_responses: bool) {
+        self.ignore_foreign_responses = ignore_foreign_responses;
+    }
+
+    /// Get the device label that can be read and written by the controller.
+    pub fn get_device_label(&self) -> &str {
+        self.rdm_receiver_handler.get_device_label()
+    }
+
+    /// Set the device label. Labels longer than 32 bytes will be truncated.
+    pub fn set_device_label(&mut self, device_label: &str) {
+        self.rdm_receiver_handler.set_device_label(device_label)
+    }
+
+    /// Returns true if the write protection is active.
+    pub fn is_write_protected(&self) -> bool {
+        self.rdm_receiver_handler.is_write_protected()
+    }
+
+    /// Activate or deactivate the write protection of the write protected pids.
+    pub fn set_write_protected(&mut self, write_protected: bool) {
+        self.rdm_receiver_handler
+            .set_write_protected(write_protected)
+    }
+
+    /// Returns true if the device is identifying itself.
+    pub fn is_identifying(&self) -> bool {
+        self.rdm_receiver_handler.is_identifying()
+    }
+
+    /// Start or stop the identification of the device.
+    pub fn set_identify(&mut self, identify: bool) {
+        self.rdm_receiver_handler.set_identify(identify)
+    }
+
+    /// Get how the device identifies itself while identifying.
+    pub fn get_identify_mode(&self) -> IdentifyMode {
+        self.rdm_receiver_handler.get_identify_mode()
+    }
+
+    /// Set how the device identifies itself while identifying.
+    pub fn set_identify_mode(&mut self, identify_mode: IdentifyMode) {
+        self.rdm_receiver_handler.set_identify_mode(identify_mode)
+    }
+
+    /// Get the current power state.
+    pub fn get_power_state(&self) -> PowerState {
+        self.rdm_receiver_handler.get_power_state()
+    }
+
+    /// Set the current power state (e.g. if the device was woken up locally).
+    pub fn set_power_state(&mut self, power_state: PowerState) {
+        self.rdm_receiver_handler.set_power_state(power_state)
+    }
+
+    /// Get the self test that is currently performed.
+    /// [RDM_SELF_TEST_OFF](crate::consts::RDM_SELF_TEST_OFF) if no self test is running.
+    pub fn get_self_test(&self) -> u8 {
+        self.rdm_receiver_handler.get_self_test()
+    }
+
+    /// Set the self test that is currently performed.
+    /// Set to [RDM_SELF_TEST_OFF](crate::consts::RDM_SELF_TEST_OFF) once the self test has finished.
+    pub fn set_self_test(&mut self, self_test: u8) {
+        self.rdm_receiver_handler.set_self_test(self_test)
+    }
+
+    /// Get the current dimming curve. Curves are numbered starting at 1.
+    pub fn get_curve(&self) -> u8 {
+        self.rdm_receiver_handler.get_curve()
+    }
+
+    /// Set the current dimming curve. Returns false if the curve doesn't exist.
+    pub fn set_curve(&mut self, curve: u8) -> bool {
+        self.rdm_receiver_handler.set_curve(curve)
+    }
+
+    /// Get the communication error counters (COMMS_STATUS).
+    pub fn get_comms_status(&self) -> CommsStatus {
+        self.rdm_receiver_handler.get_comms_status()
+    }
+
+    /// Get the current preset playback mode.
+    pub fn get_preset_playback(&self) -> PresetPlayback {
+        self.rdm_receiver_handler.get_preset_playback()
+    }
+
+    /// Set the current preset playback mode (e.g. if the playback was started locally).
+    pub fn set_preset_playback(&mut self, preset_playback: PresetPlayback) {
+        self.rdm_receiver_handler
+            .set_preset_playback(preset_playback)
+    }
+
+    /// Get the current personality. Personalities are numbered starting at 1.
+    pub fn get_dmx_personality(&self) -> u8 {
+        self.rdm_receiver_handler.get_dmx_personality()
+    }
+
+    /// Set the current personality and the dmx footprint of the personality.
+    /// Returns false if the personality doesn't exist.
+    pub fn set_dmx_personality(&mut self, personality: u8) -> bool {
+        self.rdm_receiver_handler.set_dmx_personality(personality)
+    }
+
+    /// Get the state of the sub-devices. Sub-device 1 is at index 0.
+    pub fn get_sub_devices(&self) -> &[SubDevice; SUB_DEVICE_COUNT] {
+        self.rdm_receiver_handler.get_sub_devices()
+    }
+
+    /// Get the state of the sub-devices to modify it. Sub-device 1 is at index 0.
+    pub fn get_sub_devices_mut(&mut self) -> &mut [SubDevice; SUB_DEVICE_COUNT] {
+        self.rdm_receiver_handler.get_sub_devices_mut()
+    }
+
+    /// Get the values of the sensors. The index is the sensor number.
+    pub fn get_sensor_values(&self) -> &[SensorValue] {
+        self.rdm_receiver_handler.get_sensor_values()
+    }
+
+    /// Get the values of the sensors to modify them. The index is the sensor number.
+    pub fn get_sensor_values_mut(&mut self) -> &mut [SensorValue] {
+        self.rdm_receiver_handler.get_sensor_values_mut()
+    }
+
+    /// Set the present value of a sensor and update its lowest and highest detected values.
+    /// Returns false if the sensor doesn't exist.
+    pub fn update_sensor_value(&mut self, sensor_number: u8, present_value: i16) -> bool {
+        self.rdm_receiver_handler
+            .update_sensor_value(sensor_number, present_value)
+    }
+
     /// Get the message queue that contains the results of [RdmResult::AcknowledgedTimer] packages.
-    pub fn get_message_queue(&self) -> &heapless::Deque<RdmResponseData, MQ_SIZE> {
+    pub fn get_message_queue(&self) -> &heapless::Vec<QueuedMessage, MQ_SIZE> {
         self.rdm_receiver_handler.get_message_queue()
     }
 
     /// Get the message queue to add the results of [RdmResult::AcknowledgedTimer] packages to.
-    pub fn get_message_queue_mut(&mut self) -> &mut heapless::Deque<RdmResponseData, MQ_SIZE> {
+    /// The messages are sent from the back of the queue, so the last pushed message is sent first.
+    pub fn get_message_queue_mut(&mut self) -> &mut heapless::Vec<QueuedMessage, MQ_SIZE> {
         self.rdm_receiver_handler.get_message_queue_mut()
     }
 
+    /// Add a message to the message queue according to the configured
+    /// [MessageQueueFullPolicy](crate::rdm_responder::MessageQueueFullPolicy).
+    pub fn queue_message(&mut self, message: QueuedMessage) -> Result<(), MessageQueueFullError> {
+        self.rdm_receiver_handler.queue_message(message)
+    }
+
+    /// Add a message to the status vector according to the configured
+    /// [MessageQueueFullPolicy](crate::rdm_responder::MessageQueueFullPolicy).
+    pub fn queue_status_message(
+        &mut self,
+        status_message: StatusMessage,
+    ) -> Result<(), MessageQueueFullError> {
+        self.rdm_receiver_handler
+            .queue_status_message(status_message)
+    }
+
+    /// Get the amount of messages that were dropped since the message queue or the status
+    /// vector was full.
+    pub fn get_dropped_message_count(&self) -> u32 {
+        self.rdm_receiver_handler.get_dropped_message_count()
+    }
+
     /// Get the amount of queued messages.
     pub fn get_message_count(&self) -> u8 {
         self.rdm_receiver_handler.get_message_count()
@@ -257,3 +960,535 @@ impl<D: DmxReceiver + RdmControllerDriver, const MQ_SIZE: usize> RdmResponder<D,
         self.rdm_receiver_handler.get_status_vec_mut()
     }
 }
+
+#[cfg(test)]
+#[cfg_attr(not(feature = "std"), allow(unused_imports))]
+mod tests {
+    use crate::command_class::RequestCommandClass;
+    use crate::command_class::ResponseCommandClass;
+    use crate::consts::{DMX_NULL_START, SC_RDM, SC_SUB_MESSAGE};
+    use crate::dmx_receiver::{
+        DmxFrame, DmxFrameExt, DmxResponderHandler, PollingError, PollingErrorStatus, RdmResponder,
+        ReceivedFrame,
+    };
+    use crate::dmx_uart_driver::{
+        DmxRecvUartDriver, DmxRespUartDriver, DmxUartDriver, DmxUartDriverError,
+    };
+    use crate::pids;
+    use crate::rdm_data::{RdmData, RdmDeserializationError, RdmRequestData, RdmResponseData};
+    use crate::rdm_responder::{DmxReceiverContext, RdmResponderConfig, RdmResult};
+    use crate::rdm_types::{CommsStatus, DmxPersonalityDescription, StatusType};
+    use crate::types::{DataPack, ResponseType};
+    use crate::unique_identifier::{PackageAddress, UniqueIdentifier};
+
+    #[cfg(feature = "std")]
+    struct FrameDriver {
+        frame: Vec<u8>,
+        position: usize,
+        /// A mock clock that only advances on delays.
+        elapsed_us: u32,
+        /// The time of the clock at each written frame.
+        write_times_us: Vec<u32>,
+    }
+
+    #[cfg(feature = "std")]
+    impl FrameDriver {
+        fn new(frame: &[u8]) -> Self {
+            Self {
+                frame: frame.to_vec(),
+                position: 0,
+                elapsed_us: 0,
+                write_times_us: Vec::new(),
+            }
+        }
+
+        fn read(&mut self, buffer: &mut [u8]) -> Result<usize, DmxUartDriverError<()>> {
+            let bytes_left = self.frame.len() - self.position;
+            if bytes_left == 0 {
+                return Err(DmxUartDriverError::TimeoutError);
+            }
+
+            let bytes_read = bytes_left.min(buffer.len());
+            buffer[..bytes_read]
+                .copy_from_slice(&self.frame[self.position..self.position + bytes_read]);
+            self.position += bytes_read;
+
+            Ok(bytes_read)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl DmxUartDriver for FrameDriver {
+        type DriverError = ();
+
+        fn delay_us(&mut self, delay_us: u32) {
+            self.elapsed_us += delay_us;
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl DmxRecvUartDriver for FrameDriver {
+        fn read_frames(
+            &mut self,
+            buffer: &mut [u8],
+            _: u32,
+        ) -> Result<usize, DmxUartDriverError<()>> {
+            self.read(buffer)
+        }
+
+        fn read_frames_no_break(
+            &mut self,
+            buffer: &mut [u8],
+            _: u32,
+        ) -> Result<usize, DmxUartDriverError<()>> {
+            match self.read(buffer) {
+                Err(DmxUartDriverError::TimeoutError) => Ok(0),
+                result => result,
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl DmxRespUartDriver for FrameDriver {
+        fn write_frames(&mut self, buffer: &[u8]) -> Result<usize, DmxUartDriverError<()>> {
+            self.write_times_us.push(self.elapsed_us);
+            Ok(buffer.len())
+        }
+
+        fn write_frames_no_break(
+            &mut self,
+            buffer: &[u8],
+        ) -> Result<usize, DmxUartDriverError<()>> {
+            Ok(buffer.len())
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn build_responder(frame: &[u8]) -> RdmResponder<FrameDriver, 4> {
+        RdmResponder::new(FrameDriver::new(frame), build_config())
+    }
+
+    #[cfg(feature = "std")]
+    fn build_config() -> RdmResponderConfig {
+        RdmResponderConfig {
+            uid: UniqueIdentifier::new(0x7FF0, 1).unwrap(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_dmx_frame_ext() {
+        let mut full_frame = DmxFrame::new();
+        full_frame.push(DMX_NULL_START).unwrap();
+        full_frame.extend((0..512).map(|channel| channel as u8));
+        assert_eq!(full_frame.start_code(), DMX_NULL_START);
+        assert_eq!(full_frame.channels().len(), 512);
+        assert_eq!(full_frame.channel(0), None);
+        assert_eq!(full_frame.channel(1), Some(0));
+        assert_eq!(full_frame.channel(512), Some(255));
+        assert_eq!(full_frame.channel(513), None);
+
+        let short_frame = DmxFrame::from_slice(&[0x17, 0x01, 0x02]).unwrap();
+        assert_eq!(short_frame.start_code(), 0x17);
+        assert_eq!(short_frame.channels(), &[0x01, 0x02]);
+        assert_eq!(short_frame.channel(2), Some(0x02));
+        assert_eq!(short_frame.channel(3), None);
+
+        let empty_frame = DmxFrame::new();
+        assert_eq!(empty_frame.start_code(), DMX_NULL_START);
+        assert!(empty_frame.channels().is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_receive_classified() {
+        let mut responder = build_responder(&[0x00, 1, 2, 3]);
+        match responder.receive_classified().unwrap() {
+            Some(ReceivedFrame::Dmx(frame)) => assert_eq!(&frame[..], &[0x00, 1, 2, 3]),
+            _ => panic!("expected dmx frame"),
+        }
+        assert!(responder.receive_classified().unwrap().is_none());
+
+        let mut responder = build_responder(&[0x17, 4, 5]);
+        match responder.receive_classified().unwrap() {
+            Some(ReceivedFrame::Custom(0x17, frame)) => assert_eq!(&frame[..], &[0x17, 4, 5]),
+            _ => panic!("expected custom frame"),
+        }
+
+        let request = RdmData::Request(RdmRequestData {
+            destination_uid: PackageAddress::Device(UniqueIdentifier::new(0x7FF0, 1).unwrap()),
+            source_uid: UniqueIdentifier::new(0x7FF0, 0).unwrap(),
+            transaction_number: 3,
+            port_id: 1,
+            message_count: 0,
+            sub_device: 0,
+            command_class: RequestCommandClass::GetCommand,
+            parameter_id: 0x1000,
+            parameter_data: DataPack::new(),
+        });
+        let mut responder = build_responder(&request.serialize());
+        match responder.receive_classified().unwrap() {
+            Some(ReceivedFrame::Rdm(request)) => {
+                assert_eq!(request.transaction_number, 3);
+                assert_eq!(request.parameter_id, 0x1000);
+            },
+            _ => panic!("expected rdm request"),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_unknown_command_class_is_counted() {
+        let request = RdmData::Request(RdmRequestData {
+            destination_uid: PackageAddress::Device(UniqueIdentifier::new(0x7FF0, 1).unwrap()),
+            source_uid: UniqueIdentifier::new(0x7FF0, 0).unwrap(),
+            transaction_number: 0,
+            port_id: 1,
+            message_count: 0,
+            sub_device: 0,
+            command_class: RequestCommandClass::GetCommand,
+            parameter_id: 0x1000,
+            parameter_data: DataPack::new(),
+        });
+
+        // patch the command class and fix the checksum
+        let mut frame = request.serialize();
+        frame[20] = 0x40;
+        let checksum_index = frame.len() - 2;
+        let checksum = frame[..checksum_index]
+            .iter()
+            .fold(0u16, |checksum, byte| checksum.wrapping_add(*byte as u16));
+        frame[checksum_index..].copy_from_slice(&checksum.to_be_bytes());
+
+        let mut responder = build_responder(&frame);
+        assert_eq!(responder.get_unknown_command_class_count(), 0);
+
+        match responder.receive_classified() {
+            Err(PollingError::DeserializationError(
+                RdmDeserializationError::CommandClassNotFound(0x40),
+            )) => {},
+            _ => panic!("expected unknown command class"),
+        }
+        assert_eq!(responder.get_unknown_command_class_count(), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_raw_request_in_context() {
+        struct RawRequestHandler {
+            raw_request: Option<Vec<u8>>,
+        }
+
+        impl DmxResponderHandler for RawRequestHandler {
+            type Error = ();
+
+            fn handle_rdm(
+                &mut self,
+                _: &RdmRequestData,
+                context: &mut DmxReceiverContext,
+            ) -> Result<RdmResult, Self::Error> {
+                self.raw_request = context.raw_request.map(|raw_request| raw_request.to_vec());
+                Ok(RdmResult::Acknowledged(DataPack::new()))
+            }
+        }
+
+        let request = RdmData::Request(RdmRequestData {
+            destination_uid: PackageAddress::Device(UniqueIdentifier::new(0x7FF0, 1).unwrap()),
+            source_uid: UniqueIdentifier::new(0x7FF0, 0).unwrap(),
+            transaction_number: 7,
+            port_id: 1,
+            message_count: 0,
+            sub_device: 0,
+            command_class: RequestCommandClass::GetCommand,
+            parameter_id: 0x8000,
+            parameter_data: DataPack::from_slice(&[1, 2, 3]).unwrap(),
+        });
+        let frame = request.serialize();
+
+        let mut responder = build_responder(&frame);
+        let mut handler = RawRequestHandler { raw_request: None };
+        assert!(responder.poll(&mut handler).unwrap());
+        assert_eq!(handler.raw_request.as_deref(), Some(&frame[..]));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_polling_errors_queue_status_message() {
+        struct EmptyHandler;
+
+        impl DmxResponderHandler for EmptyHandler {
+            type Error = ();
+        }
+
+        let request = RdmData::Request(RdmRequestData {
+            destination_uid: PackageAddress::Device(UniqueIdentifier::new(0x7FF0, 1).unwrap()),
+            source_uid: UniqueIdentifier::new(0x7FF0, 0).unwrap(),
+            transaction_number: 0,
+            port_id: 1,
+            message_count: 0,
+            sub_device: 0,
+            command_class: RequestCommandClass::GetCommand,
+            parameter_id: 0x1000,
+            parameter_data: DataPack::new(),
+        });
+
+        // two packages with a broken checksum
+        let mut frame = request.serialize().to_vec();
+        let checksum_index = frame.len() - 1;
+        frame[checksum_index] ^= 0xFF;
+        let frames = [frame.clone(), frame].concat();
+
+        let mut responder = build_responder(&frames);
+        responder.set_polling_error_status(Some(PollingErrorStatus {
+            status_type: StatusType::StatusWarning,
+            status_message_id: 0x8001,
+        }));
+
+        assert!(responder.poll(&mut EmptyHandler).is_err());
+        assert!(responder.poll(&mut EmptyHandler).is_err());
+        assert!(!responder.poll(&mut EmptyHandler).unwrap());
+
+        let status_vec = responder.get_status_vec();
+        assert_eq!(status_vec.len(), 1);
+        assert_eq!(status_vec[0].status_type, StatusType::StatusWarning);
+        assert_eq!(status_vec[0].status_message_id, 0x8001);
+        assert_eq!(status_vec[0].data_value_1, 2);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_corrupted_frame_counts_comms_status() {
+        struct EmptyHandler;
+
+        impl DmxResponderHandler for EmptyHandler {
+            type Error = ();
+        }
+
+        let request = RdmData::Request(RdmRequestData {
+            destination_uid: PackageAddress::Device(UniqueIdentifier::new(0x7FF0, 1).unwrap()),
+            source_uid: UniqueIdentifier::new(0x7FF0, 0).unwrap(),
+            transaction_number: 0,
+            port_id: 1,
+            message_count: 0,
+            sub_device: 0,
+            command_class: RequestCommandClass::GetCommand,
+            parameter_id: 0x1000,
+            parameter_data: DataPack::new(),
+        });
+
+        let mut frame = request.serialize().to_vec();
+        let checksum_index = frame.len() - 1;
+        frame[checksum_index] ^= 0xFF;
+
+        let mut responder = build_responder(&frame);
+        assert_eq!(responder.get_comms_status(), CommsStatus::default());
+
+        assert!(matches!(
+            responder.poll(&mut EmptyHandler),
+            Err(PollingError::DeserializationError(
+                RdmDeserializationError::WrongChecksum { .. }
+            ))
+        ));
+        assert_eq!(
+            responder.get_comms_status(),
+            CommsStatus {
+                short_message: 0,
+                length_mismatch: 0,
+                checksum_fail: 1,
+            }
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_invalid_message_length() {
+        struct EmptyHandler;
+
+        impl DmxResponderHandler for EmptyHandler {
+            type Error = ();
+        }
+
+        let mut responder = build_responder(&[SC_RDM, SC_SUB_MESSAGE, 0]);
+
+        assert!(matches!(
+            responder.poll(&mut EmptyHandler),
+            Err(PollingError::DeserializationError(
+                RdmDeserializationError::WrongMessageLength(2)
+            ))
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_response_delay() {
+        struct EmptyHandler;
+
+        impl DmxResponderHandler for EmptyHandler {
+            type Error = ();
+        }
+
+        let request = RdmData::Request(RdmRequestData {
+            destination_uid: PackageAddress::Device(UniqueIdentifier::new(0x7FF0, 1).unwrap()),
+            source_uid: UniqueIdentifier::new(0x7FF0, 0).unwrap(),
+            transaction_number: 0,
+            port_id: 1,
+            message_count: 0,
+            sub_device: 0,
+            command_class: RequestCommandClass::GetCommand,
+            parameter_id: pids::IDENTIFY_DEVICE,
+            parameter_data: DataPack::new(),
+        });
+        let frame = request.serialize().to_vec();
+        let frames = [frame.clone(), frame].concat();
+
+        let mut responder = RdmResponder::<_, 4>::new(
+            FrameDriver::new(&frames),
+            RdmResponderConfig {
+                response_delay_us: 500,
+                ..build_config()
+            },
+        );
+        assert!(responder.poll(&mut EmptyHandler).unwrap());
+        assert!(responder.poll(&mut EmptyHandler).unwrap());
+
+        // every response is sent after its delay has passed
+        assert_eq!(responder.get_driver().write_times_us, [500, 1000]);
+
+        let mut responder = build_responder(&request.serialize());
+        assert!(responder.poll(&mut EmptyHandler).unwrap());
+        assert_eq!(responder.get_driver().write_times_us, [0]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_foreign_responses_are_ignored() {
+        struct EmptyHandler;
+
+        impl DmxResponderHandler for EmptyHandler {
+            type Error = ();
+        }
+
+        let response = RdmData::Response(RdmResponseData {
+            destination_uid: PackageAddress::Device(UniqueIdentifier::new(0x7FF0, 0).unwrap()),
+            source_uid: UniqueIdentifier::new(0x7FF0, 2).unwrap(),
+            transaction_number: 0,
+            response_type: ResponseType::ResponseTypeAck,
+            message_count: 0,
+            sub_device: 0,
+            command_class: ResponseCommandClass::GetCommandResponse,
+            parameter_id: 0x1000,
+            parameter_data: DataPack::from_slice(&[0]).unwrap(),
+        });
+        let frame = response.serialize().to_vec();
+        let frames = [frame.clone(), frame].concat();
+
+        let mut responder = build_responder(&frames);
+        responder.set_polling_error_status(Some(PollingErrorStatus {
+            status_type: StatusType::StatusWarning,
+            status_message_id: 0x8001,
+        }));
+
+        assert!(responder.poll(&mut EmptyHandler).unwrap());
+        assert_eq!(responder.get_foreign_response_count(), 1);
+        assert!(responder.get_status_vec().is_empty());
+
+        responder.set_ignore_foreign_responses(false);
+        assert!(matches!(
+            responder.poll(&mut EmptyHandler),
+            Err(PollingError::NotMatching)
+        ));
+        assert_eq!(responder.get_foreign_response_count(), 2);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_identify_accessor() {
+        struct EmptyHandler;
+
+        impl DmxResponderHandler for EmptyHandler {
+            type Error = ();
+        }
+
+        let request = RdmData::Request(RdmRequestData {
+            destination_uid: PackageAddress::Device(UniqueIdentifier::new(0x7FF0, 1).unwrap()),
+            source_uid: UniqueIdentifier::new(0x7FF0, 0).unwrap(),
+            transaction_number: 0,
+            port_id: 1,
+            message_count: 0,
+            sub_device: 0,
+            command_class: RequestCommandClass::SetCommand,
+            parameter_id: pids::IDENTIFY_DEVICE,
+            parameter_data: DataPack::from_slice(&[0x01]).unwrap(),
+        });
+
+        let mut responder = build_responder(&request.serialize());
+        assert!(!responder.is_identifying());
+
+        assert!(responder.poll(&mut EmptyHandler).unwrap());
+        assert!(responder.is_identifying());
+
+        responder.set_identify(false);
+        assert!(!responder.is_identifying());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_device_label_accessor() {
+        struct EmptyHandler;
+
+        impl DmxResponderHandler for EmptyHandler {
+            type Error = ();
+        }
+
+        let request = RdmData::Request(RdmRequestData {
+            destination_uid: PackageAddress::Device(UniqueIdentifier::new(0x7FF0, 1).unwrap()),
+            source_uid: UniqueIdentifier::new(0x7FF0, 0).unwrap(),
+            transaction_number: 0,
+            port_id: 1,
+            message_count: 0,
+            sub_device: 0,
+            command_class: RequestCommandClass::SetCommand,
+            parameter_id: pids::DEVICE_LABEL,
+            parameter_data: DataPack::from_slice(b"Stage Left").unwrap(),
+        });
+
+        let mut responder = build_responder(&request.serialize());
+        responder.set_device_label("Stage Right");
+        assert_eq!(responder.get_device_label(), "Stage Right");
+
+        assert!(responder.poll(&mut EmptyHandler).unwrap());
+        assert_eq!(responder.get_device_label(), "Stage Left");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_dmx_personality_accessor() {
+        static DMX_PERSONALITIES: [DmxPersonalityDescription<&str>; 2] = [
+            DmxPersonalityDescription {
+                personality: 1,
+                dmx_footprint: 3,
+                description: "RGB",
+            },
+            DmxPersonalityDescription {
+                personality: 2,
+                dmx_footprint: 4,
+                description: "RGBW",
+            },
+        ];
+
+        let mut responder: RdmResponder<FrameDriver, 4> = RdmResponder::new(
+            FrameDriver::new(&[]),
+            RdmResponderConfig {
+                dmx_personalities: &DMX_PERSONALITIES,
+                ..build_config()
+            },
+        );
+        assert_eq!(responder.get_dmx_personality(), 1);
+
+        assert!(responder.set_dmx_personality(2));
+        assert_eq!(responder.get_dmx_personality(), 2);
+
+        assert!(!responder.set_dmx_personality(3));
+        assert_eq!(responder.get_dmx_personality(), 2);
+    }
+}
@@ -1,3 +1,5 @@
+use crate::timing::DmxTiming;
+
 #[derive(Debug)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum DmxUartDriverError<E> {
@@ -29,6 +31,118 @@ impl<E> From<E> for DmxUartDriverError<E> {
 
 pub trait DmxUartDriver {
     type DriverError;
+
+    /// Returns the timing the driver should use on the bus.
+    ///
+    /// Drivers that generate the break themselves should use the break and mark after break
+    /// of the returned timing. Use [TimedUartDriver] to change the timing of an existing driver.
+    fn get_timing(&self) -> DmxTiming {
+        DmxTiming::DEFAULT
+    }
+
+    /// Blocks for the given amount of microseconds.
+    ///
+    /// Drivers that can't wait should keep the default implementation, which returns immediately.
+    fn delay_us(&mut self, _delay_us: u32) {}
+
+    /// Blocks until all written bytes have been sent on the bus.
+    ///
+    /// Drivers that don't buffer should keep the default implementation, which returns immediately.
+    fn flush(&mut self) -> Result<(), DmxUartDriverError<Self::DriverError>> {
+        Ok(())
+    }
+}
+
+/// Wraps a uart driver and overrides its [DmxTiming].
+#[derive(Debug)]
+pub struct TimedUartDriver<D> {
+    driver: D,
+    timing: DmxTiming,
+}
+
+impl<D: DmxUartDriver> TimedUartDriver<D> {
+    /// Creates a new [TimedUartDriver] that uses the specified timing.
+    pub fn new(driver: D, timing: DmxTiming) -> Self {
+        Self { driver, timing }
+    }
+
+    /// Returns the wrapped driver.
+    pub fn get_driver(&mut self) -> &mut D {
+        &mut self.driver
+    }
+
+    /// Changes the timing.
+    pub fn set_timing(&mut self, timing: DmxTiming) {
+        self.timing = timing;
+    }
+
+    /// Consumes the wrapper and returns the wrapped driver.
+    pub fn into_inner(self) -> D {
+        self.driver
+    }
+}
+
+impl<D: DmxUartDriver> DmxUartDriver for TimedUartDriver<D> {
+    type DriverError = D::DriverError;
+
+    fn get_timing(&self) -> DmxTiming {
+        self.timing
+    }
+
+    fn delay_us(&mut self, delay_us: u32) {
+        self.driver.delay_us(delay_us)
+    }
+
+    fn flush(&mut self) -> Result<(), DmxUartDriverError<Self::DriverError>> {
+        self.driver.flush()
+    }
+}
+
+impl<D: DmxRecvUartDriver> DmxRecvUartDriver for TimedUartDriver<D> {
+    fn read_frames(
+        &mut self,
+        buffer: &mut [u8],
+        timeout_us: u32,
+    ) -> Result<usize, DmxUartDriverError<Self::DriverError>> {
+        self.driver.read_frames(buffer, timeout_us)
+    }
+
+    fn read_frames_no_break(
+        &mut self,
+        buffer: &mut [u8],
+        timeout_us: u32,
+    ) -> Result<usize, DmxUartDriverError<Self::DriverError>> {
+        self.driver.read_frames_no_break(buffer, timeout_us)
+    }
+
+    fn read_frames_no_break_with_inter_byte_timeout(
+        &mut self,
+        buffer: &mut [u8],
+        timeout_us: u32,
+        inter_byte_timeout_us: u32,
+    ) -> Result<usize, DmxUartDriverError<Self::DriverError>> {
+        self.driver.read_frames_no_break_with_inter_byte_timeout(
+            buffer,
+            timeout_us,
+            inter_byte_timeout_us,
+        )
+    }
+}
+
+impl<D: DmxRespUartDriver> DmxRespUartDriver for TimedUartDriver<D> {
+    fn write_frames(
+        &mut self,
+        buffer: &[u8],
+    ) -> Result<usize, DmxUartDriverError<Self::DriverError>> {
+        self.driver.write_frames(buffer)
+    }
+
+    fn write_frames_no_break(
+        &mut self,
+        buffer: &[u8],
+    ) -> Result<usize, DmxUartDriverError<Self::DriverError>> {
+        self.driver.write_frames_no_break(buffer)
+    }
 }
 
 /// Object to implement access to the uart.
@@ -50,6 +164,22 @@ pub trait DmxRecvUartDriver: DmxUartDriver {
         buffer: &mut [u8],
         timeout_us: u32,
     ) -> Result<usize, DmxUartDriverError<Self::DriverError>>;
+
+    /// Read frames without waiting for break and stop reading as soon as the gap between
+    /// two bytes exceeds `inter_byte_timeout_us`.
+    /// Returns the number of bytes actually read.
+    ///
+    /// Drivers that can't measure the gap between two bytes should keep the default
+    /// implementation, which ignores the inter byte timeout and calls
+    /// [DmxRecvUartDriver::read_frames_no_break].
+    fn read_frames_no_break_with_inter_byte_timeout(
+        &mut self,
+        buffer: &mut [u8],
+        timeout_us: u32,
+        _inter_byte_timeout_us: u32,
+    ) -> Result<usize, DmxUartDriverError<Self::DriverError>> {
+        self.read_frames_no_break(buffer, timeout_us)
+    }
 }
 
 /// Object to implement access to the uart.
@@ -70,3 +200,51 @@ pub trait DmxRespUartDriver: DmxUartDriver {
         buffer: &[u8],
     ) -> Result<usize, DmxUartDriverError<Self::DriverError>>;
 }
+
+/// Async counterpart of [DmxRecvUartDriver] for executors like Embassy or RTIC.
+/// It can read frames.
+/// It has to communicate at 250000 baud.
+#[cfg(feature = "async")]
+#[allow(async_fn_in_trait)]
+pub trait AsyncDmxRecvUartDriver: DmxUartDriver {
+    /// Read frames (used for rdm discovery response).
+    /// Returns the number of bytes actually read.
+    async fn read_frames(
+        &mut self,
+        buffer: &mut [u8],
+        timeout_us: u32,
+    ) -> Result<usize, DmxUartDriverError<Self::DriverError>>;
+
+    /// Read frames without waiting for break.
+    /// Returns the number of bytes actually read.
+    async fn read_frames_no_break(
+        &mut self,
+        buffer: &mut [u8],
+        timeout_us: u32,
+    ) -> Result<usize, DmxUartDriverError<Self::DriverError>>;
+}
+
+/// Async counterpart of [DmxRespUartDriver] for executors like Embassy or RTIC.
+/// It can write frames.
+/// It has to communicate at 250000 baud.
+#[cfg(feature = "async")]
+#[allow(async_fn_in_trait)]
+pub trait AsyncDmxRespUartDriver: DmxUartDriver {
+    /// Write dmx frames with break.
+    /// Returns the number of bytes actually written.
+    async fn write_frames(
+        &mut self,
+        buffer: &[u8],
+    ) -> Result<usize, DmxUartDriverError<Self::DriverError>>;
+
+    /// Write dmx frames without break (used for rdm discovery response).
+    /// Returns the number of bytes actually written.
+    async fn write_frames_no_break(
+        &mut self,
+        buffer: &[u8],
+    ) -> Result<usize, DmxUartDriverError<Self::DriverError>>;
+
+    /// Waits for the given amount of microseconds before an RDM response is sent.
+    /// Drivers that can't wait should keep the default implementation, which returns immediately.
+    async fn delay_response(&mut self, _delay_us: u32) {}
+}
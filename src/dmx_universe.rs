@@ -0,0 +1,145 @@
+use crate::consts::DMX_NULL_START;
+use crate::dmx_receiver::DmxFrame;
+use core::ops::Range;
+
+/// The amount of channels in a DMX512 universe.
+pub const DMX_UNIVERSE_SIZE: usize = 512;
+
+/// Raised if a channel is not between 1 and 512. The [u16] is the invalid channel.
+#[derive(Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct InvalidChannelError(pub u16);
+
+impl core::fmt::Display for InvalidChannelError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Channel {} is not between 1 and 512.", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidChannelError {}
+
+/// Builds a full DMX512 frame including the null start code from a sparse channel map.
+/// The channels start at 1. Channels that aren't specified are set to 0. If a channel
+/// is specified multiple times, the last value is used.
+pub fn build_dmx_frame(
+    channels: impl IntoIterator<Item = (u16, u8)>,
+) -> Result<DmxFrame, InvalidChannelError> {
+    let mut frame = DmxFrame::new();
+    frame.push(DMX_NULL_START).unwrap();
+    frame.resize(DMX_UNIVERSE_SIZE + 1, 0).unwrap();
+
+    for (channel, value) in channels {
+        if !(1..=DMX_UNIVERSE_SIZE as u16).contains(&channel) {
+            return Err(InvalidChannelError(channel));
+        }
+
+        frame[channel as usize] = value;
+    }
+
+    Ok(frame)
+}
+
+/// A snapshot of the 512 channels of a DMX512 universe, excluding the start code.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DmxUniverse {
+    channels: [u8; DMX_UNIVERSE_SIZE],
+}
+
+impl Default for DmxUniverse {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DmxUniverse {
+    /// Creates a universe with all channels set to 0.
+    pub const fn new() -> Self {
+        Self {
+            channels: [0; DMX_UNIVERSE_SIZE],
+        }
+    }
+
+    /// Creates a universe from a slice. Missing channels are set to 0.
+    /// Returns None if the slice is longer than 512 bytes.
+    pub fn from_slice(channels: &[u8]) -> Option<Self> {
+        if channels.len() > DMX_UNIVERSE_SIZE {
+            return None;
+        }
+
+        let mut universe = Self::new();
+        universe.channels[..channels.len()].copy_from_slice(channels);
+
+        Some(universe)
+    }
+
+    /// Get the value of a channel. The channel index starts at 0.
+    pub fn get_channel(&self, index: usize) -> Option<u8> {
+        self.channels.get(index).copied()
+    }
+
+    /// Set the value of a channel. The channel index starts at 0.
+    /// Panics if the index is out of range.
+    pub fn set_channel(&mut self, index: usize, value: u8) {
+        self.channels[index] = value;
+    }
+
+    /// Get all channels.
+    pub fn as_slice(&self) -> &[u8] {
+        &self.channels
+    }
+
+    /// Get all channels to modify them.
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.channels
+    }
+
+    /// Returns the range of channel indexes that differ from the previous snapshot
+    /// or None if nothing changed.
+    pub fn changed_since(&self, prev: &DmxUniverse) -> Option<Range<usize>> {
+        let first_changed = self
+            .channels
+            .iter()
+            .zip(prev.channels.iter())
+            .position(|(current, previous)| current != previous)?;
+        let last_changed = self
+            .channels
+            .iter()
+            .zip(prev.channels.iter())
+            .rposition(|(current, previous)| current != previous)?;
+
+        Some(first_changed..last_changed + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmx_universe::{build_dmx_frame, DmxUniverse, InvalidChannelError};
+
+    #[test]
+    fn test_changed_since() {
+        let previous = DmxUniverse::new();
+        let mut current = previous.clone();
+        assert_eq!(current.changed_since(&previous), None);
+
+        current.set_channel(3, 255);
+        current.set_channel(10, 1);
+        assert_eq!(current.changed_since(&previous), Some(3..11));
+    }
+
+    #[test]
+    fn test_build_dmx_frame() {
+        let frame = build_dmx_frame([(1, 10), (512, 20), (1, 30)]).unwrap();
+        assert_eq!(frame.len(), 513);
+        assert_eq!(frame[0], 0x00);
+        assert_eq!(frame[1], 30);
+        assert_eq!(frame[512], 20);
+        assert!(frame[2..512].iter().all(|value| *value == 0));
+
+        assert_eq!(build_dmx_frame([(0, 1)]), Err(InvalidChannelError(0)));
+        assert_eq!(
+            build_dmx_frame([(1, 1), (513, 1)]),
+            Err(InvalidChannelError(513))
+        );
+    }
+}
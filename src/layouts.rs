@@ -33,3 +33,38 @@ binary_layout::binary_layout!(rdm_device_info_layout, BigEndian, {
     sub_device_count: u16,
     sensor_count: u8,
 });
+
+binary_layout::binary_layout!(rdm_parameter_description_layout, BigEndian, {
+    pid: u16,
+    pdl_size: u8,
+    data_type: u8,
+    command_class: u8,
+    parameter_type: u8,
+    unit: u8,
+    prefix: u8,
+    min_valid_value: u32,
+    max_valid_value: u32,
+    default_value: u32,
+    description: [u8],
+});
+
+binary_layout::binary_layout!(rdm_sensor_definition_layout, BigEndian, {
+    sensor_number: u8,
+    sensor_type: u8,
+    unit: u8,
+    prefix: u8,
+    range_min: i16,
+    range_max: i16,
+    normal_min: i16,
+    normal_max: i16,
+    recorded_value_support: u8,
+    description: [u8],
+});
+
+binary_layout::binary_layout!(rdm_sensor_value_layout, BigEndian, {
+    sensor_number: u8,
+    present_value: i16,
+    lowest_detected_value: i16,
+    highest_detected_value: i16,
+    recorded_value: i16,
+});
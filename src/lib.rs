@@ -56,63 +56,35 @@
 //! ## Responder
 //!
 //! ```rust
-//! use dmx_rdm::command_class::RequestCommandClass;
 //! use dmx_rdm::dmx_receiver::{DmxResponderHandler, RdmResponder};
 //! use dmx_rdm::rdm_data::RdmRequestData;
 //! use dmx_rdm::rdm_responder::{DmxReceiverContext, RdmResponderConfig, RdmResult};
-//! use dmx_rdm::types::{DataPack, NackReason};
+//! use dmx_rdm::types::NackReason;
 //! use dmx_rdm::unique_identifier::UniqueIdentifier;
 //! use dmx_rdm_ftdi::{FtdiDriver, FtdiDriverConfig};
 //!
-//! struct RdmHandler {
-//!     identify: bool,
-//! }
-//!
-//! const PID_IDENTIFY_DEVICE: u16 = 0x1000;
-//!
-//! impl RdmHandler {
-//!     fn handle_get_identify(&self) -> RdmResult {
-//!         RdmResult::Acknowledged(DataPack::from_slice(&[self.identify as u8]).unwrap())
-//!     }
-//!
-//!     fn handle_set_identify(&mut self, parameter_data: &[u8]) -> Result<RdmResult, std::fmt::Error> {
-//!         // Check if the parameter data has the correct size
-//!         if parameter_data.len() != 1 {
-//!             return Ok(RdmResult::NotAcknowledged(
-//!                 NackReason::DataOutOfRange as u16,
-//!             ));
-//!         }
-//!
-//!         // Convert identify flag to bool and set that in the state.
-//!         self.identify = parameter_data[0] != 0;
-//!
-//!         println!("Current identify is {}", self.identify);
-//!
-//!         // Acknowledge request with an empty response.
-//!         Ok(RdmResult::Acknowledged(DataPack::new()))
-//!     }
-//! }
+//! struct RdmHandler;
 //!
 //! impl DmxResponderHandler for RdmHandler {
 //!     type Error = std::fmt::Error;
 //!
 //!     fn handle_rdm(
 //!         &mut self,
-//!         request: &RdmRequestData,
+//!         _: &RdmRequestData,
 //!         _: &mut DmxReceiverContext,
 //!     ) -> Result<RdmResult, Self::Error> {
-//!         match request.parameter_id {
-//!             PID_IDENTIFY_DEVICE => match request.command_class {
-//!                 RequestCommandClass::GetCommand => Ok(self.handle_get_identify()),
-//!                 RequestCommandClass::SetCommand => {
-//!                     self.handle_set_identify(&request.parameter_data)
-//!                 },
-//!                 _ => Ok(RdmResult::NotAcknowledged(
-//!                     NackReason::UnsupportedCommandClass as u16,
-//!                 )),
-//!             },
-//!             _ => Ok(RdmResult::NotAcknowledged(NackReason::UnknownPid as u16)),
-//!         }
+//!         Ok(RdmResult::NotAcknowledged(u16::from(NackReason::UnknownPid)))
+//!     }
+//!
+//!     // IDENTIFY_DEVICE is handled by the responder, we only get notified about changes.
+//!     fn on_identify_changed(
+//!         &mut self,
+//!         identify: bool,
+//!         _: &mut DmxReceiverContext,
+//!     ) -> Result<(), Self::Error> {
+//!         println!("Current identify is {identify}");
+//!
+//!         Ok(())
 //!     }
 //! }
 //!
@@ -124,13 +96,11 @@
 //!     dmx_driver,
 //!     RdmResponderConfig {
 //!         uid: UniqueIdentifier::new(0x7FF0, 1).unwrap(),
-//!         // Won't add PID_IDENTIFY_DEVICE since this is a required pid.
-//!         supported_pids: &[],
-//!         rdm_receiver_metadata: Default::default(),
+//!         ..Default::default()
 //!     },
 //! );
 //!
-//! let mut rdm_handler = RdmHandler { identify: false };
+//! let mut rdm_handler = RdmHandler;
 //!
 //! loop {
 //!     // poll for new packages using our handler
@@ -145,7 +115,23 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+/// Module for bridging Art-Net to dmx controllers.
+#[cfg(feature = "artnet")]
+#[cfg_attr(docsrs, doc(cfg(feature = "artnet")))]
+pub mod artnet;
+/// Module for building dmx-rdm receivers on top of async uart drivers.
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub mod async_responder;
+/// Module for parsing RDM packages from captures of sniffers or logic analyzers.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod capture;
 pub mod command_class;
+/// Module for checking whether a responder answers all required pids correctly.
+#[cfg(feature = "mock")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mock")))]
+pub mod compliance;
 pub mod consts;
 /// Module for building dmx-rdm controllers.
 pub mod dmx_controller;
@@ -156,14 +142,27 @@ pub mod dmx_driver;
 pub mod dmx_receiver;
 /// Module for simplifying the implementation of new drivers/hardware that behave like direct uart devices.
 pub mod dmx_uart_driver;
+/// Module for keeping track of the channels of a DMX512 universe.
+pub mod dmx_universe;
 mod layouts;
-mod pids;
+/// Module for testing dmx-rdm devices without hardware.
+#[cfg(feature = "mock")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mock")))]
+pub mod mock;
+/// The parameter ids defined by E1.20.
+pub mod pids;
 pub mod rdm_data;
 pub mod rdm_packages;
 /// Parser for handling rdm requests without an underlying driver.
 /// Mainly for highly interrupt driven applications.
 pub mod rdm_responder;
 pub mod rdm_types;
+/// Module for bridging sACN (E1.31) to a dmx controller.
+#[cfg(feature = "sacn")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sacn")))]
+pub mod sacn;
+/// Module for estimating the time RDM transactions take on the bus.
+pub mod timing;
 pub mod types;
 pub mod unique_identifier;
 pub mod utils;
@@ -0,0 +1,310 @@
+use crate::dmx_driver::{
+    ControllerDriverErrorDef, DiscoveryOption, DmxError, DmxReceiver, RdmControllerDriver,
+};
+use crate::dmx_receiver::DmxFrame;
+use crate::dmx_uart_driver::{
+    DmxRecvUartDriver, DmxRespUartDriver, DmxUartDriver, DmxUartDriverError,
+};
+use crate::rdm_data::RdmData;
+use crate::unique_identifier::UniqueIdentifier;
+use core::convert::Infallible;
+use std::collections::VecDeque;
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+/// An in-memory bus for testing an [RdmResponder](crate::dmx_receiver::RdmResponder)
+/// without hardware.
+///
+/// Queued frames are received by the responder, everything the responder sends can be
+/// taken from the bus afterward.
+#[derive(Debug, Default)]
+pub struct MockBus {
+    received_frames: VecDeque<DmxFrame>,
+    sent_packages: VecDeque<RdmData>,
+    sent_discovery_responses: VecDeque<UniqueIdentifier>,
+}
+
+impl MockBus {
+    /// Creates an empty [MockBus].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues a frame that is going to be received. The first byte is the start code.
+    pub fn push_frame(&mut self, frame: DmxFrame) {
+        self.received_frames.push_back(frame);
+    }
+
+    /// Queues an RDM package that is going to be received.
+    pub fn push_rdm(&mut self, package: &RdmData) {
+        self.push_frame(DmxFrame::from_slice(&package.serialize()).unwrap());
+    }
+
+    /// Takes the oldest RDM package that was sent.
+    pub fn pop_sent_package(&mut self) -> Option<RdmData> {
+        self.sent_packages.pop_front()
+    }
+
+    /// Takes the oldest discovery response that was sent.
+    pub fn pop_sent_discovery_response(&mut self) -> Option<UniqueIdentifier> {
+        self.sent_discovery_responses.pop_front()
+    }
+}
+
+impl ControllerDriverErrorDef for MockBus {
+    type DriverError = Infallible;
+}
+
+impl DmxReceiver for MockBus {
+    fn receive_package(&mut self) -> Result<DmxFrame, DmxError<Infallible>> {
+        self.received_frames
+            .pop_front()
+            .ok_or(DmxError::TimeoutError)
+    }
+}
+
+impl RdmControllerDriver for MockBus {
+    fn send_rdm(&mut self, package: RdmData) -> Result<(), DmxError<Infallible>> {
+        self.sent_packages.push_back(package);
+
+        Ok(())
+    }
+
+    fn receive_rdm(&mut self) -> Result<RdmData, DmxError<Infallible>> {
+        let frame = self.receive_package()?;
+
+        RdmData::deserialize(&frame).map_err(DmxError::DeserializationError)
+    }
+
+    fn receive_rdm_discovery_response(&mut self) -> Result<DiscoveryOption, DmxError<Infallible>> {
+        Ok(DiscoveryOption::NoDevice)
+    }
+
+    fn send_rdm_discovery_response(
+        &mut self,
+        uid: UniqueIdentifier,
+    ) -> Result<(), DmxError<Infallible>> {
+        self.sent_discovery_responses.push_back(uid);
+
+        Ok(())
+    }
+}
+
+/// The default time a [LoopbackBus] waits for data.
+pub const LOOPBACK_READ_TIMEOUT: Duration = Duration::from_millis(100);
+
+#[derive(Debug)]
+struct LoopbackFrame {
+    /// true if the frame was sent with a break and nothing has been read yet.
+    with_break: bool,
+    data: VecDeque<u8>,
+}
+
+/// One direction of a [LoopbackBus].
+#[derive(Debug, Default)]
+struct LoopbackLine {
+    frames: Mutex<VecDeque<LoopbackFrame>>,
+    frame_written: Condvar,
+}
+
+impl LoopbackLine {
+    fn write(&self, buffer: &[u8], with_break: bool) -> usize {
+        self.frames.lock().unwrap().push_back(LoopbackFrame {
+            with_break,
+            data: buffer.iter().copied().collect(),
+        });
+        self.frame_written.notify_all();
+
+        buffer.len()
+    }
+
+    fn read(
+        &self,
+        buffer: &mut [u8],
+        wait_for_break: bool,
+        timeout: Duration,
+    ) -> Result<usize, DmxUartDriverError<Infallible>> {
+        let deadline = Instant::now() + timeout;
+        let mut frames = self.frames.lock().unwrap();
+
+        loop {
+            if wait_for_break {
+                // skip the rest of partially read frames and frames without break
+                while frames.front().is_some_and(|frame| !frame.with_break) {
+                    frames.pop_front();
+                }
+            }
+
+            if !frames.is_empty() {
+                break;
+            }
+
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(DmxUartDriverError::TimeoutError);
+            }
+
+            frames = self
+                .frame_written
+                .wait_timeout(frames, remaining)
+                .unwrap()
+                .0;
+        }
+
+        let frame = frames.front_mut().unwrap();
+        if !wait_for_break && frame.with_break {
+            // the current frame has ended
+            return Ok(0);
+        }
+
+        frame.with_break = false;
+        let bytes_read = buffer.len().min(frame.data.len());
+        for (byte, data) in buffer.iter_mut().zip(frame.data.drain(..bytes_read)) {
+            *byte = data;
+        }
+
+        if frame.data.is_empty() {
+            frames.pop_front();
+        }
+
+        Ok(bytes_read)
+    }
+}
+
+/// One end of an in-memory uart connection for wiring a
+/// [DmxController](crate::dmx_controller::DmxController) and an
+/// [RdmResponder](crate::dmx_receiver::RdmResponder) together without hardware.
+///
+/// Everything written to one end of the pair can be read from the other end. Since both ends are
+/// usually driven by different threads, the timeouts of the reads are replaced by the read
+/// timeout of the bus ([LOOPBACK_READ_TIMEOUT] by default).
+#[derive(Debug)]
+pub struct LoopbackBus {
+    tx: Arc<LoopbackLine>,
+    rx: Arc<LoopbackLine>,
+    read_timeout: Duration,
+}
+
+impl LoopbackBus {
+    /// Creates the two connected ends of a loopback bus.
+    pub fn pair() -> (Self, Self) {
+        let first_line = Arc::new(LoopbackLine::default());
+        let second_line = Arc::new(LoopbackLine::default());
+
+        (
+            Self {
+                tx: first_line.clone(),
+                rx: second_line.clone(),
+                read_timeout: LOOPBACK_READ_TIMEOUT,
+            },
+            Self {
+                tx: second_line,
+                rx: first_line,
+                read_timeout: LOOPBACK_READ_TIMEOUT,
+            },
+        )
+    }
+
+    /// Set the time a read waits for data before it times out.
+    pub fn set_read_timeout(&mut self, read_timeout: Duration) {
+        self.read_timeout = read_timeout;
+    }
+}
+
+impl DmxUartDriver for LoopbackBus {
+    type DriverError = Infallible;
+}
+
+impl DmxRecvUartDriver for LoopbackBus {
+    fn read_frames(
+        &mut self,
+        buffer: &mut [u8],
+        _timeout_us: u32,
+    ) -> Result<usize, DmxUartDriverError<Infallible>> {
+        self.rx.read(buffer, true, self.read_timeout)
+    }
+
+    fn read_frames_no_break(
+        &mut self,
+        buffer: &mut [u8],
+        _timeout_us: u32,
+    ) -> Result<usize, DmxUartDriverError<Infallible>> {
+        self.rx.read(buffer, false, self.read_timeout)
+    }
+}
+
+impl DmxRespUartDriver for LoopbackBus {
+    fn write_frames(&mut self, buffer: &[u8]) -> Result<usize, DmxUartDriverError<Infallible>> {
+        Ok(self.tx.write(buffer, true))
+    }
+
+    fn write_frames_no_break(
+        &mut self,
+        buffer: &[u8],
+    ) -> Result<usize, DmxUartDriverError<Infallible>> {
+        Ok(self.tx.write(buffer, false))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmx_controller::{DmxController, DmxControllerConfig};
+    use crate::dmx_receiver::{DmxResponderHandler, RdmResponder};
+    use crate::mock::LoopbackBus;
+    use crate::rdm_responder::{RdmReceiverMetadata, RdmResponderConfig};
+    use crate::rdm_types::DmxStartAddress;
+    use crate::unique_identifier::{PackageAddress, UniqueIdentifier};
+    use crate::utils::run_full_discovery;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    struct EmptyHandler;
+
+    impl DmxResponderHandler for EmptyHandler {
+        type Error = ();
+    }
+
+    #[test]
+    fn test_loopback_discovery() {
+        let (controller_bus, responder_bus) = LoopbackBus::pair();
+        let responder_uid = UniqueIdentifier::new(0x7FF0, 0x1234).unwrap();
+
+        let mut responder = RdmResponder::<_, 4>::new(
+            responder_bus,
+            RdmResponderConfig {
+                uid: responder_uid,
+                rdm_receiver_metadata: RdmReceiverMetadata {
+                    device_model_id: 0x0042,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let responder_thread = std::thread::spawn({
+            let stop = stop.clone();
+            move || {
+                while !stop.load(Ordering::Relaxed) {
+                    responder.poll(&mut EmptyHandler).unwrap();
+                }
+            }
+        });
+
+        let mut controller = DmxController::new(controller_bus, &DmxControllerConfig::default());
+        controller
+            .rdm_disc_un_mute(PackageAddress::Broadcast)
+            .unwrap();
+
+        let mut uids = [UniqueIdentifier::new(1, 1).unwrap(); 4];
+        let devices_found = run_full_discovery(&mut controller, &mut uids).unwrap();
+        assert_eq!(&uids[..devices_found], &[responder_uid]);
+
+        let device_info = controller.rdm_get_device_info(responder_uid).unwrap();
+        assert_eq!(device_info.device_model_id, 0x0042);
+        assert_eq!(device_info.dmx_start_address, DmxStartAddress::NoAddress);
+
+        stop.store(true, Ordering::Relaxed);
+        responder_thread.join().unwrap();
+    }
+}
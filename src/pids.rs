@@ -2,9 +2,79 @@ pub const DISC_UNIQUE_BRANCH: u16 = 0x0001;
 pub const DISC_MUTE: u16 = 0x0002;
 pub const DISC_UN_MUTE: u16 = 0x0003;
 pub const IDENTIFY_DEVICE: u16 = 0x1000;
+pub const RESET_DEVICE: u16 = 0x1001;
+pub const POWER_STATE: u16 = 0x1010;
+pub const PERFORM_SELFTEST: u16 = 0x1020;
+pub const SELF_TEST_DESCRIPTION: u16 = 0x1021;
+pub const CAPTURE_PRESET: u16 = 0x1030;
+pub const PRESET_PLAYBACK: u16 = 0x1031;
+/// Defined by E1.37-1.
+pub const IDENTIFY_MODE: u16 = 0x1040;
+/// Defined by E1.37-1.
+pub const CURVE: u16 = 0x0343;
+/// Defined by E1.37-1.
+pub const CURVE_DESCRIPTION: u16 = 0x0344;
 pub const SOFTWARE_VERSION_LABEL: u16 = 0x00C0;
+pub const BOOT_SOFTWARE_VERSION_ID: u16 = 0x00C2;
+pub const BOOT_SOFTWARE_VERSION_LABEL: u16 = 0x00C3;
 pub const DMX_START_ADDRESS: u16 = 0x00F0;
+pub const DMX_BLOCK_ADDRESS: u16 = 0x0140;
+pub const DMX_PERSONALITY: u16 = 0x00E0;
+pub const DMX_PERSONALITY_DESCRIPTION: u16 = 0x00E1;
+pub const COMMS_STATUS: u16 = 0x0015;
 pub const QUEUED_MESSAGE: u16 = 0x0020;
 pub const STATUS_MESSAGES: u16 = 0x0030;
 pub const SUPPORTED_PARAMETERS: u16 = 0x0050;
+pub const PARAMETER_DESCRIPTION: u16 = 0x0051;
 pub const DEVICE_INFO: u16 = 0x0060;
+pub const DEVICE_MODEL_DESCRIPTION: u16 = 0x0080;
+pub const MANUFACTURER_LABEL: u16 = 0x0081;
+pub const DEVICE_LABEL: u16 = 0x0082;
+pub const FACTORY_DEFAULTS: u16 = 0x0090;
+pub const DEFAULT_SLOT_VALUE: u16 = 0x0122;
+pub const SENSOR_DEFINITION: u16 = 0x0200;
+pub const SENSOR_VALUE: u16 = 0x0201;
+pub const RECORD_SENSORS: u16 = 0x0202;
+pub const DEVICE_HOURS: u16 = 0x0400;
+pub const LAMP_HOURS: u16 = 0x0401;
+pub const DEVICE_POWER_CYCLES: u16 = 0x0405;
+pub const REAL_TIME_CLOCK: u16 = 0x0603;
+
+const REQUIRED_PIDS: [u16; 8] = [
+    DISC_UNIQUE_BRANCH,
+    DISC_MUTE,
+    DISC_UN_MUTE,
+    SUPPORTED_PARAMETERS,
+    DEVICE_INFO,
+    SOFTWARE_VERSION_LABEL,
+    DMX_START_ADDRESS,
+    IDENTIFY_DEVICE,
+];
+
+/// Returns the pids every responder has to support according to E1.20.
+/// [DMX_START_ADDRESS] is only required if the device has a dmx footprint.
+pub fn required_pids() -> &'static [u16] {
+    &REQUIRED_PIDS
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pids::*;
+
+    #[test]
+    fn test_required_pids() {
+        assert_eq!(
+            required_pids(),
+            &[
+                0x0001, // DISC_UNIQUE_BRANCH
+                0x0002, // DISC_MUTE
+                0x0003, // DISC_UN_MUTE
+                0x0050, // SUPPORTED_PARAMETERS
+                0x0060, // DEVICE_INFO
+                0x00C0, // SOFTWARE_VERSION_LABEL
+                0x00F0, // DMX_START_ADDRESS
+                0x1000, // IDENTIFY_DEVICE
+            ]
+        );
+    }
+}
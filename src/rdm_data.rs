@@ -1,16 +1,20 @@
 use crate::command_class::{RequestCommandClass, ResponseCommandClass};
 use crate::consts::{
-    RDM_DISCOVERY_RESPONSE_SIZE, RDM_MAX_PACKAGE_SIZE, RDM_MAX_PARAMETER_DATA_LENGTH,
+    PREAMBLE_BYTE, RDM_DISCOVERY_RESPONSE_SIZE, RDM_MAX_DISCOVERY_PREAMBLE_SIZE,
+    RDM_MAX_DISCOVERY_RESPONSE_SIZE, RDM_MAX_PACKAGE_SIZE, RDM_MAX_PARAMETER_DATA_LENGTH,
     RDM_MIN_PACKAGE_SIZE, SC_RDM, SC_SUB_MESSAGE, SEPARATOR_BYTE,
 };
 use crate::layouts::rdm_request_layout;
 use crate::types::{DataPack, ResponseType};
 use crate::unique_identifier::{PackageAddress, UniqueIdentifier};
-use crate::utils::calculate_checksum;
+use crate::utils::{calculate_checksum, encode_disc_unique};
 
 /// Binary representation of an RDM package.
 pub type BinaryRdmPackage = heapless::Vec<u8, RDM_MAX_PACKAGE_SIZE>;
 
+/// Binary representation of an RDM discovery response including preamble and separator.
+pub type BinaryDiscoveryResponse = heapless::Vec<u8, RDM_MAX_DISCOVERY_RESPONSE_SIZE>;
+
 /// Error that gets raised when attempting to convert an [RdmRequestData] object
 /// to a [RdmResponseData] object that contains a broadcast destination address.
 #[derive(Debug)]
@@ -26,8 +30,29 @@ impl core::fmt::Display for IsBroadcastError {
 #[cfg(feature = "std")]
 impl std::error::Error for IsBroadcastError {}
 
+/// Error that gets raised when an RDM package can't be serialized.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SerializationError {
+    /// The parameter data is longer than 231 bytes; contains the length of the parameter data
+    ParameterDataTooLong(usize),
+}
+
+impl core::fmt::Display for SerializationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SerializationError::ParameterDataTooLong(length) => {
+                write!(f, "parameter data length {} is too long", length)
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SerializationError {}
+
 /// An RDM Request package that does not have its parameter data deserialized.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct RdmRequestData {
     pub destination_uid: PackageAddress,
     pub source_uid: UniqueIdentifier,
@@ -91,8 +116,8 @@ pub enum RdmDeserializationError {
     ResponseTypeNotFound(u8),
     /// The message length field is incorrect; contains result of parsing
     WrongMessageLength(usize),
-    /// Wrong checksum; contains result of parsing
-    WrongChecksum,
+    /// Wrong checksum; contains the calculated and the received checksum
+    WrongChecksum { expected: u16, actual: u16 },
     /// Received wrong start code (0xCC) or sub start code (0x01); contains result of parsing
     WrongStartCode,
     /// The source uid is a broadcast address.
@@ -113,7 +138,11 @@ impl core::fmt::Display for RdmDeserializationError {
             RdmDeserializationError::WrongMessageLength(message_length) => {
                 write!(f, "message length {} is incorrect", message_length)
             },
-            RdmDeserializationError::WrongChecksum => write!(f, "checksum is incorrect"),
+            RdmDeserializationError::WrongChecksum { expected, actual } => write!(
+                f,
+                "checksum {:#06X} is incorrect, expected {:#06X}",
+                actual, expected
+            ),
             RdmDeserializationError::WrongStartCode => write!(f, "start code is incorrect"),
             RdmDeserializationError::SourceUidIsBroadcast => write!(f, "source uid is a broadcast"),
         }
@@ -144,6 +173,23 @@ impl RdmData {
     pub fn serialize(&self) -> BinaryRdmPackage {
         serialize_rdm_data(self)
     }
+
+    /// Convenience function for serializing an RDM package without panicking.
+    /// Refer to [try_serialize_rdm_data].
+    pub fn try_serialize(&self) -> Result<BinaryRdmPackage, SerializationError> {
+        try_serialize_rdm_data(self)
+    }
+
+    /// Returns the length of the serialized package including the start code and the checksum.
+    pub fn serialized_len(&self) -> usize {
+        let parameter_data_length = match self {
+            RdmData::Request(request) => request.parameter_data.len(),
+            RdmData::Response(response) => response.parameter_data.len(),
+        };
+
+        // parameter data length + all other fields including checksum
+        parameter_data_length + 26
+    }
 }
 
 /// Deserialize rdm data.
@@ -166,7 +212,10 @@ pub fn deserialize_rdm_data(buffer: &[u8]) -> Result<RdmData, RdmDeserialization
         u16::from_be_bytes(buffer[buffer_size - 2..buffer_size].try_into().unwrap());
 
     if expected_checksum != actual_checksum {
-        return Err(RdmDeserializationError::WrongChecksum);
+        return Err(RdmDeserializationError::WrongChecksum {
+            expected: expected_checksum,
+            actual: actual_checksum,
+        });
     }
 
     let request_data_view = rdm_request_layout::View::new(buffer);
@@ -191,6 +240,11 @@ pub fn deserialize_rdm_data(buffer: &[u8]) -> Result<RdmData, RdmDeserialization
 
     let command_class_field = request_data_view.command_class().read();
     let is_request = RequestCommandClass::try_from(command_class_field).is_ok();
+    if !is_request && ResponseCommandClass::try_from(command_class_field).is_err() {
+        return Err(RdmDeserializationError::CommandClassNotFound(
+            command_class_field,
+        ));
+    }
 
     let rdm_data = if is_request {
         RdmData::Request(RdmRequestData {
@@ -237,17 +291,26 @@ pub fn deserialize_rdm_data(buffer: &[u8]) -> Result<RdmData, RdmDeserialization
 }
 
 /// Serializes RDM data to a binary Vec.
+///
+/// Panics if the parameter data is too long. Use [try_serialize_rdm_data] for packages that
+/// weren't built by this crate.
 pub fn serialize_rdm_data(rdm_data: &RdmData) -> BinaryRdmPackage {
+    try_serialize_rdm_data(rdm_data).expect("The parameter data of the package is too long.")
+}
+
+/// Serializes RDM data to a binary Vec.
+/// Raises an error instead of panicking if the package is malformed.
+pub fn try_serialize_rdm_data(rdm_data: &RdmData) -> Result<BinaryRdmPackage, SerializationError> {
     let mut dst = [0u8; RDM_MAX_PACKAGE_SIZE];
 
-    let parameter_data_length = match rdm_data {
-        RdmData::Request(ref request) => request.parameter_data.len(),
-        RdmData::Response(ref response) => response.parameter_data.len(),
-    };
-    assert!(parameter_data_length <= RDM_MAX_PARAMETER_DATA_LENGTH);
+    let total_package_length = rdm_data.serialized_len();
+    let parameter_data_length = total_package_length - 26;
+    if parameter_data_length > RDM_MAX_PARAMETER_DATA_LENGTH {
+        return Err(SerializationError::ParameterDataTooLong(
+            parameter_data_length,
+        ));
+    }
 
-    // parameter data length + all other fields including checksum
-    let total_package_length = parameter_data_length + 26;
     let mut memory_view = rdm_request_layout::View::new(&mut dst[..total_package_length]);
 
     memory_view.start_code_mut().write(SC_RDM);
@@ -320,13 +383,75 @@ pub fn serialize_rdm_data(rdm_data: &RdmData) -> BinaryRdmPackage {
     }
 
     // In the industry we call this a pro gamer move.
-    heapless::Vec::from_slice(&dst[..total_package_length]).unwrap()
+    heapless::Vec::from_slice(&dst[..total_package_length]).or(Err(
+        SerializationError::ParameterDataTooLong(parameter_data_length),
+    ))
+}
+
+/// Serializes a discovery response with the given amount of preamble bytes (0-7).
+pub fn serialize_discovery_response(
+    uid: UniqueIdentifier,
+    preamble_length: usize,
+) -> BinaryDiscoveryResponse {
+    assert!(
+        preamble_length <= RDM_MAX_DISCOVERY_PREAMBLE_SIZE,
+        "The preamble can't be longer than 7 bytes."
+    );
+
+    let mut frame_buffer = [PREAMBLE_BYTE; RDM_MAX_DISCOVERY_RESPONSE_SIZE];
+    let frame_length = preamble_length + 1 + RDM_DISCOVERY_RESPONSE_SIZE;
+    let start_index = preamble_length + 1;
+    frame_buffer[preamble_length] = SEPARATOR_BYTE;
+
+    let uid_buffer = uid.to_bytes();
+    encode_disc_unique(
+        &uid_buffer,
+        &mut frame_buffer[start_index..start_index + 12],
+    );
+
+    let checksum = calculate_checksum(&frame_buffer[start_index..start_index + 12]);
+    encode_disc_unique(
+        &checksum.to_be_bytes(),
+        &mut frame_buffer[start_index + 12..frame_length],
+    );
+
+    heapless::Vec::from_slice(&frame_buffer[..frame_length]).unwrap()
 }
 
 /// Returns received device id if there is no collision.
+///
+/// The separator has to be preceded by at most 7 preamble bytes. A buffer with anything
+/// else in front of the separator or a separator inside the encoded data is rejected
+/// with [RdmDeserializationError::WrongStartCode].
 pub fn deserialize_discovery_response(
     buffer: &[u8],
 ) -> Result<UniqueIdentifier, RdmDeserializationError> {
+    let (uid, expected, actual) = decode_discovery_response(buffer)?;
+    if expected != actual {
+        return Err(RdmDeserializationError::WrongChecksum { expected, actual });
+    }
+
+    Ok(uid)
+}
+
+/// Works like [deserialize_discovery_response], but also returns the decoded device id if only
+/// the checksum is wrong. The bool is true if the checksum matches.
+///
+/// Overlapping responses of multiple devices are often still encoded correctly, so a
+/// device id with a wrong checksum is most likely the result of a collision.
+pub fn deserialize_discovery_response_unchecked(
+    buffer: &[u8],
+) -> Result<(UniqueIdentifier, bool), RdmDeserializationError> {
+    let (uid, expected, actual) = decode_discovery_response(buffer)?;
+
+    Ok((uid, expected == actual))
+}
+
+/// Decodes a discovery response and returns the device id, the calculated and the received
+/// checksum.
+fn decode_discovery_response(
+    buffer: &[u8],
+) -> Result<(UniqueIdentifier, u16, u16), RdmDeserializationError> {
     let index_of_separator_byte = match buffer.iter().position(|&x| x == SEPARATOR_BYTE) {
         None => {
             return Err(RdmDeserializationError::WrongStartCode); // idk
@@ -334,12 +459,28 @@ pub fn deserialize_discovery_response(
         Some(index) => index,
     };
 
+    let preamble = &buffer[..index_of_separator_byte];
+    if preamble.len() > RDM_MAX_DISCOVERY_PREAMBLE_SIZE
+        || preamble.iter().any(|&byte| byte != PREAMBLE_BYTE)
+    {
+        return Err(RdmDeserializationError::WrongStartCode);
+    }
+
     let start_index = index_of_separator_byte + 1;
     let message_length = buffer.len() - start_index;
     if message_length < RDM_DISCOVERY_RESPONSE_SIZE {
         return Err(RdmDeserializationError::WrongMessageLength(message_length));
     }
 
+    // Every encoded byte is split into a byte or'ed with 0xAA and a byte or'ed with 0x55.
+    // A stray separator in the preamble shifts the data so this doesn't hold anymore.
+    let is_encoded_correctly = buffer[start_index..start_index + RDM_DISCOVERY_RESPONSE_SIZE]
+        .chunks(2)
+        .all(|chunk| chunk[0] & 0xAA == 0xAA && chunk[1] & 0x55 == 0x55);
+    if !is_encoded_correctly {
+        return Err(RdmDeserializationError::WrongStartCode);
+    }
+
     let calculated_checksum = calculate_checksum(&buffer[start_index..start_index + 12]);
 
     let mut device_id_buf = [0u8; 6];
@@ -356,11 +497,7 @@ pub fn deserialize_discovery_response(
     );
     let received_checksum = u16::from_be_bytes(checksum_buf);
 
-    if calculated_checksum != received_checksum {
-        return Err(RdmDeserializationError::WrongChecksum);
-    }
-
-    Ok(uid)
+    Ok((uid, calculated_checksum, received_checksum))
 }
 
 /// Decode a discovery package. The destination has to be at least half the source size.
@@ -374,3 +511,157 @@ fn decode_disc_unique(src: &[u8], dest: &mut [u8]) {
         dest[index] = byte;
     }
 }
+
+#[cfg(test)]
+#[cfg_attr(not(feature = "std"), allow(unused_imports))]
+mod tests {
+    use crate::command_class::ResponseCommandClass;
+    use crate::consts::{PREAMBLE_BYTE, SEPARATOR_BYTE};
+    use crate::rdm_data::{
+        deserialize_discovery_response, deserialize_discovery_response_unchecked,
+        serialize_discovery_response, RdmData, RdmDeserializationError, RdmResponseData,
+    };
+    use crate::types::{DataPack, ResponseType};
+    use crate::unique_identifier::PackageAddress;
+    use crate::unique_identifier::UniqueIdentifier;
+
+    #[test]
+    fn test_serialize_discovery_response_short_preamble() {
+        let uid = UniqueIdentifier::new(0x7FF0, 0x12345678).unwrap();
+        let frame = serialize_discovery_response(uid, 4);
+
+        assert_eq!(frame.len(), 21);
+        assert_eq!(&frame[..4], &[PREAMBLE_BYTE; 4]);
+        assert_eq!(frame[4], SEPARATOR_BYTE);
+        assert_eq!(deserialize_discovery_response(&frame).unwrap(), uid);
+    }
+
+    #[test]
+    fn test_serialize_discovery_response_default_preamble() {
+        let uid = UniqueIdentifier::new(0x7FF0, 1).unwrap();
+        let frame = serialize_discovery_response(uid, 7);
+
+        assert_eq!(frame.len(), 24);
+        assert_eq!(frame[7], SEPARATOR_BYTE);
+        assert_eq!(deserialize_discovery_response(&frame).unwrap(), uid);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_deserialize_discovery_response_rejects_malformed_frames() {
+        let uid = UniqueIdentifier::new(0x7FF0, 1).unwrap();
+        let frame = serialize_discovery_response(uid, 7);
+
+        assert!(matches!(
+            deserialize_discovery_response(&frame[..frame.len() - 1]),
+            Err(RdmDeserializationError::WrongMessageLength(15))
+        ));
+
+        let mut extra_preamble = vec![PREAMBLE_BYTE];
+        extra_preamble.extend_from_slice(&frame);
+        assert!(matches!(
+            deserialize_discovery_response(&extra_preamble),
+            Err(RdmDeserializationError::WrongStartCode)
+        ));
+
+        let mut separator_in_preamble = frame.clone();
+        separator_in_preamble[2] = SEPARATOR_BYTE;
+        assert!(matches!(
+            deserialize_discovery_response(&separator_in_preamble),
+            Err(RdmDeserializationError::WrongStartCode)
+        ));
+
+        let mut noise_in_preamble = frame.clone();
+        noise_in_preamble[0] = 0x00;
+        assert!(matches!(
+            deserialize_discovery_response(&noise_in_preamble),
+            Err(RdmDeserializationError::WrongStartCode)
+        ));
+    }
+
+    #[test]
+    fn test_deserialize_discovery_response_wrong_checksum() {
+        let uid = UniqueIdentifier::new(0x7FF0, 1).unwrap();
+        let mut frame = serialize_discovery_response(uid, 7);
+
+        assert_eq!(
+            deserialize_discovery_response_unchecked(&frame),
+            Ok((uid, true))
+        );
+
+        // flip a bit of the checksum while keeping the encoding intact
+        let last_index = frame.len() - 1;
+        frame[last_index] ^= 0x02;
+        match deserialize_discovery_response(&frame) {
+            Err(RdmDeserializationError::WrongChecksum { expected, actual }) => {
+                assert_eq!(actual, expected ^ 0x0002)
+            },
+            result => panic!("expected a wrong checksum, got {result:?}"),
+        }
+        assert_eq!(
+            deserialize_discovery_response_unchecked(&frame),
+            Ok((uid, false))
+        );
+    }
+
+    #[test]
+    fn test_try_serialize() {
+        let response = RdmData::Response(RdmResponseData {
+            destination_uid: PackageAddress::Device(UniqueIdentifier::new(0x7FF0, 0).unwrap()),
+            source_uid: UniqueIdentifier::new(0x7FF0, 1).unwrap(),
+            transaction_number: 1,
+            response_type: ResponseType::ResponseTypeAck,
+            message_count: 0,
+            sub_device: 0,
+            command_class: ResponseCommandClass::GetCommandResponse,
+            parameter_id: 0x8000,
+            parameter_data: DataPack::from_slice(&[0xAB; 231]).unwrap(),
+        });
+
+        let frame = response.try_serialize().unwrap();
+        assert_eq!(frame.len(), 257);
+        assert_eq!(frame, response.serialize());
+        assert!(matches!(
+            RdmData::deserialize(&frame),
+            Ok(RdmData::Response(response)) if response.parameter_data.len() == 231
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_wrong_checksum_values() {
+        let response = RdmData::Response(RdmResponseData {
+            destination_uid: PackageAddress::Device(UniqueIdentifier::new(0x7FF0, 0).unwrap()),
+            source_uid: UniqueIdentifier::new(0x7FF0, 1).unwrap(),
+            transaction_number: 1,
+            response_type: ResponseType::ResponseTypeAck,
+            message_count: 0,
+            sub_device: 0,
+            command_class: ResponseCommandClass::GetCommandResponse,
+            parameter_id: 0x8000,
+            parameter_data: DataPack::from_slice(&[0x01]).unwrap(),
+        });
+
+        let mut frame = response.serialize();
+        let last_index = frame.len() - 1;
+        let checksum = u16::from_be_bytes([frame[last_index - 1], frame[last_index]]);
+        frame[last_index] ^= 0xFF;
+
+        let error = RdmData::deserialize(&frame).unwrap_err();
+        assert_eq!(
+            error,
+            RdmDeserializationError::WrongChecksum {
+                expected: checksum,
+                actual: checksum ^ 0x00FF,
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "checksum {:#06X} is incorrect, expected {:#06X}",
+                checksum ^ 0x00FF,
+                checksum
+            )
+        );
+    }
+}
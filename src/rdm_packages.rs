@@ -1,13 +1,49 @@
-use crate::consts::RDM_STATUS_MESSAGE_SIZE;
+use crate::consts::{RDM_DEFAULT_SLOT_VALUE_SIZE, RDM_STATUS_MESSAGE_SIZE};
 use crate::pids;
 use crate::rdm_types::{
-    DeserializationError, DmxStartAddress, StatusMessage, StatusMessages, SupportedParameters,
+    CommsStatus, Curve, CurveDescription, DefaultSlotValue, DefaultSlotValues,
+    DeserializationError, DeviceInfo, DmxBlockAddress, DmxPersonality, DmxPersonalityDescription,
+    DmxStartAddress, IdentifyMode, ParameterDescription, PowerState, PresetPlayback, RealTimeClock,
+    SelfTestDescription, SensorDefinition, SensorValue, StatisticsCounter, StatusMessage,
+    StatusMessages, SupportedParameters,
 };
 use crate::types::DataPack;
 
 pub fn deserialize_identify(buffer: &[u8]) -> Result<bool, DeserializationError> {
     if buffer.len() != 1 {
-        return Err(DeserializationError);
+        return Err(DeserializationError::WrongLength);
+    }
+
+    Ok(buffer[0] != 0)
+}
+
+pub fn deserialize_identify_mode(buffer: &[u8]) -> Result<IdentifyMode, DeserializationError> {
+    if buffer.len() != 1 {
+        return Err(DeserializationError::WrongLength);
+    }
+
+    IdentifyMode::try_from(buffer[0])
+}
+
+pub fn deserialize_power_state(buffer: &[u8]) -> Result<PowerState, DeserializationError> {
+    if buffer.len() != 1 {
+        return Err(DeserializationError::WrongLength);
+    }
+
+    PowerState::try_from(buffer[0])
+}
+
+pub fn deserialize_statistics_counter(buffer: &[u8]) -> Result<u32, DeserializationError> {
+    Ok(u32::from_be_bytes(
+        buffer
+            .try_into()
+            .map_err(|_| DeserializationError::WrongLength)?,
+    ))
+}
+
+pub fn deserialize_factory_defaults(buffer: &[u8]) -> Result<bool, DeserializationError> {
+    if buffer.len() != 1 {
+        return Err(DeserializationError::WrongLength);
     }
 
     Ok(buffer[0] != 0)
@@ -17,21 +53,56 @@ pub fn deserialize_software_version_label(
     buffer: &[u8],
 ) -> Result<heapless::String<32>, DeserializationError> {
     heapless::String::from_utf8(
-        heapless::Vec::<_, 32>::from_slice(buffer).or(Err(DeserializationError))?,
+        heapless::Vec::<_, 32>::from_slice(buffer).or(Err(DeserializationError::WrongLength))?,
     )
-    .or(Err(DeserializationError))
+    .or(Err(DeserializationError::InvalidUtf8))
+}
+
+pub fn deserialize_boot_software_version_id(buffer: &[u8]) -> Result<u32, DeserializationError> {
+    Ok(u32::from_be_bytes(
+        buffer
+            .try_into()
+            .or(Err(DeserializationError::WrongLength))?,
+    ))
+}
+
+pub fn deserialize_boot_software_version_label(
+    buffer: &[u8],
+) -> Result<heapless::String<32>, DeserializationError> {
+    deserialize_software_version_label(buffer)
+}
+
+pub fn deserialize_device_model_description(
+    buffer: &[u8],
+) -> Result<heapless::String<32>, DeserializationError> {
+    deserialize_software_version_label(buffer)
+}
+
+pub fn deserialize_manufacturer_label(
+    buffer: &[u8],
+) -> Result<heapless::String<32>, DeserializationError> {
+    deserialize_software_version_label(buffer)
+}
+
+pub fn deserialize_device_label(
+    buffer: &[u8],
+) -> Result<heapless::String<32>, DeserializationError> {
+    heapless::String::from_utf8(
+        heapless::Vec::<_, 32>::from_slice(buffer).or(Err(DeserializationError::WrongLength))?,
+    )
+    .or(Err(DeserializationError::InvalidUtf8))
 }
 
 pub fn deserialize_status_messages(buffer: &[u8]) -> Result<StatusMessages, DeserializationError> {
-    if buffer.len() % RDM_STATUS_MESSAGE_SIZE != 0 {
-        return Err(DeserializationError);
+    if !buffer.len().is_multiple_of(RDM_STATUS_MESSAGE_SIZE) {
+        return Err(DeserializationError::WrongLength);
     }
 
     let mut status_messages = heapless::Vec::new();
     for package_bytes in buffer.chunks(RDM_STATUS_MESSAGE_SIZE) {
         status_messages
             .push(StatusMessage::deserialize(package_bytes)?)
-            .map_err(|_| DeserializationError)?;
+            .map_err(|_| DeserializationError::WrongLength)?;
     }
 
     Ok(status_messages)
@@ -40,20 +111,37 @@ pub fn deserialize_status_messages(buffer: &[u8]) -> Result<StatusMessages, Dese
 pub fn deserialize_supported_parameters(
     buffer: &[u8],
 ) -> Result<SupportedParameters, DeserializationError> {
-    if buffer.len() % 2 != 0 {
-        return Err(DeserializationError);
+    if !buffer.len().is_multiple_of(2) {
+        return Err(DeserializationError::WrongLength);
     }
 
     let mut supported_parameters = heapless::Vec::new();
     for package_bytes in buffer.chunks(2) {
         supported_parameters
             .push(u16::from_be_bytes(package_bytes.try_into().unwrap()))
-            .map_err(|_| DeserializationError)?;
+            .map_err(|_| DeserializationError::WrongLength)?;
     }
 
     Ok(supported_parameters)
 }
 
+pub fn deserialize_default_slot_values(
+    buffer: &[u8],
+) -> Result<DefaultSlotValues, DeserializationError> {
+    if !buffer.len().is_multiple_of(RDM_DEFAULT_SLOT_VALUE_SIZE) {
+        return Err(DeserializationError::WrongLength);
+    }
+
+    let mut default_slot_values = heapless::Vec::new();
+    for package_bytes in buffer.chunks(RDM_DEFAULT_SLOT_VALUE_SIZE) {
+        default_slot_values
+            .push(DefaultSlotValue::deserialize(package_bytes)?)
+            .map_err(|_| DeserializationError::WrongLength)?;
+    }
+
+    Ok(default_slot_values)
+}
+
 #[derive(Debug)]
 pub struct RdmResponseInfo {
     pub parameter_id: u16,
@@ -65,9 +153,32 @@ pub struct RdmResponseInfo {
 pub enum RdmResponsePackage {
     IdentifyDevice(bool),
     SoftwareVersionLabel(heapless::String<32>),
+    BootSoftwareVersionId(u32),
+    BootSoftwareVersionLabel(heapless::String<32>),
+    DeviceModelDescription(heapless::String<32>),
+    ManufacturerLabel(heapless::String<32>),
+    DeviceLabel(heapless::String<32>),
+    FactoryDefaults(bool),
     DmxStartAddress(DmxStartAddress),
+    DmxPersonality(DmxPersonality),
+    DmxPersonalityDescription(DmxPersonalityDescription),
     StatusMessages(StatusMessages),
     SupportedParameters(SupportedParameters),
+    ParameterDescription(ParameterDescription),
+    SensorDefinition(SensorDefinition),
+    SensorValue(SensorValue),
+    PresetPlayback(PresetPlayback),
+    IdentifyMode(IdentifyMode),
+    PowerState(PowerState),
+    SelfTestDescription(SelfTestDescription),
+    Curve(Curve),
+    CurveDescription(CurveDescription),
+    CommsStatus(CommsStatus),
+    RealTimeClock(RealTimeClock),
+    DmxBlockAddress(DmxBlockAddress),
+    StatisticsCounter(StatisticsCounter, u32),
+    DeviceInfo(DeviceInfo),
+    DefaultSlotValues(DefaultSlotValues),
     Custom(RdmResponseInfo),
 }
 
@@ -82,8 +193,33 @@ impl RdmResponsePackage {
             pids::SOFTWARE_VERSION_LABEL => RdmResponsePackage::SoftwareVersionLabel(
                 deserialize_software_version_label(&response_info.data)?,
             ),
+            pids::BOOT_SOFTWARE_VERSION_ID => RdmResponsePackage::BootSoftwareVersionId(
+                deserialize_boot_software_version_id(&response_info.data)?,
+            ),
+            pids::BOOT_SOFTWARE_VERSION_LABEL => RdmResponsePackage::BootSoftwareVersionLabel(
+                deserialize_boot_software_version_label(&response_info.data)?,
+            ),
+            pids::DEVICE_MODEL_DESCRIPTION => RdmResponsePackage::DeviceModelDescription(
+                deserialize_device_model_description(&response_info.data)?,
+            ),
+            pids::MANUFACTURER_LABEL => RdmResponsePackage::ManufacturerLabel(
+                deserialize_manufacturer_label(&response_info.data)?,
+            ),
+            pids::DEVICE_LABEL => {
+                RdmResponsePackage::DeviceLabel(deserialize_device_label(&response_info.data)?)
+            },
+            pids::FACTORY_DEFAULTS => RdmResponsePackage::FactoryDefaults(
+                deserialize_factory_defaults(&response_info.data)?,
+            ),
             pids::DMX_START_ADDRESS => RdmResponsePackage::DmxStartAddress(
-                DmxStartAddress::deserialize(&response_info.data)?,
+                DmxStartAddress::deserialize(&response_info.data)
+                    .map_err(DeserializationError::from)?,
+            ),
+            pids::DMX_PERSONALITY => RdmResponsePackage::DmxPersonality(
+                DmxPersonality::deserialize(&response_info.data)?,
+            ),
+            pids::DMX_PERSONALITY_DESCRIPTION => RdmResponsePackage::DmxPersonalityDescription(
+                DmxPersonalityDescription::deserialize(&response_info.data)?,
             ),
             pids::STATUS_MESSAGES => RdmResponsePackage::StatusMessages(
                 deserialize_status_messages(&response_info.data)?,
@@ -91,6 +227,52 @@ impl RdmResponsePackage {
             pids::SUPPORTED_PARAMETERS => RdmResponsePackage::SupportedParameters(
                 deserialize_supported_parameters(&response_info.data)?,
             ),
+            pids::PARAMETER_DESCRIPTION => RdmResponsePackage::ParameterDescription(
+                ParameterDescription::deserialize(&response_info.data)?,
+            ),
+            pids::SENSOR_DEFINITION => RdmResponsePackage::SensorDefinition(
+                SensorDefinition::deserialize(&response_info.data)?,
+            ),
+            pids::SENSOR_VALUE => {
+                RdmResponsePackage::SensorValue(SensorValue::deserialize(&response_info.data)?)
+            },
+            pids::PRESET_PLAYBACK => RdmResponsePackage::PresetPlayback(
+                PresetPlayback::deserialize(&response_info.data)?,
+            ),
+            pids::IDENTIFY_MODE => {
+                RdmResponsePackage::IdentifyMode(deserialize_identify_mode(&response_info.data)?)
+            },
+            pids::POWER_STATE => {
+                RdmResponsePackage::PowerState(deserialize_power_state(&response_info.data)?)
+            },
+            pids::DEVICE_HOURS | pids::LAMP_HOURS | pids::DEVICE_POWER_CYCLES => {
+                RdmResponsePackage::StatisticsCounter(
+                    StatisticsCounter::from_parameter_id(response_info.parameter_id).unwrap(),
+                    deserialize_statistics_counter(&response_info.data)?,
+                )
+            },
+            pids::CURVE => RdmResponsePackage::Curve(Curve::deserialize(&response_info.data)?),
+            pids::CURVE_DESCRIPTION => RdmResponsePackage::CurveDescription(
+                CurveDescription::deserialize(&response_info.data)?,
+            ),
+            pids::SELF_TEST_DESCRIPTION => RdmResponsePackage::SelfTestDescription(
+                SelfTestDescription::deserialize(&response_info.data)?,
+            ),
+            pids::COMMS_STATUS => {
+                RdmResponsePackage::CommsStatus(CommsStatus::deserialize(&response_info.data)?)
+            },
+            pids::DMX_BLOCK_ADDRESS => RdmResponsePackage::DmxBlockAddress(
+                DmxBlockAddress::deserialize(&response_info.data)?,
+            ),
+            pids::REAL_TIME_CLOCK => {
+                RdmResponsePackage::RealTimeClock(RealTimeClock::deserialize(&response_info.data)?)
+            },
+            pids::DEVICE_INFO => {
+                RdmResponsePackage::DeviceInfo(DeviceInfo::deserialize(&response_info.data)?)
+            },
+            pids::DEFAULT_SLOT_VALUE => RdmResponsePackage::DefaultSlotValues(
+                deserialize_default_slot_values(&response_info.data)?,
+            ),
             _ => Self::Custom(response_info),
         })
     }
@@ -98,7 +280,8 @@ impl RdmResponsePackage {
 
 #[cfg(test)]
 mod tests {
-    use crate::rdm_packages::deserialize_identify;
+    use crate::rdm_packages::{deserialize_identify, deserialize_power_state};
+    use crate::rdm_types::PowerState;
 
     #[test]
     fn test_deserialize_identify_success() {
@@ -114,4 +297,23 @@ mod tests {
         deserialize_identify(&[2, 1]).unwrap_err();
         deserialize_identify(&[0, 0]).unwrap_err();
     }
+
+    #[test]
+    fn test_deserialize_power_state() {
+        for power_state in [
+            PowerState::FullOff,
+            PowerState::Shutdown,
+            PowerState::Standby,
+            PowerState::Normal,
+        ] {
+            assert_eq!(
+                deserialize_power_state(&[power_state as u8]).unwrap(),
+                power_state
+            );
+        }
+
+        deserialize_power_state(&[0x03]).unwrap_err();
+        deserialize_power_state(&[]).unwrap_err();
+        deserialize_power_state(&[0xFF, 0xFF]).unwrap_err();
+    }
 }
@@ -1,16 +1,36 @@
-use crate::command_class::RequestCommandClass;
-use crate::consts::{RDM_MAX_PARAMETER_DATA_LENGTH, RDM_MAX_STATUS_PACKAGES_PER_REQUEST};
+use crate::command_class::{RequestCommandClass, ResponseCommandClass};
+use crate::consts::{
+    RDM_ALL_SENSORS, RDM_CAPTURE_PRESET_SIZE, RDM_DEFAULT_SLOT_VALUE_SIZE,
+    RDM_MAX_DISCOVERY_PREAMBLE_SIZE, RDM_MAX_OVERFLOW_BUFFER_SIZE, RDM_MAX_PARAMETER_DATA_LENGTH,
+    RDM_MAX_STATUS_PACKAGES_PER_REQUEST, RDM_SELF_TEST_ALL, RDM_SELF_TEST_OFF,
+};
 use crate::pids;
-use crate::rdm_data::{IsBroadcastError, RdmRequestData, RdmResponseData};
+use crate::rdm_data::{IsBroadcastError, RdmDeserializationError, RdmRequestData, RdmResponseData};
+use crate::rdm_packages::deserialize_device_label;
 use crate::rdm_types::{
-    DeviceInfo, DiscoveryMuteResponse, DmxStartAddress, StatusMessage, StatusType,
+    CommsStatus, Curve, DefaultSlotValue, DeviceInfo, DiscoveryMuteResponse, DmxBlockAddress,
+    DmxPersonality, DmxPersonalityDescription, DmxStartAddress, DmxStartAddressError, IdentifyMode,
+    ParameterDescription, PowerState, PresetPlayback, RealTimeClock, ResetType, SensorDefinition,
+    SensorValue, StatisticsCounter, StatusMessage, StatusType,
 };
 use crate::types::{DataPack, NackReason, ResponseType};
 use crate::unique_identifier::{PackageAddress, UniqueIdentifier};
 
-const INTERNALLY_SUPPORTED_PIDS: [u16; 2] = [pids::QUEUED_MESSAGE, pids::STATUS_MESSAGES];
+/// The sub-device id that addresses all sub-devices at once.
+pub const SUB_DEVICE_ALL_CALL: u16 = 0xFFFF;
+/// The maximum amount of sub-devices allowed by the standard.
+pub const MAX_SUB_DEVICE_COUNT: usize = 512;
+
+const INTERNALLY_SUPPORTED_PIDS: [u16; 5] = [
+    pids::BOOT_SOFTWARE_VERSION_ID,
+    pids::BOOT_SOFTWARE_VERSION_LABEL,
+    pids::DEVICE_MODEL_DESCRIPTION,
+    pids::MANUFACTURER_LABEL,
+    pids::DEVICE_LABEL,
+];
 
 /// The result object of an RDM handler.
+#[allow(clippy::large_enum_variant)]
 pub enum RdmResult {
     /// The package was acknowledged. The [DataPack] contains the response data.
     Acknowledged(DataPack),
@@ -29,6 +49,35 @@ pub enum RdmResult {
     NoResponse,
     /// A custom response.
     Custom(RdmResponseData),
+    /// The package was acknowledged with a response that might not fit into one [DataPack].
+    /// The response is split into chunks that are sent on repeated get requests of the same pid
+    /// without calling the handler again.
+    AcknowledgedStream(OverflowBuffer),
+}
+
+/// A response that is sent in chunks by the [RdmResponderPackageHandler].
+/// Return it in an [RdmResult::AcknowledgedStream].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct OverflowBuffer {
+    data: heapless::Vec<u8, RDM_MAX_OVERFLOW_BUFFER_SIZE>,
+}
+
+impl OverflowBuffer {
+    /// Creates an overflow buffer that owns the data of the response. The buffer is kept by the
+    /// [RdmResponderPackageHandler] until every chunk has been sent.
+    pub const fn new(data: heapless::Vec<u8, RDM_MAX_OVERFLOW_BUFFER_SIZE>) -> Self {
+        Self { data }
+    }
+
+    /// The total amount of bytes of the response.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// true if the response doesn't contain any data.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
 }
 
 /// A context object for accessing the state of a [RdmResponder] from a [crate::dmx_receiver::DmxResponderHandler].
@@ -41,6 +90,37 @@ pub struct DmxReceiverContext<'a> {
     pub discovery_muted: &'a mut bool,
     /// The amount of messages in the message queue.
     pub message_count: u8,
+    /// true if the write protection of the write protected pids is active (e.g. LOCK_STATE).
+    pub write_protected: &'a mut bool,
+    /// The state of the sub-devices. Sub-device 1 is at index 0.
+    pub sub_devices: &'a mut [SubDevice],
+    /// true if the device is identifying itself (e.g. by flashing an LED).
+    pub identify: &'a mut bool,
+    /// How the device identifies itself while [DmxReceiverContext::identify] is true.
+    /// Only changed by the controller if [IDENTIFY_MODE](pids::IDENTIFY_MODE) is in the
+    /// supported pids.
+    pub identify_mode: &'a mut IdentifyMode,
+    /// The raw bytes of the received rdm request if they are available.
+    /// Can be used for logging or to verify signatures over the whole frame.
+    pub raw_request: Option<&'a [u8]>,
+}
+
+/// The state of a sub-device that is handled by the [RdmResponderPackageHandler].
+#[derive(Debug, Clone)]
+pub struct SubDevice {
+    /// The start address of the dmx space of the sub-device.
+    pub dmx_start_address: DmxStartAddress,
+    /// The amount of dmx addresses the sub-device allocates.
+    pub dmx_footprint: u16,
+}
+
+impl Default for SubDevice {
+    fn default() -> Self {
+        Self {
+            dmx_start_address: DmxStartAddress::NoAddress,
+            dmx_footprint: 1,
+        }
+    }
 }
 
 /// A handler for dmx and custom rdm packages.
@@ -53,15 +133,269 @@ pub trait RdmResponderHandlerFunc {
         _request: &RdmRequestData,
         _context: &mut DmxReceiverContext,
     ) -> Result<RdmResult, Self::Error> {
-        Ok(RdmResult::NotAcknowledged(
-            NackReason::UnsupportedCommandClass as u16,
-        ))
+        Ok(RdmResult::NotAcknowledged(u16::from(
+            NackReason::UnsupportedCommandClass,
+        )))
+    }
+
+    /// Handle rdm requests to a sub-device that aren't handled by the [RdmResponder] itself.
+    /// The sub_device is between 1 and the configured sub-device count. Set requests to all
+    /// sub-devices (0xFFFF) are passed to every sub-device and their responses are discarded.
+    fn handle_rdm_sub_device(
+        &mut self,
+        _sub_device: u16,
+        _request: &RdmRequestData,
+        _context: &mut DmxReceiverContext,
+    ) -> Result<RdmResult, Self::Error> {
+        Ok(RdmResult::NotAcknowledged(u16::from(
+            NackReason::UnknownPid,
+        )))
+    }
+
+    /// Called on a FACTORY_DEFAULTS set request after the [RdmResponderPackageHandler] reset
+    /// its own state (device label, dmx start addresses, personality, identify, curve and
+    /// preset playback). Reset the remaining state of the device here.
+    /// Only called if FACTORY_DEFAULTS is listed in the supported pids of the config.
+    fn on_factory_reset(&mut self, _context: &mut DmxReceiverContext) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Intercept IDENTIFY_DEVICE requests to implement custom behavior.
+    /// Return None to let the [RdmResponderPackageHandler] handle the request itself.
+    fn handle_identify_device(
+        &mut self,
+        _request: &RdmRequestData,
+        _context: &mut DmxReceiverContext,
+    ) -> Result<Option<RdmResult>, Self::Error> {
+        Ok(None)
+    }
+
+    /// Called when the identify state was changed by an IDENTIFY_DEVICE or FACTORY_DEFAULTS
+    /// set request.
+    /// Use this to start or stop the identification (e.g. flashing an LED).
+    /// This is also called for broadcast requests, even though they don't get a response.
+    fn on_identify_changed(
+        &mut self,
+        _identify: bool,
+        _context: &mut DmxReceiverContext,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Called on a RESET_DEVICE set request after the response has been built.
+    /// Reset the device once the response has been sent.
+    /// Only called if RESET_DEVICE is listed in the supported pids of the config.
+    fn on_reset(
+        &mut self,
+        _reset_type: ResetType,
+        _context: &mut DmxReceiverContext,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Called on a POWER_STATE set request after the response has been built.
+    /// Change the power state once the response has been sent.
+    /// Only called if [POWER_STATE](pids::POWER_STATE) is in the supported pids.
+    fn on_power_state(
+        &mut self,
+        _power_state: PowerState,
+        _context: &mut DmxReceiverContext,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Called on a PERFORM_SELFTEST set request after the response has been built.
+    /// Start the self test, or all self tests if it is [RDM_SELF_TEST_ALL]. Stop the running
+    /// self tests if it is [RDM_SELF_TEST_OFF]. Use [RdmResponderPackageHandler::set_self_test]
+    /// once the self test has finished.
+    /// Only called if the self test descriptions are configured.
+    fn on_self_test(
+        &mut self,
+        _self_test: u8,
+        _context: &mut DmxReceiverContext,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Called on a get request of a statistics counter. Return the current value of the counter.
+    /// Only called if the pid of the counter is in the supported pids.
+    fn get_statistics_counter(
+        &mut self,
+        _counter: StatisticsCounter,
+        _context: &mut DmxReceiverContext,
+    ) -> Result<u32, Self::Error> {
+        Ok(0)
+    }
+
+    /// Called on a set request of a statistics counter. Reset the counter to the value.
+    /// Return false if the counter is read-only, which nacks the request with a write protect.
+    /// Only called if the pid of the counter is in the supported pids.
+    fn on_set_statistics_counter(
+        &mut self,
+        _counter: StatisticsCounter,
+        _value: u32,
+        _context: &mut DmxReceiverContext,
+    ) -> Result<bool, Self::Error> {
+        Ok(false)
+    }
+
+    /// Called on a REAL_TIME_CLOCK get request. Return the current date and time of the device.
+    /// Only called if [REAL_TIME_CLOCK](pids::REAL_TIME_CLOCK) is in the supported pids.
+    fn get_clock(
+        &mut self,
+        _context: &mut DmxReceiverContext,
+    ) -> Result<RealTimeClock, Self::Error> {
+        Ok(RealTimeClock::default())
+    }
+
+    /// Called on a REAL_TIME_CLOCK set request. Set the real time clock of the device.
+    /// The date is already validated.
+    /// Only called if [REAL_TIME_CLOCK](pids::REAL_TIME_CLOCK) is in the supported pids.
+    fn on_set_clock(
+        &mut self,
+        _clock: RealTimeClock,
+        _context: &mut DmxReceiverContext,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Called on a CAPTURE_PRESET set request. Store the current state as the scene.
+    /// The scene is between 1 and 0xFFFE. Return false if the scene doesn't exist.
+    /// Only called if [CAPTURE_PRESET](pids::CAPTURE_PRESET) is in the supported pids.
+    fn on_capture_preset(
+        &mut self,
+        _scene: u16,
+        _context: &mut DmxReceiverContext,
+    ) -> Result<bool, Self::Error> {
+        Ok(true)
+    }
+
+    /// Called on a PRESET_PLAYBACK set request. Start the playback of the scene at the level.
+    /// See [PresetPlayback] for the special scene values. Return false if the scene doesn't exist.
+    /// Only called if [PRESET_PLAYBACK](pids::PRESET_PLAYBACK) is in the supported pids.
+    fn on_preset_playback(
+        &mut self,
+        _scene: u16,
+        _level: u8,
+        _context: &mut DmxReceiverContext,
+    ) -> Result<bool, Self::Error> {
+        Ok(true)
+    }
+}
+
+/// A message in the message queue of the [RdmResponderPackageHandler].
+///
+/// Only the contents of the response are stored. The uids, the transaction number and
+/// the message count are filled in when the message is requested using QUEUED_MESSAGE.
+#[derive(Debug, Clone)]
+pub struct QueuedMessage {
+    pub response_type: ResponseType,
+    pub sub_device: u16,
+    pub command_class: ResponseCommandClass,
+    pub parameter_id: u16,
+    pub parameter_data: DataPack,
+}
+
+impl QueuedMessage {
+    /// Creates an acknowledged get response of the root device.
+    pub fn new(parameter_id: u16, parameter_data: DataPack) -> Self {
+        Self {
+            response_type: ResponseType::ResponseTypeAck,
+            sub_device: 0,
+            command_class: ResponseCommandClass::GetCommandResponse,
+            parameter_id,
+            parameter_data,
+        }
+    }
+}
+
+/// What happens if a message is queued while the message queue or the status vector is full.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum MessageQueueFullPolicy {
+    /// The oldest message is dropped to make room for the new one.
+    #[default]
+    DropOldest,
+    /// The new message is dropped.
+    DropNewest,
+    /// The new message is rejected with a [MessageQueueFullError]. Nack the request that
+    /// caused the message with [NackReason::BufferFull] if it's still unanswered.
+    Reject,
+}
+
+/// Returned when queueing a message if the queue is full and the
+/// [MessageQueueFullPolicy::Reject] policy is configured.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct MessageQueueFullError;
+
+impl core::fmt::Display for MessageQueueFullError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "The message queue is full.")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MessageQueueFullError {}
+
+/// Pushes the item according to the policy. Increments the dropped count for every dropped item.
+fn push_with_policy<T, const N: usize>(
+    queue: &mut heapless::Vec<T, N>,
+    item: T,
+    policy: MessageQueueFullPolicy,
+    dropped_count: &mut u32,
+) -> Result<(), MessageQueueFullError> {
+    if !queue.is_full() {
+        // can't fail since the vector isn't full
+        let _ = queue.push(item);
+        return Ok(());
+    }
+
+    match policy {
+        MessageQueueFullPolicy::DropOldest => {
+            // a queue without capacity can't hold the new message either
+            if N > 0 {
+                queue.remove(0);
+                let _ = queue.push(item);
+            }
+        },
+        MessageQueueFullPolicy::DropNewest => {},
+        MessageQueueFullPolicy::Reject => return Err(MessageQueueFullError),
+    }
+
+    *dropped_count = dropped_count.saturating_add(1);
+
+    Ok(())
+}
+
+/// Moves the next message of the queue to the last message, so it can be sent again on a
+/// STATUS_GET_LAST_MESSAGE request without being copied. Returns false if the queue is empty.
+fn take_next_message<T, const N: usize>(
+    message_queue: &mut heapless::Vec<T, N>,
+    last_message: &mut Option<T>,
+) -> bool {
+    match message_queue.pop() {
+        Some(message) => {
+            *last_message = Some(message);
+            true
+        },
+        None => false,
     }
 }
 
 struct UnfinishedRequest {
     pid: u16,
     iteration: u16,
+    /// The response of a handler that is sent in chunks.
+    overflow_buffer: Option<OverflowBuffer>,
+}
+
+/// The part of a handler response that didn't fit into the last response.
+struct PendingResponse {
+    pid: u16,
+    data: DataPack,
+    /// true if the handler responded with [RdmResult::AcknowledgedOverflow].
+    overflow: bool,
 }
 
 /// The RDM answer from the [RdmResponderPackageHandler]
@@ -70,12 +404,18 @@ struct UnfinishedRequest {
 pub enum RdmAnswer {
     /// Has to be sent with an uart break
     Response(RdmResponseData),
-    /// Has to be sent without an uart break
+    /// Has to be sent without an uart break. Use
+    /// [RdmResponderPackageHandler::get_discovery_preamble_len] for the amount of preamble bytes.
     DiscoveryResponse(UniqueIdentifier),
     /// No response to send
     NoResponse,
 }
 
+/// Serializes a label and truncates it to 32 bytes.
+fn serialize_label(label: &str) -> DataPack {
+    DataPack::from_slice(&label.as_bytes()[..label.len().min(32)]).unwrap()
+}
+
 macro_rules! build_nack {
     ($request:path, $nack_reason:path, $message_count:path) => {
         $request.build_response(
@@ -86,6 +426,11 @@ macro_rules! build_nack {
     };
 }
 
+/// Verifies that a request is an unicast get request.
+///
+/// Parameter data of a get request for a pid that doesn't take any is ignored. Handlers of pids
+/// that take parameter data on get validate the length themselves and nack with
+/// [NackReason::FormatError].
 macro_rules! verify_get_request {
     ($request:path, $responder:path) => {
         if $request.destination_uid.is_broadcast() {
@@ -97,9 +442,16 @@ macro_rules! verify_get_request {
         if $request.command_class != RequestCommandClass::GetCommand {
             return build_nack!($request, NackReason::UnsupportedCommandClass, message_count).ok();
         }
+    };
+}
 
-        if $request.sub_device != 0 {
-            return build_nack!($request, NackReason::SubDeviceOutOfRange, message_count).ok();
+/// Verifies that a request doesn't carry parameter data, nacks with [NackReason::FormatError]
+/// otherwise. Broadcast requests aren't answered.
+macro_rules! verify_empty_parameter_data {
+    ($request:path, $responder:path) => {
+        if !$request.parameter_data.is_empty() {
+            let message_count = $responder.get_message_count();
+            return build_nack!($request, NackReason::FormatError, message_count).ok();
         }
     };
 }
@@ -117,7 +469,16 @@ pub struct RdmReceiverMetadata {
     pub device_model_id: u16,
     pub product_category: u16,
     pub software_version_id: u32,
+    /// The software version label. Labels longer than 32 bytes get truncated.
     pub software_version_label: &'static str,
+    /// The version id of the boot software (e.g. the bootloader).
+    pub boot_software_version_id: u32,
+    /// The version label of the boot software. Labels longer than 32 bytes get truncated.
+    pub boot_software_version_label: &'static str,
+    /// The description of the device model. Descriptions longer than 32 bytes get truncated.
+    pub device_model_description: &'static str,
+    /// The name of the manufacturer. Labels longer than 32 bytes get truncated.
+    pub manufacturer_label: &'static str,
 }
 
 impl Default for RdmReceiverMetadata {
@@ -127,17 +488,209 @@ impl Default for RdmReceiverMetadata {
             product_category: 0,
             software_version_id: 0,
             software_version_label: "dmx-rdm-rs device",
+            boot_software_version_id: 0,
+            boot_software_version_label: "",
+            device_model_description: "",
+            manufacturer_label: "",
         }
     }
 }
 
+/// Serializes the pids within the range of indices.
+fn serialize_pid_range(
+    pids: impl Iterator<Item = u16>,
+    range: core::ops::Range<usize>,
+) -> DataPack {
+    let mut serialized_pids = DataPack::new();
+
+    for pid in pids.skip(range.start).take(range.len()) {
+        serialized_pids
+            .extend_from_slice(&pid.to_be_bytes())
+            .unwrap();
+    }
+
+    serialized_pids
+}
+
 pub struct RdmResponderConfig {
     /// The unique id that is used as a source id in the packages.
     pub uid: UniqueIdentifier,
     /// An array that contains all the supported pids excluding once that are required by the standard.
+    ///
+    /// The automatically advertised pids ([DEVICE_LABEL](pids::DEVICE_LABEL),
+    /// [MANUFACTURER_LABEL](pids::MANUFACTURER_LABEL), ...) that are listed here are passed to the
+    /// handler instead of being answered internally.
+    /// [FACTORY_DEFAULTS](pids::FACTORY_DEFAULTS) and [RESET_DEVICE](pids::RESET_DEVICE) are
+    /// only handled internally if they are listed here.
     pub supported_pids: &'static [u16],
     /// Additional metadata of the RDM-receiver.
     pub rdm_receiver_metadata: RdmReceiverMetadata,
+    /// The amount of preamble bytes (0-7) sent before a discovery response.
+    /// Defaults to 7 (the maximum allowed by the standard).
+    pub discovery_preamble_len: usize,
+    /// The maximum parameter data length (2-231) of a response. Responses to get requests that are
+    /// longer will be split using [ResponseType::ResponseTypeAckOverflow].
+    /// None uses the maximum allowed by the standard.
+    pub max_response_parameter_len: Option<usize>,
+    /// The internally handled pids that can't be set while the write protection is active.
+    /// Currently [DMX_START_ADDRESS](pids::DMX_START_ADDRESS),
+    /// [DMX_BLOCK_ADDRESS](pids::DMX_BLOCK_ADDRESS), [DMX_PERSONALITY](pids::DMX_PERSONALITY)
+    /// and [DEVICE_LABEL](pids::DEVICE_LABEL) are supported.
+    pub write_protected_pids: &'static [u16],
+    /// The descriptions of manufacturer specific pids that are answered on
+    /// PARAMETER_DESCRIPTION requests. If empty, PARAMETER_DESCRIPTION requests are
+    /// passed to the handler.
+    pub parameter_descriptions: &'static [ParameterDescription<&'static str>],
+    /// The personalities of the device. The index plus one is the personality number.
+    /// If empty, DMX_PERSONALITY requests are passed to the handler.
+    pub dmx_personalities: &'static [DmxPersonalityDescription<&'static str>],
+    /// The definitions of the sensors. The index is the sensor number.
+    /// Can't contain more definitions than the SENSOR_COUNT of the responder.
+    pub sensor_definitions: &'static [SensorDefinition<&'static str>],
+    /// If true, the internally handled pids are reported in SUPPORTED_PARAMETERS in addition
+    /// to the supported_pids. Set to false to report only the supported_pids.
+    pub advertise_internal_pids: bool,
+    /// If true, SUPPORTED_PARAMETERS is answered in ascending order instead of the internal
+    /// pids followed by the supported_pids. The standard doesn't require any order, but some
+    /// controllers expect the pids to be sorted.
+    pub sort_supported_parameters: bool,
+    /// The power-up default values of the dmx slots that are answered on DEFAULT_SLOT_VALUE
+    /// requests. If empty, DEFAULT_SLOT_VALUE requests are passed to the handler.
+    pub default_slot_values: &'static [DefaultSlotValue],
+    /// The control flags and the binding uid that are answered on DISC_MUTE and DISC_UN_MUTE
+    /// requests. Set the binding uid to the uid of the primary port on devices with multiple
+    /// responder ports. The sub-device flag is always set if the responder has sub-devices.
+    pub discovery_mute_response: DiscoveryMuteResponse,
+    /// What happens if a message is queued using [RdmResponderPackageHandler::queue_message]
+    /// or [RdmResponderPackageHandler::queue_status_message] while the queue is full.
+    pub message_queue_full_policy: MessageQueueFullPolicy,
+    /// The labels of the self tests. The index plus one is the self test number.
+    /// Labels longer than 32 bytes get truncated.
+    /// If empty, PERFORM_SELFTEST and SELF_TEST_DESCRIPTION requests are passed to the handler.
+    pub self_test_descriptions: &'static [&'static str],
+    /// The labels of the dimming curves. The index plus one is the curve number.
+    /// Labels longer than 32 bytes get truncated.
+    /// If empty, CURVE and CURVE_DESCRIPTION requests are passed to the handler.
+    pub curve_descriptions: &'static [&'static str],
+    /// The time in microseconds the [RdmResponder](crate::dmx_receiver::RdmResponder) and the
+    /// `AsyncRdmResponder` wait before sending a response. Some older controllers need a gap
+    /// to switch to receiving. Discovery responses aren't delayed. Use 0 unless the controller requires otherwise.
+    pub response_delay_us: u32,
+}
+
+/// The uid of the default config is in the prototyping range of the ESTA (manufacturer id 0x7FF0)
+/// and has to be replaced by the uid of the device.
+impl Default for RdmResponderConfig {
+    fn default() -> Self {
+        Self {
+            uid: UniqueIdentifier::new(0x7FF0, 1).unwrap(),
+            supported_pids: &[],
+            rdm_receiver_metadata: Default::default(),
+            discovery_preamble_len: RDM_MAX_DISCOVERY_PREAMBLE_SIZE,
+            max_response_parameter_len: None,
+            write_protected_pids: &[],
+            parameter_descriptions: &[],
+            sensor_definitions: &[],
+            dmx_personalities: &[],
+            advertise_internal_pids: true,
+            sort_supported_parameters: false,
+            default_slot_values: &[],
+            discovery_mute_response: Default::default(),
+            message_queue_full_policy: Default::default(),
+            self_test_descriptions: &[],
+            curve_descriptions: &[],
+            response_delay_us: 0,
+        }
+    }
+}
+
+impl RdmResponderConfig {
+    /// Checks the supported pids for mistakes.
+    ///
+    /// The supported pids must not contain duplicates, reserved pids or pids that are required
+    /// by the standard. If the internal pids are advertised, they must not contain pids that
+    /// are answered by the [RdmResponderPackageHandler] because of the config (e.g.
+    /// [DMX_PERSONALITY](pids::DMX_PERSONALITY) if personalities are configured).
+    ///
+    /// This is checked when creating an [RdmResponderPackageHandler] in debug builds.
+    pub fn validate(&self) -> Result<(), ConfigValidationError> {
+        for (index, &pid) in self.supported_pids.iter().enumerate() {
+            if pid == 0x0000 || pid >= 0xFFE0 {
+                return Err(ConfigValidationError::ReservedPid(pid));
+            }
+
+            if pids::required_pids().contains(&pid) {
+                return Err(ConfigValidationError::RequiredPid(pid));
+            }
+
+            if self.advertise_internal_pids && self.is_internal_pid(pid) {
+                return Err(ConfigValidationError::InternalPid(pid));
+            }
+
+            if self.supported_pids[..index].contains(&pid) {
+                return Err(ConfigValidationError::DuplicatePid(pid));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn is_internal_pid(&self, pid: u16) -> bool {
+        match pid {
+            pids::QUEUED_MESSAGE | pids::STATUS_MESSAGES | pids::DMX_BLOCK_ADDRESS => true,
+            pids::PARAMETER_DESCRIPTION => !self.parameter_descriptions.is_empty(),
+            pids::DMX_PERSONALITY | pids::DMX_PERSONALITY_DESCRIPTION => {
+                !self.dmx_personalities.is_empty()
+            },
+            pids::SENSOR_DEFINITION | pids::SENSOR_VALUE | pids::RECORD_SENSORS => {
+                !self.sensor_definitions.is_empty()
+            },
+            pids::DEFAULT_SLOT_VALUE => !self.default_slot_values.is_empty(),
+            pids::PERFORM_SELFTEST | pids::SELF_TEST_DESCRIPTION => {
+                !self.self_test_descriptions.is_empty()
+            },
+            pids::CURVE | pids::CURVE_DESCRIPTION => !self.curve_descriptions.is_empty(),
+            _ => false,
+        }
+    }
+}
+
+/// Returned by [RdmResponderConfig::validate] if the supported pids contain a mistake.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ConfigValidationError {
+    /// The pid is contained more than once.
+    DuplicatePid(u16),
+    /// The pid is required by the standard and must not be advertised.
+    RequiredPid(u16),
+    /// The pid is already advertised by the [RdmResponderPackageHandler].
+    InternalPid(u16),
+    /// The pid is reserved by the standard.
+    ReservedPid(u16),
+}
+
+impl core::fmt::Display for ConfigValidationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::DuplicatePid(pid) => write!(f, "pid {pid:#06X} is supported more than once"),
+            Self::RequiredPid(pid) => write!(f, "pid {pid:#06X} is required by the standard"),
+            Self::InternalPid(pid) => write!(f, "pid {pid:#06X} is already supported internally"),
+            Self::ReservedPid(pid) => write!(f, "pid {pid:#06X} is reserved"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConfigValidationError {}
+
+/// An advertised pid that isn't handled, returned by
+/// [RdmResponderPackageHandler::verify_advertised_pids].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct UnhandledPid {
+    pub parameter_id: u16,
+    /// The reason the get request has been nacked with.
+    pub nack_reason: NackReason,
 }
 
 /// A structure to handle RDM requests and generate the responses.
@@ -146,8 +699,14 @@ pub struct RdmResponderConfig {
 /// without it in order to realize custom dmx setups that can't rely on the
 /// dmx_driver pattern.
 /// MQ_SIZE specifies the size of the message queue and the status vector. MQ_SIZE cannot be greater
-/// than 255.
-pub struct RdmResponderPackageHandler<const MQ_SIZE: usize> {
+/// than 255. If MQ_SIZE is 0, QUEUED_MESSAGE and STATUS_MESSAGES are not supported.
+/// SUB_DEVICE_COUNT specifies the amount of sub-devices. SUB_DEVICE_COUNT cannot be greater than 512.
+/// SENSOR_COUNT specifies the maximum amount of sensors. SENSOR_COUNT cannot be greater than 255.
+pub struct RdmResponderPackageHandler<
+    const MQ_SIZE: usize,
+    const SUB_DEVICE_COUNT: usize = 0,
+    const SENSOR_COUNT: usize = 0,
+> {
     /// The start of the dmx address space.
     pub dmx_start_address: DmxStartAddress,
     /// The amount of addresses the dmx device allocates.
@@ -155,34 +714,141 @@ pub struct RdmResponderPackageHandler<const MQ_SIZE: usize> {
     supported_pids: &'static [u16],
     rdm_receiver_metadata: RdmReceiverMetadata,
     uid: UniqueIdentifier,
+    discovery_preamble_len: usize,
+    response_delay_us: u32,
+    max_response_parameter_len: usize,
     discovery_muted: bool,
     unfinished_request: Option<UnfinishedRequest>,
-    message_queue: heapless::Deque<RdmResponseData, MQ_SIZE>,
+    pending_response: Option<PendingResponse>,
+    message_queue: heapless::Vec<QueuedMessage, MQ_SIZE>,
     status_vec: heapless::Vec<StatusMessage, MQ_SIZE>,
-    last_queued_message: Option<RdmResponseData>,
+    last_queued_message: Option<QueuedMessage>,
     last_status_vec_message: DataPack,
+    device_label: heapless::String<32>,
+    write_protected_pids: &'static [u16],
+    parameter_descriptions: &'static [ParameterDescription<&'static str>],
+    dmx_personalities: &'static [DmxPersonalityDescription<&'static str>],
+    dmx_personality: u8,
+    write_protected: bool,
+    factory_defaults: bool,
+    identify: bool,
+    identify_mode: IdentifyMode,
+    power_state: PowerState,
+    self_test_descriptions: &'static [&'static str],
+    self_test: u8,
+    curve_descriptions: &'static [&'static str],
+    curve: u8,
+    comms_status: CommsStatus,
+    preset_playback: PresetPlayback,
+    sub_devices: [SubDevice; SUB_DEVICE_COUNT],
+    sensor_definitions: &'static [SensorDefinition<&'static str>],
+    advertise_internal_pids: bool,
+    sort_supported_parameters: bool,
+    default_slot_values: &'static [DefaultSlotValue],
+    discovery_mute_response: DiscoveryMuteResponse,
+    message_queue_full_policy: MessageQueueFullPolicy,
+    dropped_message_count: u32,
+    sensor_values: heapless::Vec<SensorValue, SENSOR_COUNT>,
 }
 
-impl<const MQ_SIZE: usize> RdmResponderPackageHandler<MQ_SIZE> {
+impl<const MQ_SIZE: usize, const SUB_DEVICE_COUNT: usize, const SENSOR_COUNT: usize>
+    RdmResponderPackageHandler<MQ_SIZE, SUB_DEVICE_COUNT, SENSOR_COUNT>
+{
     /// Creates a new [RdmResponderPackageHandler].
     pub fn new(config: RdmResponderConfig) -> Self {
         assert!(
             MQ_SIZE <= u8::MAX as usize,
             "Message queue size cannot be greater than 255."
         );
+        assert!(
+            SUB_DEVICE_COUNT <= MAX_SUB_DEVICE_COUNT,
+            "Sub-device count cannot be greater than 512."
+        );
+        assert!(
+            SENSOR_COUNT <= u8::MAX as usize,
+            "Sensor count cannot be greater than 255."
+        );
+        assert!(
+            config.sensor_definitions.len() <= SENSOR_COUNT,
+            "There are more sensor definitions than SENSOR_COUNT."
+        );
+        assert!(
+            config.dmx_personalities.len() <= u8::MAX as usize,
+            "There cannot be more than 255 personalities."
+        );
+        assert!(
+            config.self_test_descriptions.len() < RDM_SELF_TEST_ALL as usize,
+            "There cannot be more than 254 self tests."
+        );
+        assert!(
+            config.curve_descriptions.len() <= u8::MAX as usize,
+            "There cannot be more than 255 curves."
+        );
+        assert!(
+            config.discovery_preamble_len <= RDM_MAX_DISCOVERY_PREAMBLE_SIZE,
+            "Discovery preamble length cannot be greater than 7."
+        );
+        #[cfg(debug_assertions)]
+        if let Err(error) = config.validate() {
+            panic!("The supported pids are invalid: {error}");
+        }
+
+        let max_response_parameter_len = config
+            .max_response_parameter_len
+            .unwrap_or(RDM_MAX_PARAMETER_DATA_LENGTH);
+        assert!(
+            (2..=RDM_MAX_PARAMETER_DATA_LENGTH).contains(&max_response_parameter_len),
+            "Maximum response parameter length has to be between 2 and 231."
+        );
 
         Self {
             supported_pids: config.supported_pids,
             dmx_start_address: DmxStartAddress::NoAddress,
-            dmx_footprint: 1,
+            dmx_footprint: config
+                .dmx_personalities
+                .first()
+                .map_or(1, |personality| personality.dmx_footprint),
             rdm_receiver_metadata: config.rdm_receiver_metadata,
             uid: config.uid,
+            discovery_preamble_len: config.discovery_preamble_len,
+            response_delay_us: config.response_delay_us,
+            max_response_parameter_len,
             discovery_muted: false,
             unfinished_request: None,
-            message_queue: heapless::Deque::new(),
+            pending_response: None,
+            message_queue: heapless::Vec::new(),
             status_vec: heapless::Vec::new(),
             last_queued_message: None,
             last_status_vec_message: DataPack::new(),
+            device_label: heapless::String::new(),
+            write_protected_pids: config.write_protected_pids,
+            parameter_descriptions: config.parameter_descriptions,
+            dmx_personalities: config.dmx_personalities,
+            dmx_personality: 1,
+            write_protected: false,
+            factory_defaults: true,
+            identify: false,
+            identify_mode: IdentifyMode::default(),
+            power_state: PowerState::default(),
+            self_test_descriptions: config.self_test_descriptions,
+            self_test: RDM_SELF_TEST_OFF,
+            curve_descriptions: config.curve_descriptions,
+            curve: 1,
+            comms_status: CommsStatus::default(),
+            preset_playback: PresetPlayback::default(),
+            sub_devices: core::array::from_fn(|_| SubDevice::default()),
+            sensor_definitions: config.sensor_definitions,
+            advertise_internal_pids: config.advertise_internal_pids,
+            sort_supported_parameters: config.sort_supported_parameters,
+            default_slot_values: config.default_slot_values,
+            discovery_mute_response: config.discovery_mute_response,
+            message_queue_full_policy: config.message_queue_full_policy,
+            dropped_message_count: 0,
+            sensor_values: config
+                .sensor_definitions
+                .iter()
+                .map(SensorValue::from_definition)
+                .collect(),
         }
     }
 
@@ -191,19 +857,61 @@ impl<const MQ_SIZE: usize> RdmResponderPackageHandler<MQ_SIZE> {
         self.uid
     }
 
+    /// Get the amount of preamble bytes that have to be sent before an
+    /// [RdmAnswer::DiscoveryResponse].
+    pub fn get_discovery_preamble_len(&self) -> usize {
+        self.discovery_preamble_len
+    }
+
+    /// Get the time in microseconds to wait before sending an [RdmAnswer::Response].
+    pub fn get_response_delay_us(&self) -> u32 {
+        self.response_delay_us
+    }
+
     /// Get the message queue that contains the results of [RdmResult::AcknowledgedTimer] packages.
-    pub fn get_message_queue(&self) -> &heapless::Deque<RdmResponseData, MQ_SIZE> {
+    pub fn get_message_queue(&self) -> &heapless::Vec<QueuedMessage, MQ_SIZE> {
         &self.message_queue
     }
 
     /// Get the message queue to add the results of [RdmResult::AcknowledgedTimer] packages to.
-    pub fn get_message_queue_mut(&mut self) -> &mut heapless::Deque<RdmResponseData, MQ_SIZE> {
+    /// The messages are sent from the back of the queue, so the last pushed message is sent first.
+    pub fn get_message_queue_mut(&mut self) -> &mut heapless::Vec<QueuedMessage, MQ_SIZE> {
         &mut self.message_queue
     }
 
-    /// Get the amount of queued messages.
+    /// Add a message to the message queue according to the configured
+    /// [MessageQueueFullPolicy]. The most recently queued message is sent first.
+    pub fn queue_message(&mut self, message: QueuedMessage) -> Result<(), MessageQueueFullError> {
+        push_with_policy(
+            &mut self.message_queue,
+            message,
+            self.message_queue_full_policy,
+            &mut self.dropped_message_count,
+        )
+    }
+
+    /// Add a message to the status vector according to the configured [MessageQueueFullPolicy].
+    pub fn queue_status_message(
+        &mut self,
+        status_message: StatusMessage,
+    ) -> Result<(), MessageQueueFullError> {
+        push_with_policy(
+            &mut self.status_vec,
+            status_message,
+            self.message_queue_full_policy,
+            &mut self.dropped_message_count,
+        )
+    }
+
+    /// Get the amount of messages that were dropped since the message queue or the status
+    /// vector was full.
+    pub fn get_dropped_message_count(&self) -> u32 {
+        self.dropped_message_count
+    }
+
+    /// Get the amount of queued messages, saturated at 255.
     pub fn get_message_count(&self) -> u8 {
-        self.message_queue.len() as u8
+        self.message_queue.len().min(u8::MAX as usize) as u8
     }
 
     /// Get the status queue that contains the current status messages.
@@ -216,434 +924,5230 @@ impl<const MQ_SIZE: usize> RdmResponderPackageHandler<MQ_SIZE> {
         &mut self.status_vec
     }
 
-    /// Gets a context object that contains references to the current internal state
-    /// of some of the parameters.
-    pub fn get_context(&mut self) -> DmxReceiverContext {
-        let message_count = self.get_message_count();
+    /// Get the device label that can be read and written by the controller.
+    pub fn get_device_label(&self) -> &str {
+        &self.device_label
+    }
 
-        DmxReceiverContext {
-            dmx_start_address: &mut self.dmx_start_address,
-            dmx_footprint: &mut self.dmx_footprint,
-            discovery_muted: &mut self.discovery_muted,
-            message_count,
+    /// Set the device label. Labels longer than 32 bytes will be truncated.
+    pub fn set_device_label(&mut self, device_label: &str) {
+        let mut end_index = device_label.len().min(32);
+        while !device_label.is_char_boundary(end_index) {
+            end_index -= 1;
         }
+
+        self.device_label.clear();
+        self.device_label
+            .push_str(&device_label[..end_index])
+            .unwrap();
     }
 
-    /// Method to handle a received and deserialized RdmPackage from the RDM-Controller.
-    /// This method will return the response package that has to be sent back to the RDM-Controller.
-    pub fn handle_rdm_request<HandlerError>(
-        &mut self,
-        request: RdmRequestData,
-        handler: &mut dyn RdmResponderHandlerFunc<Error = HandlerError>,
-    ) -> Result<RdmAnswer, HandlerError> {
-        match request.destination_uid {
-            PackageAddress::ManufacturerBroadcast(manufacturer_uid) => {
-                if manufacturer_uid != self.uid.manufacturer_uid() {
-                    return Ok(RdmAnswer::NoResponse);
-                }
-            },
-            PackageAddress::Device(device_uid) => {
-                if self.uid != device_uid {
-                    return Ok(RdmAnswer::NoResponse);
-                }
-            },
-            _ => {},
-        }
+    /// Returns true if the write protection is active.
+    pub fn is_write_protected(&self) -> bool {
+        self.write_protected
+    }
 
-        if request.command_class == RequestCommandClass::DiscoveryCommand
-            && ![
-                pids::DISC_UNIQUE_BRANCH,
-                pids::DISC_MUTE,
-                pids::DISC_UN_MUTE,
-            ]
-            .contains(&request.parameter_id)
-        {
-            return Ok(RdmAnswer::NoResponse);
-        }
+    /// Activate or deactivate the write protection. While active, set requests of the
+    /// write protected pids will be not acknowledged with [NackReason::WriteProtect].
+    pub fn set_write_protected(&mut self, write_protected: bool) {
+        self.write_protected = write_protected;
+    }
 
-        let response = match request.parameter_id {
-            pids::DISC_UNIQUE_BRANCH => return Ok(self.handle_disc_unique_branch(&request)),
-            pids::DISC_MUTE => self.handle_disc_mute(&request),
-            pids::DISC_UN_MUTE => self.handle_disc_unmute(&request),
-            pids::SUPPORTED_PARAMETERS => self.handle_supported_parameters(&request),
-            pids::DEVICE_INFO => self.handle_device_info(&request),
-            pids::SOFTWARE_VERSION_LABEL => self.handle_get_software_version_label(&request),
-            pids::DMX_START_ADDRESS => self.handle_dmx_start_address(&request),
-            pids::QUEUED_MESSAGE => self.handle_queued_message(&request),
-            pids::STATUS_MESSAGES => self.handle_status_messages(&request),
-            _ => self.handle_other_request(&request, handler)?,
-        };
+    /// Get the state of the sub-devices. Sub-device 1 is at index 0.
+    pub fn get_sub_devices(&self) -> &[SubDevice; SUB_DEVICE_COUNT] {
+        &self.sub_devices
+    }
 
-        // Was this a broadcast?
-        if let Some(response_data) = response {
-            return Ok(RdmAnswer::Response(response_data));
-        }
+    /// Get the state of the sub-devices to modify it. Sub-device 1 is at index 0.
+    pub fn get_sub_devices_mut(&mut self) -> &mut [SubDevice; SUB_DEVICE_COUNT] {
+        &mut self.sub_devices
+    }
 
-        // No response since the request is a broadcast
-        Ok(RdmAnswer::NoResponse)
+    /// Returns true if the device is at its factory defaults.
+    ///
+    /// This is set after a FACTORY_DEFAULTS set request and cleared as soon as a set request
+    /// of any other pid was acknowledged.
+    pub fn is_factory_defaults(&self) -> bool {
+        self.factory_defaults
     }
 
-    fn handle_other_request<HandlerError>(
-        &mut self,
-        request: &RdmRequestData,
-        handler: &mut dyn RdmResponderHandlerFunc<Error = HandlerError>,
-    ) -> Result<Option<RdmResponseData>, HandlerError> {
-        let response = match handler.handle_rdm(request, &mut self.get_context())? {
-            RdmResult::Acknowledged(response_data) => request.build_response(
-                ResponseType::ResponseTypeAck,
-                response_data,
-                self.get_message_count(),
-            ),
-            RdmResult::AcknowledgedOverflow(response_data) => request.build_response(
-                ResponseType::ResponseTypeAckOverflow,
-                response_data,
-                self.get_message_count(),
-            ),
-            RdmResult::NotAcknowledged(nack_reason) => request.build_response(
-                ResponseType::ResponseTypeNackReason,
-                DataPack::from_slice(&nack_reason.to_be_bytes()).unwrap(),
-                self.get_message_count(),
-            ),
-            RdmResult::AcknowledgedTimer(timer) => request.build_response(
-                ResponseType::ResponseTypeAckTimer,
-                DataPack::from_slice(&timer.to_be_bytes()).unwrap(),
-                self.get_message_count(),
-            ),
-            RdmResult::NoResponse => {
-                return Ok(None);
-            },
-            RdmResult::Custom(response_data) => Ok(response_data),
-        };
+    /// Set whether the device is at its factory defaults.
+    pub fn set_factory_defaults(&mut self, factory_defaults: bool) {
+        self.factory_defaults = factory_defaults;
+    }
 
-        Ok(response.ok())
+    /// Returns true if the device is identifying itself.
+    pub fn is_identifying(&self) -> bool {
+        self.identify
     }
 
-    fn handle_disc_unique_branch(&self, request: &RdmRequestData) -> RdmAnswer {
-        if request.command_class != RequestCommandClass::DiscoveryCommand {
-            let message_count = self.get_message_count();
-            return match build_nack!(request, NackReason::UnsupportedCommandClass, message_count) {
-                Ok(response) => RdmAnswer::Response(response),
-                Err(_) => RdmAnswer::NoResponse,
-            };
-        }
+    /// Returns the pid and the iteration of the internally handled get request that is
+    /// currently being paginated.
+    #[cfg(all(test, feature = "std"))]
+    pub(crate) fn pagination_state(&self) -> Option<(u16, u16)> {
+        self.unfinished_request
+            .as_ref()
+            .map(|unfinished_request| (unfinished_request.pid, unfinished_request.iteration))
+    }
 
-        if request.parameter_data.len() != 12 {
-            return RdmAnswer::NoResponse;
-        }
+    /// Start or stop the identification of the device.
+    pub fn set_identify(&mut self, identify: bool) {
+        self.identify = identify;
+    }
 
-        let lower_bound: u64 =
-            PackageAddress::from_bytes(&request.parameter_data[..6].try_into().unwrap()).into();
-        let upper_bound: u64 =
-            PackageAddress::from_bytes(&request.parameter_data[6..].try_into().unwrap()).into();
-        let own_uid: u64 = self.uid.into();
+    /// Get how the device identifies itself while identifying.
+    pub fn get_identify_mode(&self) -> IdentifyMode {
+        self.identify_mode
+    }
 
-        if !self.discovery_muted && own_uid >= lower_bound && own_uid <= upper_bound {
-            return RdmAnswer::DiscoveryResponse(self.uid);
-        }
+    /// Set how the device identifies itself while identifying.
+    pub fn set_identify_mode(&mut self, identify_mode: IdentifyMode) {
+        self.identify_mode = identify_mode;
+    }
 
-        RdmAnswer::NoResponse
+    /// Get the current power state.
+    pub fn get_power_state(&self) -> PowerState {
+        self.power_state
     }
 
-    fn handle_disc_mute(&mut self, request: &RdmRequestData) -> Option<RdmResponseData> {
-        verify_disc_request!(request, self);
+    /// Set the current power state (e.g. if the device was woken up locally).
+    pub fn set_power_state(&mut self, power_state: PowerState) {
+        self.power_state = power_state;
+    }
 
-        if !request.parameter_data.is_empty() {
-            return None;
-        }
+    /// Get the self test that is currently performed. [RDM_SELF_TEST_OFF] if no self test
+    /// is running.
+    pub fn get_self_test(&self) -> u8 {
+        self.self_test
+    }
 
-        self.discovery_muted = true;
-        self.build_disc_mute_response(request).ok()
+    /// Set the self test that is currently performed. Set to [RDM_SELF_TEST_OFF] once the self
+    /// test has finished.
+    pub fn set_self_test(&mut self, self_test: u8) {
+        self.self_test = self_test;
     }
 
-    fn handle_disc_unmute(&mut self, request: &RdmRequestData) -> Option<RdmResponseData> {
-        verify_disc_request!(request, self);
+    /// Get the communication error counters (COMMS_STATUS).
+    pub fn get_comms_status(&self) -> CommsStatus {
+        self.comms_status
+    }
 
-        if !request.parameter_data.is_empty() {
-            return None;
-        }
+    /// Count a received package that couldn't be deserialized in the COMMS_STATUS counters.
+    /// Errors that don't belong to a counter are ignored.
+    pub fn record_deserialization_error(&mut self, error: &RdmDeserializationError) {
+        let counter = match error {
+            RdmDeserializationError::BufferTooSmall => &mut self.comms_status.short_message,
+            RdmDeserializationError::WrongMessageLength(_) => {
+                &mut self.comms_status.length_mismatch
+            },
+            RdmDeserializationError::WrongChecksum { .. } => &mut self.comms_status.checksum_fail,
+            _ => return,
+        };
 
-        self.discovery_muted = false;
-        self.build_disc_mute_response(request).ok()
+        *counter = counter.saturating_add(1);
     }
 
-    fn handle_get_software_version_label(
-        &self,
-        request: &RdmRequestData,
-    ) -> Option<RdmResponseData> {
-        verify_get_request!(request, self);
-
-        let software_version_label = self.rdm_receiver_metadata.software_version_label;
+    /// Get the current preset playback mode.
+    pub fn get_preset_playback(&self) -> PresetPlayback {
+        self.preset_playback
+    }
 
-        request
-            .build_response(
-                ResponseType::ResponseTypeAck,
-                DataPack::from_slice(
-                    &software_version_label.as_bytes()[..software_version_label.len().min(32)],
-                )
-                .unwrap(),
-                self.get_message_count(),
-            )
-            .ok()
+    /// Set the current preset playback mode (e.g. if the playback was started locally).
+    pub fn set_preset_playback(&mut self, preset_playback: PresetPlayback) {
+        self.preset_playback = preset_playback;
     }
 
-    fn handle_supported_parameters(&mut self, request: &RdmRequestData) -> Option<RdmResponseData> {
-        verify_get_request!(request, self);
+    /// Get the current personality. Personalities are numbered starting at 1.
+    pub fn get_dmx_personality(&self) -> u8 {
+        self.dmx_personality
+    }
 
-        let current_iteration = match &self.unfinished_request {
-            Some(UnfinishedRequest {
-                pid: pids::SUPPORTED_PARAMETERS,
-                iteration,
-            }) => *iteration,
-            _ => 0,
+    /// Set the current personality and the dmx footprint of the personality.
+    /// Returns false if the personality doesn't exist.
+    pub fn set_dmx_personality(&mut self, personality: u8) -> bool {
+        let dmx_personality = match (personality as usize)
+            .checked_sub(1)
+            .and_then(|index| self.dmx_personalities.get(index))
+        {
+            Some(dmx_personality) => dmx_personality,
+            None => return false,
         };
 
-        // one pid is u16
-        const MAX_PIDS_PER_RESPONSE: usize = RDM_MAX_PARAMETER_DATA_LENGTH / 2;
-        let current_parameter_index = MAX_PIDS_PER_RESPONSE * (current_iteration as usize);
+        self.dmx_personality = personality;
+        self.dmx_footprint = dmx_personality.dmx_footprint;
 
-        let amount_pids = self.supported_pids.len() + INTERNALLY_SUPPORTED_PIDS.len();
-        let end_parameter_index = amount_pids.min(current_parameter_index + MAX_PIDS_PER_RESPONSE);
+        true
+    }
 
-        let mut response_package = DataPack::new();
+    /// Get the current dimming curve. Curves are numbered starting at 1.
+    pub fn get_curve(&self) -> u8 {
+        self.curve
+    }
 
-        for supported_pid in INTERNALLY_SUPPORTED_PIDS
-            .iter()
-            .chain(self.supported_pids.iter())
-        {
-            response_package
-                .extend_from_slice(&supported_pid.to_be_bytes())
-                .unwrap();
+    /// Set the current dimming curve. Returns false if the curve doesn't exist.
+    pub fn set_curve(&mut self, curve: u8) -> bool {
+        if !(1..=self.curve_descriptions.len()).contains(&(curve as usize)) {
+            return false;
         }
 
-        if end_parameter_index != amount_pids {
-            self.unfinished_request = Some(UnfinishedRequest {
-                pid: pids::SUPPORTED_PARAMETERS,
-                iteration: current_iteration + 1,
-            });
-
-            request
-                .build_response(
-                    ResponseType::ResponseTypeAckOverflow,
-                    response_package,
-                    self.get_message_count(),
-                )
-                .ok()
-        } else {
-            self.unfinished_request = None;
+        self.curve = curve;
 
-            request
-                .build_response(
-                    ResponseType::ResponseTypeAck,
-                    response_package,
-                    self.get_message_count(),
-                )
-                .ok()
-        }
+        true
     }
 
-    fn handle_dmx_start_address(&mut self, request: &RdmRequestData) -> Option<RdmResponseData> {
-        let message_count = self.get_message_count();
-
-        match request.command_class {
-            RequestCommandClass::GetCommand => request.build_response(
-                ResponseType::ResponseTypeAck,
-                self.dmx_start_address.serialize(),
-                self.message_queue.len() as u8,
-            ),
-            RequestCommandClass::SetCommand => 'set_command: {
-                if request.parameter_data.len() != 2 {
-                    break 'set_command build_nack!(
-                        request,
-                        NackReason::FormatError,
-                        message_count
-                    );
-                }
-
-                let dmx_start_address = match DmxStartAddress::deserialize(&request.parameter_data)
-                {
-                    Ok(start_address) => start_address,
-                    Err(_) => {
-                        break 'set_command build_nack!(
-                            request,
-                            NackReason::DataOutOfRange,
-                            message_count
-                        );
-                    },
-                };
+    /// Get the values of the sensors. The index is the sensor number.
+    pub fn get_sensor_values(&self) -> &[SensorValue] {
+        &self.sensor_values
+    }
 
-                self.dmx_start_address = dmx_start_address;
+    /// Get the values of the sensors to modify them. The index is the sensor number.
+    pub fn get_sensor_values_mut(&mut self) -> &mut [SensorValue] {
+        &mut self.sensor_values
+    }
 
-                request.build_response(
-                    ResponseType::ResponseTypeAck,
-                    DataPack::new(),
-                    self.message_queue.len() as u8,
-                )
-            },
-            RequestCommandClass::DiscoveryCommand => {
-                build_nack!(request, NackReason::UnsupportedCommandClass, message_count)
+    /// Set the present value of a sensor and update its lowest and highest detected values.
+    /// Returns false if the sensor doesn't exist.
+    pub fn update_sensor_value(&mut self, sensor_number: u8, present_value: i16) -> bool {
+        match self.sensor_values.get_mut(sensor_number as usize) {
+            Some(sensor_value) => {
+                sensor_value.update(present_value);
+                true
             },
+            None => false,
         }
-        .ok()
     }
 
-    fn handle_device_info(&self, request: &RdmRequestData) -> Option<RdmResponseData> {
-        verify_get_request!(request, self);
-
-        request
-            .build_response(
-                ResponseType::ResponseTypeAck,
-                DeviceInfo {
-                    device_model_id: self.rdm_receiver_metadata.device_model_id,
-                    product_category: self.rdm_receiver_metadata.product_category,
-                    software_version: self.rdm_receiver_metadata.software_version_id,
-                    dmx_footprint: self.dmx_footprint,
-                    dmx_personality: 1,
-                    dmx_start_address: self.dmx_start_address.clone(),
-                    sub_device_count: 0,
-                    sensor_count: 0,
-                }
-                .serialize(),
-                self.get_message_count(),
-            )
-            .ok()
+    fn is_pid_write_protected(&self, pid: u16) -> bool {
+        self.write_protected && self.write_protected_pids.contains(&pid)
     }
 
-    fn build_disc_mute_response(
-        &self,
-        request: &RdmRequestData,
-    ) -> Result<RdmResponseData, IsBroadcastError> {
-        request.build_response(
-            ResponseType::ResponseTypeAck,
-            DiscoveryMuteResponse {
-                managed_proxy: false,
-                sub_device: false,
-                boot_loader: false,
-                proxy_device: false,
-                binding_uid: None,
-            }
-            .serialize(),
-            self.get_message_count(),
-        )
+    /// Gets a context object that contains references to the current internal state
+    /// of some of the parameters.
+    pub fn get_context(&mut self) -> DmxReceiverContext<'_> {
+        self.get_context_with_raw_request(None)
     }
 
-    fn handle_queued_message(&mut self, request: &RdmRequestData) -> Option<RdmResponseData> {
-        verify_get_request!(request, self);
-
+    fn get_context_with_raw_request<'a>(
+        &'a mut self,
+        raw_request: Option<&'a [u8]>,
+    ) -> DmxReceiverContext<'a> {
         let message_count = self.get_message_count();
 
-        let status_type_requested = match StatusType::deserialize(&request.parameter_data) {
-            Ok(status) => status,
-            Err(_) => return build_nack!(request, NackReason::DataOutOfRange, message_count).ok(),
-        };
-
-        if status_type_requested == StatusType::StatusNone {
-            return build_nack!(request, NackReason::DataOutOfRange, message_count).ok();
+        DmxReceiverContext {
+            dmx_start_address: &mut self.dmx_start_address,
+            dmx_footprint: &mut self.dmx_footprint,
+            discovery_muted: &mut self.discovery_muted,
+            message_count,
+            write_protected: &mut self.write_protected,
+            sub_devices: &mut self.sub_devices,
+            identify: &mut self.identify,
+            identify_mode: &mut self.identify_mode,
+            raw_request,
         }
+    }
 
-        if status_type_requested == StatusType::StatusGetLastMessage {
-            return match self.last_queued_message {
-                None => request
-                    .build_response(
-                        ResponseType::ResponseTypeAck,
-                        DataPack::new(),
-                        message_count,
-                    )
-                    .ok(),
-                Some(ref mut response) => {
-                    response.message_count = message_count;
-                    response.transaction_number = request.transaction_number;
-                    Some(response.clone())
+    /// Returns the amount of pids that are reported in SUPPORTED_PARAMETERS, including the
+    /// internally handled pids.
+    pub fn supported_pid_count(&self) -> usize {
+        self.advertised_pids().count()
+    }
+
+    /// Sends a get request for each of the supported pids to the handler and returns the pids
+    /// that are nacked with [NackReason::UnknownPid] or [NackReason::UnsupportedCommandClass].
+    /// Useful to check in a test that the handler handles all the pids the device advertises.
+    ///
+    /// Pids that only support set requests are returned as well and have to be ignored.
+    /// The handler receives real get requests, so only call this while the device isn't
+    /// connected to a controller.
+    #[cfg(feature = "std")]
+    pub fn verify_advertised_pids<HandlerError>(
+        &mut self,
+        handler: &mut dyn RdmResponderHandlerFunc<Error = HandlerError>,
+    ) -> Result<Vec<UnhandledPid>, HandlerError> {
+        // the requests must not interfere with an overflowing response that is in progress
+        let unfinished_request = self.unfinished_request.take();
+        let pending_response = self.pending_response.take();
+
+        let mut unhandled_pids = Vec::new();
+        let mut result = Ok(());
+        for &parameter_id in self.supported_pids {
+            let request = RdmRequestData {
+                destination_uid: PackageAddress::Device(self.uid),
+                source_uid: self.uid,
+                transaction_number: 0,
+                port_id: 1,
+                message_count: 0,
+                sub_device: 0,
+                command_class: RequestCommandClass::GetCommand,
+                parameter_id,
+                parameter_data: DataPack::new(),
+            };
+
+            let response = match self.handle_rdm_request(request, handler) {
+                Ok(RdmAnswer::Response(response)) => response,
+                Ok(_) => continue,
+                Err(error) => {
+                    result = Err(error);
+                    break;
                 },
             };
-        }
 
-        match status_type_requested {
-            StatusType::StatusWarning | StatusType::StatusError | StatusType::StatusAdvisory => {},
-            _ => return build_nack!(request, NackReason::DataOutOfRange, message_count).ok(),
+            if response.response_type != ResponseType::ResponseTypeNackReason {
+                continue;
+            }
+
+            let nack_reason = match response.parameter_data[..] {
+                [high, low] => NackReason::try_from(u16::from_be_bytes([high, low])),
+                _ => continue,
+            };
+
+            if let Ok(
+                nack_reason @ (NackReason::UnknownPid | NackReason::UnsupportedCommandClass),
+            ) = nack_reason
+            {
+                unhandled_pids.push(UnhandledPid {
+                    parameter_id,
+                    nack_reason,
+                });
+            }
         }
 
-        let response = match self.message_queue.pop_back() {
-            None => {
-                let response_data = self.pop_filtered_statuses(status_type_requested);
+        self.unfinished_request = unfinished_request;
+        self.pending_response = pending_response;
 
-                let status_message_response = RdmResponseData {
-                    destination_uid: PackageAddress::Device(request.source_uid),
-                    source_uid: self.uid,
-                    transaction_number: request.transaction_number,
-                    response_type: ResponseType::ResponseTypeAck,
-                    message_count: 0,
-                    sub_device: 0,
-                    command_class: request.command_class.get_response_class(),
-                    parameter_id: pids::STATUS_MESSAGES,
-                    parameter_data: response_data,
-                };
-                self.last_status_vec_message = status_message_response.parameter_data.clone();
+        result.map(|_| unhandled_pids)
+    }
+
+    /// Method to handle a received and deserialized RdmPackage from the RDM-Controller.
+    /// This method will return the response package that has to be sent back to the RDM-Controller.
+    pub fn handle_rdm_request<HandlerError>(
+        &mut self,
+        request: RdmRequestData,
+        handler: &mut dyn RdmResponderHandlerFunc<Error = HandlerError>,
+    ) -> Result<RdmAnswer, HandlerError> {
+        self.handle_rdm_request_with_raw(request, None, handler)
+    }
 
-                status_message_response
+    /// Same as [RdmResponderPackageHandler::handle_rdm_request] but makes the raw bytes
+    /// of the received request available to the handler via [DmxReceiverContext::raw_request].
+    pub fn handle_rdm_request_with_raw<HandlerError>(
+        &mut self,
+        request: RdmRequestData,
+        raw_request: Option<&[u8]>,
+        handler: &mut dyn RdmResponderHandlerFunc<Error = HandlerError>,
+    ) -> Result<RdmAnswer, HandlerError> {
+        match request.destination_uid {
+            PackageAddress::ManufacturerBroadcast(manufacturer_uid)
+                if manufacturer_uid != self.uid.manufacturer_uid() =>
+            {
+                return Ok(RdmAnswer::NoResponse);
             },
-            Some(mut response_data) => {
-                response_data.message_count = self.get_message_count();
-                response_data.transaction_number = request.transaction_number;
-                response_data
+            PackageAddress::Device(device_uid) if self.uid != device_uid => {
+                return Ok(RdmAnswer::NoResponse);
             },
-        };
-
-        self.last_queued_message = Some(response.clone());
-        Some(response)
-    }
+            // get requests to broadcast addresses aren't allowed and must not have side effects
+            _ if request.destination_uid.is_broadcast()
+                && request.command_class == RequestCommandClass::GetCommand =>
+            {
+                return Ok(RdmAnswer::NoResponse);
+            },
+            _ => {},
+        }
 
-    fn handle_status_messages(&mut self, request: &RdmRequestData) -> Option<RdmResponseData> {
-        verify_get_request!(request, self);
+        if request.command_class == RequestCommandClass::DiscoveryCommand
+            && ![
+                pids::DISC_UNIQUE_BRANCH,
+                pids::DISC_MUTE,
+                pids::DISC_UN_MUTE,
+            ]
+            .contains(&request.parameter_id)
+        {
+            return Ok(RdmAnswer::NoResponse);
+        }
 
-        let message_count = self.get_message_count();
+        if request.sub_device != 0 && request.command_class != RequestCommandClass::DiscoveryCommand
+        {
+            return Ok(
+                match self.handle_sub_device_request(&request, raw_request, handler)? {
+                    Some(response_data) => RdmAnswer::Response(response_data),
+                    None => RdmAnswer::NoResponse,
+                },
+            );
+        }
 
-        let status_type_requested = match StatusType::deserialize(&request.parameter_data) {
-            Ok(status) => status,
-            Err(_) => return build_nack!(request, NackReason::FormatError, message_count).ok(),
-        };
+        // a request for another pid ends the pagination
+        if self
+            .unfinished_request
+            .as_ref()
+            .is_some_and(|unfinished_request| unfinished_request.pid != request.parameter_id)
+        {
+            self.unfinished_request = None;
+        }
 
-        match status_type_requested {
-            StatusType::StatusNone => request.build_response(
+        let response = match request.parameter_id {
+            pids::DISC_UNIQUE_BRANCH => return Ok(self.handle_disc_unique_branch(&request)),
+            pids::DISC_MUTE => self.handle_disc_mute(&request),
+            pids::DISC_UN_MUTE => self.handle_disc_unmute(&request),
+            pids::SUPPORTED_PARAMETERS => self.handle_supported_parameters(&request),
+            pids::DEVICE_INFO => self.handle_device_info(&request),
+            pids::PARAMETER_DESCRIPTION if !self.parameter_descriptions.is_empty() => {
+                self.handle_parameter_description(&request)
+            },
+            pids::SOFTWARE_VERSION_LABEL => self.handle_get_software_version_label(&request),
+            pids::BOOT_SOFTWARE_VERSION_ID
+                if !self.is_handler_pid(pids::BOOT_SOFTWARE_VERSION_ID) =>
+            {
+                self.handle_get_boot_software_version_id(&request)
+            },
+            pids::BOOT_SOFTWARE_VERSION_LABEL
+                if !self.is_handler_pid(pids::BOOT_SOFTWARE_VERSION_LABEL) =>
+            {
+                self.handle_get_boot_software_version_label(&request)
+            },
+            pids::DEVICE_MODEL_DESCRIPTION
+                if !self.is_handler_pid(pids::DEVICE_MODEL_DESCRIPTION) =>
+            {
+                self.handle_get_device_model_description(&request)
+            },
+            pids::MANUFACTURER_LABEL if !self.is_handler_pid(pids::MANUFACTURER_LABEL) => {
+                self.handle_get_manufacturer_label(&request)
+            },
+            pids::DMX_START_ADDRESS => self.handle_dmx_start_address(&request),
+            pids::DMX_BLOCK_ADDRESS if SUB_DEVICE_COUNT > 0 => {
+                self.handle_dmx_block_address(&request)
+            },
+            pids::DEVICE_LABEL if !self.is_handler_pid(pids::DEVICE_LABEL) => {
+                self.handle_device_label(&request)
+            },
+            pids::DMX_PERSONALITY if !self.dmx_personalities.is_empty() => {
+                self.handle_dmx_personality(&request)
+            },
+            pids::DMX_PERSONALITY_DESCRIPTION if !self.dmx_personalities.is_empty() => {
+                self.handle_dmx_personality_description(&request)
+            },
+            pids::FACTORY_DEFAULTS if self.supported_pids.contains(&pids::FACTORY_DEFAULTS) => {
+                self.handle_factory_defaults(&request, raw_request, handler)?
+            },
+            pids::IDENTIFY_DEVICE => self.handle_identify_device(&request, raw_request, handler)?,
+            pids::RESET_DEVICE if self.supported_pids.contains(&pids::RESET_DEVICE) => {
+                self.handle_reset_device(&request, raw_request, handler)?
+            },
+            pids::POWER_STATE if self.supported_pids.contains(&pids::POWER_STATE) => {
+                self.handle_power_state(&request, raw_request, handler)?
+            },
+            pids::CURVE if !self.curve_descriptions.is_empty() => self.handle_curve(&request),
+            pids::CURVE_DESCRIPTION if !self.curve_descriptions.is_empty() => {
+                self.handle_curve_description(&request)
+            },
+            pids::PERFORM_SELFTEST if !self.self_test_descriptions.is_empty() => {
+                self.handle_perform_self_test(&request, raw_request, handler)?
+            },
+            pids::SELF_TEST_DESCRIPTION if !self.self_test_descriptions.is_empty() => {
+                self.handle_self_test_description(&request)
+            },
+            pids::COMMS_STATUS if self.supported_pids.contains(&pids::COMMS_STATUS) => {
+                self.handle_comms_status(&request)
+            },
+            pids::REAL_TIME_CLOCK if self.supported_pids.contains(&pids::REAL_TIME_CLOCK) => {
+                self.handle_real_time_clock(&request, raw_request, handler)?
+            },
+            pids::DEVICE_HOURS | pids::LAMP_HOURS | pids::DEVICE_POWER_CYCLES
+                if self.supported_pids.contains(&request.parameter_id) =>
+            {
+                // can't fail since the pid belongs to a counter
+                let counter = StatisticsCounter::from_parameter_id(request.parameter_id).unwrap();
+                self.handle_statistics_counter(&request, counter, raw_request, handler)?
+            },
+            pids::CAPTURE_PRESET if self.supported_pids.contains(&pids::CAPTURE_PRESET) => {
+                self.handle_capture_preset(&request, raw_request, handler)?
+            },
+            pids::PRESET_PLAYBACK if self.supported_pids.contains(&pids::PRESET_PLAYBACK) => {
+                self.handle_preset_playback(&request, raw_request, handler)?
+            },
+            pids::IDENTIFY_MODE if self.supported_pids.contains(&pids::IDENTIFY_MODE) => {
+                self.handle_identify_mode(&request)
+            },
+            pids::SENSOR_DEFINITION if !self.sensor_definitions.is_empty() => {
+                self.handle_sensor_definition(&request)
+            },
+            pids::SENSOR_VALUE if !self.sensor_definitions.is_empty() => {
+                self.handle_sensor_value(&request)
+            },
+            pids::RECORD_SENSORS if !self.sensor_definitions.is_empty() => {
+                self.handle_record_sensors(&request)
+            },
+            pids::DEFAULT_SLOT_VALUE if !self.default_slot_values.is_empty() => {
+                self.handle_default_slot_value(&request)
+            },
+            pids::QUEUED_MESSAGE if MQ_SIZE > 0 => self.handle_queued_message(&request),
+            pids::STATUS_MESSAGES if MQ_SIZE > 0 => self.handle_status_messages(&request),
+            _ => self.handle_other_request(&request, raw_request, handler)?,
+        };
+
+        // Was this a broadcast?
+        if let Some(response_data) = response {
+            return Ok(RdmAnswer::Response(response_data));
+        }
+
+        // No response since the request is a broadcast
+        Ok(RdmAnswer::NoResponse)
+    }
+
+    fn handle_sub_device_request<HandlerError>(
+        &mut self,
+        request: &RdmRequestData,
+        raw_request: Option<&[u8]>,
+        handler: &mut dyn RdmResponderHandlerFunc<Error = HandlerError>,
+    ) -> Result<Option<RdmResponseData>, HandlerError> {
+        if request.sub_device == SUB_DEVICE_ALL_CALL {
+            return self.handle_all_sub_devices_request(request, raw_request, handler);
+        }
+
+        if request.sub_device as usize > SUB_DEVICE_COUNT {
+            let message_count = self.get_message_count();
+            return Ok(build_nack!(request, NackReason::SubDeviceOutOfRange, message_count).ok());
+        }
+
+        Ok(match request.parameter_id {
+            pids::DEVICE_INFO => self.handle_device_info(request),
+            pids::DMX_START_ADDRESS => self.handle_dmx_start_address(request),
+            _ => return self.handle_other_request(request, raw_request, handler),
+        })
+    }
+
+    /// Applies a set request to every sub-device without responding.
+    /// Get requests to all sub-devices are not allowed by the standard.
+    fn handle_all_sub_devices_request<HandlerError>(
+        &mut self,
+        request: &RdmRequestData,
+        raw_request: Option<&[u8]>,
+        handler: &mut dyn RdmResponderHandlerFunc<Error = HandlerError>,
+    ) -> Result<Option<RdmResponseData>, HandlerError> {
+        if request.command_class != RequestCommandClass::SetCommand {
+            let message_count = self.get_message_count();
+            return Ok(build_nack!(request, NackReason::SubDeviceOutOfRange, message_count).ok());
+        }
+
+        for sub_device in 1..=SUB_DEVICE_COUNT as u16 {
+            let sub_device_request = RdmRequestData {
+                sub_device,
+                ..request.clone()
+            };
+            self.handle_sub_device_request(&sub_device_request, raw_request, handler)?;
+        }
+
+        Ok(None)
+    }
+
+    fn handle_other_request<HandlerError>(
+        &mut self,
+        request: &RdmRequestData,
+        raw_request: Option<&[u8]>,
+        handler: &mut dyn RdmResponderHandlerFunc<Error = HandlerError>,
+    ) -> Result<Option<RdmResponseData>, HandlerError> {
+        match self.pending_response.take() {
+            Some(pending_response)
+                if pending_response.pid == request.parameter_id
+                    && request.command_class == RequestCommandClass::GetCommand =>
+            {
+                return Ok(self
+                    .paginate_response(request, pending_response.data, pending_response.overflow)
+                    .ok());
+            },
+            _ => {},
+        }
+
+        match self.unfinished_request.take() {
+            Some(UnfinishedRequest {
+                pid,
+                iteration,
+                overflow_buffer: Some(overflow_buffer),
+            }) if pid == request.parameter_id
+                && request.command_class == RequestCommandClass::GetCommand =>
+            {
+                return Ok(self
+                    .stream_response(request, overflow_buffer, iteration)
+                    .ok());
+            },
+            unfinished_request => self.unfinished_request = unfinished_request,
+        }
+
+        let result = match request.sub_device {
+            0 => {
+                handler.handle_rdm(request, &mut self.get_context_with_raw_request(raw_request))?
+            },
+            sub_device => handler.handle_rdm_sub_device(
+                sub_device,
+                request,
+                &mut self.get_context_with_raw_request(raw_request),
+            )?,
+        };
+
+        Ok(self.build_result_response(request, result))
+    }
+
+    /// Converts the [RdmResult] of a handler into the response package.
+    fn build_result_response(
+        &mut self,
+        request: &RdmRequestData,
+        result: RdmResult,
+    ) -> Option<RdmResponseData> {
+        let response = match result {
+            RdmResult::Acknowledged(response_data) => {
+                if request.command_class == RequestCommandClass::SetCommand {
+                    self.factory_defaults = false;
+                }
+
+                self.paginate_response(request, response_data, false)
+            },
+            RdmResult::AcknowledgedOverflow(response_data) => {
+                self.paginate_response(request, response_data, true)
+            },
+            RdmResult::AcknowledgedStream(overflow_buffer) => {
+                if request.command_class == RequestCommandClass::SetCommand {
+                    self.factory_defaults = false;
+                }
+
+                self.stream_response(request, overflow_buffer, 0)
+            },
+            RdmResult::NotAcknowledged(nack_reason) => request.build_response(
+                ResponseType::ResponseTypeNackReason,
+                DataPack::from_slice(&nack_reason.to_be_bytes()).unwrap(),
+                self.get_message_count(),
+            ),
+            RdmResult::AcknowledgedTimer(timer) => request.build_response(
+                ResponseType::ResponseTypeAckTimer,
+                DataPack::from_slice(&timer.to_be_bytes()).unwrap(),
+                self.get_message_count(),
+            ),
+            RdmResult::NoResponse => {
+                return None;
+            },
+            // broadcasts are never answered, even if the handler built a response
+            RdmResult::Custom(_) if request.destination_uid.is_broadcast() => return None,
+            RdmResult::Custom(response_data) => Ok(response_data),
+        };
+
+        response.ok()
+    }
+
+    /// Builds the response with the chunk of the overflow buffer at the iteration. The position
+    /// is kept in the unfinished request if there are chunks left and the request is a get.
+    fn stream_response(
+        &mut self,
+        request: &RdmRequestData,
+        overflow_buffer: OverflowBuffer,
+        iteration: u16,
+    ) -> Result<RdmResponseData, IsBroadcastError> {
+        let chunk_size = self.max_response_parameter_len;
+        let start = (chunk_size * iteration as usize).min(overflow_buffer.len());
+        let end = (start + chunk_size).min(overflow_buffer.len());
+        let chunk = DataPack::from_slice(&overflow_buffer.data[start..end]).unwrap();
+
+        let response_type = if end < overflow_buffer.len() {
+            if request.command_class == RequestCommandClass::GetCommand {
+                self.unfinished_request = Some(UnfinishedRequest {
+                    pid: request.parameter_id,
+                    iteration: iteration + 1,
+                    overflow_buffer: Some(overflow_buffer),
+                });
+            }
+
+            ResponseType::ResponseTypeAckOverflow
+        } else {
+            ResponseType::ResponseTypeAck
+        };
+
+        request.build_response(response_type, chunk, self.get_message_count())
+    }
+
+    /// Builds an acknowledged response and keeps the part of a get response that exceeds
+    /// the maximum response parameter length for the next request of the same pid.
+    fn paginate_response(
+        &mut self,
+        request: &RdmRequestData,
+        response_data: DataPack,
+        overflow: bool,
+    ) -> Result<RdmResponseData, IsBroadcastError> {
+        if request.command_class != RequestCommandClass::GetCommand
+            || response_data.len() <= self.max_response_parameter_len
+        {
+            let response_type = if overflow {
+                ResponseType::ResponseTypeAckOverflow
+            } else {
+                ResponseType::ResponseTypeAck
+            };
+
+            return request.build_response(response_type, response_data, self.get_message_count());
+        }
+
+        let (current_data, pending_data) = response_data.split_at(self.max_response_parameter_len);
+        self.pending_response = Some(PendingResponse {
+            pid: request.parameter_id,
+            data: DataPack::from_slice(pending_data).unwrap(),
+            overflow,
+        });
+
+        request.build_response(
+            ResponseType::ResponseTypeAckOverflow,
+            DataPack::from_slice(current_data).unwrap(),
+            self.get_message_count(),
+        )
+    }
+
+    fn handle_disc_unique_branch(&self, request: &RdmRequestData) -> RdmAnswer {
+        if request.command_class != RequestCommandClass::DiscoveryCommand {
+            let message_count = self.get_message_count();
+            return match build_nack!(request, NackReason::UnsupportedCommandClass, message_count) {
+                Ok(response) => RdmAnswer::Response(response),
+                Err(_) => RdmAnswer::NoResponse,
+            };
+        }
+
+        if request.parameter_data.len() != 12 {
+            return RdmAnswer::NoResponse;
+        }
+
+        let lower_bound: u64 =
+            PackageAddress::from_bytes(&request.parameter_data[..6].try_into().unwrap()).into();
+        let upper_bound: u64 =
+            PackageAddress::from_bytes(&request.parameter_data[6..].try_into().unwrap()).into();
+        let own_uid: u64 = self.uid.into();
+
+        if !self.discovery_muted && own_uid >= lower_bound && own_uid <= upper_bound {
+            return RdmAnswer::DiscoveryResponse(self.uid);
+        }
+
+        RdmAnswer::NoResponse
+    }
+
+    fn handle_disc_mute(&mut self, request: &RdmRequestData) -> Option<RdmResponseData> {
+        verify_disc_request!(request, self);
+        verify_empty_parameter_data!(request, self);
+
+        self.discovery_muted = true;
+        self.build_disc_mute_response(request).ok()
+    }
+
+    fn handle_disc_unmute(&mut self, request: &RdmRequestData) -> Option<RdmResponseData> {
+        verify_disc_request!(request, self);
+        verify_empty_parameter_data!(request, self);
+
+        self.discovery_muted = false;
+        self.build_disc_mute_response(request).ok()
+    }
+
+    fn handle_get_software_version_label(
+        &self,
+        request: &RdmRequestData,
+    ) -> Option<RdmResponseData> {
+        verify_get_request!(request, self);
+
+        request
+            .build_response(
                 ResponseType::ResponseTypeAck,
-                DataPack::new(),
-                message_count,
+                serialize_label(self.rdm_receiver_metadata.software_version_label),
+                self.get_message_count(),
+            )
+            .ok()
+    }
+
+    fn handle_get_boot_software_version_id(
+        &self,
+        request: &RdmRequestData,
+    ) -> Option<RdmResponseData> {
+        verify_get_request!(request, self);
+
+        request
+            .build_response(
+                ResponseType::ResponseTypeAck,
+                DataPack::from_slice(
+                    &self
+                        .rdm_receiver_metadata
+                        .boot_software_version_id
+                        .to_be_bytes(),
+                )
+                .unwrap(),
+                self.get_message_count(),
+            )
+            .ok()
+    }
+
+    fn handle_get_boot_software_version_label(
+        &self,
+        request: &RdmRequestData,
+    ) -> Option<RdmResponseData> {
+        verify_get_request!(request, self);
+
+        request
+            .build_response(
+                ResponseType::ResponseTypeAck,
+                serialize_label(self.rdm_receiver_metadata.boot_software_version_label),
+                self.get_message_count(),
+            )
+            .ok()
+    }
+
+    fn handle_get_device_model_description(
+        &self,
+        request: &RdmRequestData,
+    ) -> Option<RdmResponseData> {
+        verify_get_request!(request, self);
+
+        request
+            .build_response(
+                ResponseType::ResponseTypeAck,
+                serialize_label(self.rdm_receiver_metadata.device_model_description),
+                self.get_message_count(),
+            )
+            .ok()
+    }
+
+    fn handle_get_manufacturer_label(&self, request: &RdmRequestData) -> Option<RdmResponseData> {
+        verify_get_request!(request, self);
+
+        request
+            .build_response(
+                ResponseType::ResponseTypeAck,
+                serialize_label(self.rdm_receiver_metadata.manufacturer_label),
+                self.get_message_count(),
+            )
+            .ok()
+    }
+
+    /// Returns true if an automatically advertised pid is passed to the handler because it is
+    /// listed in the supported pids.
+    fn is_handler_pid(&self, pid: u16) -> bool {
+        self.advertise_internal_pids && self.supported_pids.contains(&pid)
+    }
+
+    /// The internally handled pids that are reported in SUPPORTED_PARAMETERS.
+    fn internal_pids(&self) -> impl Iterator<Item = u16> + '_ {
+        let advertise_internal_pids = self.advertise_internal_pids;
+        let internal_pids: &[u16] = match advertise_internal_pids {
+            true => &INTERNALLY_SUPPORTED_PIDS,
+            false => &[],
+        };
+
+        internal_pids
+            .iter()
+            .copied()
+            .filter(|&pid| !self.is_handler_pid(pid))
+            .chain(
+                self.optional_internal_pids()
+                    .filter(move |_| advertise_internal_pids),
+            )
+    }
+
+    /// All pids that are reported in SUPPORTED_PARAMETERS in the configured order.
+    fn advertised_pids(&self) -> impl Iterator<Item = u16> + '_ {
+        self.internal_pids()
+            .chain(self.supported_pids.iter().copied())
+    }
+
+    /// All pids that are reported in SUPPORTED_PARAMETERS in ascending order.
+    /// Sorts without allocating by searching the next bigger pid for every item.
+    fn sorted_advertised_pids(&self) -> impl Iterator<Item = u16> + '_ {
+        let mut last_pid = None;
+
+        core::iter::from_fn(move || {
+            let next_pid = self
+                .advertised_pids()
+                .filter(|&pid| last_pid.is_none_or(|last_pid| pid > last_pid))
+                .min()?;
+            last_pid = Some(next_pid);
+
+            Some(next_pid)
+        })
+    }
+
+    /// The internally handled pids that are only supported if they have been configured.
+    fn optional_internal_pids(&self) -> impl Iterator<Item = u16> {
+        let message_queue_pids: &[u16] = match MQ_SIZE {
+            0 => &[],
+            _ => &[pids::QUEUED_MESSAGE, pids::STATUS_MESSAGES],
+        };
+        let parameter_description_pids: &[u16] = match self.parameter_descriptions.is_empty() {
+            true => &[],
+            false => &[pids::PARAMETER_DESCRIPTION],
+        };
+        let dmx_personality_pids: &[u16] = match self.dmx_personalities.is_empty() {
+            true => &[],
+            false => &[pids::DMX_PERSONALITY, pids::DMX_PERSONALITY_DESCRIPTION],
+        };
+        let sensor_pids: &[u16] = match self.sensor_definitions.is_empty() {
+            true => &[],
+            false => &[
+                pids::SENSOR_DEFINITION,
+                pids::SENSOR_VALUE,
+                pids::RECORD_SENSORS,
+            ],
+        };
+
+        let default_slot_value_pids: &[u16] = match self.default_slot_values.is_empty() {
+            true => &[],
+            false => &[pids::DEFAULT_SLOT_VALUE],
+        };
+        let self_test_pids: &[u16] = match self.self_test_descriptions.is_empty() {
+            true => &[],
+            false => &[pids::PERFORM_SELFTEST, pids::SELF_TEST_DESCRIPTION],
+        };
+        let curve_pids: &[u16] = match self.curve_descriptions.is_empty() {
+            true => &[],
+            false => &[pids::CURVE, pids::CURVE_DESCRIPTION],
+        };
+        let sub_device_pids: &[u16] = match SUB_DEVICE_COUNT {
+            0 => &[],
+            _ => &[pids::DMX_BLOCK_ADDRESS],
+        };
+
+        message_queue_pids
+            .iter()
+            .chain(parameter_description_pids.iter())
+            .chain(dmx_personality_pids.iter())
+            .chain(sensor_pids.iter())
+            .chain(default_slot_value_pids.iter())
+            .chain(self_test_pids.iter())
+            .chain(curve_pids.iter())
+            .chain(sub_device_pids.iter())
+            .copied()
+    }
+
+    /// Parses the sensor number of a sensor request. Returns the nack reason if the
+    /// sensor number is invalid. [RDM_ALL_SENSORS] is only allowed if `allow_all_sensors` is true.
+    fn parse_sensor_number(
+        &self,
+        request: &RdmRequestData,
+        allow_all_sensors: bool,
+    ) -> Result<u8, NackReason> {
+        let sensor_number = match request.parameter_data[..] {
+            [sensor_number] => sensor_number,
+            _ => return Err(NackReason::FormatError),
+        };
+
+        if (sensor_number as usize) < self.sensor_values.len()
+            || (allow_all_sensors && sensor_number == RDM_ALL_SENSORS)
+        {
+            return Ok(sensor_number);
+        }
+
+        Err(NackReason::DataOutOfRange)
+    }
+
+    fn handle_sensor_definition(&self, request: &RdmRequestData) -> Option<RdmResponseData> {
+        verify_get_request!(request, self);
+        let message_count = self.get_message_count();
+
+        let sensor_number = match self.parse_sensor_number(request, false) {
+            Ok(sensor_number) => sensor_number,
+            Err(nack_reason) => return build_nack!(request, nack_reason, message_count).ok(),
+        };
+
+        let mut response_data = self.sensor_definitions[sensor_number as usize].serialize();
+        // the index is the sensor number
+        response_data[0] = sensor_number;
+
+        request
+            .build_response(ResponseType::ResponseTypeAck, response_data, message_count)
+            .ok()
+    }
+
+    fn handle_sensor_value(&mut self, request: &RdmRequestData) -> Option<RdmResponseData> {
+        let message_count = self.get_message_count();
+
+        match request.command_class {
+            RequestCommandClass::GetCommand => 'get_command: {
+                if request.destination_uid.is_broadcast() {
+                    return None;
+                }
+
+                let sensor_number = match self.parse_sensor_number(request, false) {
+                    Ok(sensor_number) => sensor_number,
+                    Err(nack_reason) => {
+                        break 'get_command build_nack!(request, nack_reason, message_count);
+                    },
+                };
+
+                request.build_response(
+                    ResponseType::ResponseTypeAck,
+                    DataPack::from_slice(&self.sensor_values[sensor_number as usize].serialize())
+                        .unwrap(),
+                    message_count,
+                )
+            },
+            RequestCommandClass::SetCommand => 'set_command: {
+                let sensor_number = match self.parse_sensor_number(request, true) {
+                    Ok(sensor_number) => sensor_number,
+                    Err(nack_reason) => {
+                        break 'set_command build_nack!(request, nack_reason, message_count);
+                    },
+                };
+
+                // a reset of all sensors is answered with zeroed values
+                let mut response_value = SensorValue::new(sensor_number);
+                for sensor_value in self.sensor_values.iter_mut().filter(|sensor_value| {
+                    sensor_number == RDM_ALL_SENSORS || sensor_value.sensor_number == sensor_number
+                }) {
+                    sensor_value.reset();
+
+                    if sensor_number != RDM_ALL_SENSORS {
+                        response_value = *sensor_value;
+                    }
+                }
+
+                request.build_response(
+                    ResponseType::ResponseTypeAck,
+                    DataPack::from_slice(&response_value.serialize()).unwrap(),
+                    message_count,
+                )
+            },
+            RequestCommandClass::DiscoveryCommand => {
+                build_nack!(request, NackReason::UnsupportedCommandClass, message_count)
+            },
+        }
+        .ok()
+    }
+
+    fn handle_record_sensors(&mut self, request: &RdmRequestData) -> Option<RdmResponseData> {
+        let message_count = self.get_message_count();
+
+        if request.command_class != RequestCommandClass::SetCommand {
+            return build_nack!(request, NackReason::UnsupportedCommandClass, message_count).ok();
+        }
+
+        let sensor_number = match self.parse_sensor_number(request, true) {
+            Ok(sensor_number) => sensor_number,
+            Err(nack_reason) => return build_nack!(request, nack_reason, message_count).ok(),
+        };
+
+        self.sensor_values
+            .iter_mut()
+            .filter(|sensor_value| {
+                sensor_number == RDM_ALL_SENSORS || sensor_value.sensor_number == sensor_number
+            })
+            .for_each(|sensor_value| sensor_value.recorded_value = sensor_value.present_value);
+
+        request
+            .build_response(
+                ResponseType::ResponseTypeAck,
+                DataPack::new(),
+                message_count,
+            )
+            .ok()
+    }
+
+    fn handle_parameter_description(&self, request: &RdmRequestData) -> Option<RdmResponseData> {
+        verify_get_request!(request, self);
+        let message_count = self.get_message_count();
+
+        let requested_pid = match request.parameter_data[..] {
+            [pid_high, pid_low] => u16::from_be_bytes([pid_high, pid_low]),
+            _ => return build_nack!(request, NackReason::FormatError, message_count).ok(),
+        };
+
+        match self
+            .parameter_descriptions
+            .iter()
+            .find(|parameter_description| parameter_description.pid == requested_pid)
+        {
+            Some(parameter_description) => request.build_response(
+                ResponseType::ResponseTypeAck,
+                parameter_description.serialize(),
+                message_count,
+            ),
+            None => build_nack!(request, NackReason::DataOutOfRange, message_count),
+        }
+        .ok()
+    }
+
+    /// Builds the next response to a get request for a list of items that might not fit into a
+    /// single response. The current position is kept in the unfinished request, so a request
+    /// for another pid restarts the list.
+    fn build_paginated_response(
+        &mut self,
+        request: &RdmRequestData,
+        item_size: usize,
+        item_count: usize,
+        serialize_items: impl FnOnce(&Self, core::ops::Range<usize>) -> DataPack,
+    ) -> Option<RdmResponseData> {
+        let current_iteration = match &self.unfinished_request {
+            Some(UnfinishedRequest { pid, iteration, .. }) if *pid == request.parameter_id => {
+                *iteration
+            },
+            _ => 0,
+        };
+
+        // at least one item has to be sent, otherwise the list never ends
+        let max_items_per_response = (self.max_response_parameter_len / item_size).max(1);
+        let current_item_index = max_items_per_response * (current_iteration as usize);
+        let end_item_index = item_count.min(current_item_index + max_items_per_response);
+
+        let response_package = serialize_items(self, current_item_index..end_item_index);
+
+        if end_item_index != item_count {
+            self.unfinished_request = Some(UnfinishedRequest {
+                pid: request.parameter_id,
+                iteration: current_iteration + 1,
+                overflow_buffer: None,
+            });
+
+            request
+                .build_response(
+                    ResponseType::ResponseTypeAckOverflow,
+                    response_package,
+                    self.get_message_count(),
+                )
+                .ok()
+        } else {
+            self.unfinished_request = None;
+
+            request
+                .build_response(
+                    ResponseType::ResponseTypeAck,
+                    response_package,
+                    self.get_message_count(),
+                )
+                .ok()
+        }
+    }
+
+    fn handle_supported_parameters(&mut self, request: &RdmRequestData) -> Option<RdmResponseData> {
+        verify_get_request!(request, self);
+
+        // one pid is u16
+        let amount_pids = self.supported_pid_count();
+        self.build_paginated_response(request, 2, amount_pids, |handler, pid_range| {
+            if handler.sort_supported_parameters {
+                serialize_pid_range(handler.sorted_advertised_pids(), pid_range)
+            } else {
+                serialize_pid_range(handler.advertised_pids(), pid_range)
+            }
+        })
+    }
+
+    fn handle_default_slot_value(&mut self, request: &RdmRequestData) -> Option<RdmResponseData> {
+        verify_get_request!(request, self);
+
+        self.build_paginated_response(
+            request,
+            RDM_DEFAULT_SLOT_VALUE_SIZE,
+            self.default_slot_values.len(),
+            |handler, slot_range| {
+                handler.default_slot_values[slot_range]
+                    .iter()
+                    .flat_map(|default_slot_value| default_slot_value.serialize())
+                    .collect()
+            },
+        )
+    }
+
+    fn handle_dmx_start_address(&mut self, request: &RdmRequestData) -> Option<RdmResponseData> {
+        let message_count = self.get_message_count();
+
+        match request.command_class {
+            RequestCommandClass::GetCommand => request.build_response(
+                ResponseType::ResponseTypeAck,
+                self.dmx_start_address_mut(request.sub_device).serialize(),
+                message_count,
+            ),
+            RequestCommandClass::SetCommand => 'set_command: {
+                if self.is_pid_write_protected(pids::DMX_START_ADDRESS) {
+                    break 'set_command build_nack!(
+                        request,
+                        NackReason::WriteProtect,
+                        message_count
+                    );
+                }
+
+                let dmx_start_address = match DmxStartAddress::deserialize(&request.parameter_data)
+                {
+                    Ok(start_address) => start_address,
+                    Err(DmxStartAddressError::WrongLength) => {
+                        break 'set_command build_nack!(
+                            request,
+                            NackReason::FormatError,
+                            message_count
+                        );
+                    },
+                    Err(DmxStartAddressError::OutOfRange) => {
+                        break 'set_command build_nack!(
+                            request,
+                            NackReason::DataOutOfRange,
+                            message_count
+                        );
+                    },
+                };
+
+                *self.dmx_start_address_mut(request.sub_device) = dmx_start_address;
+                self.factory_defaults = false;
+
+                request.build_response(
+                    ResponseType::ResponseTypeAck,
+                    DataPack::new(),
+                    message_count,
+                )
+            },
+            RequestCommandClass::DiscoveryCommand => {
+                build_nack!(request, NackReason::UnsupportedCommandClass, message_count)
+            },
+        }
+        .ok()
+    }
+
+    /// Reports the dmx addresses of all sub-devices as one block or assigns
+    /// consecutive start addresses to them, beginning at the requested base address.
+    fn handle_dmx_block_address(&mut self, request: &RdmRequestData) -> Option<RdmResponseData> {
+        let message_count = self.get_message_count();
+        let addressed_sub_devices = self
+            .sub_devices
+            .iter()
+            .filter(|sub_device| sub_device.dmx_footprint > 0);
+        let total_footprint = addressed_sub_devices
+            .clone()
+            .map(|sub_device| sub_device.dmx_footprint)
+            .fold(0u16, u16::saturating_add);
+
+        match request.command_class {
+            RequestCommandClass::GetCommand => {
+                let mut next_address = None;
+                let mut base_address = None;
+                for sub_device in addressed_sub_devices {
+                    let address = match sub_device.dmx_start_address {
+                        DmxStartAddress::Address(address)
+                            if next_address.is_none_or(|next| next == address) =>
+                        {
+                            address
+                        },
+                        _ => {
+                            base_address = Some(DmxBlockAddress::NOT_CONTIGUOUS);
+                            break;
+                        },
+                    };
+
+                    base_address.get_or_insert(address);
+                    next_address = match address.checked_add(sub_device.dmx_footprint) {
+                        Some(next_address) => Some(next_address),
+                        None => {
+                            base_address = Some(DmxBlockAddress::NOT_CONTIGUOUS);
+                            break;
+                        },
+                    };
+                }
+
+                let block_address = DmxBlockAddress {
+                    total_footprint,
+                    base_address: base_address.unwrap_or(DmxBlockAddress::NOT_CONTIGUOUS),
+                };
+
+                request.build_response(
+                    ResponseType::ResponseTypeAck,
+                    DataPack::from_slice(&block_address.serialize()).unwrap(),
+                    message_count,
+                )
+            },
+            RequestCommandClass::SetCommand => 'set_command: {
+                if self.is_pid_write_protected(pids::DMX_BLOCK_ADDRESS) {
+                    break 'set_command build_nack!(
+                        request,
+                        NackReason::WriteProtect,
+                        message_count
+                    );
+                }
+
+                let base_address = match request.parameter_data.as_slice() {
+                    &[high, low] => u16::from_be_bytes([high, low]),
+                    _ => {
+                        break 'set_command build_nack!(
+                            request,
+                            NackReason::FormatError,
+                            message_count
+                        );
+                    },
+                };
+
+                if !(1..=512).contains(&base_address)
+                    || base_address as u32 + total_footprint as u32 - 1 > 512
+                {
+                    break 'set_command build_nack!(
+                        request,
+                        NackReason::DataOutOfRange,
+                        message_count
+                    );
+                }
+
+                let mut next_address = base_address;
+                for sub_device in self.sub_devices.iter_mut() {
+                    if sub_device.dmx_footprint == 0 {
+                        sub_device.dmx_start_address = DmxStartAddress::NoAddress;
+                        continue;
+                    }
+
+                    sub_device.dmx_start_address = DmxStartAddress::Address(next_address);
+                    next_address += sub_device.dmx_footprint;
+                }
+                self.factory_defaults = false;
+
+                request.build_response(
+                    ResponseType::ResponseTypeAck,
+                    DataPack::new(),
+                    message_count,
+                )
+            },
+            RequestCommandClass::DiscoveryCommand => {
+                build_nack!(request, NackReason::UnsupportedCommandClass, message_count)
+            },
+        }
+        .ok()
+    }
+
+    fn handle_dmx_personality(&mut self, request: &RdmRequestData) -> Option<RdmResponseData> {
+        let message_count = self.get_message_count();
+
+        match request.command_class {
+            RequestCommandClass::GetCommand => request.build_response(
+                ResponseType::ResponseTypeAck,
+                DataPack::from_slice(
+                    &DmxPersonality {
+                        current_personality: self.dmx_personality,
+                        personality_count: self.dmx_personalities.len() as u8,
+                    }
+                    .serialize(),
+                )
+                .unwrap(),
+                message_count,
+            ),
+            RequestCommandClass::SetCommand => 'set_command: {
+                if self.is_pid_write_protected(pids::DMX_PERSONALITY) {
+                    break 'set_command build_nack!(
+                        request,
+                        NackReason::WriteProtect,
+                        message_count
+                    );
+                }
+
+                let personality = match request.parameter_data[..] {
+                    [personality] => personality,
+                    _ => {
+                        break 'set_command build_nack!(
+                            request,
+                            NackReason::FormatError,
+                            message_count
+                        );
+                    },
+                };
+
+                if !self.set_dmx_personality(personality) {
+                    break 'set_command build_nack!(
+                        request,
+                        NackReason::DataOutOfRange,
+                        message_count
+                    );
+                }
+                self.factory_defaults = false;
+
+                request.build_response(
+                    ResponseType::ResponseTypeAck,
+                    DataPack::new(),
+                    message_count,
+                )
+            },
+            RequestCommandClass::DiscoveryCommand => {
+                build_nack!(request, NackReason::UnsupportedCommandClass, message_count)
+            },
+        }
+        .ok()
+    }
+
+    fn handle_dmx_personality_description(
+        &self,
+        request: &RdmRequestData,
+    ) -> Option<RdmResponseData> {
+        verify_get_request!(request, self);
+        let message_count = self.get_message_count();
+
+        let personality = match request.parameter_data[..] {
+            [personality] => personality,
+            _ => return build_nack!(request, NackReason::FormatError, message_count).ok(),
+        };
+
+        let dmx_personality = match (personality as usize)
+            .checked_sub(1)
+            .and_then(|index| self.dmx_personalities.get(index))
+        {
+            Some(dmx_personality) => dmx_personality,
+            None => return build_nack!(request, NackReason::DataOutOfRange, message_count).ok(),
+        };
+
+        let mut response_data = dmx_personality.serialize();
+        // the index plus one is the personality number
+        response_data[0] = personality;
+
+        request
+            .build_response(ResponseType::ResponseTypeAck, response_data, message_count)
+            .ok()
+    }
+
+    fn handle_device_label(&mut self, request: &RdmRequestData) -> Option<RdmResponseData> {
+        let message_count = self.get_message_count();
+
+        match request.command_class {
+            RequestCommandClass::GetCommand => {
+                if request.destination_uid.is_broadcast() {
+                    return None;
+                }
+
+                request.build_response(
+                    ResponseType::ResponseTypeAck,
+                    DataPack::from_slice(self.device_label.as_bytes()).unwrap(),
+                    message_count,
+                )
+            },
+            RequestCommandClass::SetCommand => 'set_command: {
+                if self.is_pid_write_protected(pids::DEVICE_LABEL) {
+                    break 'set_command build_nack!(
+                        request,
+                        NackReason::WriteProtect,
+                        message_count
+                    );
+                }
+
+                let device_label = match deserialize_device_label(&request.parameter_data) {
+                    Ok(device_label) => device_label,
+                    Err(_) => {
+                        break 'set_command build_nack!(
+                            request,
+                            NackReason::DataOutOfRange,
+                            message_count
+                        );
+                    },
+                };
+
+                self.device_label = device_label;
+                self.factory_defaults = false;
+
+                request.build_response(
+                    ResponseType::ResponseTypeAck,
+                    DataPack::new(),
+                    message_count,
+                )
+            },
+            RequestCommandClass::DiscoveryCommand => {
+                build_nack!(request, NackReason::UnsupportedCommandClass, message_count)
+            },
+        }
+        .ok()
+    }
+
+    fn handle_identify_device<HandlerError>(
+        &mut self,
+        request: &RdmRequestData,
+        raw_request: Option<&[u8]>,
+        handler: &mut dyn RdmResponderHandlerFunc<Error = HandlerError>,
+    ) -> Result<Option<RdmResponseData>, HandlerError> {
+        if let Some(result) = handler
+            .handle_identify_device(request, &mut self.get_context_with_raw_request(raw_request))?
+        {
+            return Ok(self.build_result_response(request, result));
+        }
+
+        let message_count = self.get_message_count();
+
+        let response = match request.command_class {
+            RequestCommandClass::GetCommand => {
+                if request.destination_uid.is_broadcast() {
+                    return Ok(None);
+                }
+
+                request.build_response(
+                    ResponseType::ResponseTypeAck,
+                    DataPack::from_slice(&[self.identify as u8]).unwrap(),
+                    message_count,
+                )
+            },
+            RequestCommandClass::SetCommand => 'set_command: {
+                let identify = match request.parameter_data[..] {
+                    [0] => false,
+                    [1] => true,
+                    [_] => {
+                        break 'set_command build_nack!(
+                            request,
+                            NackReason::DataOutOfRange,
+                            message_count
+                        );
+                    },
+                    _ => {
+                        break 'set_command build_nack!(
+                            request,
+                            NackReason::FormatError,
+                            message_count
+                        );
+                    },
+                };
+
+                if self.identify != identify {
+                    self.identify = identify;
+                    handler.on_identify_changed(
+                        identify,
+                        &mut self.get_context_with_raw_request(raw_request),
+                    )?;
+                }
+
+                request.build_response(
+                    ResponseType::ResponseTypeAck,
+                    DataPack::new(),
+                    self.get_message_count(),
+                )
+            },
+            RequestCommandClass::DiscoveryCommand => {
+                build_nack!(request, NackReason::UnsupportedCommandClass, message_count)
+            },
+        };
+
+        Ok(response.ok())
+    }
+
+    fn handle_reset_device<HandlerError>(
+        &mut self,
+        request: &RdmRequestData,
+        raw_request: Option<&[u8]>,
+        handler: &mut dyn RdmResponderHandlerFunc<Error = HandlerError>,
+    ) -> Result<Option<RdmResponseData>, HandlerError> {
+        let message_count = self.get_message_count();
+
+        let response = match request.command_class {
+            RequestCommandClass::SetCommand => 'set_command: {
+                let reset_type = match request.parameter_data[..] {
+                    [reset_type] => match ResetType::try_from(reset_type) {
+                        Ok(reset_type) => reset_type,
+                        Err(_) => {
+                            break 'set_command build_nack!(
+                                request,
+                                NackReason::DataOutOfRange,
+                                message_count
+                            );
+                        },
+                    },
+                    _ => {
+                        break 'set_command build_nack!(
+                            request,
+                            NackReason::FormatError,
+                            message_count
+                        );
+                    },
+                };
+
+                let response = request.build_response(
+                    ResponseType::ResponseTypeAck,
+                    DataPack::new(),
+                    message_count,
+                );
+                handler.on_reset(
+                    reset_type,
+                    &mut self.get_context_with_raw_request(raw_request),
+                )?;
+
+                response
+            },
+            RequestCommandClass::GetCommand | RequestCommandClass::DiscoveryCommand => {
+                build_nack!(request, NackReason::UnsupportedCommandClass, message_count)
+            },
+        };
+
+        Ok(response.ok())
+    }
+
+    fn handle_power_state<HandlerError>(
+        &mut self,
+        request: &RdmRequestData,
+        raw_request: Option<&[u8]>,
+        handler: &mut dyn RdmResponderHandlerFunc<Error = HandlerError>,
+    ) -> Result<Option<RdmResponseData>, HandlerError> {
+        let message_count = self.get_message_count();
+
+        let response = match request.command_class {
+            RequestCommandClass::GetCommand => {
+                if request.destination_uid.is_broadcast() {
+                    return Ok(None);
+                }
+
+                request.build_response(
+                    ResponseType::ResponseTypeAck,
+                    DataPack::from_slice(&[self.power_state as u8]).unwrap(),
+                    message_count,
+                )
+            },
+            RequestCommandClass::SetCommand => 'set_command: {
+                let power_state = match request.parameter_data[..] {
+                    [power_state] => match PowerState::try_from(power_state) {
+                        Ok(power_state) => power_state,
+                        Err(_) => {
+                            break 'set_command build_nack!(
+                                request,
+                                NackReason::DataOutOfRange,
+                                message_count
+                            );
+                        },
+                    },
+                    _ => {
+                        break 'set_command build_nack!(
+                            request,
+                            NackReason::FormatError,
+                            message_count
+                        );
+                    },
+                };
+
+                self.power_state = power_state;
+                let response = request.build_response(
+                    ResponseType::ResponseTypeAck,
+                    DataPack::new(),
+                    message_count,
+                );
+                handler.on_power_state(
+                    power_state,
+                    &mut self.get_context_with_raw_request(raw_request),
+                )?;
+
+                response
+            },
+            RequestCommandClass::DiscoveryCommand => {
+                build_nack!(request, NackReason::UnsupportedCommandClass, message_count)
+            },
+        };
+
+        Ok(response.ok())
+    }
+
+    fn handle_curve(&mut self, request: &RdmRequestData) -> Option<RdmResponseData> {
+        let message_count = self.get_message_count();
+
+        match request.command_class {
+            RequestCommandClass::GetCommand => request.build_response(
+                ResponseType::ResponseTypeAck,
+                DataPack::from_slice(
+                    &Curve {
+                        current_curve: self.curve,
+                        curve_count: self.curve_descriptions.len() as u8,
+                    }
+                    .serialize(),
+                )
+                .unwrap(),
+                message_count,
+            ),
+            RequestCommandClass::SetCommand => 'set_command: {
+                let curve = match request.parameter_data[..] {
+                    [curve] => curve,
+                    _ => {
+                        break 'set_command build_nack!(
+                            request,
+                            NackReason::FormatError,
+                            message_count
+                        );
+                    },
+                };
+
+                if !self.set_curve(curve) {
+                    break 'set_command build_nack!(
+                        request,
+                        NackReason::DataOutOfRange,
+                        message_count
+                    );
+                }
+                self.factory_defaults = false;
+
+                request.build_response(
+                    ResponseType::ResponseTypeAck,
+                    DataPack::new(),
+                    message_count,
+                )
+            },
+            RequestCommandClass::DiscoveryCommand => {
+                build_nack!(request, NackReason::UnsupportedCommandClass, message_count)
+            },
+        }
+        .ok()
+    }
+
+    fn handle_curve_description(&self, request: &RdmRequestData) -> Option<RdmResponseData> {
+        verify_get_request!(request, self);
+        let message_count = self.get_message_count();
+
+        let curve = match request.parameter_data[..] {
+            [curve] => curve,
+            _ => return build_nack!(request, NackReason::FormatError, message_count).ok(),
+        };
+
+        let description = match (curve as usize)
+            .checked_sub(1)
+            .and_then(|index| self.curve_descriptions.get(index))
+        {
+            Some(description) => description,
+            None => return build_nack!(request, NackReason::DataOutOfRange, message_count).ok(),
+        };
+
+        let mut response_data = DataPack::from_slice(&[curve]).unwrap();
+        response_data
+            .extend_from_slice(&serialize_label(description))
+            .unwrap();
+
+        request
+            .build_response(ResponseType::ResponseTypeAck, response_data, message_count)
+            .ok()
+    }
+
+    fn handle_perform_self_test<HandlerError>(
+        &mut self,
+        request: &RdmRequestData,
+        raw_request: Option<&[u8]>,
+        handler: &mut dyn RdmResponderHandlerFunc<Error = HandlerError>,
+    ) -> Result<Option<RdmResponseData>, HandlerError> {
+        let message_count = self.get_message_count();
+
+        let response = match request.command_class {
+            RequestCommandClass::GetCommand => {
+                if request.destination_uid.is_broadcast() {
+                    return Ok(None);
+                }
+
+                request.build_response(
+                    ResponseType::ResponseTypeAck,
+                    DataPack::from_slice(&[self.self_test]).unwrap(),
+                    message_count,
+                )
+            },
+            RequestCommandClass::SetCommand => 'set_command: {
+                let self_test = match request.parameter_data[..] {
+                    [self_test] => self_test,
+                    _ => {
+                        break 'set_command build_nack!(
+                            request,
+                            NackReason::FormatError,
+                            message_count
+                        );
+                    },
+                };
+
+                if self_test != RDM_SELF_TEST_OFF
+                    && self_test != RDM_SELF_TEST_ALL
+                    && self_test as usize > self.self_test_descriptions.len()
+                {
+                    break 'set_command build_nack!(
+                        request,
+                        NackReason::DataOutOfRange,
+                        message_count
+                    );
+                }
+
+                self.self_test = self_test;
+                let response = request.build_response(
+                    ResponseType::ResponseTypeAck,
+                    DataPack::new(),
+                    message_count,
+                );
+                handler.on_self_test(
+                    self_test,
+                    &mut self.get_context_with_raw_request(raw_request),
+                )?;
+
+                response
+            },
+            RequestCommandClass::DiscoveryCommand => {
+                build_nack!(request, NackReason::UnsupportedCommandClass, message_count)
+            },
+        };
+
+        Ok(response.ok())
+    }
+
+    fn handle_self_test_description(&self, request: &RdmRequestData) -> Option<RdmResponseData> {
+        verify_get_request!(request, self);
+        let message_count = self.get_message_count();
+
+        let self_test = match request.parameter_data[..] {
+            [self_test] => self_test,
+            _ => return build_nack!(request, NackReason::FormatError, message_count).ok(),
+        };
+
+        let description = match (self_test as usize)
+            .checked_sub(1)
+            .and_then(|index| self.self_test_descriptions.get(index))
+        {
+            Some(description) => description,
+            None => return build_nack!(request, NackReason::DataOutOfRange, message_count).ok(),
+        };
+
+        let mut response_data = DataPack::from_slice(&[self_test]).unwrap();
+        response_data
+            .extend_from_slice(&serialize_label(description))
+            .unwrap();
+
+        request
+            .build_response(ResponseType::ResponseTypeAck, response_data, message_count)
+            .ok()
+    }
+
+    fn handle_comms_status(&mut self, request: &RdmRequestData) -> Option<RdmResponseData> {
+        let message_count = self.get_message_count();
+
+        let response = match request.command_class {
+            RequestCommandClass::GetCommand => {
+                if request.destination_uid.is_broadcast() {
+                    return None;
+                }
+
+                request.build_response(
+                    ResponseType::ResponseTypeAck,
+                    DataPack::from_slice(&self.comms_status.serialize()).unwrap(),
+                    message_count,
+                )
+            },
+            RequestCommandClass::SetCommand => {
+                if !request.parameter_data.is_empty() {
+                    build_nack!(request, NackReason::FormatError, message_count)
+                } else {
+                    self.comms_status = CommsStatus::default();
+                    request.build_response(
+                        ResponseType::ResponseTypeAck,
+                        DataPack::new(),
+                        message_count,
+                    )
+                }
+            },
+            RequestCommandClass::DiscoveryCommand => {
+                build_nack!(request, NackReason::UnsupportedCommandClass, message_count)
+            },
+        };
+
+        response.ok()
+    }
+
+    fn handle_real_time_clock<HandlerError>(
+        &mut self,
+        request: &RdmRequestData,
+        raw_request: Option<&[u8]>,
+        handler: &mut dyn RdmResponderHandlerFunc<Error = HandlerError>,
+    ) -> Result<Option<RdmResponseData>, HandlerError> {
+        let message_count = self.get_message_count();
+
+        let response = match request.command_class {
+            RequestCommandClass::GetCommand => {
+                if request.destination_uid.is_broadcast() {
+                    return Ok(None);
+                }
+
+                let clock =
+                    handler.get_clock(&mut self.get_context_with_raw_request(raw_request))?;
+
+                request.build_response(
+                    ResponseType::ResponseTypeAck,
+                    DataPack::from_slice(&clock.serialize()).unwrap(),
+                    message_count,
+                )
+            },
+            RequestCommandClass::SetCommand => 'set_command: {
+                let clock = match RealTimeClock::deserialize(&request.parameter_data) {
+                    Ok(clock) => clock,
+                    Err(_) => {
+                        break 'set_command build_nack!(
+                            request,
+                            NackReason::FormatError,
+                            message_count
+                        );
+                    },
+                };
+
+                if !clock.is_valid() {
+                    break 'set_command build_nack!(
+                        request,
+                        NackReason::DataOutOfRange,
+                        message_count
+                    );
+                }
+
+                handler.on_set_clock(clock, &mut self.get_context_with_raw_request(raw_request))?;
+
+                request.build_response(
+                    ResponseType::ResponseTypeAck,
+                    DataPack::new(),
+                    self.get_message_count(),
+                )
+            },
+            RequestCommandClass::DiscoveryCommand => {
+                build_nack!(request, NackReason::UnsupportedCommandClass, message_count)
+            },
+        };
+
+        Ok(response.ok())
+    }
+
+    fn handle_statistics_counter<HandlerError>(
+        &mut self,
+        request: &RdmRequestData,
+        counter: StatisticsCounter,
+        raw_request: Option<&[u8]>,
+        handler: &mut dyn RdmResponderHandlerFunc<Error = HandlerError>,
+    ) -> Result<Option<RdmResponseData>, HandlerError> {
+        let message_count = self.get_message_count();
+
+        let response = match request.command_class {
+            RequestCommandClass::GetCommand => {
+                if request.destination_uid.is_broadcast() {
+                    return Ok(None);
+                }
+
+                let value = handler.get_statistics_counter(
+                    counter,
+                    &mut self.get_context_with_raw_request(raw_request),
+                )?;
+
+                request.build_response(
+                    ResponseType::ResponseTypeAck,
+                    DataPack::from_slice(&value.to_be_bytes()).unwrap(),
+                    message_count,
+                )
+            },
+            RequestCommandClass::SetCommand => 'set_command: {
+                let value = match request.parameter_data[..] {
+                    [a, b, c, d] => u32::from_be_bytes([a, b, c, d]),
+                    _ => {
+                        break 'set_command build_nack!(
+                            request,
+                            NackReason::FormatError,
+                            message_count
+                        );
+                    },
+                };
+
+                if !handler.on_set_statistics_counter(
+                    counter,
+                    value,
+                    &mut self.get_context_with_raw_request(raw_request),
+                )? {
+                    break 'set_command build_nack!(
+                        request,
+                        NackReason::WriteProtect,
+                        message_count
+                    );
+                }
+
+                request.build_response(
+                    ResponseType::ResponseTypeAck,
+                    DataPack::new(),
+                    self.get_message_count(),
+                )
+            },
+            RequestCommandClass::DiscoveryCommand => {
+                build_nack!(request, NackReason::UnsupportedCommandClass, message_count)
+            },
+        };
+
+        Ok(response.ok())
+    }
+
+    fn handle_capture_preset<HandlerError>(
+        &mut self,
+        request: &RdmRequestData,
+        raw_request: Option<&[u8]>,
+        handler: &mut dyn RdmResponderHandlerFunc<Error = HandlerError>,
+    ) -> Result<Option<RdmResponseData>, HandlerError> {
+        let message_count = self.get_message_count();
+
+        let response = match request.command_class {
+            RequestCommandClass::SetCommand => 'set_command: {
+                if request.parameter_data.len() != RDM_CAPTURE_PRESET_SIZE {
+                    break 'set_command build_nack!(
+                        request,
+                        NackReason::FormatError,
+                        message_count
+                    );
+                }
+
+                let scene =
+                    u16::from_be_bytes([request.parameter_data[0], request.parameter_data[1]]);
+                if scene == PresetPlayback::OFF || scene == PresetPlayback::ALL {
+                    break 'set_command build_nack!(
+                        request,
+                        NackReason::DataOutOfRange,
+                        message_count
+                    );
+                }
+
+                if !handler
+                    .on_capture_preset(scene, &mut self.get_context_with_raw_request(raw_request))?
+                {
+                    break 'set_command build_nack!(
+                        request,
+                        NackReason::DataOutOfRange,
+                        message_count
+                    );
+                }
+
+                request.build_response(
+                    ResponseType::ResponseTypeAck,
+                    DataPack::new(),
+                    self.get_message_count(),
+                )
+            },
+            RequestCommandClass::GetCommand | RequestCommandClass::DiscoveryCommand => {
+                build_nack!(request, NackReason::UnsupportedCommandClass, message_count)
+            },
+        };
+
+        Ok(response.ok())
+    }
+
+    fn handle_preset_playback<HandlerError>(
+        &mut self,
+        request: &RdmRequestData,
+        raw_request: Option<&[u8]>,
+        handler: &mut dyn RdmResponderHandlerFunc<Error = HandlerError>,
+    ) -> Result<Option<RdmResponseData>, HandlerError> {
+        let message_count = self.get_message_count();
+
+        let response = match request.command_class {
+            RequestCommandClass::GetCommand => {
+                if request.destination_uid.is_broadcast() {
+                    return Ok(None);
+                }
+
+                request.build_response(
+                    ResponseType::ResponseTypeAck,
+                    DataPack::from_slice(&self.preset_playback.serialize()).unwrap(),
+                    message_count,
+                )
+            },
+            RequestCommandClass::SetCommand => 'set_command: {
+                let preset_playback = match PresetPlayback::deserialize(&request.parameter_data) {
+                    Ok(preset_playback) => preset_playback,
+                    Err(_) => {
+                        break 'set_command build_nack!(
+                            request,
+                            NackReason::FormatError,
+                            message_count
+                        );
+                    },
+                };
+
+                if !handler.on_preset_playback(
+                    preset_playback.scene,
+                    preset_playback.level,
+                    &mut self.get_context_with_raw_request(raw_request),
+                )? {
+                    break 'set_command build_nack!(
+                        request,
+                        NackReason::DataOutOfRange,
+                        message_count
+                    );
+                }
+
+                self.preset_playback = preset_playback;
+                self.factory_defaults = false;
+
+                request.build_response(
+                    ResponseType::ResponseTypeAck,
+                    DataPack::new(),
+                    self.get_message_count(),
+                )
+            },
+            RequestCommandClass::DiscoveryCommand => {
+                build_nack!(request, NackReason::UnsupportedCommandClass, message_count)
+            },
+        };
+
+        Ok(response.ok())
+    }
+
+    fn handle_identify_mode(&mut self, request: &RdmRequestData) -> Option<RdmResponseData> {
+        let message_count = self.get_message_count();
+
+        let response = match request.command_class {
+            RequestCommandClass::GetCommand => request.build_response(
+                ResponseType::ResponseTypeAck,
+                DataPack::from_slice(&[self.identify_mode as u8]).unwrap(),
+                message_count,
+            ),
+            RequestCommandClass::SetCommand => 'set_command: {
+                if request.parameter_data.len() != 1 {
+                    break 'set_command build_nack!(
+                        request,
+                        NackReason::FormatError,
+                        message_count
+                    );
+                }
+
+                self.identify_mode = match IdentifyMode::try_from(request.parameter_data[0]) {
+                    Ok(identify_mode) => identify_mode,
+                    Err(_) => {
+                        break 'set_command build_nack!(
+                            request,
+                            NackReason::DataOutOfRange,
+                            message_count
+                        );
+                    },
+                };
+                self.factory_defaults = false;
+
+                request.build_response(
+                    ResponseType::ResponseTypeAck,
+                    DataPack::new(),
+                    message_count,
+                )
+            },
+            RequestCommandClass::DiscoveryCommand => {
+                build_nack!(request, NackReason::UnsupportedCommandClass, message_count)
+            },
+        };
+
+        response.ok()
+    }
+
+    /// Resets the state that is owned by the responder to the state after [Self::new].
+    fn reset_to_factory_defaults(&mut self) {
+        self.device_label.clear();
+        self.dmx_start_address = DmxStartAddress::NoAddress;
+        self.dmx_personality = 1;
+        self.dmx_footprint = self
+            .dmx_personalities
+            .first()
+            .map_or(1, |personality| personality.dmx_footprint);
+        self.identify = false;
+        self.identify_mode = IdentifyMode::default();
+        self.curve = 1;
+        self.preset_playback = PresetPlayback::default();
+        for sub_device in self.sub_devices.iter_mut() {
+            sub_device.dmx_start_address = DmxStartAddress::NoAddress;
+        }
+    }
+
+    fn handle_factory_defaults<HandlerError>(
+        &mut self,
+        request: &RdmRequestData,
+        raw_request: Option<&[u8]>,
+        handler: &mut dyn RdmResponderHandlerFunc<Error = HandlerError>,
+    ) -> Result<Option<RdmResponseData>, HandlerError> {
+        let message_count = self.get_message_count();
+
+        let response = match request.command_class {
+            RequestCommandClass::GetCommand => {
+                if request.destination_uid.is_broadcast() {
+                    return Ok(None);
+                }
+
+                request.build_response(
+                    ResponseType::ResponseTypeAck,
+                    DataPack::from_slice(&[self.factory_defaults as u8]).unwrap(),
+                    message_count,
+                )
+            },
+            RequestCommandClass::SetCommand => 'set_command: {
+                if !request.parameter_data.is_empty() {
+                    break 'set_command build_nack!(
+                        request,
+                        NackReason::FormatError,
+                        message_count
+                    );
+                }
+
+                let identify = self.identify;
+                self.reset_to_factory_defaults();
+                if identify {
+                    handler.on_identify_changed(
+                        false,
+                        &mut self.get_context_with_raw_request(raw_request),
+                    )?;
+                }
+                handler.on_factory_reset(&mut self.get_context_with_raw_request(raw_request))?;
+                self.factory_defaults = true;
+
+                request.build_response(
+                    ResponseType::ResponseTypeAck,
+                    DataPack::new(),
+                    self.get_message_count(),
+                )
+            },
+            RequestCommandClass::DiscoveryCommand => {
+                build_nack!(request, NackReason::UnsupportedCommandClass, message_count)
+            },
+        };
+
+        Ok(response.ok())
+    }
+
+    /// Get the start address of the root device (0) or a sub-device.
+    fn dmx_start_address_mut(&mut self, sub_device: u16) -> &mut DmxStartAddress {
+        match sub_device {
+            0 => &mut self.dmx_start_address,
+            sub_device => &mut self.sub_devices[sub_device as usize - 1].dmx_start_address,
+        }
+    }
+
+    fn handle_device_info(&self, request: &RdmRequestData) -> Option<RdmResponseData> {
+        verify_get_request!(request, self);
+
+        let (dmx_start_address, dmx_footprint, dmx_personality, personality_count) =
+            match request.sub_device {
+                0 => (
+                    &self.dmx_start_address,
+                    self.dmx_footprint,
+                    self.dmx_personality,
+                    (self.dmx_personalities.len() as u8).max(1),
+                ),
+                sub_device => {
+                    let sub_device = &self.sub_devices[sub_device as usize - 1];
+                    (
+                        &sub_device.dmx_start_address,
+                        sub_device.dmx_footprint,
+                        1,
+                        1,
+                    )
+                },
+            };
+
+        request
+            .build_response(
+                ResponseType::ResponseTypeAck,
+                DeviceInfo {
+                    device_model_id: self.rdm_receiver_metadata.device_model_id,
+                    product_category: self.rdm_receiver_metadata.product_category,
+                    software_version: self.rdm_receiver_metadata.software_version_id,
+                    dmx_footprint,
+                    // the current personality is in the high byte, the count in the low byte
+                    dmx_personality: u16::from_be_bytes([dmx_personality, personality_count]),
+                    dmx_start_address: dmx_start_address.clone(),
+                    sub_device_count: SUB_DEVICE_COUNT as u16,
+                    sensor_count: match request.sub_device {
+                        0 => self.sensor_values.len() as u8,
+                        _ => 0,
+                    },
+                }
+                .serialize(),
+                self.get_message_count(),
+            )
+            .ok()
+    }
+
+    fn build_disc_mute_response(
+        &self,
+        request: &RdmRequestData,
+    ) -> Result<RdmResponseData, IsBroadcastError> {
+        request.build_response(
+            ResponseType::ResponseTypeAck,
+            DiscoveryMuteResponse {
+                sub_device: self.discovery_mute_response.sub_device || SUB_DEVICE_COUNT > 0,
+                ..self.discovery_mute_response
+            }
+            .serialize(),
+            self.get_message_count(),
+        )
+    }
+
+    fn handle_queued_message(&mut self, request: &RdmRequestData) -> Option<RdmResponseData> {
+        verify_get_request!(request, self);
+
+        let message_count = self.get_message_count();
+
+        let status_type_requested = match StatusType::deserialize(&request.parameter_data) {
+            Ok(status) => status,
+            Err(_) => return build_nack!(request, NackReason::DataOutOfRange, message_count).ok(),
+        };
+
+        if status_type_requested == StatusType::StatusNone {
+            return build_nack!(request, NackReason::DataOutOfRange, message_count).ok();
+        }
+
+        if status_type_requested == StatusType::StatusGetLastMessage {
+            return match self.last_queued_message {
+                None => request
+                    .build_response(
+                        ResponseType::ResponseTypeAck,
+                        DataPack::new(),
+                        message_count,
+                    )
+                    .ok(),
+                Some(ref message) => Some(self.build_queued_message_response(request, message)),
+            };
+        }
+
+        match status_type_requested {
+            StatusType::StatusWarning | StatusType::StatusError | StatusType::StatusAdvisory => {},
+            _ => return build_nack!(request, NackReason::DataOutOfRange, message_count).ok(),
+        }
+
+        if !take_next_message(&mut self.message_queue, &mut self.last_queued_message) {
+            let response_data = self.pop_filtered_statuses(status_type_requested);
+            self.last_status_vec_message = response_data.clone();
+            self.last_queued_message =
+                Some(QueuedMessage::new(pids::STATUS_MESSAGES, response_data));
+        }
+
+        // can't fail since the last message has just been set
+        let message = self.last_queued_message.as_ref().unwrap();
+        Some(self.build_queued_message_response(request, message))
+    }
+
+    /// Builds the response to a QUEUED_MESSAGE request from a message.
+    /// Only the parameter data is copied, so the message can be kept as the last message.
+    fn build_queued_message_response(
+        &self,
+        request: &RdmRequestData,
+        message: &QueuedMessage,
+    ) -> RdmResponseData {
+        RdmResponseData {
+            destination_uid: PackageAddress::Device(request.source_uid),
+            source_uid: self.uid,
+            transaction_number: request.transaction_number,
+            response_type: message.response_type,
+            message_count: self.get_message_count(),
+            sub_device: message.sub_device,
+            command_class: message.command_class,
+            parameter_id: message.parameter_id,
+            parameter_data: message.parameter_data.clone(),
+        }
+    }
+
+    fn handle_status_messages(&mut self, request: &RdmRequestData) -> Option<RdmResponseData> {
+        verify_get_request!(request, self);
+
+        let message_count = self.get_message_count();
+
+        let status_type_requested = match StatusType::deserialize(&request.parameter_data) {
+            Ok(status) => status,
+            Err(_) => return build_nack!(request, NackReason::FormatError, message_count).ok(),
+        };
+
+        match status_type_requested {
+            StatusType::StatusNone => request.build_response(
+                ResponseType::ResponseTypeAck,
+                DataPack::new(),
+                message_count,
+            ),
+            StatusType::StatusGetLastMessage => request.build_response(
+                ResponseType::ResponseTypeAck,
+                self.last_status_vec_message.clone(),
+                message_count,
+            ),
+            StatusType::StatusWarning | StatusType::StatusError | StatusType::StatusAdvisory => {
+                let response_vec = self.pop_filtered_statuses(status_type_requested);
+
+                self.last_status_vec_message = response_vec.clone();
+                request.build_response(ResponseType::ResponseTypeAck, response_vec, message_count)
+            },
+            _ => build_nack!(request, NackReason::DataOutOfRange, message_count),
+        }
+        .ok()
+    }
+
+    fn pop_filtered_statuses(&mut self, status_filter: StatusType) -> DataPack {
+        let mut indexes_to_remove =
+            heapless::Vec::<usize, RDM_MAX_STATUS_PACKAGES_PER_REQUEST>::new();
+        let mut parameter_data = DataPack::new();
+
+        self.status_vec
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| item.status_type.severity() >= status_filter.severity())
+            .take(RDM_MAX_STATUS_PACKAGES_PER_REQUEST)
+            .for_each(|(index, item)| {
+                parameter_data.extend_from_slice(&item.serialize()).unwrap();
+                indexes_to_remove.push(index).unwrap();
+            });
+
+        // remove from the back, so the remaining indexes stay valid
+        for &index_to_remove in indexes_to_remove.iter().rev() {
+            self.status_vec.remove(index_to_remove);
+        }
+
+        parameter_data
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(not(feature = "std"), allow(unused_imports))]
+mod tests {
+    use crate::command_class::{RequestCommandClass, ResponseCommandClass};
+    use crate::consts::{RDM_SELF_TEST_ALL, RDM_SELF_TEST_OFF};
+    use crate::pids;
+    use crate::rdm_data::{RdmDeserializationError, RdmRequestData, RdmResponseData};
+    use crate::rdm_packages::deserialize_default_slot_values;
+    #[cfg(feature = "std")]
+    use crate::rdm_responder::take_next_message;
+    use crate::rdm_responder::{
+        ConfigValidationError, DmxReceiverContext, MessageQueueFullError, MessageQueueFullPolicy,
+        OverflowBuffer, QueuedMessage, RdmAnswer, RdmReceiverMetadata, RdmResponderConfig,
+        RdmResponderHandlerFunc, RdmResponderPackageHandler, RdmResult, UnhandledPid,
+        INTERNALLY_SUPPORTED_PIDS, SUB_DEVICE_ALL_CALL,
+    };
+    use crate::rdm_types::{
+        CommsStatus, Curve, DefaultSlotValue, DeviceInfo, DiscoveryMuteResponse, DmxBlockAddress,
+        DmxPersonality, DmxPersonalityDescription, DmxStartAddress, IdentifyMode,
+        ParameterDescription, PidCommandClassSupport, PowerState, PresetPlayback, RdmDataType,
+        RealTimeClock, ResetType, SensorDefinition, SensorValue, StatisticsCounter, StatusMessage,
+        StatusType,
+    };
+    use crate::types::{DataPack, NackReason, ResponseType};
+    use crate::unique_identifier::{PackageAddress, UniqueIdentifier};
+
+    #[cfg(feature = "std")]
+    const RESPONDER_UID: UniqueIdentifier = match UniqueIdentifier::new(0x7FF0, 1) {
+        Ok(uid) => uid,
+        Err(_) => panic!(),
+    };
+
+    #[cfg(feature = "std")]
+    struct EmptyHandler;
+
+    #[cfg(feature = "std")]
+    impl RdmResponderHandlerFunc for EmptyHandler {
+        type Error = ();
+    }
+
+    #[cfg(feature = "std")]
+    fn build_config(supported_pids: &'static [u16]) -> RdmResponderConfig {
+        RdmResponderConfig {
+            uid: RESPONDER_UID,
+            supported_pids,
+            ..Default::default()
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn build_package_handler(supported_pids: &'static [u16]) -> RdmResponderPackageHandler<4> {
+        RdmResponderPackageHandler::new(build_config(supported_pids))
+    }
+
+    #[cfg(feature = "std")]
+    fn build_request(
+        command_class: RequestCommandClass,
+        parameter_id: u16,
+        parameter_data: &[u8],
+    ) -> RdmRequestData {
+        RdmRequestData {
+            destination_uid: PackageAddress::Device(RESPONDER_UID),
+            source_uid: UniqueIdentifier::new(0x7FF0, 0).unwrap(),
+            transaction_number: 0,
+            port_id: 1,
+            message_count: 0,
+            sub_device: 0,
+            command_class,
+            parameter_id,
+            parameter_data: DataPack::from_slice(parameter_data).unwrap(),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn handle_request<
+        const MQ_SIZE: usize,
+        const SUB_DEVICE_COUNT: usize,
+        const SENSOR_COUNT: usize,
+    >(
+        package_handler: &mut RdmResponderPackageHandler<MQ_SIZE, SUB_DEVICE_COUNT, SENSOR_COUNT>,
+        request: RdmRequestData,
+    ) -> RdmResponseData {
+        match package_handler.handle_rdm_request(request, &mut EmptyHandler) {
+            Ok(RdmAnswer::Response(response)) => response,
+            _ => panic!("expected a response"),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_discovery_mute_response() {
+        let binding_uid = UniqueIdentifier::new(0x7FF0, 0x0100).unwrap();
+        let mut package_handler = RdmResponderPackageHandler::<4>::new(RdmResponderConfig {
+            discovery_mute_response: DiscoveryMuteResponse {
+                managed_proxy: true,
+                binding_uid: Some(binding_uid),
+                ..Default::default()
+            },
+            ..build_config(&[])
+        });
+
+        for pid in [pids::DISC_MUTE, pids::DISC_UN_MUTE] {
+            let response = handle_request(
+                &mut package_handler,
+                build_request(RequestCommandClass::DiscoveryCommand, pid, &[]),
+            );
+            assert_eq!(response.parameter_data.len(), 8);
+            assert_eq!(
+                DiscoveryMuteResponse::deserialize(&response.parameter_data).unwrap(),
+                DiscoveryMuteResponse {
+                    managed_proxy: true,
+                    binding_uid: Some(binding_uid),
+                    ..Default::default()
+                }
+            );
+        }
+
+        // without a binding uid only the control field is sent
+        let mut package_handler = build_package_handler(&[]);
+        let response = handle_request(
+            &mut package_handler,
+            build_request(RequestCommandClass::DiscoveryCommand, pids::DISC_MUTE, &[]),
+        );
+        assert_eq!(&response.parameter_data[..], &[0x00, 0x00]);
+
+        // the sub-device flag is derived from the sub-device count
+        let mut package_handler = RdmResponderPackageHandler::<4, 2>::new(build_config(&[]));
+        let response = handle_request(
+            &mut package_handler,
+            build_request(RequestCommandClass::DiscoveryCommand, pids::DISC_MUTE, &[]),
+        );
+        assert!(
+            DiscoveryMuteResponse::deserialize(&response.parameter_data)
+                .unwrap()
+                .sub_device
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_disc_mute_with_parameter_data() {
+        let mut package_handler = build_package_handler(&[]);
+
+        for pid in [pids::DISC_MUTE, pids::DISC_UN_MUTE] {
+            let response = handle_request(
+                &mut package_handler,
+                build_request(RequestCommandClass::DiscoveryCommand, pid, &[0x00]),
+            );
+            assert_eq!(response.response_type, ResponseType::ResponseTypeNackReason);
+            assert_eq!(
+                &response.parameter_data[..],
+                &u16::from(NackReason::FormatError).to_be_bytes()
+            );
+
+            // broadcasts are never answered
+            let request = RdmRequestData {
+                destination_uid: PackageAddress::Broadcast,
+                ..build_request(RequestCommandClass::DiscoveryCommand, pid, &[0x00])
+            };
+            assert!(matches!(
+                package_handler.handle_rdm_request(request, &mut EmptyHandler),
+                Ok(RdmAnswer::NoResponse)
+            ));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_required_pids_are_handled() {
+        let mut package_handler = build_package_handler(&[]);
+
+        for &pid in pids::required_pids() {
+            let request = match pid {
+                pids::DISC_UNIQUE_BRANCH => build_request(
+                    RequestCommandClass::DiscoveryCommand,
+                    pid,
+                    &[
+                        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE,
+                    ],
+                ),
+                pids::DISC_MUTE | pids::DISC_UN_MUTE => {
+                    build_request(RequestCommandClass::DiscoveryCommand, pid, &[])
+                },
+                _ => build_request(RequestCommandClass::GetCommand, pid, &[]),
+            };
+
+            match package_handler.handle_rdm_request(request, &mut EmptyHandler) {
+                Ok(RdmAnswer::Response(response)) => assert_eq!(
+                    response.response_type,
+                    ResponseType::ResponseTypeAck,
+                    "pid {pid:#06X} isn't handled"
+                ),
+                Ok(RdmAnswer::DiscoveryResponse(uid)) => assert_eq!(uid, RESPONDER_UID),
+                _ => panic!("pid {pid:#06X} isn't handled"),
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_supported_parameters_pagination() {
+        let supported_pids: &'static [u16] = (0x8000..0x8000 + 200).collect::<Vec<u16>>().leak();
+        let mut package_handler = build_package_handler(supported_pids);
+
+        let mut received_pids = Vec::new();
+        loop {
+            let response = handle_request(
+                &mut package_handler,
+                build_request(
+                    RequestCommandClass::GetCommand,
+                    pids::SUPPORTED_PARAMETERS,
+                    &[],
+                ),
+            );
+
+            let chunk: Vec<u16> = response
+                .parameter_data
+                .chunks(2)
+                .map(|pid_bytes| u16::from_be_bytes(pid_bytes.try_into().unwrap()))
+                .collect();
+            assert!(!chunk.is_empty());
+            received_pids.extend(chunk);
+
+            match response.response_type {
+                ResponseType::ResponseTypeAckOverflow => continue,
+                ResponseType::ResponseTypeAck => break,
+                response_type => panic!("unexpected response type {response_type:?}"),
+            }
+        }
+
+        let expected_pids: Vec<u16> = INTERNALLY_SUPPORTED_PIDS
+            .iter()
+            .chain([pids::QUEUED_MESSAGE, pids::STATUS_MESSAGES].iter())
+            .chain(supported_pids.iter())
+            .copied()
+            .collect();
+        assert_eq!(received_pids, expected_pids);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_default_slot_value_pagination() {
+        let default_slot_values: &'static [DefaultSlotValue] = (0..100)
+            .map(|slot_offset| DefaultSlotValue {
+                slot_offset,
+                default_value: slot_offset as u8 * 2,
+            })
+            .collect::<Vec<_>>()
+            .leak();
+        let mut package_handler = RdmResponderPackageHandler::<4>::new(RdmResponderConfig {
+            default_slot_values,
+            ..build_config(&[])
+        });
+
+        let get_default_slot_values = |package_handler: &mut RdmResponderPackageHandler<4>| {
+            let response = handle_request(
+                package_handler,
+                build_request(
+                    RequestCommandClass::GetCommand,
+                    pids::DEFAULT_SLOT_VALUE,
+                    &[],
+                ),
+            );
+            let default_slot_values =
+                deserialize_default_slot_values(&response.parameter_data).unwrap();
+
+            (response.response_type, default_slot_values)
+        };
+
+        let (response_type, first_chunk) = get_default_slot_values(&mut package_handler);
+        assert_eq!(response_type, ResponseType::ResponseTypeAckOverflow);
+        assert_eq!(&first_chunk[..], &default_slot_values[..77]);
+
+        // another overflowing get restarts the pagination
+        assert!(get_supported_parameters(&mut package_handler).contains(&pids::DEFAULT_SLOT_VALUE));
+        let (response_type, chunk) = get_default_slot_values(&mut package_handler);
+        assert_eq!(response_type, ResponseType::ResponseTypeAckOverflow);
+        assert_eq!(chunk, first_chunk);
+
+        let (response_type, chunk) = get_default_slot_values(&mut package_handler);
+        assert_eq!(response_type, ResponseType::ResponseTypeAck);
+        assert_eq!(&chunk[..], &default_slot_values[77..]);
+
+        let response = handle_request(
+            &mut package_handler,
+            build_request(
+                RequestCommandClass::SetCommand,
+                pids::DEFAULT_SLOT_VALUE,
+                &[],
+            ),
+        );
+        assert_eq!(response.response_type, ResponseType::ResponseTypeNackReason);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_pagination_state() {
+        let default_slot_values: &'static [DefaultSlotValue] = (0..100)
+            .map(|slot_offset| DefaultSlotValue {
+                slot_offset,
+                default_value: 0,
+            })
+            .collect::<Vec<_>>()
+            .leak();
+        let mut package_handler = RdmResponderPackageHandler::<4>::new(RdmResponderConfig {
+            default_slot_values,
+            ..build_config(&[])
+        });
+        let get_request = |pid| build_request(RequestCommandClass::GetCommand, pid, &[]);
+
+        assert_eq!(package_handler.pagination_state(), None);
+
+        handle_request(&mut package_handler, get_request(pids::DEFAULT_SLOT_VALUE));
+        assert_eq!(
+            package_handler.pagination_state(),
+            Some((pids::DEFAULT_SLOT_VALUE, 1))
+        );
+
+        // a request for another pid resets the pagination
+        handle_request(&mut package_handler, get_request(pids::DEVICE_INFO));
+        assert_eq!(package_handler.pagination_state(), None);
+
+        handle_request(&mut package_handler, get_request(pids::DEFAULT_SLOT_VALUE));
+        assert_eq!(
+            package_handler.pagination_state(),
+            Some((pids::DEFAULT_SLOT_VALUE, 1))
+        );
+
+        let response = handle_request(&mut package_handler, get_request(pids::DEFAULT_SLOT_VALUE));
+        assert_eq!(response.response_type, ResponseType::ResponseTypeAck);
+        assert_eq!(package_handler.pagination_state(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_sorted_supported_parameters_pagination() {
+        let supported_pids: &'static [u16] =
+            (0x8000..0x8000 + 200).rev().collect::<Vec<u16>>().leak();
+        let mut package_handler = RdmResponderPackageHandler::<32>::new(RdmResponderConfig {
+            sort_supported_parameters: true,
+            ..build_config(supported_pids)
+        });
+
+        let mut received_pids = Vec::new();
+        let mut response_count = 0;
+        loop {
+            let response = handle_request(
+                &mut package_handler,
+                build_request(
+                    RequestCommandClass::GetCommand,
+                    pids::SUPPORTED_PARAMETERS,
+                    &[],
+                ),
+            );
+            response_count += 1;
+
+            received_pids.extend(
+                response
+                    .parameter_data
+                    .chunks(2)
+                    .map(|pid_bytes| u16::from_be_bytes(pid_bytes.try_into().unwrap())),
+            );
+
+            match response.response_type {
+                ResponseType::ResponseTypeAckOverflow => continue,
+                ResponseType::ResponseTypeAck => break,
+                response_type => panic!("unexpected response type {response_type:?}"),
+            }
+        }
+
+        let mut expected_pids: Vec<u16> = INTERNALLY_SUPPORTED_PIDS
+            .iter()
+            .chain([pids::QUEUED_MESSAGE, pids::STATUS_MESSAGES].iter())
+            .chain(supported_pids.iter())
+            .copied()
+            .collect();
+        expected_pids.sort();
+
+        assert!(response_count > 1);
+        assert_eq!(received_pids, expected_pids);
+    }
+
+    #[cfg(feature = "std")]
+    fn get_supported_parameters<const MQ_SIZE: usize, const SUB_DEVICE_COUNT: usize>(
+        package_handler: &mut RdmResponderPackageHandler<MQ_SIZE, SUB_DEVICE_COUNT>,
+    ) -> Vec<u16> {
+        let response = handle_request(
+            package_handler,
+            build_request(
+                RequestCommandClass::GetCommand,
+                pids::SUPPORTED_PARAMETERS,
+                &[],
+            ),
+        );
+        assert_eq!(response.response_type, ResponseType::ResponseTypeAck);
+
+        response
+            .parameter_data
+            .chunks(2)
+            .map(|pid_bytes| u16::from_be_bytes(pid_bytes.try_into().unwrap()))
+            .collect()
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_supported_pid_count() {
+        let mut package_handler = build_package_handler(&[pids::DEVICE_HOURS, 0x8000]);
+        let supported_pids = get_supported_parameters(&mut package_handler);
+        assert_eq!(package_handler.supported_pid_count(), supported_pids.len());
+        assert_eq!(
+            package_handler.supported_pid_count(),
+            INTERNALLY_SUPPORTED_PIDS.len() + 4
+        );
+
+        let mut package_handler = RdmResponderPackageHandler::<4>::new(RdmResponderConfig {
+            curve_descriptions: &["linear"],
+            advertise_internal_pids: false,
+            ..build_config(&[0x8000])
+        });
+        assert_eq!(
+            package_handler.supported_pid_count(),
+            get_supported_parameters(&mut package_handler).len()
+        );
+        assert_eq!(package_handler.supported_pid_count(), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_supported_parameters_without_message_queue() {
+        let mut package_handler = RdmResponderPackageHandler::<0>::new(RdmResponderConfig {
+            uid: RESPONDER_UID,
+            ..Default::default()
+        });
+
+        let supported_pids = get_supported_parameters(&mut package_handler);
+        assert_eq!(supported_pids, INTERNALLY_SUPPORTED_PIDS);
+        assert!(!supported_pids.contains(&pids::QUEUED_MESSAGE));
+        assert!(!supported_pids.contains(&pids::STATUS_MESSAGES));
+
+        let response = handle_request(
+            &mut package_handler,
+            build_request(
+                RequestCommandClass::GetCommand,
+                pids::QUEUED_MESSAGE,
+                &[0x04],
+            ),
+        );
+        assert_eq!(response.response_type, ResponseType::ResponseTypeNackReason);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_config_validation() {
+        let build_config = |supported_pids: &'static [u16]| RdmResponderConfig {
+            uid: RESPONDER_UID,
+            supported_pids,
+            ..Default::default()
+        };
+
+        assert_eq!(build_config(&[0x8000, 0x8001]).validate(), Ok(()));
+        assert_eq!(
+            build_config(&[0x8000, 0x8001, 0x8000]).validate(),
+            Err(ConfigValidationError::DuplicatePid(0x8000))
+        );
+        assert_eq!(
+            build_config(&[0x8000, pids::DEVICE_INFO]).validate(),
+            Err(ConfigValidationError::RequiredPid(pids::DEVICE_INFO))
+        );
+        assert_eq!(
+            RdmResponderConfig {
+                curve_descriptions: &["linear"],
+                ..build_config(&[pids::CURVE])
+            }
+            .validate(),
+            Err(ConfigValidationError::InternalPid(pids::CURVE))
+        );
+        // automatically advertised pids can be passed to the handler
+        assert_eq!(build_config(&[pids::DEVICE_LABEL]).validate(), Ok(()));
+        assert_eq!(
+            build_config(&[0xFFE0]).validate(),
+            Err(ConfigValidationError::ReservedPid(0xFFE0))
+        );
+
+        // the internal pids have to be listed if they aren't advertised automatically
+        let config = RdmResponderConfig {
+            advertise_internal_pids: false,
+            ..build_config(&[pids::DEVICE_LABEL])
+        };
+        assert_eq!(config.validate(), Ok(()));
+    }
+
+    #[test]
+    #[should_panic(expected = "The supported pids are invalid")]
+    #[cfg(debug_assertions)]
+    #[cfg(feature = "std")]
+    fn test_invalid_config_panics() {
+        build_package_handler(&[pids::IDENTIFY_DEVICE]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_reset_pids_are_opt_in() {
+        let mut package_handler = build_package_handler(&[]);
+        let supported_pids = get_supported_parameters(&mut package_handler);
+        assert!(!supported_pids.contains(&pids::FACTORY_DEFAULTS));
+        assert!(!supported_pids.contains(&pids::RESET_DEVICE));
+
+        for (parameter_id, parameter_data) in [
+            (pids::FACTORY_DEFAULTS, &[][..]),
+            (pids::RESET_DEVICE, &[0xFF][..]),
+        ] {
+            let response = handle_request(
+                &mut package_handler,
+                build_request(
+                    RequestCommandClass::SetCommand,
+                    parameter_id,
+                    parameter_data,
+                ),
+            );
+            assert_eq!(response.response_type, ResponseType::ResponseTypeNackReason);
+        }
+
+        let mut package_handler =
+            build_package_handler(&[pids::FACTORY_DEFAULTS, pids::RESET_DEVICE]);
+        let supported_pids = get_supported_parameters(&mut package_handler);
+        assert!(supported_pids.contains(&pids::FACTORY_DEFAULTS));
+        assert!(supported_pids.contains(&pids::RESET_DEVICE));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_internal_pid_passed_to_handler() {
+        struct LabelHandler;
+
+        impl RdmResponderHandlerFunc for LabelHandler {
+            type Error = ();
+
+            fn handle_rdm(
+                &mut self,
+                _: &RdmRequestData,
+                _: &mut DmxReceiverContext,
+            ) -> Result<RdmResult, Self::Error> {
+                Ok(RdmResult::Acknowledged(
+                    DataPack::from_slice(b"Handler label").unwrap(),
+                ))
+            }
+        }
+
+        let mut package_handler = build_package_handler(&[pids::DEVICE_LABEL]);
+        package_handler.set_device_label("Internal label");
+
+        let response = match package_handler.handle_rdm_request(
+            build_request(RequestCommandClass::GetCommand, pids::DEVICE_LABEL, &[]),
+            &mut LabelHandler,
+        ) {
+            Ok(RdmAnswer::Response(response)) => response,
+            _ => panic!("expected a response"),
+        };
+        assert_eq!(&response.parameter_data[..], b"Handler label");
+
+        let supported_pids = get_supported_parameters(&mut package_handler);
+        assert_eq!(
+            supported_pids
+                .iter()
+                .filter(|&&pid| pid == pids::DEVICE_LABEL)
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_supported_parameters_without_internal_pids() {
+        let mut package_handler = RdmResponderPackageHandler::<4>::new(RdmResponderConfig {
+            uid: RESPONDER_UID,
+            supported_pids: &[pids::DEVICE_LABEL, 0x8000],
+            advertise_internal_pids: false,
+            ..Default::default()
+        });
+
+        assert_eq!(
+            get_supported_parameters(&mut package_handler),
+            [pids::DEVICE_LABEL, 0x8000]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_overflow_buffer() {
+        struct BlobHandler {
+            blob: Vec<u8>,
+            calls: usize,
+        }
+
+        impl RdmResponderHandlerFunc for BlobHandler {
+            type Error = ();
+
+            fn handle_rdm(
+                &mut self,
+                _: &RdmRequestData,
+                _: &mut DmxReceiverContext,
+            ) -> Result<RdmResult, Self::Error> {
+                self.calls += 1;
+                Ok(RdmResult::AcknowledgedStream(OverflowBuffer::new(
+                    heapless::Vec::from_slice(&self.blob).unwrap(),
+                )))
+            }
+        }
+
+        let mut package_handler = build_package_handler(&[0x8000]);
+        // the blob is built at runtime and owned by the handler
+        let blob: Vec<u8> = (0..600).map(|index| (index % 251) as u8).collect();
+        let mut handler = BlobHandler {
+            blob: blob.clone(),
+            calls: 0,
+        };
+        let mut get_blob =
+            |package_handler: &mut RdmResponderPackageHandler<4>| match package_handler
+                .handle_rdm_request(
+                    build_request(RequestCommandClass::GetCommand, 0x8000, &[]),
+                    &mut handler,
+                ) {
+                Ok(RdmAnswer::Response(response)) => response,
+                _ => panic!("expected a response"),
+            };
+
+        let mut received = Vec::new();
+        for (response_type, length) in [
+            (ResponseType::ResponseTypeAckOverflow, 231),
+            (ResponseType::ResponseTypeAckOverflow, 231),
+            (ResponseType::ResponseTypeAck, 138),
+        ] {
+            let response = get_blob(&mut package_handler);
+            assert_eq!(response.response_type, response_type);
+            assert_eq!(response.parameter_data.len(), length);
+            received.extend_from_slice(&response.parameter_data);
+        }
+        assert_eq!(received, blob);
+
+        // the stream is finished, so the next request starts a new one
+        let response = get_blob(&mut package_handler);
+        assert_eq!(
+            response.response_type,
+            ResponseType::ResponseTypeAckOverflow
+        );
+        assert_eq!(&response.parameter_data[..], &blob[..231]);
+
+        // a request for another pid ends the stream
+        handle_request(
+            &mut package_handler,
+            build_request(RequestCommandClass::GetCommand, pids::DEVICE_INFO, &[]),
+        );
+        let response = get_blob(&mut package_handler);
+        assert_eq!(&response.parameter_data[..], &blob[..231]);
+        assert_eq!(handler.calls, 3);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_max_response_parameter_len_paginates() {
+        struct LongResponseHandler {
+            calls: usize,
+        }
+
+        impl RdmResponderHandlerFunc for LongResponseHandler {
+            type Error = ();
+
+            fn handle_rdm(
+                &mut self,
+                _: &RdmRequestData,
+                _: &mut DmxReceiverContext,
+            ) -> Result<RdmResult, Self::Error> {
+                self.calls += 1;
+                let response_data: Vec<u8> = (0..200).map(|index| index as u8).collect();
+                Ok(RdmResult::Acknowledged(
+                    DataPack::from_slice(&response_data).unwrap(),
+                ))
+            }
+        }
+
+        let mut package_handler = RdmResponderPackageHandler::<4>::new(RdmResponderConfig {
+            uid: RESPONDER_UID,
+            supported_pids: &[0x8000],
+            max_response_parameter_len: Some(100),
+            ..Default::default()
+        });
+        let mut handler = LongResponseHandler { calls: 0 };
+
+        let mut received_data = Vec::new();
+        for expected_response_type in [
+            ResponseType::ResponseTypeAckOverflow,
+            ResponseType::ResponseTypeAck,
+        ] {
+            let response = match package_handler.handle_rdm_request(
+                build_request(RequestCommandClass::GetCommand, 0x8000, &[]),
+                &mut handler,
+            ) {
+                Ok(RdmAnswer::Response(response)) => response,
+                _ => panic!("expected a response"),
+            };
+
+            assert_eq!(response.response_type, expected_response_type);
+            assert_eq!(response.parameter_data.len(), 100);
+            received_data.extend_from_slice(&response.parameter_data);
+        }
+
+        assert_eq!(handler.calls, 1);
+        assert_eq!(
+            received_data,
+            (0..200).map(|index| index as u8).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_write_protected_dmx_start_address() {
+        let mut package_handler = RdmResponderPackageHandler::<4>::new(RdmResponderConfig {
+            uid: RESPONDER_UID,
+            write_protected_pids: &[pids::DMX_START_ADDRESS],
+            ..Default::default()
+        });
+
+        package_handler.set_write_protected(true);
+        let response = handle_request(
+            &mut package_handler,
+            build_request(
+                RequestCommandClass::SetCommand,
+                pids::DMX_START_ADDRESS,
+                &[0x00, 0x10],
+            ),
+        );
+        assert_eq!(response.response_type, ResponseType::ResponseTypeNackReason);
+        assert_eq!(
+            &response.parameter_data[..],
+            &u16::from(NackReason::WriteProtect).to_be_bytes()
+        );
+        assert_eq!(
+            package_handler.dmx_start_address,
+            DmxStartAddress::NoAddress
+        );
+
+        package_handler.set_write_protected(false);
+        let response = handle_request(
+            &mut package_handler,
+            build_request(
+                RequestCommandClass::SetCommand,
+                pids::DMX_START_ADDRESS,
+                &[0x00, 0x10],
+            ),
+        );
+        assert_eq!(response.response_type, ResponseType::ResponseTypeAck);
+        assert_eq!(
+            package_handler.dmx_start_address,
+            DmxStartAddress::Address(0x10)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_invalid_dmx_start_address() {
+        let mut package_handler = build_package_handler(&[]);
+
+        for (parameter_data, nack_reason) in [
+            (&[0x00][..], NackReason::FormatError),
+            (&[0x00, 0x00][..], NackReason::DataOutOfRange),
+            (&[0x02, 0x01][..], NackReason::DataOutOfRange),
+        ] {
+            let response = handle_request(
+                &mut package_handler,
+                build_request(
+                    RequestCommandClass::SetCommand,
+                    pids::DMX_START_ADDRESS,
+                    parameter_data,
+                ),
+            );
+            assert_eq!(response.response_type, ResponseType::ResponseTypeNackReason);
+            assert_eq!(
+                &response.parameter_data[..],
+                &u16::from(nack_reason).to_be_bytes()
+            );
+        }
+
+        assert_eq!(
+            package_handler.dmx_start_address,
+            DmxStartAddress::NoAddress
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_factory_defaults() {
+        struct FactoryResetHandler {
+            resets: usize,
+            identify_changes: Vec<bool>,
+        }
+
+        impl RdmResponderHandlerFunc for FactoryResetHandler {
+            type Error = ();
+
+            fn on_identify_changed(
+                &mut self,
+                identify: bool,
+                _: &mut DmxReceiverContext,
+            ) -> Result<(), Self::Error> {
+                self.identify_changes.push(identify);
+                Ok(())
+            }
+
+            fn on_factory_reset(&mut self, _: &mut DmxReceiverContext) -> Result<(), Self::Error> {
+                self.resets += 1;
+                Ok(())
+            }
+        }
+
+        let dmx_personalities = vec![
+            DmxPersonalityDescription {
+                personality: 1,
+                dmx_footprint: 3,
+                description: "RGB",
+            },
+            DmxPersonalityDescription {
+                personality: 2,
+                dmx_footprint: 4,
+                description: "RGBW",
+            },
+        ]
+        .leak();
+        let mut package_handler = RdmResponderPackageHandler::<4, 2>::new(RdmResponderConfig {
+            uid: RESPONDER_UID,
+            supported_pids: &[pids::FACTORY_DEFAULTS],
+            dmx_personalities,
+            curve_descriptions: &["linear", "square law"],
+            ..Default::default()
+        });
+        let mut handler = FactoryResetHandler {
+            resets: 0,
+            identify_changes: Vec::new(),
+        };
+        let get_factory_defaults = |package_handler: &mut RdmResponderPackageHandler<4, 2>| {
+            let response = match package_handler.handle_rdm_request(
+                build_request(RequestCommandClass::GetCommand, pids::FACTORY_DEFAULTS, &[]),
+                &mut EmptyHandler,
+            ) {
+                Ok(RdmAnswer::Response(response)) => response,
+                _ => panic!("expected a response"),
+            };
+            assert_eq!(response.response_type, ResponseType::ResponseTypeAck);
+            response.parameter_data[0] != 0
+        };
+
+        assert!(get_factory_defaults(&mut package_handler));
+
+        let response = match package_handler.handle_rdm_request(
+            build_request(
+                RequestCommandClass::SetCommand,
+                pids::DMX_START_ADDRESS,
+                &[0x00, 0x10],
+            ),
+            &mut handler,
+        ) {
+            Ok(RdmAnswer::Response(response)) => response,
+            _ => panic!("expected a response"),
+        };
+        assert_eq!(response.response_type, ResponseType::ResponseTypeAck);
+        assert!(!get_factory_defaults(&mut package_handler));
+
+        package_handler.set_device_label("Stage Left");
+        assert!(package_handler.set_dmx_personality(2));
+        package_handler.set_identify(true);
+        package_handler.set_identify_mode(IdentifyMode::Quiet);
+        assert!(package_handler.set_curve(2));
+        package_handler.set_preset_playback(PresetPlayback {
+            scene: 3,
+            level: 0xFF,
+        });
+        package_handler.get_sub_devices_mut()[1].dmx_start_address = DmxStartAddress::Address(100);
+
+        let response = match package_handler.handle_rdm_request(
+            build_request(RequestCommandClass::SetCommand, pids::FACTORY_DEFAULTS, &[]),
+            &mut handler,
+        ) {
+            Ok(RdmAnswer::Response(response)) => response,
+            _ => panic!("expected a response"),
+        };
+        assert_eq!(response.response_type, ResponseType::ResponseTypeAck);
+        assert_eq!(handler.resets, 1);
+        assert_eq!(handler.identify_changes, [false]);
+        assert!(get_factory_defaults(&mut package_handler));
+
+        assert_eq!(package_handler.get_device_label(), "");
+        assert_eq!(
+            package_handler.dmx_start_address,
+            DmxStartAddress::NoAddress
+        );
+        assert_eq!(package_handler.get_dmx_personality(), 1);
+        assert_eq!(package_handler.dmx_footprint, 3);
+        assert!(!package_handler.is_identifying());
+        assert_eq!(package_handler.get_identify_mode(), IdentifyMode::default());
+        assert_eq!(package_handler.get_curve(), 1);
+        assert_eq!(
+            package_handler.get_preset_playback(),
+            PresetPlayback::default()
+        );
+        assert!(package_handler
+            .get_sub_devices()
+            .iter()
+            .all(|sub_device| sub_device.dmx_start_address == DmxStartAddress::NoAddress));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_identify_device() {
+        struct IdentifyHandler {
+            identify_changes: Vec<bool>,
+            intercept: bool,
+        }
+
+        impl RdmResponderHandlerFunc for IdentifyHandler {
+            type Error = ();
+
+            fn handle_identify_device(
+                &mut self,
+                _: &RdmRequestData,
+                _: &mut DmxReceiverContext,
+            ) -> Result<Option<RdmResult>, Self::Error> {
+                Ok(self
+                    .intercept
+                    .then(|| RdmResult::NotAcknowledged(u16::from(NackReason::HardwareFault))))
+            }
+
+            fn on_identify_changed(
+                &mut self,
+                identify: bool,
+                _: &mut DmxReceiverContext,
+            ) -> Result<(), Self::Error> {
+                self.identify_changes.push(identify);
+                Ok(())
+            }
+        }
+
+        let mut package_handler = build_package_handler(&[]);
+        let mut handler = IdentifyHandler {
+            identify_changes: Vec::new(),
+            intercept: false,
+        };
+        let mut handle_identify_request =
+            |handler: &mut IdentifyHandler, command_class, parameter_data: &[u8]| {
+                match package_handler.handle_rdm_request(
+                    build_request(command_class, pids::IDENTIFY_DEVICE, parameter_data),
+                    handler,
+                ) {
+                    Ok(RdmAnswer::Response(response)) => response,
+                    _ => panic!("expected a response"),
+                }
+            };
+
+        let response = handle_identify_request(&mut handler, RequestCommandClass::GetCommand, &[]);
+        assert_eq!(&response.parameter_data[..], &[0]);
+
+        let response = handle_identify_request(&mut handler, RequestCommandClass::SetCommand, &[1]);
+        assert_eq!(response.response_type, ResponseType::ResponseTypeAck);
+        let response = handle_identify_request(&mut handler, RequestCommandClass::GetCommand, &[]);
+        assert_eq!(&response.parameter_data[..], &[1]);
+
+        // setting the same value again doesn't fire the callback
+        handle_identify_request(&mut handler, RequestCommandClass::SetCommand, &[1]);
+        assert_eq!(handler.identify_changes, [true]);
+
+        let response = handle_identify_request(&mut handler, RequestCommandClass::SetCommand, &[2]);
+        assert_eq!(response.response_type, ResponseType::ResponseTypeNackReason);
+
+        handler.intercept = true;
+        let response = handle_identify_request(&mut handler, RequestCommandClass::SetCommand, &[0]);
+        assert_eq!(response.response_type, ResponseType::ResponseTypeNackReason);
+        assert_eq!(handler.identify_changes, [true]);
+        assert!(package_handler.is_identifying());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_identify_device_broadcast() {
+        struct IdentifyHandler {
+            identify_changes: Vec<bool>,
+        }
+
+        impl RdmResponderHandlerFunc for IdentifyHandler {
+            type Error = ();
+
+            fn on_identify_changed(
+                &mut self,
+                identify: bool,
+                _: &mut DmxReceiverContext,
+            ) -> Result<(), Self::Error> {
+                self.identify_changes.push(identify);
+                Ok(())
+            }
+        }
+
+        let mut package_handler = build_package_handler(&[]);
+        let mut handler = IdentifyHandler {
+            identify_changes: Vec::new(),
+        };
+
+        for destination_uid in [
+            PackageAddress::Broadcast,
+            PackageAddress::ManufacturerBroadcast(RESPONDER_UID.manufacturer_uid()),
+        ] {
+            let identify = !package_handler.is_identifying();
+            let mut request = build_request(
+                RequestCommandClass::SetCommand,
+                pids::IDENTIFY_DEVICE,
+                &[identify as u8],
+            );
+            request.destination_uid = destination_uid;
+
+            assert!(matches!(
+                package_handler.handle_rdm_request(request, &mut handler),
+                Ok(RdmAnswer::NoResponse)
+            ));
+            assert_eq!(package_handler.is_identifying(), identify);
+        }
+
+        assert_eq!(handler.identify_changes, [true, false]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_manufacturer_broadcast() {
+        struct CustomResponseHandler {
+            requests: Vec<RequestCommandClass>,
+        }
+
+        impl RdmResponderHandlerFunc for CustomResponseHandler {
+            type Error = ();
+
+            fn handle_rdm(
+                &mut self,
+                request: &RdmRequestData,
+                _: &mut DmxReceiverContext,
+            ) -> Result<RdmResult, Self::Error> {
+                self.requests.push(request.command_class);
+                // a handler that answers without checking for broadcasts
+                Ok(RdmResult::Custom(RdmResponseData {
+                    destination_uid: PackageAddress::Device(request.source_uid),
+                    source_uid: RESPONDER_UID,
+                    transaction_number: request.transaction_number,
+                    response_type: ResponseType::ResponseTypeAck,
+                    message_count: 0,
+                    sub_device: request.sub_device,
+                    command_class: request.command_class.get_response_class(),
+                    parameter_id: request.parameter_id,
+                    parameter_data: DataPack::new(),
+                }))
+            }
+        }
+
+        let mut package_handler = build_package_handler(&[0x8000]);
+        let mut handler = CustomResponseHandler {
+            requests: Vec::new(),
+        };
+        let matching = PackageAddress::ManufacturerBroadcast(RESPONDER_UID.manufacturer_uid());
+        let other = PackageAddress::ManufacturerBroadcast(RESPONDER_UID.manufacturer_uid() + 1);
+
+        let mut handle_broadcast = |destination_uid: PackageAddress,
+                                    command_class: RequestCommandClass,
+                                    parameter_id: u16,
+                                    parameter_data: &[u8]| {
+            let mut request = build_request(command_class, parameter_id, parameter_data);
+            request.destination_uid = destination_uid;
+            package_handler.handle_rdm_request(request, &mut handler)
+        };
+
+        for destination_uid in [matching, other] {
+            for (parameter_id, parameter_data) in [
+                (pids::DEVICE_LABEL, &[][..]),
+                (pids::QUEUED_MESSAGE, &[StatusType::StatusError as u8][..]),
+                (0x8000, &[][..]),
+            ] {
+                assert!(matches!(
+                    handle_broadcast(
+                        destination_uid,
+                        RequestCommandClass::GetCommand,
+                        parameter_id,
+                        parameter_data
+                    ),
+                    Ok(RdmAnswer::NoResponse)
+                ));
+            }
+        }
+
+        for (destination_uid, label) in [(matching, "matching"), (other, "other")] {
+            assert!(matches!(
+                handle_broadcast(
+                    destination_uid,
+                    RequestCommandClass::SetCommand,
+                    pids::DEVICE_LABEL,
+                    label.as_bytes()
+                ),
+                Ok(RdmAnswer::NoResponse)
+            ));
+            assert!(matches!(
+                handle_broadcast(
+                    destination_uid,
+                    RequestCommandClass::SetCommand,
+                    0x8000,
+                    &[]
+                ),
+                Ok(RdmAnswer::NoResponse)
+            ));
+        }
+
+        // only the set request to the matching manufacturer is applied
+        assert_eq!(package_handler.get_device_label(), "matching");
+        assert_eq!(handler.requests, [RequestCommandClass::SetCommand]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_boot_software_version() {
+        let mut package_handler = RdmResponderPackageHandler::<4>::new(RdmResponderConfig {
+            uid: RESPONDER_UID,
+            rdm_receiver_metadata: RdmReceiverMetadata {
+                boot_software_version_id: 0x01020304,
+                boot_software_version_label: "Bootloader 1.2.3 built on 2024-01-01",
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+
+        let response = handle_request(
+            &mut package_handler,
+            build_request(
+                RequestCommandClass::GetCommand,
+                pids::BOOT_SOFTWARE_VERSION_ID,
+                &[],
+            ),
+        );
+        assert_eq!(&response.parameter_data[..], &[0x01, 0x02, 0x03, 0x04]);
+
+        let response = handle_request(
+            &mut package_handler,
+            build_request(
+                RequestCommandClass::GetCommand,
+                pids::BOOT_SOFTWARE_VERSION_LABEL,
+                &[],
+            ),
+        );
+        assert_eq!(response.parameter_data.len(), 32);
+        assert_eq!(
+            &response.parameter_data[..],
+            b"Bootloader 1.2.3 built on 2024-0"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_device_model_description_and_manufacturer_label() {
+        let get_labels = |rdm_receiver_metadata: RdmReceiverMetadata| {
+            let mut package_handler = RdmResponderPackageHandler::<4>::new(RdmResponderConfig {
+                uid: RESPONDER_UID,
+                rdm_receiver_metadata,
+                ..Default::default()
+            });
+
+            [pids::DEVICE_MODEL_DESCRIPTION, pids::MANUFACTURER_LABEL].map(|pid| {
+                let response = handle_request(
+                    &mut package_handler,
+                    build_request(RequestCommandClass::GetCommand, pid, &[]),
+                );
+                assert_eq!(response.response_type, ResponseType::ResponseTypeAck);
+                response.parameter_data
+            })
+        };
+
+        let [device_model_description, manufacturer_label] = get_labels(Default::default());
+        assert!(device_model_description.is_empty());
+        assert!(manufacturer_label.is_empty());
+
+        let [device_model_description, manufacturer_label] = get_labels(RdmReceiverMetadata {
+            device_model_description: "Moving head with exactly 32 byte",
+            manufacturer_label: "A manufacturer with a label that is too long",
+            ..Default::default()
+        });
+        assert_eq!(
+            &device_model_description[..],
+            b"Moving head with exactly 32 byte"
+        );
+        assert_eq!(&manufacturer_label[..], b"A manufacturer with a label that");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_message_count_saturates() {
+        let mut package_handler = RdmResponderPackageHandler::<255>::new(build_config(&[]));
+        for pid in 0x8000..0x8000 + 300 {
+            package_handler
+                .queue_message(QueuedMessage::new(pid, DataPack::new()))
+                .unwrap();
+        }
+        assert_eq!(package_handler.get_message_count(), 255);
+
+        let response = handle_request(
+            &mut package_handler,
+            build_request(
+                RequestCommandClass::GetCommand,
+                pids::DMX_START_ADDRESS,
+                &[],
+            ),
+        );
+        assert_eq!(response.message_count, 255);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_message_queue_full_policy() {
+        let queue_messages = |policy: MessageQueueFullPolicy| {
+            let mut package_handler = RdmResponderPackageHandler::<2>::new(RdmResponderConfig {
+                message_queue_full_policy: policy,
+                ..build_config(&[])
+            });
+            let results: Vec<_> = (0x8000..0x8003)
+                .map(|pid| package_handler.queue_message(QueuedMessage::new(pid, DataPack::new())))
+                .collect();
+            let queued_pids: Vec<_> = package_handler
+                .get_message_queue()
+                .iter()
+                .map(|message| message.parameter_id)
+                .collect();
+
+            (
+                results,
+                queued_pids,
+                package_handler.get_dropped_message_count(),
+            )
+        };
+
+        assert_eq!(
+            queue_messages(MessageQueueFullPolicy::DropOldest),
+            (vec![Ok(()), Ok(()), Ok(())], vec![0x8001, 0x8002], 1)
+        );
+        assert_eq!(
+            queue_messages(MessageQueueFullPolicy::DropNewest),
+            (vec![Ok(()), Ok(()), Ok(())], vec![0x8000, 0x8001], 1)
+        );
+        assert_eq!(
+            queue_messages(MessageQueueFullPolicy::Reject),
+            (
+                vec![Ok(()), Ok(()), Err(MessageQueueFullError)],
+                vec![0x8000, 0x8001],
+                0
+            )
+        );
+
+        // a responder without a message queue drops every message
+        let mut package_handler = RdmResponderPackageHandler::<0>::new(build_config(&[]));
+        package_handler
+            .queue_message(QueuedMessage::new(0x8000, DataPack::new()))
+            .unwrap();
+        assert!(package_handler.get_message_queue().is_empty());
+        assert_eq!(package_handler.get_dropped_message_count(), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_queued_messages() {
+        let mut package_handler = build_package_handler(&[]);
+        // the messages are sent from the back of the queue
+        let message_queue = package_handler.get_message_queue_mut();
+        message_queue
+            .push(QueuedMessage {
+                response_type: ResponseType::ResponseTypeNackReason,
+                sub_device: 0,
+                command_class: ResponseCommandClass::SetCommandResponse,
+                parameter_id: 0x8001,
+                parameter_data: DataPack::from_slice(
+                    &u16::from(NackReason::HardwareFault).to_be_bytes(),
+                )
+                .unwrap(),
+            })
+            .unwrap();
+        message_queue
+            .push(QueuedMessage::new(
+                0x8000,
+                DataPack::from_slice(&[0x01]).unwrap(),
+            ))
+            .unwrap();
+
+        let mut request_queued_message = |status_type: StatusType, transaction_number: u8| {
+            let mut request = build_request(
+                RequestCommandClass::GetCommand,
+                pids::QUEUED_MESSAGE,
+                &[status_type as u8],
+            );
+            request.transaction_number = transaction_number;
+            handle_request(&mut package_handler, request)
+        };
+
+        let response = request_queued_message(StatusType::StatusError, 1);
+        assert_eq!(response.parameter_id, 0x8000);
+        assert_eq!(&response.parameter_data[..], &[0x01]);
+        assert_eq!(response.response_type, ResponseType::ResponseTypeAck);
+        assert_eq!(
+            response.command_class,
+            ResponseCommandClass::GetCommandResponse
+        );
+        assert_eq!(response.transaction_number, 1);
+        assert_eq!(response.message_count, 1);
+        assert_eq!(
+            response.destination_uid,
+            PackageAddress::Device(UniqueIdentifier::new(0x7FF0, 0).unwrap())
+        );
+        assert_eq!(response.source_uid, RESPONDER_UID);
+
+        let response = request_queued_message(StatusType::StatusGetLastMessage, 2);
+        assert_eq!(response.parameter_id, 0x8000);
+        assert_eq!(response.transaction_number, 2);
+        assert_eq!(response.message_count, 1);
+
+        let response = request_queued_message(StatusType::StatusError, 3);
+        assert_eq!(response.parameter_id, 0x8001);
+        assert_eq!(response.response_type, ResponseType::ResponseTypeNackReason);
+        assert_eq!(
+            response.command_class,
+            ResponseCommandClass::SetCommandResponse
+        );
+        assert_eq!(response.message_count, 0);
+
+        // an empty queue answers with the status messages
+        let response = request_queued_message(StatusType::StatusError, 4);
+        assert_eq!(response.parameter_id, pids::STATUS_MESSAGES);
+        assert!(response.parameter_data.is_empty());
+
+        assert!(core::mem::size_of::<QueuedMessage>() < core::mem::size_of::<RdmResponseData>());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_queued_message_order() {
+        let mut package_handler = build_package_handler(&[]);
+        for parameter_id in [0x8000, 0x8001] {
+            package_handler
+                .queue_message(QueuedMessage::new(parameter_id, DataPack::new()))
+                .unwrap();
+        }
+
+        // the most recently queued message is sent first
+        for parameter_id in [0x8001, 0x8000] {
+            let response = handle_request(
+                &mut package_handler,
+                build_request(
+                    RequestCommandClass::GetCommand,
+                    pids::QUEUED_MESSAGE,
+                    &[StatusType::StatusError as u8],
+                ),
+            );
+            assert_eq!(response.parameter_id, parameter_id);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_status_messages() {
+        let mut package_handler = build_package_handler(&[]);
+        for (status_message_id, status_type) in [
+            (1, StatusType::StatusAdvisory),
+            (2, StatusType::StatusError),
+            (3, StatusType::StatusWarning),
+            (4, StatusType::StatusError),
+        ] {
+            package_handler
+                .queue_status_message(StatusMessage {
+                    sub_device_id: 0,
+                    status_type,
+                    status_message_id,
+                    data_value_1: 0,
+                    data_value_2: 0,
+                })
+                .unwrap();
+        }
+
+        let mut get_status_messages = |status_type: StatusType| -> Vec<u16> {
+            let response = handle_request(
+                &mut package_handler,
+                build_request(
+                    RequestCommandClass::GetCommand,
+                    pids::STATUS_MESSAGES,
+                    &[status_type as u8],
+                ),
+            );
+            assert_eq!(response.response_type, ResponseType::ResponseTypeAck);
+
+            response
+                .parameter_data
+                .chunks(9)
+                .map(|status_message| {
+                    StatusMessage::deserialize(status_message)
+                        .unwrap()
+                        .status_message_id
+                })
+                .collect()
+        };
+
+        assert_eq!(get_status_messages(StatusType::StatusError), [2, 4]);
+        assert_eq!(get_status_messages(StatusType::StatusWarning), [3]);
+        assert_eq!(get_status_messages(StatusType::StatusAdvisory), [1]);
+        assert!(get_status_messages(StatusType::StatusAdvisory).is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_queued_messages_are_not_cloned() {
+        /// A message that counts how often it has been cloned.
+        struct CloneCounter<'a> {
+            parameter_id: u16,
+            clones: &'a core::cell::Cell<usize>,
+        }
+
+        impl Clone for CloneCounter<'_> {
+            fn clone(&self) -> Self {
+                self.clones.set(self.clones.get() + 1);
+                Self {
+                    parameter_id: self.parameter_id,
+                    clones: self.clones,
+                }
+            }
+        }
+
+        let clones = core::cell::Cell::new(0);
+        let mut message_queue = heapless::Vec::<_, 4>::new();
+        for parameter_id in [0x8000, 0x8001] {
+            message_queue
+                .push(CloneCounter {
+                    parameter_id,
+                    clones: &clones,
+                })
+                .unwrap_or_else(|_| panic!("the queue is full"));
+        }
+        let mut last_message = None;
+
+        for parameter_id in [0x8001, 0x8000] {
+            assert!(take_next_message(&mut message_queue, &mut last_message));
+            assert_eq!(
+                last_message.as_ref().map(|message| message.parameter_id),
+                Some(parameter_id)
+            );
+        }
+        assert!(!take_next_message(&mut message_queue, &mut last_message));
+        assert_eq!(
+            last_message.as_ref().map(|message| message.parameter_id),
+            Some(0x8000)
+        );
+
+        assert_eq!(clones.get(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_reset_device() {
+        struct ResetHandler {
+            resets: Vec<ResetType>,
+        }
+
+        impl RdmResponderHandlerFunc for ResetHandler {
+            type Error = ();
+
+            fn on_reset(
+                &mut self,
+                reset_type: ResetType,
+                _: &mut DmxReceiverContext,
+            ) -> Result<(), Self::Error> {
+                self.resets.push(reset_type);
+                Ok(())
+            }
+        }
+
+        let mut package_handler = build_package_handler(&[pids::RESET_DEVICE]);
+        let mut handler = ResetHandler { resets: Vec::new() };
+        let mut handle_reset_request =
+            |command_class: RequestCommandClass, parameter_data: &[u8]| -> RdmResponseData {
+                match package_handler.handle_rdm_request(
+                    build_request(command_class, pids::RESET_DEVICE, parameter_data),
+                    &mut handler,
+                ) {
+                    Ok(RdmAnswer::Response(response)) => response,
+                    _ => panic!("expected a response"),
+                }
+            };
+
+        let response = handle_reset_request(RequestCommandClass::SetCommand, &[0x01]);
+        assert_eq!(response.response_type, ResponseType::ResponseTypeAck);
+        let response = handle_reset_request(RequestCommandClass::SetCommand, &[0xFF]);
+        assert_eq!(response.response_type, ResponseType::ResponseTypeAck);
+
+        let response = handle_reset_request(RequestCommandClass::SetCommand, &[0x02]);
+        assert_eq!(response.response_type, ResponseType::ResponseTypeNackReason);
+        assert_eq!(
+            &response.parameter_data[..],
+            &u16::from(NackReason::DataOutOfRange).to_be_bytes()
+        );
+
+        let response = handle_reset_request(RequestCommandClass::GetCommand, &[]);
+        assert_eq!(response.response_type, ResponseType::ResponseTypeNackReason);
+
+        assert_eq!(handler.resets, [ResetType::Warm, ResetType::Cold]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_power_state() {
+        struct PowerStateHandler {
+            power_states: Vec<PowerState>,
+        }
+
+        impl RdmResponderHandlerFunc for PowerStateHandler {
+            type Error = ();
+
+            fn on_power_state(
+                &mut self,
+                power_state: PowerState,
+                _: &mut DmxReceiverContext,
+            ) -> Result<(), Self::Error> {
+                self.power_states.push(power_state);
+                Ok(())
+            }
+        }
+
+        let mut package_handler = build_package_handler(&[pids::POWER_STATE]);
+        let mut handler = PowerStateHandler {
+            power_states: Vec::new(),
+        };
+        assert_eq!(package_handler.get_power_state(), PowerState::Normal);
+
+        let mut handle_power_state_request =
+            |command_class: RequestCommandClass, parameter_data: &[u8]| -> RdmResponseData {
+                match package_handler.handle_rdm_request(
+                    build_request(command_class, pids::POWER_STATE, parameter_data),
+                    &mut handler,
+                ) {
+                    Ok(RdmAnswer::Response(response)) => response,
+                    _ => panic!("expected a response"),
+                }
+            };
+
+        for power_state in [
+            PowerState::Standby,
+            PowerState::Shutdown,
+            PowerState::FullOff,
+            PowerState::Normal,
+        ] {
+            let response =
+                handle_power_state_request(RequestCommandClass::SetCommand, &[power_state as u8]);
+            assert_eq!(response.response_type, ResponseType::ResponseTypeAck);
+
+            let response = handle_power_state_request(RequestCommandClass::GetCommand, &[]);
+            assert_eq!(&response.parameter_data[..], &[power_state as u8]);
+        }
+
+        let response = handle_power_state_request(RequestCommandClass::SetCommand, &[0x03]);
+        assert_eq!(response.response_type, ResponseType::ResponseTypeNackReason);
+        assert_eq!(
+            &response.parameter_data[..],
+            &u16::from(NackReason::DataOutOfRange).to_be_bytes()
+        );
+
+        let response = handle_power_state_request(RequestCommandClass::SetCommand, &[]);
+        assert_eq!(
+            &response.parameter_data[..],
+            &u16::from(NackReason::FormatError).to_be_bytes()
+        );
+
+        assert_eq!(
+            handler.power_states,
+            [
+                PowerState::Standby,
+                PowerState::Shutdown,
+                PowerState::FullOff,
+                PowerState::Normal
+            ]
+        );
+        assert_eq!(package_handler.get_power_state(), PowerState::Normal);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_curve() {
+        let mut package_handler = RdmResponderPackageHandler::<4>::new(RdmResponderConfig {
+            curve_descriptions: &["linear", "square law", "s-curve"],
+            ..build_config(&[])
+        });
+        let supported_pids = get_supported_parameters(&mut package_handler);
+        assert!(supported_pids.contains(&pids::CURVE));
+        assert!(supported_pids.contains(&pids::CURVE_DESCRIPTION));
+        assert_eq!(package_handler.get_curve(), 1);
+
+        let response = handle_request(
+            &mut package_handler,
+            build_request(RequestCommandClass::SetCommand, pids::CURVE, &[3]),
+        );
+        assert_eq!(response.response_type, ResponseType::ResponseTypeAck);
+        assert_eq!(package_handler.get_curve(), 3);
+
+        let response = handle_request(
+            &mut package_handler,
+            build_request(RequestCommandClass::GetCommand, pids::CURVE, &[]),
+        );
+        assert_eq!(
+            Curve::deserialize(&response.parameter_data).unwrap(),
+            Curve {
+                current_curve: 3,
+                curve_count: 3,
+            }
+        );
+
+        for curve in [0, 4] {
+            let response = handle_request(
+                &mut package_handler,
+                build_request(RequestCommandClass::SetCommand, pids::CURVE, &[curve]),
+            );
+            assert_eq!(
+                &response.parameter_data[..],
+                &u16::from(NackReason::DataOutOfRange).to_be_bytes()
+            );
+        }
+        assert_eq!(package_handler.get_curve(), 3);
+
+        let response = handle_request(
+            &mut package_handler,
+            build_request(
+                RequestCommandClass::GetCommand,
+                pids::CURVE_DESCRIPTION,
+                &[2],
+            ),
+        );
+        assert_eq!(&response.parameter_data[..], b"\x02square law");
+
+        let response = handle_request(
+            &mut package_handler,
+            build_request(
+                RequestCommandClass::GetCommand,
+                pids::CURVE_DESCRIPTION,
+                &[4],
+            ),
+        );
+        assert_eq!(
+            &response.parameter_data[..],
+            &u16::from(NackReason::DataOutOfRange).to_be_bytes()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_self_test() {
+        struct SelfTestHandler {
+            self_tests: Vec<u8>,
+        }
+
+        impl RdmResponderHandlerFunc for SelfTestHandler {
+            type Error = ();
+
+            fn on_self_test(
+                &mut self,
+                self_test: u8,
+                _: &mut DmxReceiverContext,
+            ) -> Result<(), Self::Error> {
+                self.self_tests.push(self_test);
+                Ok(())
+            }
+        }
+
+        let mut package_handler = RdmResponderPackageHandler::<4>::new(RdmResponderConfig {
+            self_test_descriptions: &["lamp test", "motor test"],
+            ..build_config(&[])
+        });
+        let mut handler = SelfTestHandler {
+            self_tests: Vec::new(),
+        };
+        assert_eq!(package_handler.get_self_test(), RDM_SELF_TEST_OFF);
+
+        let mut handle_self_test_request =
+            |command_class: RequestCommandClass, pid: u16, parameter_data: &[u8]| {
+                match package_handler.handle_rdm_request(
+                    build_request(command_class, pid, parameter_data),
+                    &mut handler,
+                ) {
+                    Ok(RdmAnswer::Response(response)) => response,
+                    _ => panic!("expected a response"),
+                }
+            };
+
+        for self_test in [2, RDM_SELF_TEST_ALL, RDM_SELF_TEST_OFF] {
+            let response = handle_self_test_request(
+                RequestCommandClass::SetCommand,
+                pids::PERFORM_SELFTEST,
+                &[self_test],
+            );
+            assert_eq!(response.response_type, ResponseType::ResponseTypeAck);
+
+            let response = handle_self_test_request(
+                RequestCommandClass::GetCommand,
+                pids::PERFORM_SELFTEST,
+                &[],
+            );
+            assert_eq!(&response.parameter_data[..], &[self_test]);
+        }
+
+        // only two self tests are configured
+        let response = handle_self_test_request(
+            RequestCommandClass::SetCommand,
+            pids::PERFORM_SELFTEST,
+            &[3],
+        );
+        assert_eq!(
+            &response.parameter_data[..],
+            &u16::from(NackReason::DataOutOfRange).to_be_bytes()
+        );
+
+        let response =
+            handle_self_test_request(RequestCommandClass::SetCommand, pids::PERFORM_SELFTEST, &[]);
+        assert_eq!(
+            &response.parameter_data[..],
+            &u16::from(NackReason::FormatError).to_be_bytes()
+        );
+
+        let response = handle_self_test_request(
+            RequestCommandClass::GetCommand,
+            pids::SELF_TEST_DESCRIPTION,
+            &[1],
+        );
+        assert_eq!(&response.parameter_data[..], b"\x01lamp test");
+
+        for self_test in [RDM_SELF_TEST_OFF, 3, RDM_SELF_TEST_ALL] {
+            let response = handle_self_test_request(
+                RequestCommandClass::GetCommand,
+                pids::SELF_TEST_DESCRIPTION,
+                &[self_test],
+            );
+            assert_eq!(
+                &response.parameter_data[..],
+                &u16::from(NackReason::DataOutOfRange).to_be_bytes()
+            );
+        }
+
+        assert_eq!(
+            handler.self_tests,
+            [2, RDM_SELF_TEST_ALL, RDM_SELF_TEST_OFF]
+        );
+        assert_eq!(package_handler.get_self_test(), RDM_SELF_TEST_OFF);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_verify_advertised_pids() {
+        struct ManufacturerPidHandler;
+
+        impl RdmResponderHandlerFunc for ManufacturerPidHandler {
+            type Error = ();
+
+            fn handle_rdm(
+                &mut self,
+                request: &RdmRequestData,
+                _: &mut DmxReceiverContext,
+            ) -> Result<RdmResult, Self::Error> {
+                Ok(match request.parameter_id {
+                    0x8000 => RdmResult::Acknowledged(DataPack::from_slice(&[0x01]).unwrap()),
+                    _ => RdmResult::NotAcknowledged(u16::from(NackReason::UnknownPid)),
+                })
+            }
+        }
+
+        // 0x8001 is advertised but not handled, the default handler doesn't handle 0x8000 either
+        let mut package_handler = build_package_handler(&[pids::POWER_STATE, 0x8000, 0x8001]);
+        assert_eq!(
+            package_handler
+                .verify_advertised_pids(&mut ManufacturerPidHandler)
+                .unwrap(),
+            [UnhandledPid {
+                parameter_id: 0x8001,
+                nack_reason: NackReason::UnknownPid,
+            }]
+        );
+        assert_eq!(
+            package_handler
+                .verify_advertised_pids(&mut EmptyHandler)
+                .unwrap(),
+            [
+                UnhandledPid {
+                    parameter_id: 0x8000,
+                    nack_reason: NackReason::UnsupportedCommandClass,
+                },
+                UnhandledPid {
+                    parameter_id: 0x8001,
+                    nack_reason: NackReason::UnsupportedCommandClass,
+                }
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_comms_status() {
+        let mut package_handler = build_package_handler(&[pids::COMMS_STATUS]);
+
+        package_handler.record_deserialization_error(&RdmDeserializationError::BufferTooSmall);
+        package_handler
+            .record_deserialization_error(&RdmDeserializationError::WrongMessageLength(10));
+        package_handler
+            .record_deserialization_error(&RdmDeserializationError::WrongMessageLength(20));
+        package_handler.record_deserialization_error(&RdmDeserializationError::WrongChecksum {
+            expected: 0,
+            actual: 1,
+        });
+        package_handler.record_deserialization_error(&RdmDeserializationError::WrongStartCode);
+
+        let expected_status = CommsStatus {
+            short_message: 1,
+            length_mismatch: 2,
+            checksum_fail: 1,
+        };
+        assert_eq!(package_handler.get_comms_status(), expected_status);
+
+        let mut handle_comms_status_request =
+            |command_class: RequestCommandClass, parameter_data: &[u8]| -> RdmResponseData {
+                match package_handler.handle_rdm_request(
+                    build_request(command_class, pids::COMMS_STATUS, parameter_data),
+                    &mut EmptyHandler,
+                ) {
+                    Ok(RdmAnswer::Response(response)) => response,
+                    _ => panic!("expected a response"),
+                }
+            };
+
+        let response = handle_comms_status_request(RequestCommandClass::GetCommand, &[]);
+        assert_eq!(response.response_type, ResponseType::ResponseTypeAck);
+        assert_eq!(
+            CommsStatus::deserialize(&response.parameter_data).unwrap(),
+            expected_status
+        );
+
+        let response = handle_comms_status_request(RequestCommandClass::SetCommand, &[0x00]);
+        assert_eq!(
+            &response.parameter_data[..],
+            &u16::from(NackReason::FormatError).to_be_bytes()
+        );
+
+        let response = handle_comms_status_request(RequestCommandClass::SetCommand, &[]);
+        assert_eq!(response.response_type, ResponseType::ResponseTypeAck);
+
+        let response = handle_comms_status_request(RequestCommandClass::GetCommand, &[]);
+        assert_eq!(&response.parameter_data[..], &[0; 6]);
+        assert_eq!(package_handler.get_comms_status(), CommsStatus::default());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_statistics_counters() {
+        struct CounterHandler {
+            device_hours: u32,
+            lamp_hours: u32,
+            device_power_cycles: u32,
+        }
+
+        impl RdmResponderHandlerFunc for CounterHandler {
+            type Error = ();
+
+            fn get_statistics_counter(
+                &mut self,
+                counter: StatisticsCounter,
+                _: &mut DmxReceiverContext,
+            ) -> Result<u32, Self::Error> {
+                Ok(match counter {
+                    StatisticsCounter::DeviceHours => self.device_hours,
+                    StatisticsCounter::LampHours => self.lamp_hours,
+                    StatisticsCounter::DevicePowerCycles => self.device_power_cycles,
+                })
+            }
+
+            fn on_set_statistics_counter(
+                &mut self,
+                counter: StatisticsCounter,
+                value: u32,
+                _: &mut DmxReceiverContext,
+            ) -> Result<bool, Self::Error> {
+                match counter {
+                    StatisticsCounter::DeviceHours => self.device_hours = value,
+                    StatisticsCounter::LampHours => self.lamp_hours = value,
+                    // the power cycles are read-only
+                    StatisticsCounter::DevicePowerCycles => return Ok(false),
+                }
+
+                Ok(true)
+            }
+        }
+
+        let mut package_handler = build_package_handler(&[
+            pids::DEVICE_HOURS,
+            pids::LAMP_HOURS,
+            pids::DEVICE_POWER_CYCLES,
+        ]);
+        let mut handler = CounterHandler {
+            device_hours: 1200,
+            lamp_hours: 300,
+            device_power_cycles: 42,
+        };
+        let mut handle_counter_request = |command_class: RequestCommandClass,
+                                          parameter_id: u16,
+                                          parameter_data: &[u8]|
+         -> RdmResponseData {
+            match package_handler.handle_rdm_request(
+                build_request(command_class, parameter_id, parameter_data),
+                &mut handler,
+            ) {
+                Ok(RdmAnswer::Response(response)) => response,
+                _ => panic!("expected a response"),
+            }
+        };
+
+        for (parameter_id, value) in [
+            (pids::DEVICE_HOURS, 1200u32),
+            (pids::LAMP_HOURS, 300),
+            (pids::DEVICE_POWER_CYCLES, 42),
+        ] {
+            let response =
+                handle_counter_request(RequestCommandClass::GetCommand, parameter_id, &[]);
+            assert_eq!(response.response_type, ResponseType::ResponseTypeAck);
+            assert_eq!(&response.parameter_data[..], &value.to_be_bytes());
+        }
+
+        // reset the lamp hours after replacing the lamp
+        let response = handle_counter_request(
+            RequestCommandClass::SetCommand,
+            pids::LAMP_HOURS,
+            &0u32.to_be_bytes(),
+        );
+        assert_eq!(response.response_type, ResponseType::ResponseTypeAck);
+        let response =
+            handle_counter_request(RequestCommandClass::GetCommand, pids::LAMP_HOURS, &[]);
+        assert_eq!(&response.parameter_data[..], &[0x00, 0x00, 0x00, 0x00]);
+
+        let response = handle_counter_request(
+            RequestCommandClass::SetCommand,
+            pids::DEVICE_POWER_CYCLES,
+            &0u32.to_be_bytes(),
+        );
+        assert_eq!(response.response_type, ResponseType::ResponseTypeNackReason);
+        assert_eq!(
+            &response.parameter_data[..],
+            &u16::from(NackReason::WriteProtect).to_be_bytes()
+        );
+
+        let response =
+            handle_counter_request(RequestCommandClass::SetCommand, pids::DEVICE_HOURS, &[0x01]);
+        assert_eq!(
+            &response.parameter_data[..],
+            &u16::from(NackReason::FormatError).to_be_bytes()
+        );
+
+        assert_eq!(handler.device_hours, 1200);
+        assert_eq!(handler.lamp_hours, 0);
+        assert_eq!(handler.device_power_cycles, 42);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_real_time_clock() {
+        struct ClockHandler {
+            clock: RealTimeClock,
+        }
+
+        impl RdmResponderHandlerFunc for ClockHandler {
+            type Error = ();
+
+            fn get_clock(&mut self, _: &mut DmxReceiverContext) -> Result<RealTimeClock, ()> {
+                Ok(self.clock)
+            }
+
+            fn on_set_clock(
+                &mut self,
+                clock: RealTimeClock,
+                _: &mut DmxReceiverContext,
+            ) -> Result<(), ()> {
+                self.clock = clock;
+                Ok(())
+            }
+        }
+
+        let mut package_handler = build_package_handler(&[pids::REAL_TIME_CLOCK]);
+        let mut handler = ClockHandler {
+            clock: RealTimeClock::default(),
+        };
+        let mut handle_clock_request =
+            |command_class: RequestCommandClass, parameter_data: &[u8]| -> RdmResponseData {
+                match package_handler.handle_rdm_request(
+                    build_request(command_class, pids::REAL_TIME_CLOCK, parameter_data),
+                    &mut handler,
+                ) {
+                    Ok(RdmAnswer::Response(response)) => response,
+                    _ => panic!("expected a response"),
+                }
+            };
+
+        let response = handle_clock_request(RequestCommandClass::GetCommand, &[]);
+        assert_eq!(response.response_type, ResponseType::ResponseTypeAck);
+        assert_eq!(
+            RealTimeClock::deserialize(&response.parameter_data).unwrap(),
+            RealTimeClock::default()
+        );
+
+        for (year, month, day, hour, minute, second) in [
+            (2024, 1, 1, 0, 0, 0),
+            (2024, 12, 31, 23, 59, 59),
+            (2024, 2, 29, 12, 0, 0),
+            (2023, 4, 30, 0, 0, 0),
+            // leap second
+            (2016, 12, 31, 23, 59, 60),
+        ] {
+            let clock = RealTimeClock {
+                year,
+                month,
+                day,
+                hour,
+                minute,
+                second,
+            };
+            let response =
+                handle_clock_request(RequestCommandClass::SetCommand, &clock.serialize());
+            assert_eq!(response.response_type, ResponseType::ResponseTypeAck);
+
+            let response = handle_clock_request(RequestCommandClass::GetCommand, &[]);
+            assert_eq!(
+                RealTimeClock::deserialize(&response.parameter_data).unwrap(),
+                clock
+            );
+        }
+
+        for (year, month, day, hour, minute, second) in [
+            (2024, 0, 1, 0, 0, 0),
+            (2024, 13, 1, 0, 0, 0),
+            (2024, 1, 0, 0, 0, 0),
+            (2024, 1, 32, 0, 0, 0),
+            (2024, 4, 31, 0, 0, 0),
+            (2023, 2, 29, 0, 0, 0),
+            (2024, 1, 1, 24, 0, 0),
+            (2024, 1, 1, 0, 60, 0),
+            (2024, 1, 1, 0, 0, 61),
+            (2002, 1, 1, 0, 0, 0),
+        ] {
+            let clock = RealTimeClock {
+                year,
+                month,
+                day,
+                hour,
+                minute,
+                second,
+            };
+            let response =
+                handle_clock_request(RequestCommandClass::SetCommand, &clock.serialize());
+            assert_eq!(response.response_type, ResponseType::ResponseTypeNackReason);
+            assert_eq!(
+                &response.parameter_data[..],
+                &u16::from(NackReason::DataOutOfRange).to_be_bytes()
+            );
+        }
+
+        let response = handle_clock_request(RequestCommandClass::SetCommand, &[0x07, 0xE8, 0x01]);
+        assert_eq!(
+            &response.parameter_data[..],
+            &u16::from(NackReason::FormatError).to_be_bytes()
+        );
+
+        // the invalid requests didn't change the clock
+        assert_eq!(
+            handler.clock,
+            RealTimeClock {
+                year: 2016,
+                month: 12,
+                day: 31,
+                hour: 23,
+                minute: 59,
+                second: 60,
+            }
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_presets() {
+        struct PresetHandler {
+            captured_scenes: Vec<u16>,
+        }
+
+        impl RdmResponderHandlerFunc for PresetHandler {
+            type Error = ();
+
+            fn on_capture_preset(
+                &mut self,
+                scene: u16,
+                _: &mut DmxReceiverContext,
+            ) -> Result<bool, Self::Error> {
+                self.captured_scenes.push(scene);
+                Ok(true)
+            }
+
+            fn on_preset_playback(
+                &mut self,
+                scene: u16,
+                _: u8,
+                _: &mut DmxReceiverContext,
+            ) -> Result<bool, Self::Error> {
+                Ok(scene == PresetPlayback::OFF || self.captured_scenes.contains(&scene))
+            }
+        }
+
+        let mut package_handler =
+            build_package_handler(&[pids::CAPTURE_PRESET, pids::PRESET_PLAYBACK]);
+        let mut handler = PresetHandler {
+            captured_scenes: Vec::new(),
+        };
+
+        let mut send = |command_class: RequestCommandClass, pid: u16, parameter_data: &[u8]| {
+            match package_handler.handle_rdm_request(
+                build_request(command_class, pid, parameter_data),
+                &mut handler,
+            ) {
+                Ok(RdmAnswer::Response(response)) => response,
+                _ => panic!("expected a response"),
+            }
+        };
+        let assert_nack = |response: RdmResponseData, nack_reason: NackReason| {
+            assert_eq!(response.response_type, ResponseType::ResponseTypeNackReason);
+            assert_eq!(
+                &response.parameter_data[..],
+                &u16::from(nack_reason).to_be_bytes()
+            );
+        };
+
+        // the special values can't be captured
+        for scene in [PresetPlayback::OFF, PresetPlayback::ALL] {
+            let mut parameter_data = [0u8; 8];
+            parameter_data[..2].copy_from_slice(&scene.to_be_bytes());
+            assert_nack(
+                send(
+                    RequestCommandClass::SetCommand,
+                    pids::CAPTURE_PRESET,
+                    &parameter_data,
+                ),
+                NackReason::DataOutOfRange,
+            );
+        }
+        assert_nack(
+            send(
+                RequestCommandClass::SetCommand,
+                pids::CAPTURE_PRESET,
+                &[0x00, 0x03],
             ),
-            StatusType::StatusGetLastMessage => request.build_response(
-                ResponseType::ResponseTypeAck,
-                self.last_status_vec_message.clone(),
-                message_count,
+            NackReason::FormatError,
+        );
+        assert_nack(
+            send(RequestCommandClass::GetCommand, pids::CAPTURE_PRESET, &[]),
+            NackReason::UnsupportedCommandClass,
+        );
+
+        let response = send(
+            RequestCommandClass::SetCommand,
+            pids::CAPTURE_PRESET,
+            &[0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+        );
+        assert_eq!(response.response_type, ResponseType::ResponseTypeAck);
+
+        // scene 4 was never captured
+        assert_nack(
+            send(
+                RequestCommandClass::SetCommand,
+                pids::PRESET_PLAYBACK,
+                &[0x00, 0x04, 0xFF],
             ),
-            StatusType::StatusWarning | StatusType::StatusError | StatusType::StatusAdvisory => {
-                let response_vec = self.pop_filtered_statuses(status_type_requested);
+            NackReason::DataOutOfRange,
+        );
 
-                self.last_status_vec_message = response_vec.clone();
-                request.build_response(ResponseType::ResponseTypeAck, response_vec, message_count)
+        let response = send(
+            RequestCommandClass::SetCommand,
+            pids::PRESET_PLAYBACK,
+            &[0x00, 0x03, 0x80],
+        );
+        assert_eq!(response.response_type, ResponseType::ResponseTypeAck);
+
+        let response = send(RequestCommandClass::GetCommand, pids::PRESET_PLAYBACK, &[]);
+        assert_eq!(response.response_type, ResponseType::ResponseTypeAck);
+        assert_eq!(&response.parameter_data[..], &[0x00, 0x03, 0x80]);
+
+        assert_eq!(handler.captured_scenes, [3]);
+        assert_eq!(
+            package_handler.get_preset_playback(),
+            PresetPlayback {
+                scene: 3,
+                level: 0x80,
+            }
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_presets_not_supported() {
+        let mut package_handler = build_package_handler(&[]);
+
+        let response = handle_request(
+            &mut package_handler,
+            build_request(RequestCommandClass::GetCommand, pids::PRESET_PLAYBACK, &[]),
+        );
+        assert_eq!(response.response_type, ResponseType::ResponseTypeNackReason);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_parameter_description() {
+        static PARAMETER_DESCRIPTIONS: [ParameterDescription<&str>; 1] = [ParameterDescription {
+            pid: 0x8001,
+            pdl_size: 1,
+            data_type: RdmDataType::UnsignedByte,
+            command_class: PidCommandClassSupport::GetSet,
+            unit: 0x00,
+            prefix: 0x00,
+            min_valid_value: 0,
+            max_valid_value: 100,
+            default_value: 50,
+            description: "Fan speed",
+        }];
+        let mut package_handler = RdmResponderPackageHandler::<4>::new(RdmResponderConfig {
+            uid: RESPONDER_UID,
+            supported_pids: &[0x8001],
+            parameter_descriptions: &PARAMETER_DESCRIPTIONS,
+            ..Default::default()
+        });
+
+        let response = handle_request(
+            &mut package_handler,
+            build_request(
+                RequestCommandClass::GetCommand,
+                pids::PARAMETER_DESCRIPTION,
+                &[0x80, 0x01],
+            ),
+        );
+        assert_eq!(response.response_type, ResponseType::ResponseTypeAck);
+        let parameter_description =
+            ParameterDescription::deserialize(&response.parameter_data).unwrap();
+        assert_eq!(parameter_description.pid, 0x8001);
+        assert_eq!(parameter_description.description, "Fan speed");
+        assert_eq!(
+            response.parameter_data,
+            PARAMETER_DESCRIPTIONS[0].serialize()
+        );
+
+        let response = handle_request(
+            &mut package_handler,
+            build_request(
+                RequestCommandClass::GetCommand,
+                pids::PARAMETER_DESCRIPTION,
+                &[0x80, 0x02],
+            ),
+        );
+        assert_eq!(response.response_type, ResponseType::ResponseTypeNackReason);
+
+        let response = handle_request(
+            &mut package_handler,
+            build_request(
+                RequestCommandClass::GetCommand,
+                pids::SUPPORTED_PARAMETERS,
+                &[],
+            ),
+        );
+        assert!(response
+            .parameter_data
+            .chunks(2)
+            .any(|pid| pid == pids::PARAMETER_DESCRIPTION.to_be_bytes()));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_identify_mode() {
+        let mut package_handler = build_package_handler(&[pids::IDENTIFY_MODE]);
+        assert_eq!(package_handler.get_identify_mode(), IdentifyMode::Loud);
+
+        for identify_mode in [IdentifyMode::Quiet, IdentifyMode::Loud] {
+            let response = handle_request(
+                &mut package_handler,
+                build_request(
+                    RequestCommandClass::SetCommand,
+                    pids::IDENTIFY_MODE,
+                    &[identify_mode as u8],
+                ),
+            );
+            assert_eq!(response.response_type, ResponseType::ResponseTypeAck);
+
+            let response = handle_request(
+                &mut package_handler,
+                build_request(RequestCommandClass::GetCommand, pids::IDENTIFY_MODE, &[]),
+            );
+            assert_eq!(&response.parameter_data[..], &[identify_mode as u8]);
+            assert_eq!(package_handler.get_identify_mode(), identify_mode);
+        }
+
+        let response = handle_request(
+            &mut package_handler,
+            build_request(
+                RequestCommandClass::SetCommand,
+                pids::IDENTIFY_MODE,
+                &[0x01],
+            ),
+        );
+        assert_eq!(response.response_type, ResponseType::ResponseTypeNackReason);
+        assert_eq!(
+            &response.parameter_data[..],
+            &u16::from(NackReason::DataOutOfRange).to_be_bytes()
+        );
+        assert_eq!(package_handler.get_identify_mode(), IdentifyMode::Loud);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_device_info_sensor_count() {
+        let sensor_definitions = Vec::leak(
+            ["Temperature", "Voltage", "Current"]
+                .into_iter()
+                .enumerate()
+                .map(|(sensor_number, description)| SensorDefinition {
+                    sensor_number: sensor_number as u8,
+                    sensor_type: 0x00,
+                    unit: 0x01,
+                    prefix: 0x00,
+                    range_min: 0,
+                    range_max: 100,
+                    normal_min: 0,
+                    normal_max: 100,
+                    recorded_value_support: 0x00,
+                    description,
+                })
+                .collect(),
+        );
+        let mut package_handler = RdmResponderPackageHandler::<4, 0, 4>::new(RdmResponderConfig {
+            sensor_definitions,
+            ..build_config(&[])
+        });
+
+        let response = handle_request(
+            &mut package_handler,
+            build_request(RequestCommandClass::GetCommand, pids::DEVICE_INFO, &[]),
+        );
+        let sensor_count = DeviceInfo::deserialize(&response.parameter_data)
+            .unwrap()
+            .sensor_count;
+        assert_eq!(sensor_count, 3);
+
+        // every sensor number below the sensor count is valid
+        for sensor_number in 0..=sensor_count {
+            let response = handle_request(
+                &mut package_handler,
+                build_request(
+                    RequestCommandClass::GetCommand,
+                    pids::SENSOR_DEFINITION,
+                    &[sensor_number],
+                ),
+            );
+            let expected_response_type = match sensor_number < sensor_count {
+                true => ResponseType::ResponseTypeAck,
+                false => ResponseType::ResponseTypeNackReason,
+            };
+            assert_eq!(response.response_type, expected_response_type);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_sensors() {
+        const SENSOR_DEFINITIONS: &[SensorDefinition<&str>] = &[SensorDefinition {
+            sensor_number: 0,
+            sensor_type: 0x00,
+            unit: 0x01,
+            prefix: 0x00,
+            range_min: -40,
+            range_max: 120,
+            normal_min: 0,
+            normal_max: 80,
+            recorded_value_support: 0x03,
+            description: "Temperature",
+        }];
+        let mut package_handler = RdmResponderPackageHandler::<4, 0, 2>::new(RdmResponderConfig {
+            uid: RESPONDER_UID,
+            sensor_definitions: SENSOR_DEFINITIONS,
+            ..Default::default()
+        });
+
+        let response = handle_request(
+            &mut package_handler,
+            build_request(RequestCommandClass::GetCommand, pids::DEVICE_INFO, &[]),
+        );
+        assert_eq!(
+            DeviceInfo::deserialize(&response.parameter_data)
+                .unwrap()
+                .sensor_count,
+            1
+        );
+
+        let response = handle_request(
+            &mut package_handler,
+            build_request(
+                RequestCommandClass::GetCommand,
+                pids::SENSOR_DEFINITION,
+                &[0],
+            ),
+        );
+        assert_eq!(
+            SensorDefinition::deserialize(&response.parameter_data)
+                .unwrap()
+                .description,
+            "Temperature"
+        );
+        assert_eq!(response.parameter_data, SENSOR_DEFINITIONS[0].serialize());
+
+        // out of range sensors and the all sensors index can't be requested
+        for sensor_number in [1, 0xFF] {
+            for pid in [pids::SENSOR_DEFINITION, pids::SENSOR_VALUE] {
+                let response = handle_request(
+                    &mut package_handler,
+                    build_request(RequestCommandClass::GetCommand, pid, &[sensor_number]),
+                );
+                assert_eq!(response.response_type, ResponseType::ResponseTypeNackReason);
+                assert_eq!(
+                    &response.parameter_data[..],
+                    &u16::from(NackReason::DataOutOfRange).to_be_bytes()
+                );
+            }
+        }
+
+        package_handler.update_sensor_value(0, 30);
+        package_handler.update_sensor_value(0, 20);
+        handle_request(
+            &mut package_handler,
+            build_request(
+                RequestCommandClass::SetCommand,
+                pids::RECORD_SENSORS,
+                &[0xFF],
+            ),
+        );
+        package_handler.update_sensor_value(0, 25);
+
+        let response = handle_request(
+            &mut package_handler,
+            build_request(RequestCommandClass::GetCommand, pids::SENSOR_VALUE, &[0]),
+        );
+        assert_eq!(
+            SensorValue::deserialize(&response.parameter_data).unwrap(),
+            SensorValue {
+                sensor_number: 0,
+                present_value: 25,
+                lowest_detected_value: 20,
+                highest_detected_value: 30,
+                recorded_value: 20,
+            }
+        );
+
+        let response = handle_request(
+            &mut package_handler,
+            build_request(RequestCommandClass::SetCommand, pids::SENSOR_VALUE, &[0xFF]),
+        );
+        assert_eq!(
+            SensorValue::deserialize(&response.parameter_data).unwrap(),
+            SensorValue::new(0xFF)
+        );
+        assert_eq!(
+            package_handler.get_sensor_values()[0],
+            SensorValue {
+                sensor_number: 0,
+                present_value: 25,
+                lowest_detected_value: 25,
+                highest_detected_value: 25,
+                recorded_value: 25,
+            }
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_dmx_personality() {
+        let dmx_personalities = vec![
+            DmxPersonalityDescription {
+                personality: 1,
+                dmx_footprint: 3,
+                description: "RGB",
             },
-            _ => build_nack!(request, NackReason::DataOutOfRange, message_count),
+            DmxPersonalityDescription {
+                personality: 2,
+                dmx_footprint: 4,
+                description: "RGBW",
+            },
+        ];
+        let mut package_handler = RdmResponderPackageHandler::<4>::new(RdmResponderConfig {
+            uid: RESPONDER_UID,
+            dmx_personalities: Vec::leak(dmx_personalities.clone()),
+            ..Default::default()
+        });
+        let get_device_info = |package_handler: &mut RdmResponderPackageHandler<4>| {
+            let response = handle_request(
+                package_handler,
+                build_request(RequestCommandClass::GetCommand, pids::DEVICE_INFO, &[]),
+            );
+            DeviceInfo::deserialize(&response.parameter_data).unwrap()
+        };
+
+        let device_info = get_device_info(&mut package_handler);
+        assert_eq!(device_info.dmx_footprint, 3);
+        assert_eq!(device_info.dmx_personality, 0x0102);
+
+        let response = handle_request(
+            &mut package_handler,
+            build_request(RequestCommandClass::SetCommand, pids::DMX_PERSONALITY, &[2]),
+        );
+        assert_eq!(response.response_type, ResponseType::ResponseTypeAck);
+
+        let device_info = get_device_info(&mut package_handler);
+        assert_eq!(device_info.dmx_footprint, 4);
+        assert_eq!(device_info.dmx_personality, 0x0202);
+
+        let response = handle_request(
+            &mut package_handler,
+            build_request(RequestCommandClass::GetCommand, pids::DMX_PERSONALITY, &[]),
+        );
+        assert_eq!(
+            DmxPersonality::deserialize(&response.parameter_data).unwrap(),
+            DmxPersonality {
+                current_personality: 2,
+                personality_count: 2,
+            }
+        );
+
+        for personality in [0, 3] {
+            let response = handle_request(
+                &mut package_handler,
+                build_request(
+                    RequestCommandClass::SetCommand,
+                    pids::DMX_PERSONALITY,
+                    &[personality],
+                ),
+            );
+            assert_eq!(response.response_type, ResponseType::ResponseTypeNackReason);
+            assert_eq!(
+                &response.parameter_data[..],
+                &u16::from(NackReason::DataOutOfRange).to_be_bytes()
+            );
         }
-        .ok()
+        assert_eq!(package_handler.get_dmx_personality(), 2);
+
+        let response = handle_request(
+            &mut package_handler,
+            build_request(
+                RequestCommandClass::GetCommand,
+                pids::DMX_PERSONALITY_DESCRIPTION,
+                &[1],
+            ),
+        );
+        assert_eq!(response.parameter_data, dmx_personalities[0].serialize());
     }
 
-    fn pop_filtered_statuses(&mut self, status_filter: StatusType) -> DataPack {
-        let mut indexes_to_remove =
-            heapless::Vec::<usize, RDM_MAX_STATUS_PACKAGES_PER_REQUEST>::new();
-        let mut parameter_data = DataPack::new();
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_sub_device_dmx_start_address() {
+        let mut package_handler = RdmResponderPackageHandler::<4, 2>::new(RdmResponderConfig {
+            uid: RESPONDER_UID,
+            ..Default::default()
+        });
 
-        self.status_vec
+        let mut request = build_request(
+            RequestCommandClass::SetCommand,
+            pids::DMX_START_ADDRESS,
+            &[0x00, 0x20],
+        );
+        request.sub_device = 2;
+        let response = handle_request(&mut package_handler, request);
+        assert_eq!(response.response_type, ResponseType::ResponseTypeAck);
+        assert_eq!(
+            package_handler.get_sub_devices()[1].dmx_start_address,
+            DmxStartAddress::Address(0x20)
+        );
+        assert_eq!(
+            package_handler.dmx_start_address,
+            DmxStartAddress::NoAddress
+        );
+
+        let mut request = build_request(RequestCommandClass::GetCommand, pids::DEVICE_INFO, &[]);
+        request.sub_device = 3;
+        let response = handle_request(&mut package_handler, request);
+        assert_eq!(response.response_type, ResponseType::ResponseTypeNackReason);
+        assert_eq!(
+            &response.parameter_data[..],
+            &u16::from(NackReason::SubDeviceOutOfRange).to_be_bytes()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_dmx_block_address() {
+        let mut package_handler = RdmResponderPackageHandler::<4, 3>::new(RdmResponderConfig {
+            uid: RESPONDER_UID,
+            ..Default::default()
+        });
+        for (sub_device, footprint) in package_handler
+            .get_sub_devices_mut()
+            .iter_mut()
+            .zip([4, 2, 3])
+        {
+            sub_device.dmx_footprint = footprint;
+        }
+
+        let response = handle_request(
+            &mut package_handler,
+            build_request(
+                RequestCommandClass::GetCommand,
+                pids::DMX_BLOCK_ADDRESS,
+                &[],
+            ),
+        );
+        assert_eq!(
+            DmxBlockAddress::deserialize(&response.parameter_data).unwrap(),
+            DmxBlockAddress {
+                total_footprint: 9,
+                base_address: DmxBlockAddress::NOT_CONTIGUOUS,
+            }
+        );
+
+        let response = handle_request(
+            &mut package_handler,
+            build_request(
+                RequestCommandClass::SetCommand,
+                pids::DMX_BLOCK_ADDRESS,
+                &100u16.to_be_bytes(),
+            ),
+        );
+        assert_eq!(response.response_type, ResponseType::ResponseTypeAck);
+        let start_addresses: Vec<_> = package_handler
+            .get_sub_devices()
             .iter()
-            .take(RDM_MAX_STATUS_PACKAGES_PER_REQUEST)
-            .filter(|item| ((item.status_type as u8) & 0x0F) >= status_filter as u8)
-            .map(|item| item.serialize())
-            .enumerate()
-            .for_each(|(index, data_pack)| {
-                parameter_data.extend_from_slice(&data_pack).unwrap();
-                indexes_to_remove.push(index).unwrap();
-            });
+            .map(|sub_device| sub_device.dmx_start_address.clone())
+            .collect();
+        assert_eq!(
+            start_addresses,
+            [
+                DmxStartAddress::Address(100),
+                DmxStartAddress::Address(104),
+                DmxStartAddress::Address(106),
+            ]
+        );
 
-        for index_to_remove in indexes_to_remove {
-            self.status_vec.remove(index_to_remove);
+        let response = handle_request(
+            &mut package_handler,
+            build_request(
+                RequestCommandClass::GetCommand,
+                pids::DMX_BLOCK_ADDRESS,
+                &[],
+            ),
+        );
+        assert_eq!(
+            DmxBlockAddress::deserialize(&response.parameter_data).unwrap(),
+            DmxBlockAddress {
+                total_footprint: 9,
+                base_address: 100,
+            }
+        );
+
+        let response = handle_request(
+            &mut package_handler,
+            build_request(
+                RequestCommandClass::SetCommand,
+                pids::DMX_BLOCK_ADDRESS,
+                &504u16.to_be_bytes(),
+            ),
+        );
+        assert_eq!(response.response_type, ResponseType::ResponseTypeAck);
+        assert_eq!(
+            package_handler.get_sub_devices()[2].dmx_start_address,
+            DmxStartAddress::Address(510)
+        );
+
+        let response = handle_request(
+            &mut package_handler,
+            build_request(
+                RequestCommandClass::SetCommand,
+                pids::DMX_BLOCK_ADDRESS,
+                &505u16.to_be_bytes(),
+            ),
+        );
+        assert_eq!(response.response_type, ResponseType::ResponseTypeNackReason);
+        assert_eq!(
+            &response.parameter_data[..],
+            &u16::from(NackReason::DataOutOfRange).to_be_bytes()
+        );
+        assert_eq!(
+            package_handler.get_sub_devices()[0].dmx_start_address,
+            DmxStartAddress::Address(504)
+        );
+
+        assert!(get_supported_parameters(&mut package_handler).contains(&pids::DMX_BLOCK_ADDRESS));
+        assert!(!get_supported_parameters(&mut build_package_handler(&[]))
+            .contains(&pids::DMX_BLOCK_ADDRESS));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_dmx_block_address_overflow() {
+        let mut package_handler = RdmResponderPackageHandler::<4, 2>::new(RdmResponderConfig {
+            uid: RESPONDER_UID,
+            ..Default::default()
+        });
+        for (sub_device, (start_address, footprint)) in package_handler
+            .get_sub_devices_mut()
+            .iter_mut()
+            .zip([(512, u16::MAX), (511, 1)])
+        {
+            sub_device.dmx_start_address = DmxStartAddress::Address(start_address);
+            sub_device.dmx_footprint = footprint;
         }
 
-        parameter_data
+        let response = handle_request(
+            &mut package_handler,
+            build_request(
+                RequestCommandClass::GetCommand,
+                pids::DMX_BLOCK_ADDRESS,
+                &[],
+            ),
+        );
+        assert_eq!(response.response_type, ResponseType::ResponseTypeAck);
+        assert_eq!(
+            DmxBlockAddress::deserialize(&response.parameter_data).unwrap(),
+            DmxBlockAddress {
+                total_footprint: u16::MAX,
+                base_address: DmxBlockAddress::NOT_CONTIGUOUS,
+            }
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_sub_device_response_echo() {
+        struct SubDeviceHandler;
+
+        impl RdmResponderHandlerFunc for SubDeviceHandler {
+            type Error = ();
+
+            fn handle_rdm_sub_device(
+                &mut self,
+                sub_device: u16,
+                _: &RdmRequestData,
+                _: &mut DmxReceiverContext,
+            ) -> Result<RdmResult, Self::Error> {
+                Ok(RdmResult::Acknowledged(
+                    DataPack::from_slice(&sub_device.to_be_bytes()).unwrap(),
+                ))
+            }
+        }
+
+        let mut package_handler = RdmResponderPackageHandler::<4, 3>::new(build_config(&[]));
+
+        // handled by the responder itself and by the handler
+        for parameter_id in [pids::DEVICE_INFO, 0x8000] {
+            let mut request = build_request(RequestCommandClass::GetCommand, parameter_id, &[]);
+            request.sub_device = 2;
+            let response = match package_handler.handle_rdm_request(request, &mut SubDeviceHandler)
+            {
+                Ok(RdmAnswer::Response(response)) => response,
+                _ => panic!("expected a response"),
+            };
+            assert_eq!(response.response_type, ResponseType::ResponseTypeAck);
+            assert_eq!(response.sub_device, 2);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_all_sub_devices() {
+        struct SubDeviceHandler {
+            sub_devices: Vec<u16>,
+        }
+
+        impl RdmResponderHandlerFunc for SubDeviceHandler {
+            type Error = ();
+
+            fn handle_rdm_sub_device(
+                &mut self,
+                sub_device: u16,
+                _: &RdmRequestData,
+                _: &mut DmxReceiverContext,
+            ) -> Result<RdmResult, Self::Error> {
+                self.sub_devices.push(sub_device);
+                Ok(RdmResult::Acknowledged(DataPack::new()))
+            }
+        }
+
+        let mut package_handler = RdmResponderPackageHandler::<4, 3>::new(RdmResponderConfig {
+            uid: RESPONDER_UID,
+            ..Default::default()
+        });
+        let mut handler = SubDeviceHandler {
+            sub_devices: Vec::new(),
+        };
+
+        // set all: applied to every sub-device without a response
+        let mut request = build_request(
+            RequestCommandClass::SetCommand,
+            pids::DMX_START_ADDRESS,
+            &[0x00, 0x20],
+        );
+        request.sub_device = SUB_DEVICE_ALL_CALL;
+        assert!(matches!(
+            package_handler.handle_rdm_request(request, &mut handler),
+            Ok(RdmAnswer::NoResponse)
+        ));
+        for sub_device in package_handler.get_sub_devices() {
+            assert_eq!(sub_device.dmx_start_address, DmxStartAddress::Address(0x20));
+        }
+        assert_eq!(
+            package_handler.dmx_start_address,
+            DmxStartAddress::NoAddress
+        );
+
+        let mut request = build_request(RequestCommandClass::SetCommand, 0x8000, &[0x01]);
+        request.sub_device = SUB_DEVICE_ALL_CALL;
+        assert!(matches!(
+            package_handler.handle_rdm_request(request, &mut handler),
+            Ok(RdmAnswer::NoResponse)
+        ));
+        assert_eq!(handler.sub_devices, [1, 2, 3]);
+
+        // get all: not allowed
+        let mut request = build_request(RequestCommandClass::GetCommand, pids::DEVICE_INFO, &[]);
+        request.sub_device = SUB_DEVICE_ALL_CALL;
+        let response = handle_request(&mut package_handler, request);
+        assert_eq!(response.response_type, ResponseType::ResponseTypeNackReason);
+        assert_eq!(
+            &response.parameter_data[..],
+            &u16::from(NackReason::SubDeviceOutOfRange).to_be_bytes()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_get_all_sub_devices() {
+        struct SubDeviceHandler;
+
+        impl RdmResponderHandlerFunc for SubDeviceHandler {
+            type Error = ();
+
+            fn handle_rdm_sub_device(
+                &mut self,
+                _: u16,
+                _: &RdmRequestData,
+                _: &mut DmxReceiverContext,
+            ) -> Result<RdmResult, Self::Error> {
+                panic!("a get to all sub-devices must not reach the handler");
+            }
+        }
+
+        let mut package_handler = RdmResponderPackageHandler::<4, 3>::new(RdmResponderConfig {
+            uid: RESPONDER_UID,
+            ..Default::default()
+        });
+
+        for parameter_id in [
+            pids::DEVICE_INFO,
+            pids::DMX_START_ADDRESS,
+            pids::SUPPORTED_PARAMETERS,
+            0x8000,
+        ] {
+            let mut request = build_request(RequestCommandClass::GetCommand, parameter_id, &[]);
+            request.sub_device = SUB_DEVICE_ALL_CALL;
+            let response = match package_handler.handle_rdm_request(request, &mut SubDeviceHandler)
+            {
+                Ok(RdmAnswer::Response(response)) => response,
+                _ => panic!("expected a response"),
+            };
+            assert_eq!(response.response_type, ResponseType::ResponseTypeNackReason);
+            assert_eq!(
+                &response.parameter_data[..],
+                &u16::from(NackReason::SubDeviceOutOfRange).to_be_bytes()
+            );
+        }
+
+        // the sub-devices themselves are still reachable
+        let mut request = build_request(RequestCommandClass::GetCommand, pids::DEVICE_INFO, &[]);
+        request.sub_device = 3;
+        let response = handle_request(&mut package_handler, request);
+        assert_eq!(response.response_type, ResponseType::ResponseTypeAck);
     }
 }
@@ -1,27 +1,136 @@
 use crate::consts::{
-    RDM_DEVICE_INFO_SIZE, RDM_MAX_STATUS_PACKAGES_PER_REQUEST,
-    RDM_MAX_SUPPORTED_PARAMETERS_PER_REQUEST, RDM_STATUS_MESSAGE_SIZE,
+    RDM_COMMS_STATUS_SIZE, RDM_CURVE_DESCRIPTION_FIXED_SIZE, RDM_CURVE_SIZE,
+    RDM_DEFAULT_SLOT_VALUE_SIZE, RDM_DEVICE_INFO_SIZE, RDM_DMX_BLOCK_ADDRESS_SIZE,
+    RDM_DMX_PERSONALITY_DESCRIPTION_FIXED_SIZE, RDM_DMX_PERSONALITY_SIZE,
+    RDM_MAX_CURVE_DESCRIPTION_SIZE, RDM_MAX_DEFAULT_SLOT_VALUES_PER_REQUEST,
+    RDM_MAX_DMX_PERSONALITY_DESCRIPTION_SIZE, RDM_MAX_PARAMETER_DESCRIPTION_SIZE,
+    RDM_MAX_SELF_TEST_DESCRIPTION_SIZE, RDM_MAX_SENSOR_DEFINITION_SIZE,
+    RDM_MAX_STATUS_PACKAGES_PER_REQUEST, RDM_MAX_SUPPORTED_PARAMETERS_PER_REQUEST,
+    RDM_PARAMETER_DESCRIPTION_FIXED_SIZE, RDM_PRESET_PLAYBACK_SIZE, RDM_REAL_TIME_CLOCK_SIZE,
+    RDM_SELF_TEST_DESCRIPTION_FIXED_SIZE, RDM_SENSOR_DEFINITION_FIXED_SIZE, RDM_SENSOR_VALUE_SIZE,
+    RDM_STATUS_MESSAGE_SIZE,
 };
-use crate::layouts::{rdm_device_info_layout, rdm_status_message_layout};
+use crate::layouts::{
+    rdm_device_info_layout, rdm_parameter_description_layout, rdm_sensor_definition_layout,
+    rdm_sensor_value_layout, rdm_status_message_layout,
+};
+use crate::pids;
 use crate::types::DataPack;
 use crate::unique_identifier::{PackageAddress, UniqueIdentifier};
 use modular_bitfield::bitfield;
 use modular_bitfield::prelude::B12;
 
-#[derive(Debug)]
+/// Error that gets raised when parameter data or a value can't be deserialized.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub struct DeserializationError;
+pub enum DeserializationError {
+    /// The buffer is too short, too long or doesn't fit into the destination.
+    WrongLength,
+    /// A value is outside of its valid range.
+    OutOfRange,
+    /// The value doesn't belong to any variant of the enum; contains the value.
+    InvalidEnumValue(u8),
+    /// The uid is invalid or a broadcast where a device uid is expected.
+    InvalidUid,
+    /// A string isn't valid UTF-8.
+    InvalidUtf8,
+    /// The data doesn't follow the protocol (e.g. a wrong packet identifier).
+    InvalidFormat,
+}
 
 impl core::fmt::Display for DeserializationError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "There was a deserialization error.")
+        match self {
+            Self::WrongLength => write!(f, "The data has the wrong length."),
+            Self::OutOfRange => write!(f, "A value is out of range."),
+            Self::InvalidEnumValue(value) => write!(f, "The value {value:#04X} is invalid."),
+            Self::InvalidUid => write!(f, "The uid is invalid."),
+            Self::InvalidUtf8 => write!(f, "The string isn't valid UTF-8."),
+            Self::InvalidFormat => write!(f, "The data has an invalid format."),
+        }
     }
 }
 
 #[cfg(feature = "std")]
 impl std::error::Error for DeserializationError {}
 
-#[derive(Debug, Clone)]
+/// Returned if a [DmxStartAddress] couldn't be deserialized.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DmxStartAddressError {
+    /// The parameter data is not 2 bytes long.
+    WrongLength,
+    /// The address is not in the range of 1-512 and not 0xFFFF.
+    OutOfRange,
+}
+
+impl core::fmt::Display for DmxStartAddressError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::WrongLength => write!(f, "The dmx start address has to be 2 bytes long."),
+            Self::OutOfRange => write!(f, "The dmx start address is out of range."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DmxStartAddressError {}
+
+impl From<DmxStartAddressError> for DeserializationError {
+    fn from(value: DmxStartAddressError) -> Self {
+        match value {
+            DmxStartAddressError::WrongLength => Self::WrongLength,
+            DmxStartAddressError::OutOfRange => Self::OutOfRange,
+        }
+    }
+}
+
+/// A typed parameter that can be converted from and to the parameter data of an RDM package.
+/// Allows writing code that is generic over the parameter types.
+pub trait RdmParameter: Sized {
+    /// Serializes the parameter into parameter data.
+    fn serialize(&self) -> DataPack;
+    /// Deserializes the parameter from parameter data.
+    fn deserialize(buffer: &[u8]) -> Result<Self, DeserializationError>;
+}
+
+macro_rules! impl_rdm_parameter {
+    ($($parameter:ty),+) => {
+        $(
+            impl RdmParameter for $parameter {
+                fn serialize(&self) -> DataPack {
+                    DataPack::from_slice(&<$parameter>::serialize(self)).unwrap()
+                }
+
+                fn deserialize(buffer: &[u8]) -> Result<Self, DeserializationError> {
+                    <$parameter>::deserialize(buffer).map_err(DeserializationError::from)
+                }
+            }
+        )+
+    };
+}
+
+impl_rdm_parameter!(
+    DmxStartAddress,
+    DiscoveryMuteResponse,
+    StatusMessage,
+    DeviceInfo,
+    DmxPersonality,
+    DmxPersonalityDescription,
+    PresetPlayback,
+    DefaultSlotValue,
+    ParameterDescription,
+    SensorDefinition,
+    SensorValue,
+    CommsStatus,
+    DmxBlockAddress,
+    RealTimeClock,
+    SelfTestDescription,
+    Curve,
+    CurveDescription
+);
+
+#[derive(Debug, Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum DmxStartAddress {
     /// The requested device has a dmx footprint of 0.
@@ -38,10 +147,13 @@ impl DmxStartAddress {
         }
     }
 
-    pub fn deserialize(data: &[u8]) -> Result<Self, DeserializationError> {
-        let start_address = u16::from_be_bytes(data.try_into().map_err(|_| DeserializationError)?);
+    pub fn deserialize(data: &[u8]) -> Result<Self, DmxStartAddressError> {
+        let start_address = u16::from_be_bytes(
+            data.try_into()
+                .map_err(|_| DmxStartAddressError::WrongLength)?,
+        );
 
-        start_address.try_into().map_err(|_| DeserializationError)
+        start_address.try_into()
     }
 
     pub fn serialize(&self) -> DataPack {
@@ -50,15 +162,17 @@ impl DmxStartAddress {
 }
 
 impl TryFrom<u16> for DmxStartAddress {
-    type Error = DeserializationError;
+    type Error = DmxStartAddressError;
 
+    /// 0xFFFF is converted to [DmxStartAddress::NoAddress]. 0 and addresses above 512
+    /// are rejected with [DmxStartAddressError::OutOfRange].
     fn try_from(start_address: u16) -> Result<Self, Self::Error> {
         if start_address == 0xFFFF {
             return Ok(Self::NoAddress);
         }
 
         if !(1..=512).contains(&start_address) {
-            return Err(DeserializationError);
+            return Err(DmxStartAddressError::OutOfRange);
         }
 
         Ok(Self::Address(start_address))
@@ -66,7 +180,9 @@ impl TryFrom<u16> for DmxStartAddress {
 }
 
 /// Response to discovery mute/unmute requests.
-#[derive(Debug, Eq, PartialEq)]
+///
+/// Displayed as the set flags and the binding uid, e.g. `managed_proxy, boot_loader`.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
 pub struct DiscoveryMuteResponse {
     /// The responder is a proxy device.
     pub managed_proxy: bool,
@@ -98,7 +214,7 @@ struct DiscControlField {
 impl DiscoveryMuteResponse {
     pub fn deserialize(data: &[u8]) -> Result<Self, DeserializationError> {
         if data.len() < 2 {
-            return Err(DeserializationError);
+            return Err(DeserializationError::WrongLength);
         }
 
         let control_field = DiscControlField::from_bytes((&data[0..2]).try_into().unwrap());
@@ -113,7 +229,7 @@ impl DiscoveryMuteResponse {
         if data.len() == 8 {
             let binding_uuid = match PackageAddress::from_bytes((&data[2..8]).try_into().unwrap()) {
                 PackageAddress::Device(uid) => uid,
-                _ => return Err(DeserializationError),
+                _ => return Err(DeserializationError::InvalidUid),
             };
 
             discovery_mute_response.binding_uid = Some(binding_uuid);
@@ -141,6 +257,37 @@ impl DiscoveryMuteResponse {
 
         data_pack
     }
+
+    /// Returns true if no flag is set and no binding uid is included.
+    pub fn is_plain_device(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl core::fmt::Display for DiscoveryMuteResponse {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.is_plain_device() {
+            return write!(f, "plain device");
+        }
+
+        let flags = [
+            (self.managed_proxy, "managed_proxy"),
+            (self.sub_device, "sub_device"),
+            (self.boot_loader, "boot_loader"),
+            (self.proxy_device, "proxy_device"),
+        ];
+
+        let mut separator = "";
+        for (_, name) in flags.iter().filter(|(is_set, _)| *is_set) {
+            write!(f, "{separator}{name}")?;
+            separator = ", ";
+        }
+
+        match self.binding_uid {
+            Some(binding_uid) => write!(f, "{separator}binding uid {binding_uid}"),
+            None => Ok(()),
+        }
+    }
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -158,17 +305,26 @@ pub enum StatusType {
 }
 
 impl StatusType {
+    /// Returns the severity of the status type without the cleared flag.
+    /// A higher value means a higher severity.
+    pub fn severity(&self) -> u8 {
+        (*self as u8) & 0x0F
+    }
+
+    /// Returns true if the status type reports that a condition has been cleared.
+    pub fn is_cleared(&self) -> bool {
+        matches!(
+            self,
+            Self::StatusAdvisoryCleared | Self::StatusWarningCleared | Self::StatusErrorCleared
+        )
+    }
+
     pub fn deserialize(data: &[u8]) -> Result<Self, DeserializationError> {
         if data.len() != 1 {
-            return Err(DeserializationError);
+            return Err(DeserializationError::WrongLength);
         }
 
-        let status_type_requested: StatusType = match data[0].try_into() {
-            Ok(status_type) => status_type,
-            Err(_) => {
-                return Err(DeserializationError);
-            },
-        };
+        let status_type_requested: StatusType = data[0].try_into()?;
 
         Ok(status_type_requested)
     }
@@ -186,8 +342,8 @@ impl TryFrom<u8> for StatusType {
             0x04 => Self::StatusError,
             0x12 => Self::StatusAdvisoryCleared,
             0x13 => Self::StatusWarningCleared,
-            0x14 => Self::StatusWarningCleared,
-            _ => return Err(DeserializationError),
+            0x14 => Self::StatusErrorCleared,
+            _ => return Err(DeserializationError::InvalidEnumValue(value)),
         })
     }
 }
@@ -242,6 +398,8 @@ impl StatusMessage {
 
 pub type StatusMessages = heapless::Vec<StatusMessage, RDM_MAX_STATUS_PACKAGES_PER_REQUEST>;
 pub type SupportedParameters = heapless::Vec<u16, RDM_MAX_SUPPORTED_PARAMETERS_PER_REQUEST>;
+pub type DefaultSlotValues =
+    heapless::Vec<DefaultSlotValue, RDM_MAX_DEFAULT_SLOT_VALUES_PER_REQUEST>;
 
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
@@ -259,7 +417,7 @@ pub struct DeviceInfo {
 impl DeviceInfo {
     pub fn deserialize(buffer: &[u8]) -> Result<Self, DeserializationError> {
         if buffer.len() != rdm_device_info_layout::SIZE.unwrap() {
-            return Err(DeserializationError);
+            return Err(DeserializationError::WrongLength);
         }
 
         let device_info_view = rdm_device_info_layout::View::new(buffer);
@@ -307,6 +465,939 @@ impl DeviceInfo {
     }
 }
 
+/// The current personality of a device and the amount of available personalities (DMX_PERSONALITY).
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DmxPersonality {
+    /// The current personality. Personalities are numbered starting at 1.
+    pub current_personality: u8,
+    /// The amount of available personalities.
+    pub personality_count: u8,
+}
+
+impl DmxPersonality {
+    pub fn deserialize(buffer: &[u8]) -> Result<Self, DeserializationError> {
+        if buffer.len() != RDM_DMX_PERSONALITY_SIZE {
+            return Err(DeserializationError::WrongLength);
+        }
+
+        Ok(Self {
+            current_personality: buffer[0],
+            personality_count: buffer[1],
+        })
+    }
+
+    pub fn serialize(&self) -> [u8; RDM_DMX_PERSONALITY_SIZE] {
+        [self.current_personality, self.personality_count]
+    }
+}
+
+/// The description of a personality (DMX_PERSONALITY_DESCRIPTION).
+///
+/// Received descriptions own their label. Use `DmxPersonalityDescription<&'static str>` to
+/// build the personalities of a responder in a `static` or `const`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DmxPersonalityDescription<S = heapless::String<32>> {
+    /// The number of the personality. Personalities are numbered starting at 1.
+    pub personality: u8,
+    /// The amount of dmx addresses the device allocates in this personality.
+    pub dmx_footprint: u16,
+    /// The label of the personality. Labels longer than 32 bytes are truncated when serialized.
+    pub description: S,
+}
+
+impl DmxPersonalityDescription {
+    pub fn deserialize(buffer: &[u8]) -> Result<Self, DeserializationError> {
+        if !(RDM_DMX_PERSONALITY_DESCRIPTION_FIXED_SIZE..=RDM_MAX_DMX_PERSONALITY_DESCRIPTION_SIZE)
+            .contains(&buffer.len())
+        {
+            return Err(DeserializationError::WrongLength);
+        }
+
+        let description = heapless::String::from_utf8(
+            heapless::Vec::from_slice(&buffer[RDM_DMX_PERSONALITY_DESCRIPTION_FIXED_SIZE..])
+                .or(Err(DeserializationError::WrongLength))?,
+        )
+        .or(Err(DeserializationError::InvalidUtf8))?;
+
+        Ok(Self {
+            personality: buffer[0],
+            dmx_footprint: u16::from_be_bytes(buffer[1..3].try_into().unwrap()),
+            description,
+        })
+    }
+}
+
+impl<S: AsRef<str>> DmxPersonalityDescription<S> {
+    pub fn serialize(&self) -> DataPack {
+        let mut resp_buffer = DataPack::new();
+        let description = self.description.as_ref().as_bytes();
+
+        resp_buffer.push(self.personality).unwrap();
+        resp_buffer
+            .extend_from_slice(&self.dmx_footprint.to_be_bytes())
+            .unwrap();
+        resp_buffer
+            .extend_from_slice(
+                &description[..description.len().min(
+                    RDM_MAX_DMX_PERSONALITY_DESCRIPTION_SIZE
+                        - RDM_DMX_PERSONALITY_DESCRIPTION_FIXED_SIZE,
+                )],
+            )
+            .unwrap();
+
+        resp_buffer
+    }
+}
+
+/// The current dimming curve of a device and the amount of available curves (CURVE).
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Curve {
+    /// The current curve. Curves are numbered starting at 1.
+    pub current_curve: u8,
+    /// The amount of available curves.
+    pub curve_count: u8,
+}
+
+impl Curve {
+    pub fn deserialize(buffer: &[u8]) -> Result<Self, DeserializationError> {
+        if buffer.len() != RDM_CURVE_SIZE {
+            return Err(DeserializationError::WrongLength);
+        }
+
+        Ok(Self {
+            current_curve: buffer[0],
+            curve_count: buffer[1],
+        })
+    }
+
+    pub fn serialize(&self) -> [u8; RDM_CURVE_SIZE] {
+        [self.current_curve, self.curve_count]
+    }
+}
+
+/// The description of a dimming curve (CURVE_DESCRIPTION).
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CurveDescription {
+    /// The number of the curve. Curves are numbered starting at 1.
+    pub curve: u8,
+    /// The label of the curve.
+    pub description: heapless::String<32>,
+}
+
+impl CurveDescription {
+    pub fn deserialize(buffer: &[u8]) -> Result<Self, DeserializationError> {
+        if !(RDM_CURVE_DESCRIPTION_FIXED_SIZE..=RDM_MAX_CURVE_DESCRIPTION_SIZE)
+            .contains(&buffer.len())
+        {
+            return Err(DeserializationError::WrongLength);
+        }
+
+        let description = heapless::String::from_utf8(
+            heapless::Vec::from_slice(&buffer[RDM_CURVE_DESCRIPTION_FIXED_SIZE..])
+                .or(Err(DeserializationError::WrongLength))?,
+        )
+        .or(Err(DeserializationError::InvalidUtf8))?;
+
+        Ok(Self {
+            curve: buffer[0],
+            description,
+        })
+    }
+
+    pub fn serialize(&self) -> DataPack {
+        let mut resp_buffer = DataPack::new();
+
+        resp_buffer.push(self.curve).unwrap();
+        resp_buffer
+            .extend_from_slice(self.description.as_bytes())
+            .unwrap();
+
+        resp_buffer
+    }
+}
+
+/// The description of a self test (SELF_TEST_DESCRIPTION).
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SelfTestDescription {
+    /// The number of the self test. Self tests are numbered starting at 1.
+    pub self_test: u8,
+    /// The label of the self test.
+    pub description: heapless::String<32>,
+}
+
+impl SelfTestDescription {
+    pub fn deserialize(buffer: &[u8]) -> Result<Self, DeserializationError> {
+        if !(RDM_SELF_TEST_DESCRIPTION_FIXED_SIZE..=RDM_MAX_SELF_TEST_DESCRIPTION_SIZE)
+            .contains(&buffer.len())
+        {
+            return Err(DeserializationError::WrongLength);
+        }
+
+        let description = heapless::String::from_utf8(
+            heapless::Vec::from_slice(&buffer[RDM_SELF_TEST_DESCRIPTION_FIXED_SIZE..])
+                .or(Err(DeserializationError::WrongLength))?,
+        )
+        .or(Err(DeserializationError::InvalidUtf8))?;
+
+        Ok(Self {
+            self_test: buffer[0],
+            description,
+        })
+    }
+
+    pub fn serialize(&self) -> DataPack {
+        let mut resp_buffer = DataPack::new();
+
+        resp_buffer.push(self.self_test).unwrap();
+        resp_buffer
+            .extend_from_slice(self.description.as_bytes())
+            .unwrap();
+
+        resp_buffer
+    }
+}
+
+/// The type of reset requested by RESET_DEVICE.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(u8)]
+pub enum ResetType {
+    /// Reset the device without power cycling it. The device keeps responding.
+    Warm = 0x01,
+    /// Power cycle the device. The device might not respond to the request.
+    Cold = 0xFF,
+}
+
+impl TryFrom<u8> for ResetType {
+    type Error = DeserializationError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0x01 => Ok(Self::Warm),
+            0xFF => Ok(Self::Cold),
+            _ => Err(DeserializationError::InvalidEnumValue(value)),
+        }
+    }
+}
+
+/// A runtime statistic of a device that is counted by the firmware.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum StatisticsCounter {
+    /// The hours the device has been powered on (DEVICE_HOURS).
+    DeviceHours,
+    /// The hours the lamp has been on (LAMP_HOURS).
+    LampHours,
+    /// The amount of times the device has been power cycled (DEVICE_POWER_CYCLES).
+    DevicePowerCycles,
+}
+
+impl StatisticsCounter {
+    /// Get the pid of the counter.
+    pub fn parameter_id(self) -> u16 {
+        match self {
+            Self::DeviceHours => pids::DEVICE_HOURS,
+            Self::LampHours => pids::LAMP_HOURS,
+            Self::DevicePowerCycles => pids::DEVICE_POWER_CYCLES,
+        }
+    }
+
+    /// Get the counter of a pid. Returns None if the pid doesn't belong to a counter.
+    pub fn from_parameter_id(parameter_id: u16) -> Option<Self> {
+        match parameter_id {
+            pids::DEVICE_HOURS => Some(Self::DeviceHours),
+            pids::LAMP_HOURS => Some(Self::LampHours),
+            pids::DEVICE_POWER_CYCLES => Some(Self::DevicePowerCycles),
+            _ => None,
+        }
+    }
+}
+
+/// The power state of a device (POWER_STATE).
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(u8)]
+pub enum PowerState {
+    /// The device is completely turned off. It might not respond to any further requests.
+    FullOff = 0x00,
+    /// The device is in a low power state. It might only respond to POWER_STATE requests.
+    Shutdown = 0x01,
+    /// The device is ready to return to [PowerState::Normal] without a delay.
+    Standby = 0x02,
+    /// The device is operating normally.
+    #[default]
+    Normal = 0xFF,
+}
+
+impl TryFrom<u8> for PowerState {
+    type Error = DeserializationError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0x00 => Ok(Self::FullOff),
+            0x01 => Ok(Self::Shutdown),
+            0x02 => Ok(Self::Standby),
+            0xFF => Ok(Self::Normal),
+            _ => Err(DeserializationError::InvalidEnumValue(value)),
+        }
+    }
+}
+
+/// How the device identifies itself while IDENTIFY_DEVICE is active (IDENTIFY_MODE).
+///
+/// The mode only selects the kind of identification. It doesn't start the identification.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(u8)]
+pub enum IdentifyMode {
+    /// Identify without drawing much attention, e.g. by flashing a status LED.
+    Quiet = 0x00,
+    /// Identify in a way that is easy to spot, e.g. by strobing or moving.
+    #[default]
+    Loud = 0xFF,
+}
+
+impl TryFrom<u8> for IdentifyMode {
+    type Error = DeserializationError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0x00 => Ok(Self::Quiet),
+            0xFF => Ok(Self::Loud),
+            _ => Err(DeserializationError::InvalidEnumValue(value)),
+        }
+    }
+}
+
+/// The communication error counters of a device (COMMS_STATUS).
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CommsStatus {
+    /// Packages that were shorter than a valid rdm package.
+    pub short_message: u16,
+    /// Packages whose message length didn't match the received length.
+    pub length_mismatch: u16,
+    /// Packages with a wrong checksum.
+    pub checksum_fail: u16,
+}
+
+impl CommsStatus {
+    pub fn deserialize(buffer: &[u8]) -> Result<Self, DeserializationError> {
+        if buffer.len() != RDM_COMMS_STATUS_SIZE {
+            return Err(DeserializationError::WrongLength);
+        }
+
+        Ok(Self {
+            short_message: u16::from_be_bytes([buffer[0], buffer[1]]),
+            length_mismatch: u16::from_be_bytes([buffer[2], buffer[3]]),
+            checksum_fail: u16::from_be_bytes([buffer[4], buffer[5]]),
+        })
+    }
+
+    pub fn serialize(&self) -> [u8; RDM_COMMS_STATUS_SIZE] {
+        let [short_message_high, short_message_low] = self.short_message.to_be_bytes();
+        let [length_mismatch_high, length_mismatch_low] = self.length_mismatch.to_be_bytes();
+        let [checksum_fail_high, checksum_fail_low] = self.checksum_fail.to_be_bytes();
+        [
+            short_message_high,
+            short_message_low,
+            length_mismatch_high,
+            length_mismatch_low,
+            checksum_fail_high,
+            checksum_fail_low,
+        ]
+    }
+}
+
+/// The dmx addresses of all sub-devices as a single block (DMX_BLOCK_ADDRESS).
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DmxBlockAddress {
+    /// The sum of the dmx footprints of all sub-devices.
+    pub total_footprint: u16,
+    /// The start address of the first sub-device or [DmxBlockAddress::NOT_CONTIGUOUS].
+    pub base_address: u16,
+}
+
+impl DmxBlockAddress {
+    /// The base address if the dmx addresses of the sub-devices aren't contiguous.
+    pub const NOT_CONTIGUOUS: u16 = 0xFFFF;
+
+    pub fn deserialize(buffer: &[u8]) -> Result<Self, DeserializationError> {
+        if buffer.len() != RDM_DMX_BLOCK_ADDRESS_SIZE {
+            return Err(DeserializationError::WrongLength);
+        }
+
+        Ok(Self {
+            total_footprint: u16::from_be_bytes([buffer[0], buffer[1]]),
+            base_address: u16::from_be_bytes([buffer[2], buffer[3]]),
+        })
+    }
+
+    pub fn serialize(&self) -> [u8; RDM_DMX_BLOCK_ADDRESS_SIZE] {
+        let [total_footprint_high, total_footprint_low] = self.total_footprint.to_be_bytes();
+        let [base_address_high, base_address_low] = self.base_address.to_be_bytes();
+        [
+            total_footprint_high,
+            total_footprint_low,
+            base_address_high,
+            base_address_low,
+        ]
+    }
+}
+
+/// The date and time of the real time clock of a device (REAL_TIME_CLOCK).
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RealTimeClock {
+    /// The year, 2003 or later.
+    pub year: u16,
+    /// The month between 1 and 12.
+    pub month: u8,
+    /// The day of the month between 1 and 31.
+    pub day: u8,
+    /// The hour between 0 and 23.
+    pub hour: u8,
+    /// The minute between 0 and 59.
+    pub minute: u8,
+    /// The second between 0 and 59, or 60 for a leap second.
+    pub second: u8,
+}
+
+impl Default for RealTimeClock {
+    fn default() -> Self {
+        Self {
+            year: 2003,
+            month: 1,
+            day: 1,
+            hour: 0,
+            minute: 0,
+            second: 0,
+        }
+    }
+}
+
+impl RealTimeClock {
+    /// Returns true if the clock is a valid date and time according to E1.20.
+    /// The day is checked against the length of the month, including leap years.
+    pub fn is_valid(&self) -> bool {
+        let is_leap_year = self.year.is_multiple_of(4)
+            && (!self.year.is_multiple_of(100) || self.year.is_multiple_of(400));
+        let days_in_month = match self.month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if is_leap_year => 29,
+            2 => 28,
+            _ => return false,
+        };
+
+        self.year >= 2003
+            && (1..=days_in_month).contains(&self.day)
+            && self.hour <= 23
+            && self.minute <= 59
+            && self.second <= 60
+    }
+
+    pub fn deserialize(buffer: &[u8]) -> Result<Self, DeserializationError> {
+        if buffer.len() != RDM_REAL_TIME_CLOCK_SIZE {
+            return Err(DeserializationError::WrongLength);
+        }
+
+        Ok(Self {
+            year: u16::from_be_bytes([buffer[0], buffer[1]]),
+            month: buffer[2],
+            day: buffer[3],
+            hour: buffer[4],
+            minute: buffer[5],
+            second: buffer[6],
+        })
+    }
+
+    pub fn serialize(&self) -> [u8; RDM_REAL_TIME_CLOCK_SIZE] {
+        let [year_high, year_low] = self.year.to_be_bytes();
+        [
+            year_high,
+            year_low,
+            self.month,
+            self.day,
+            self.hour,
+            self.minute,
+            self.second,
+        ]
+    }
+}
+
+/// The preset playback mode of a device (PRESET_PLAYBACK).
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PresetPlayback {
+    /// The scene that is played back. [PresetPlayback::OFF] returns to normal dmx operation,
+    /// [PresetPlayback::ALL] plays back all scenes in a sequence.
+    pub scene: u16,
+    /// The level the scene is played back at.
+    pub level: u8,
+}
+
+impl PresetPlayback {
+    /// Stops the playback and returns to normal dmx operation.
+    pub const OFF: u16 = 0x0000;
+    /// Plays back all scenes in a sequence.
+    pub const ALL: u16 = 0xFFFF;
+
+    pub fn deserialize(buffer: &[u8]) -> Result<Self, DeserializationError> {
+        if buffer.len() != RDM_PRESET_PLAYBACK_SIZE {
+            return Err(DeserializationError::WrongLength);
+        }
+
+        Ok(Self {
+            scene: u16::from_be_bytes([buffer[0], buffer[1]]),
+            level: buffer[2],
+        })
+    }
+
+    pub fn serialize(&self) -> [u8; RDM_PRESET_PLAYBACK_SIZE] {
+        let [scene_high, scene_low] = self.scene.to_be_bytes();
+        [scene_high, scene_low, self.level]
+    }
+}
+
+impl Default for PresetPlayback {
+    fn default() -> Self {
+        Self {
+            scene: Self::OFF,
+            level: 0,
+        }
+    }
+}
+
+/// The value a dmx slot has after powering up the device (DEFAULT_SLOT_VALUE).
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DefaultSlotValue {
+    /// The offset of the slot relative to the dmx start address.
+    pub slot_offset: u16,
+    pub default_value: u8,
+}
+
+impl DefaultSlotValue {
+    pub fn deserialize(buffer: &[u8]) -> Result<Self, DeserializationError> {
+        if buffer.len() != RDM_DEFAULT_SLOT_VALUE_SIZE {
+            return Err(DeserializationError::WrongLength);
+        }
+
+        Ok(Self {
+            slot_offset: u16::from_be_bytes([buffer[0], buffer[1]]),
+            default_value: buffer[2],
+        })
+    }
+
+    pub fn serialize(&self) -> [u8; RDM_DEFAULT_SLOT_VALUE_SIZE] {
+        let [slot_offset_high, slot_offset_low] = self.slot_offset.to_be_bytes();
+        [slot_offset_high, slot_offset_low, self.default_value]
+    }
+}
+
+/// The data type of a parameter in a [ParameterDescription].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum RdmDataType {
+    NotDefined,
+    BitField,
+    Ascii,
+    UnsignedByte,
+    SignedByte,
+    UnsignedWord,
+    SignedWord,
+    UnsignedDword,
+    SignedDword,
+    /// A manufacturer specific data type between 0x80 and 0xDF.
+    ManufacturerSpecific(u8),
+}
+
+impl TryFrom<u8> for RdmDataType {
+    type Error = DeserializationError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0x00 => Self::NotDefined,
+            0x01 => Self::BitField,
+            0x02 => Self::Ascii,
+            0x03 => Self::UnsignedByte,
+            0x04 => Self::SignedByte,
+            0x05 => Self::UnsignedWord,
+            0x06 => Self::SignedWord,
+            0x07 => Self::UnsignedDword,
+            0x08 => Self::SignedDword,
+            0x80..=0xDF => Self::ManufacturerSpecific(value),
+            _ => return Err(DeserializationError::InvalidEnumValue(value)),
+        })
+    }
+}
+
+impl From<RdmDataType> for u8 {
+    fn from(value: RdmDataType) -> Self {
+        match value {
+            RdmDataType::NotDefined => 0x00,
+            RdmDataType::BitField => 0x01,
+            RdmDataType::Ascii => 0x02,
+            RdmDataType::UnsignedByte => 0x03,
+            RdmDataType::SignedByte => 0x04,
+            RdmDataType::UnsignedWord => 0x05,
+            RdmDataType::SignedWord => 0x06,
+            RdmDataType::UnsignedDword => 0x07,
+            RdmDataType::SignedDword => 0x08,
+            RdmDataType::ManufacturerSpecific(data_type) => data_type,
+        }
+    }
+}
+
+/// The command classes that are supported by a parameter in a [ParameterDescription].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(u8)]
+pub enum PidCommandClassSupport {
+    Get = 0x01,
+    Set = 0x02,
+    GetSet = 0x03,
+}
+
+impl PidCommandClassSupport {
+    /// Returns true if get requests are supported.
+    pub fn supports_get(&self) -> bool {
+        matches!(self, Self::Get | Self::GetSet)
+    }
+
+    /// Returns true if set requests are supported.
+    pub fn supports_set(&self) -> bool {
+        matches!(self, Self::Set | Self::GetSet)
+    }
+}
+
+impl TryFrom<u8> for PidCommandClassSupport {
+    type Error = DeserializationError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0x01 => Self::Get,
+            0x02 => Self::Set,
+            0x03 => Self::GetSet,
+            _ => return Err(DeserializationError::InvalidEnumValue(value)),
+        })
+    }
+}
+
+/// The description of a manufacturer specific parameter (PARAMETER_DESCRIPTION).
+///
+/// Received descriptions own their label. Use `ParameterDescription<&'static str>` to
+/// build the descriptions of a responder in a `static` or `const`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ParameterDescription<S = heapless::String<32>> {
+    /// The manufacturer specific pid that is described.
+    pub pid: u16,
+    /// The size of the parameter data.
+    pub pdl_size: u8,
+    /// The data type of the parameter data.
+    pub data_type: RdmDataType,
+    /// The supported command classes.
+    pub command_class: PidCommandClassSupport,
+    /// The unit of the parameter (e.g. 0x01 for degree centigrade).
+    pub unit: u8,
+    /// The prefix of the unit (e.g. 0x01 for deci).
+    pub prefix: u8,
+    /// The lowest valid value of the parameter.
+    pub min_valid_value: u32,
+    /// The highest valid value of the parameter.
+    pub max_valid_value: u32,
+    /// The default value of the parameter.
+    pub default_value: u32,
+    /// The label of the parameter. Labels longer than 32 bytes are truncated when serialized.
+    pub description: S,
+}
+
+impl ParameterDescription {
+    pub fn deserialize(buffer: &[u8]) -> Result<Self, DeserializationError> {
+        if !(RDM_PARAMETER_DESCRIPTION_FIXED_SIZE..=RDM_MAX_PARAMETER_DESCRIPTION_SIZE)
+            .contains(&buffer.len())
+        {
+            return Err(DeserializationError::WrongLength);
+        }
+
+        let parameter_description_view = rdm_parameter_description_layout::View::new(buffer);
+        let description = heapless::String::from_utf8(
+            heapless::Vec::from_slice(parameter_description_view.description())
+                .or(Err(DeserializationError::WrongLength))?,
+        )
+        .or(Err(DeserializationError::InvalidUtf8))?;
+
+        Ok(Self {
+            pid: parameter_description_view.pid().read(),
+            pdl_size: parameter_description_view.pdl_size().read(),
+            data_type: parameter_description_view.data_type().read().try_into()?,
+            command_class: parameter_description_view
+                .command_class()
+                .read()
+                .try_into()?,
+            unit: parameter_description_view.unit().read(),
+            prefix: parameter_description_view.prefix().read(),
+            min_valid_value: parameter_description_view.min_valid_value().read(),
+            max_valid_value: parameter_description_view.max_valid_value().read(),
+            default_value: parameter_description_view.default_value().read(),
+            description,
+        })
+    }
+}
+
+impl<S: AsRef<str>> ParameterDescription<S> {
+    pub fn serialize(&self) -> DataPack {
+        let mut resp_buffer = [0u8; RDM_MAX_PARAMETER_DESCRIPTION_SIZE];
+        let mut parameter_description_view =
+            rdm_parameter_description_layout::View::new(&mut resp_buffer);
+
+        parameter_description_view.pid_mut().write(self.pid);
+        parameter_description_view
+            .pdl_size_mut()
+            .write(self.pdl_size);
+        parameter_description_view
+            .data_type_mut()
+            .write(self.data_type.into());
+        parameter_description_view
+            .command_class_mut()
+            .write(self.command_class as u8);
+        parameter_description_view.parameter_type_mut().write(0);
+        parameter_description_view.unit_mut().write(self.unit);
+        parameter_description_view.prefix_mut().write(self.prefix);
+        parameter_description_view
+            .min_valid_value_mut()
+            .write(self.min_valid_value);
+        parameter_description_view
+            .max_valid_value_mut()
+            .write(self.max_valid_value);
+        parameter_description_view
+            .default_value_mut()
+            .write(self.default_value);
+
+        let description = self.description.as_ref().as_bytes();
+        let description_length = description
+            .len()
+            .min(parameter_description_view.description().len());
+        parameter_description_view.description_mut()[..description_length]
+            .copy_from_slice(&description[..description_length]);
+
+        DataPack::from_slice(
+            &resp_buffer[..RDM_PARAMETER_DESCRIPTION_FIXED_SIZE + description_length],
+        )
+        .unwrap()
+    }
+}
+
+/// The definition of a sensor (SENSOR_DEFINITION).
+///
+/// Received definitions own their label. Use `SensorDefinition<&'static str>` to build the
+/// definitions of a responder in a `static` or `const`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SensorDefinition<S = heapless::String<32>> {
+    /// The number of the sensor. Sensors are numbered starting at 0.
+    pub sensor_number: u8,
+    /// The type of the sensor (e.g. 0x00 for temperature).
+    pub sensor_type: u8,
+    /// The unit of the sensor values (e.g. 0x01 for degree centigrade).
+    pub unit: u8,
+    /// The prefix of the unit (e.g. 0x01 for deci).
+    pub prefix: u8,
+    /// The lowest value the sensor can report.
+    pub range_min: i16,
+    /// The highest value the sensor can report.
+    pub range_max: i16,
+    /// The lowest value in the normal operating range.
+    pub normal_min: i16,
+    /// The highest value in the normal operating range.
+    pub normal_max: i16,
+    /// Bit 0 is set if the sensor supports recording values (RECORD_SENSORS).
+    /// Bit 1 is set if the sensor tracks its lowest and highest detected values.
+    pub recorded_value_support: u8,
+    /// The label of the sensor. Labels longer than 32 bytes are truncated when serialized.
+    pub description: S,
+}
+
+impl SensorDefinition {
+    pub fn deserialize(buffer: &[u8]) -> Result<Self, DeserializationError> {
+        if !(RDM_SENSOR_DEFINITION_FIXED_SIZE..=RDM_MAX_SENSOR_DEFINITION_SIZE)
+            .contains(&buffer.len())
+        {
+            return Err(DeserializationError::WrongLength);
+        }
+
+        let sensor_definition_view = rdm_sensor_definition_layout::View::new(buffer);
+        let description = heapless::String::from_utf8(
+            heapless::Vec::from_slice(sensor_definition_view.description())
+                .or(Err(DeserializationError::WrongLength))?,
+        )
+        .or(Err(DeserializationError::InvalidUtf8))?;
+
+        Ok(Self {
+            sensor_number: sensor_definition_view.sensor_number().read(),
+            sensor_type: sensor_definition_view.sensor_type().read(),
+            unit: sensor_definition_view.unit().read(),
+            prefix: sensor_definition_view.prefix().read(),
+            range_min: sensor_definition_view.range_min().read(),
+            range_max: sensor_definition_view.range_max().read(),
+            normal_min: sensor_definition_view.normal_min().read(),
+            normal_max: sensor_definition_view.normal_max().read(),
+            recorded_value_support: sensor_definition_view.recorded_value_support().read(),
+            description,
+        })
+    }
+}
+
+impl<S: AsRef<str>> SensorDefinition<S> {
+    pub fn serialize(&self) -> DataPack {
+        let mut resp_buffer = [0u8; RDM_MAX_SENSOR_DEFINITION_SIZE];
+        let mut sensor_definition_view = rdm_sensor_definition_layout::View::new(&mut resp_buffer);
+
+        sensor_definition_view
+            .sensor_number_mut()
+            .write(self.sensor_number);
+        sensor_definition_view
+            .sensor_type_mut()
+            .write(self.sensor_type);
+        sensor_definition_view.unit_mut().write(self.unit);
+        sensor_definition_view.prefix_mut().write(self.prefix);
+        sensor_definition_view.range_min_mut().write(self.range_min);
+        sensor_definition_view.range_max_mut().write(self.range_max);
+        sensor_definition_view
+            .normal_min_mut()
+            .write(self.normal_min);
+        sensor_definition_view
+            .normal_max_mut()
+            .write(self.normal_max);
+        sensor_definition_view
+            .recorded_value_support_mut()
+            .write(self.recorded_value_support);
+
+        let description = self.description.as_ref().as_bytes();
+        let description_length = description
+            .len()
+            .min(sensor_definition_view.description().len());
+        sensor_definition_view.description_mut()[..description_length]
+            .copy_from_slice(&description[..description_length]);
+
+        DataPack::from_slice(&resp_buffer[..RDM_SENSOR_DEFINITION_FIXED_SIZE + description_length])
+            .unwrap()
+    }
+}
+
+/// The current value of a sensor (SENSOR_VALUE).
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SensorValue {
+    /// The number of the sensor. Sensors are numbered starting at 0.
+    pub sensor_number: u8,
+    /// The current value of the sensor.
+    pub present_value: i16,
+    /// The lowest value that was detected since the last reset.
+    pub lowest_detected_value: i16,
+    /// The highest value that was detected since the last reset.
+    pub highest_detected_value: i16,
+    /// The value that was recorded by the last RECORD_SENSORS request.
+    pub recorded_value: i16,
+}
+
+impl SensorValue {
+    /// Creates a sensor value with all values set to 0.
+    pub fn new(sensor_number: u8) -> Self {
+        Self {
+            sensor_number,
+            present_value: 0,
+            lowest_detected_value: 0,
+            highest_detected_value: 0,
+            recorded_value: 0,
+        }
+    }
+
+    /// Creates the sensor value of a defined sensor.
+    ///
+    /// No value has been detected yet, so the lowest and highest detected values start at
+    /// the opposite ends of the range of the sensor and are both set by the first update.
+    pub fn from_definition<S>(definition: &SensorDefinition<S>) -> Self {
+        Self {
+            sensor_number: definition.sensor_number,
+            present_value: 0,
+            lowest_detected_value: definition.range_max,
+            highest_detected_value: definition.range_min,
+            recorded_value: 0,
+        }
+    }
+
+    /// Set the present value and update the lowest and highest detected values.
+    pub fn update(&mut self, present_value: i16) {
+        self.present_value = present_value;
+
+        // the lowest detected value is only greater than the highest one if no value
+        // has been detected yet
+        if self.lowest_detected_value > self.highest_detected_value {
+            self.lowest_detected_value = present_value;
+            self.highest_detected_value = present_value;
+            return;
+        }
+
+        self.lowest_detected_value = self.lowest_detected_value.min(present_value);
+        self.highest_detected_value = self.highest_detected_value.max(present_value);
+    }
+
+    /// Reset the lowest, highest and recorded values to the present value.
+    pub fn reset(&mut self) {
+        self.lowest_detected_value = self.present_value;
+        self.highest_detected_value = self.present_value;
+        self.recorded_value = self.present_value;
+    }
+
+    pub fn deserialize(buffer: &[u8]) -> Result<Self, DeserializationError> {
+        if buffer.len() != RDM_SENSOR_VALUE_SIZE {
+            return Err(DeserializationError::WrongLength);
+        }
+
+        let sensor_value_view = rdm_sensor_value_layout::View::new(buffer);
+
+        Ok(Self {
+            sensor_number: sensor_value_view.sensor_number().read(),
+            present_value: sensor_value_view.present_value().read(),
+            lowest_detected_value: sensor_value_view.lowest_detected_value().read(),
+            highest_detected_value: sensor_value_view.highest_detected_value().read(),
+            recorded_value: sensor_value_view.recorded_value().read(),
+        })
+    }
+
+    pub fn serialize(&self) -> [u8; RDM_SENSOR_VALUE_SIZE] {
+        let mut resp_buffer = [0u8; RDM_SENSOR_VALUE_SIZE];
+        let mut sensor_value_view = rdm_sensor_value_layout::View::new(&mut resp_buffer);
+
+        sensor_value_view
+            .sensor_number_mut()
+            .write(self.sensor_number);
+        sensor_value_view
+            .present_value_mut()
+            .write(self.present_value);
+        sensor_value_view
+            .lowest_detected_value_mut()
+            .write(self.lowest_detected_value);
+        sensor_value_view
+            .highest_detected_value_mut()
+            .write(self.highest_detected_value);
+        sensor_value_view
+            .recorded_value_mut()
+            .write(self.recorded_value);
+
+        resp_buffer
+    }
+}
+
 /// Returned by parameter packages where the response might not fit into one package.
 pub enum OverflowMessageResp<T> {
     /// Has received the complete message.
@@ -315,3 +1406,356 @@ pub enum OverflowMessageResp<T> {
     /// Request the same pid to get the next part until you receive [OverflowMessageResp::Complete].
     Incomplete(T),
 }
+
+#[cfg(test)]
+#[cfg_attr(not(feature = "std"), allow(unused_imports))]
+mod tests {
+    use crate::consts::{RDM_MAX_PARAMETER_DESCRIPTION_SIZE, RDM_PARAMETER_DESCRIPTION_FIXED_SIZE};
+    use crate::rdm_packages::{
+        deserialize_device_label, deserialize_power_state, deserialize_statistics_counter,
+    };
+    use crate::rdm_types::{
+        CommsStatus, DefaultSlotValue, DeserializationError, DiscoveryMuteResponse,
+        DmxStartAddress, DmxStartAddressError, ParameterDescription, PidCommandClassSupport,
+        PowerState, PresetPlayback, RdmDataType, RdmParameter, SensorDefinition, SensorValue,
+        StatusType,
+    };
+    use crate::unique_identifier::UniqueIdentifier;
+
+    #[test]
+    fn test_deserialization_error_variants() {
+        assert_eq!(
+            CommsStatus::deserialize(&[0x00; 5]),
+            Err(DeserializationError::WrongLength)
+        );
+        assert_eq!(
+            deserialize_statistics_counter(&[0x00; 3]),
+            Err(DeserializationError::WrongLength)
+        );
+        assert_eq!(
+            <DmxStartAddress as RdmParameter>::deserialize(&[0x02, 0x01]),
+            Err(DeserializationError::OutOfRange)
+        );
+        assert_eq!(
+            StatusType::try_from(0x05),
+            Err(DeserializationError::InvalidEnumValue(0x05))
+        );
+        assert_eq!(
+            deserialize_power_state(&[0x03]),
+            Err(DeserializationError::InvalidEnumValue(0x03))
+        );
+        assert_eq!(
+            DiscoveryMuteResponse::deserialize(&[0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]),
+            Err(DeserializationError::InvalidUid)
+        );
+        assert_eq!(
+            "1:FFFFFFFF".parse::<UniqueIdentifier>(),
+            Err(DeserializationError::InvalidUid)
+        );
+        assert_eq!(
+            deserialize_device_label(&[0x41, 0xFF]),
+            Err(DeserializationError::InvalidUtf8)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_discovery_mute_response_display() {
+        let discovery_mute_response = DiscoveryMuteResponse {
+            managed_proxy: true,
+            boot_loader: true,
+            binding_uid: Some(UniqueIdentifier::new(0x7FF0, 1).unwrap()),
+            ..Default::default()
+        };
+        assert!(!discovery_mute_response.is_plain_device());
+        assert_eq!(
+            discovery_mute_response.to_string(),
+            "managed_proxy, boot_loader, binding uid 7FF0:00000001"
+        );
+
+        let discovery_mute_response = DiscoveryMuteResponse {
+            sub_device: true,
+            ..Default::default()
+        };
+        assert_eq!(discovery_mute_response.to_string(), "sub_device");
+
+        assert!(DiscoveryMuteResponse::default().is_plain_device());
+        assert_eq!(DiscoveryMuteResponse::default().to_string(), "plain device");
+    }
+
+    fn assert_parameter_round_trip<P: RdmParameter + PartialEq + core::fmt::Debug>(parameter: P) {
+        let parameter_data = parameter.serialize();
+        assert_eq!(P::deserialize(&parameter_data).unwrap(), parameter);
+        assert!(P::deserialize(&parameter_data[..parameter_data.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_rdm_parameter() {
+        assert_parameter_round_trip(DmxStartAddress::Address(0x0120));
+        assert_parameter_round_trip(PresetPlayback {
+            scene: 3,
+            level: 0x80,
+        });
+    }
+
+    #[test]
+    fn test_default_slot_value_round_trip() {
+        let default_slot_value = DefaultSlotValue {
+            slot_offset: 0x0102,
+            default_value: 0x7F,
+        };
+
+        assert_eq!(default_slot_value.serialize(), [0x01, 0x02, 0x7F]);
+        assert_eq!(
+            DefaultSlotValue::deserialize(&default_slot_value.serialize()).unwrap(),
+            default_slot_value
+        );
+        assert!(DefaultSlotValue::deserialize(&[0x01, 0x02]).is_err());
+    }
+
+    #[test]
+    fn test_power_state_round_trip() {
+        for (power_state, value) in [
+            (PowerState::FullOff, 0x00),
+            (PowerState::Shutdown, 0x01),
+            (PowerState::Standby, 0x02),
+            (PowerState::Normal, 0xFF),
+        ] {
+            assert_eq!(power_state as u8, value);
+            assert_eq!(PowerState::try_from(value).unwrap(), power_state);
+        }
+
+        assert!(PowerState::try_from(0x03).is_err());
+        assert!(PowerState::try_from(0xFE).is_err());
+    }
+
+    #[test]
+    fn test_preset_playback_round_trip() {
+        let preset_playback = PresetPlayback {
+            scene: 0x0102,
+            level: 0xFF,
+        };
+
+        assert_eq!(preset_playback.serialize(), [0x01, 0x02, 0xFF]);
+        assert_eq!(
+            PresetPlayback::deserialize(&preset_playback.serialize()).unwrap(),
+            preset_playback
+        );
+        assert!(PresetPlayback::deserialize(&[0x01, 0x02]).is_err());
+    }
+
+    #[test]
+    fn test_dmx_start_address_deserialize() {
+        assert_eq!(
+            DmxStartAddress::deserialize(&[0x00, 0x01]),
+            Ok(DmxStartAddress::Address(1))
+        );
+        assert_eq!(
+            DmxStartAddress::deserialize(&[0x02, 0x00]),
+            Ok(DmxStartAddress::Address(512))
+        );
+        assert_eq!(
+            DmxStartAddress::deserialize(&[0xFF, 0xFF]),
+            Ok(DmxStartAddress::NoAddress)
+        );
+        assert_eq!(
+            DmxStartAddress::deserialize(&[0x00, 0x00]),
+            Err(DmxStartAddressError::OutOfRange)
+        );
+        assert_eq!(
+            DmxStartAddress::deserialize(&[0x02, 0x01]),
+            Err(DmxStartAddressError::OutOfRange)
+        );
+        assert_eq!(
+            DmxStartAddress::deserialize(&[0x01]),
+            Err(DmxStartAddressError::WrongLength)
+        );
+        assert_eq!(
+            DmxStartAddress::deserialize(&[0x00, 0x01, 0x00]),
+            Err(DmxStartAddressError::WrongLength)
+        );
+    }
+
+    #[test]
+    fn test_status_type_round_trip() {
+        for status_type in [
+            StatusType::StatusNone,
+            StatusType::StatusGetLastMessage,
+            StatusType::StatusAdvisory,
+            StatusType::StatusWarning,
+            StatusType::StatusError,
+            StatusType::StatusAdvisoryCleared,
+            StatusType::StatusWarningCleared,
+            StatusType::StatusErrorCleared,
+        ] {
+            assert_eq!(
+                StatusType::deserialize(&[status_type as u8]).unwrap(),
+                status_type
+            );
+        }
+    }
+
+    #[test]
+    fn test_parameter_description_round_trip() {
+        let mut parameter_description = ParameterDescription {
+            pid: 0x8001,
+            pdl_size: 2,
+            data_type: RdmDataType::SignedWord,
+            command_class: PidCommandClassSupport::GetSet,
+            unit: 0x01,
+            prefix: 0x00,
+            min_valid_value: 0,
+            max_valid_value: 1000,
+            default_value: 20,
+            description: heapless::String::new(),
+        };
+
+        let serialized = parameter_description.serialize();
+        assert_eq!(serialized.len(), RDM_PARAMETER_DESCRIPTION_FIXED_SIZE);
+        assert_eq!(&serialized[..4], &[0x80, 0x01, 2, 0x06]);
+        assert_eq!(
+            ParameterDescription::deserialize(&serialized).unwrap(),
+            parameter_description
+        );
+
+        parameter_description
+            .description
+            .push_str("Fan speed in percent of maximum")
+            .unwrap();
+        let serialized = parameter_description.serialize();
+        assert_eq!(
+            &serialized[RDM_PARAMETER_DESCRIPTION_FIXED_SIZE..],
+            b"Fan speed in percent of maximum"
+        );
+        assert_eq!(
+            ParameterDescription::deserialize(&serialized).unwrap(),
+            parameter_description
+        );
+
+        // static labels that are too long are truncated
+        let parameter_description = ParameterDescription {
+            pid: 0x8001,
+            pdl_size: 2,
+            data_type: RdmDataType::SignedWord,
+            command_class: PidCommandClassSupport::GetSet,
+            unit: 0x01,
+            prefix: 0x00,
+            min_valid_value: 0,
+            max_valid_value: 1000,
+            default_value: 20,
+            description: "Fan speed in percent of the maximum",
+        };
+        let serialized = parameter_description.serialize();
+        assert_eq!(
+            &serialized[RDM_PARAMETER_DESCRIPTION_FIXED_SIZE..],
+            b"Fan speed in percent of the maxi"
+        );
+
+        ParameterDescription::deserialize(&[0u8; RDM_PARAMETER_DESCRIPTION_FIXED_SIZE - 1])
+            .unwrap_err();
+        ParameterDescription::deserialize(&[0u8; RDM_MAX_PARAMETER_DESCRIPTION_SIZE + 1])
+            .unwrap_err();
+    }
+
+    #[test]
+    fn test_sensor_round_trip() {
+        let sensor_definition = SensorDefinition {
+            sensor_number: 1,
+            sensor_type: 0x00,
+            unit: 0x01,
+            prefix: 0x00,
+            range_min: -40,
+            range_max: 120,
+            normal_min: 0,
+            normal_max: 80,
+            recorded_value_support: 0x03,
+            description: heapless::String::try_from("Temperature").unwrap(),
+        };
+        assert_eq!(
+            SensorDefinition::deserialize(&sensor_definition.serialize()).unwrap(),
+            sensor_definition
+        );
+
+        let sensor_value = SensorValue {
+            sensor_number: 1,
+            present_value: -12,
+            lowest_detected_value: -20,
+            highest_detected_value: 30,
+            recorded_value: 5,
+        };
+        assert_eq!(
+            SensorValue::deserialize(&sensor_value.serialize()).unwrap(),
+            sensor_value
+        );
+    }
+
+    #[test]
+    fn test_sensor_value_positive_range() {
+        let sensor_definition = SensorDefinition {
+            sensor_number: 0,
+            sensor_type: 0x00,
+            unit: 0x01,
+            prefix: 0x00,
+            range_min: 20,
+            range_max: 100,
+            normal_min: 40,
+            normal_max: 80,
+            recorded_value_support: 0x02,
+            description: "Temperature",
+        };
+
+        let mut sensor_value = SensorValue::from_definition(&sensor_definition);
+        sensor_value.update(50);
+        assert_eq!(sensor_value.lowest_detected_value, 50);
+        assert_eq!(sensor_value.highest_detected_value, 50);
+
+        sensor_value.update(60);
+        sensor_value.update(45);
+        assert_eq!(sensor_value.present_value, 45);
+        assert_eq!(sensor_value.lowest_detected_value, 45);
+        assert_eq!(sensor_value.highest_detected_value, 60);
+
+        // values outside of the range still seed the detected values
+        let mut sensor_value = SensorValue::from_definition(&sensor_definition);
+        sensor_value.update(120);
+        assert_eq!(sensor_value.lowest_detected_value, 120);
+        assert_eq!(sensor_value.highest_detected_value, 120);
+    }
+
+    #[test]
+    fn test_rdm_data_type_mapping() {
+        for (value, data_type) in [
+            (0x00, RdmDataType::NotDefined),
+            (0x01, RdmDataType::BitField),
+            (0x02, RdmDataType::Ascii),
+            (0x03, RdmDataType::UnsignedByte),
+            (0x04, RdmDataType::SignedByte),
+            (0x05, RdmDataType::UnsignedWord),
+            (0x06, RdmDataType::SignedWord),
+            (0x07, RdmDataType::UnsignedDword),
+            (0x08, RdmDataType::SignedDword),
+            (0x80, RdmDataType::ManufacturerSpecific(0x80)),
+            (0xDF, RdmDataType::ManufacturerSpecific(0xDF)),
+        ] {
+            assert_eq!(RdmDataType::try_from(value).unwrap(), data_type);
+            assert_eq!(u8::from(data_type), value);
+        }
+
+        RdmDataType::try_from(0x09).unwrap_err();
+        RdmDataType::try_from(0xE0).unwrap_err();
+
+        for (value, command_class) in [
+            (0x01, PidCommandClassSupport::Get),
+            (0x02, PidCommandClassSupport::Set),
+            (0x03, PidCommandClassSupport::GetSet),
+        ] {
+            assert_eq!(
+                PidCommandClassSupport::try_from(value).unwrap(),
+                command_class
+            );
+            assert_eq!(command_class as u8, value);
+        }
+
+        PidCommandClassSupport::try_from(0x00).unwrap_err();
+    }
+}
@@ -0,0 +1,504 @@
+use crate::dmx_controller::{DmxController, RdmResponseError};
+use crate::dmx_driver::DmxControllerDriver;
+use crate::dmx_universe::{DmxUniverse, DMX_UNIVERSE_SIZE};
+use crate::rdm_types::DeserializationError;
+use std::net::{Ipv4Addr, UdpSocket};
+use std::time::{Duration, Instant};
+
+/// The udp port sACN is sent to.
+pub const SACN_PORT: u16 = 5568;
+/// A source that didn't send any data for this long is considered offline.
+pub const SACN_SOURCE_TIMEOUT: Duration = Duration::from_millis(2500);
+/// The priority that is used if a source doesn't specify a different one.
+pub const SACN_DEFAULT_PRIORITY: u8 = 100;
+
+const ACN_PACKET_IDENTIFIER: [u8; 12] = *b"ASC-E1.17\0\0\0";
+const VECTOR_ROOT_E131_DATA: u32 = 0x0000_0004;
+const VECTOR_E131_DATA_PACKET: u32 = 0x0000_0002;
+const VECTOR_DMP_SET_PROPERTY: u8 = 0x02;
+const DMP_ADDRESS_AND_DATA_TYPE: u8 = 0xA1;
+const DATA_PACKET_HEADER_SIZE: usize = 126;
+const MAX_DATA_PACKET_SIZE: usize = DATA_PACKET_HEADER_SIZE + DMX_UNIVERSE_SIZE;
+const OPTION_PREVIEW_DATA: u8 = 0x80;
+const OPTION_STREAM_TERMINATED: u8 = 0x40;
+/// Packets that are up to this many sequence numbers behind the last one are discarded.
+const SEQUENCE_NUMBER_WINDOW: i8 = -20;
+
+/// Returns the multicast address a universe is sent to.
+pub fn universe_multicast_address(universe: u16) -> Ipv4Addr {
+    let [high, low] = universe.to_be_bytes();
+    Ipv4Addr::new(239, 255, high, low)
+}
+
+/// An E1.31 data packet.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SacnDataPacket {
+    /// The component identifier that identifies the source.
+    pub cid: [u8; 16],
+    /// The user assigned name of the source.
+    pub source_name: heapless::String<64>,
+    /// The priority of the data (0-200).
+    pub priority: u8,
+    pub sequence_number: u8,
+    pub options: u8,
+    pub universe: u16,
+    pub start_code: u8,
+    /// The dmx slots after the start code.
+    pub data: heapless::Vec<u8, DMX_UNIVERSE_SIZE>,
+}
+
+impl SacnDataPacket {
+    /// Deserializes an E1.31 data packet.
+    pub fn deserialize(buffer: &[u8]) -> Result<Self, DeserializationError> {
+        if buffer.len() < DATA_PACKET_HEADER_SIZE || buffer.len() > MAX_DATA_PACKET_SIZE {
+            return Err(DeserializationError::WrongLength);
+        }
+
+        let read_u16 = |index: usize| u16::from_be_bytes([buffer[index], buffer[index + 1]]);
+        let read_u32 =
+            |index: usize| u32::from_be_bytes(buffer[index..index + 4].try_into().unwrap());
+        // The length is counted from the flags and length field to the end of the packet.
+        let pdu_length_matches =
+            |index: usize| (read_u16(index) & 0x0FFF) as usize == buffer.len() - index;
+
+        if read_u16(0) != 0x0010
+            || read_u16(2) != 0x0000
+            || buffer[4..16] != ACN_PACKET_IDENTIFIER
+            || !pdu_length_matches(16)
+            || read_u32(18) != VECTOR_ROOT_E131_DATA
+            || !pdu_length_matches(38)
+            || read_u32(40) != VECTOR_E131_DATA_PACKET
+            || !pdu_length_matches(115)
+            || buffer[117] != VECTOR_DMP_SET_PROPERTY
+            || buffer[118] != DMP_ADDRESS_AND_DATA_TYPE
+            || read_u16(119) != 0x0000
+            || read_u16(121) != 0x0001
+            || read_u16(123) as usize != buffer.len() - 125
+        {
+            return Err(DeserializationError::InvalidFormat);
+        }
+
+        let source_name_bytes = &buffer[44..108];
+        let source_name_length = source_name_bytes
+            .iter()
+            .position(|&byte| byte == 0)
+            .unwrap_or(source_name_bytes.len());
+        let source_name = core::str::from_utf8(&source_name_bytes[..source_name_length])
+            .map_err(|_| DeserializationError::InvalidUtf8)?;
+
+        Ok(Self {
+            cid: buffer[22..38].try_into().unwrap(),
+            source_name: heapless::String::try_from(source_name)
+                .map_err(|_| DeserializationError::WrongLength)?,
+            priority: buffer[108],
+            sequence_number: buffer[111],
+            options: buffer[112],
+            universe: read_u16(113),
+            start_code: buffer[125],
+            data: heapless::Vec::from_slice(&buffer[126..]).unwrap(),
+        })
+    }
+}
+
+/// Raised by [Sacn1Receiver::receive_and_forward].
+#[derive(Debug)]
+pub enum SacnError<E> {
+    /// The socket raised an error.
+    Io(std::io::Error),
+    /// The received datagram isn't a valid E1.31 packet.
+    DeserializationError(DeserializationError),
+    /// The controller couldn't send the universe.
+    ControllerError(RdmResponseError<E>),
+}
+
+impl<E: core::fmt::Debug> core::fmt::Display for SacnError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SacnError::Io(error) => error.fmt(f),
+            SacnError::DeserializationError(error) => error.fmt(f),
+            SacnError::ControllerError(error) => error.fmt(f),
+        }
+    }
+}
+
+impl<E: core::fmt::Debug + core::fmt::Display> std::error::Error for SacnError<E> {}
+
+impl<E> From<std::io::Error> for SacnError<E> {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl<E> From<DeserializationError> for SacnError<E> {
+    fn from(value: DeserializationError) -> Self {
+        Self::DeserializationError(value)
+    }
+}
+
+struct SacnSource {
+    cid: [u8; 16],
+    priority: u8,
+    sequence_number: u8,
+    universe: DmxUniverse,
+    last_received: Instant,
+}
+
+/// Receives a universe via sACN (E1.31) and forwards it to a [DmxController].
+///
+/// Sources with the highest priority win. If multiple sources share the highest priority,
+/// their channels are merged by using the highest value (HTP).
+pub struct Sacn1Receiver {
+    socket: UdpSocket,
+    universe: u16,
+    sources: Vec<SacnSource>,
+    merged_universe: DmxUniverse,
+    missed_packets: u32,
+}
+
+impl Sacn1Receiver {
+    /// Binds to the sACN port and joins the multicast group of the universe.
+    pub fn bind(universe: u16) -> std::io::Result<Self> {
+        let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, SACN_PORT))?;
+        socket.join_multicast_v4(
+            &universe_multicast_address(universe),
+            &Ipv4Addr::UNSPECIFIED,
+        )?;
+
+        Ok(Self::from_socket(socket, universe))
+    }
+
+    /// Creates a receiver from an already bound socket, e.g. for receiving unicast sACN.
+    pub fn from_socket(socket: UdpSocket, universe: u16) -> Self {
+        Self {
+            socket,
+            universe,
+            sources: Vec::new(),
+            merged_universe: DmxUniverse::new(),
+            missed_packets: 0,
+        }
+    }
+
+    /// Get a reference to the underlying socket.
+    pub fn get_socket(&self) -> &UdpSocket {
+        &self.socket
+    }
+
+    /// Get the merged universe of all active sources.
+    pub fn get_universe(&self) -> &DmxUniverse {
+        &self.merged_universe
+    }
+
+    /// Get the amount of currently active sources.
+    pub fn get_source_count(&self) -> usize {
+        self.sources.len()
+    }
+
+    /// Get the amount of packets that got lost according to gaps in the sequence numbers.
+    pub fn get_missed_packets(&self) -> u32 {
+        self.missed_packets
+    }
+
+    /// Handles a received datagram.
+    ///
+    /// Packets for other universes, preview data, packets with a non-null start code and
+    /// out of order packets are ignored.
+    /// Returns true if the merged universe was updated.
+    pub fn handle_packet(
+        &mut self,
+        packet: &[u8],
+        now: Instant,
+    ) -> Result<bool, DeserializationError> {
+        let packet = SacnDataPacket::deserialize(packet)?;
+
+        self.sources
+            .retain(|source| now.duration_since(source.last_received) < SACN_SOURCE_TIMEOUT);
+
+        if packet.universe != self.universe
+            || packet.options & OPTION_PREVIEW_DATA != 0
+            || packet.start_code != 0x00
+        {
+            return Ok(false);
+        }
+
+        let source_index = self
+            .sources
+            .iter()
+            .position(|source| source.cid == packet.cid);
+
+        if packet.options & OPTION_STREAM_TERMINATED != 0 {
+            if let Some(source_index) = source_index {
+                self.sources.remove(source_index);
+                self.merge_sources();
+                return Ok(true);
+            }
+
+            return Ok(false);
+        }
+
+        let mut universe = DmxUniverse::new();
+        universe.as_mut_slice()[..packet.data.len()].copy_from_slice(&packet.data);
+
+        match source_index {
+            Some(source_index) => {
+                let source = &mut self.sources[source_index];
+                let sequence_difference =
+                    packet.sequence_number.wrapping_sub(source.sequence_number) as i8;
+                if sequence_difference <= 0 && sequence_difference > SEQUENCE_NUMBER_WINDOW {
+                    return Ok(false);
+                }
+                if sequence_difference > 1 {
+                    self.missed_packets = self
+                        .missed_packets
+                        .saturating_add(sequence_difference as u32 - 1);
+                }
+
+                source.priority = packet.priority;
+                source.sequence_number = packet.sequence_number;
+                source.universe = universe;
+                source.last_received = now;
+            },
+            None => self.sources.push(SacnSource {
+                cid: packet.cid,
+                priority: packet.priority,
+                sequence_number: packet.sequence_number,
+                universe,
+                last_received: now,
+            }),
+        }
+
+        self.merge_sources();
+
+        Ok(true)
+    }
+
+    fn merge_sources(&mut self) {
+        let highest_priority = self.sources.iter().map(|source| source.priority).max();
+
+        self.merged_universe = DmxUniverse::new();
+        for source in self
+            .sources
+            .iter()
+            .filter(|source| Some(source.priority) == highest_priority)
+        {
+            for (merged, &value) in self
+                .merged_universe
+                .as_mut_slice()
+                .iter_mut()
+                .zip(source.universe.as_slice())
+            {
+                *merged = (*merged).max(value);
+            }
+        }
+    }
+
+    /// Receives one datagram and sends the merged universe using the controller if it was updated.
+    /// Returns true if the universe was sent.
+    pub fn receive_and_forward<D: DmxControllerDriver>(
+        &mut self,
+        controller: &mut DmxController<D>,
+    ) -> Result<bool, SacnError<D::DriverError>> {
+        let mut buffer = [0u8; MAX_DATA_PACKET_SIZE];
+        let received_bytes = self.socket.recv(&mut buffer)?;
+
+        if !self.handle_packet(&buffer[..received_bytes], Instant::now())? {
+            return Ok(false);
+        }
+
+        controller
+            .send_dmx_package(self.merged_universe.as_slice())
+            .map_err(SacnError::ControllerError)?;
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dmx_controller::{DmxController, DmxControllerConfig};
+    use crate::dmx_driver::{ControllerDriverErrorDef, DmxControllerDriver, DmxError};
+    use crate::sacn::{
+        Sacn1Receiver, SacnDataPacket, OPTION_STREAM_TERMINATED, SACN_DEFAULT_PRIORITY,
+        SACN_SOURCE_TIMEOUT,
+    };
+    use std::net::UdpSocket;
+    use std::time::{Duration, Instant};
+
+    fn build_packet(
+        cid: u8,
+        priority: u8,
+        sequence_number: u8,
+        options: u8,
+        universe: u16,
+        data: &[u8],
+    ) -> Vec<u8> {
+        let mut packet = Vec::new();
+        let total_length = 126 + data.len();
+        let flags_and_length =
+            |index: usize| (0x7000 | (total_length - index) as u16).to_be_bytes();
+
+        packet.extend_from_slice(&[0x00, 0x10, 0x00, 0x00]);
+        packet.extend_from_slice(b"ASC-E1.17\0\0\0");
+        packet.extend_from_slice(&flags_and_length(16));
+        packet.extend_from_slice(&0x0000_0004u32.to_be_bytes());
+        packet.extend_from_slice(&[cid; 16]);
+
+        packet.extend_from_slice(&flags_and_length(38));
+        packet.extend_from_slice(&0x0000_0002u32.to_be_bytes());
+        let mut source_name = [0u8; 64];
+        source_name[..6].copy_from_slice(b"Source");
+        packet.extend_from_slice(&source_name);
+        packet.push(priority);
+        packet.extend_from_slice(&[0x00, 0x00]);
+        packet.push(sequence_number);
+        packet.push(options);
+        packet.extend_from_slice(&universe.to_be_bytes());
+
+        packet.extend_from_slice(&flags_and_length(115));
+        packet.extend_from_slice(&[0x02, 0xA1, 0x00, 0x00, 0x00, 0x01]);
+        packet.extend_from_slice(&(data.len() as u16 + 1).to_be_bytes());
+        packet.push(0x00);
+        packet.extend_from_slice(data);
+
+        packet
+    }
+
+    fn build_receiver() -> Sacn1Receiver {
+        Sacn1Receiver::from_socket(UdpSocket::bind("127.0.0.1:0").unwrap(), 1)
+    }
+
+    #[test]
+    fn test_deserialize_data_packet() {
+        let packet = SacnDataPacket::deserialize(&build_packet(
+            0x01,
+            SACN_DEFAULT_PRIORITY,
+            7,
+            0,
+            1,
+            &[1, 2, 3],
+        ))
+        .unwrap();
+
+        assert_eq!(packet.cid, [0x01; 16]);
+        assert_eq!(packet.source_name, "Source");
+        assert_eq!(packet.priority, SACN_DEFAULT_PRIORITY);
+        assert_eq!(packet.sequence_number, 7);
+        assert_eq!(packet.universe, 1);
+        assert_eq!(&packet.data[..], &[1, 2, 3]);
+
+        let mut corrupted_packet = build_packet(0x01, SACN_DEFAULT_PRIORITY, 7, 0, 1, &[1]);
+        corrupted_packet[4] = b'B';
+        SacnDataPacket::deserialize(&corrupted_packet).unwrap_err();
+    }
+
+    #[test]
+    fn test_universe_filtering_and_sequence_numbers() {
+        let mut receiver = build_receiver();
+        let now = Instant::now();
+
+        assert!(!receiver
+            .handle_packet(&build_packet(0x01, 100, 0, 0, 2, &[255]), now)
+            .unwrap());
+        assert_eq!(receiver.get_source_count(), 0);
+
+        assert!(receiver
+            .handle_packet(&build_packet(0x01, 100, 10, 0, 1, &[10]), now)
+            .unwrap());
+        assert!(!receiver
+            .handle_packet(&build_packet(0x01, 100, 9, 0, 1, &[9]), now)
+            .unwrap());
+        assert_eq!(receiver.get_universe().get_channel(0), Some(10));
+
+        assert!(receiver
+            .handle_packet(&build_packet(0x01, 100, 13, 0, 1, &[13]), now)
+            .unwrap());
+        assert_eq!(receiver.get_universe().get_channel(0), Some(13));
+        assert_eq!(receiver.get_missed_packets(), 2);
+    }
+
+    #[test]
+    fn test_merge_sources() {
+        let mut receiver = build_receiver();
+        let now = Instant::now();
+
+        receiver
+            .handle_packet(&build_packet(0x01, 100, 0, 0, 1, &[10, 0, 30]), now)
+            .unwrap();
+        receiver
+            .handle_packet(&build_packet(0x02, 100, 0, 0, 1, &[5, 20]), now)
+            .unwrap();
+        assert_eq!(&receiver.get_universe().as_slice()[..3], &[10, 20, 30]);
+
+        receiver
+            .handle_packet(&build_packet(0x03, 150, 0, 0, 1, &[1]), now)
+            .unwrap();
+        assert_eq!(&receiver.get_universe().as_slice()[..3], &[1, 0, 0]);
+
+        receiver
+            .handle_packet(
+                &build_packet(0x03, 150, 1, OPTION_STREAM_TERMINATED, 1, &[1]),
+                now,
+            )
+            .unwrap();
+        assert_eq!(&receiver.get_universe().as_slice()[..3], &[10, 20, 30]);
+
+        receiver
+            .handle_packet(
+                &build_packet(0x02, 100, 1, 0, 1, &[5, 20]),
+                now + SACN_SOURCE_TIMEOUT - Duration::from_millis(1),
+            )
+            .unwrap();
+        receiver
+            .handle_packet(
+                &build_packet(0x02, 100, 2, 0, 1, &[5, 20]),
+                now + SACN_SOURCE_TIMEOUT,
+            )
+            .unwrap();
+        assert_eq!(receiver.get_source_count(), 1);
+        assert_eq!(&receiver.get_universe().as_slice()[..3], &[5, 20, 0]);
+    }
+
+    #[test]
+    fn test_loopback_forwarding() {
+        struct DmxDriver {
+            sent_package: Vec<u8>,
+        }
+
+        impl ControllerDriverErrorDef for DmxDriver {
+            type DriverError = ();
+        }
+
+        impl DmxControllerDriver for DmxDriver {
+            fn send_dmx_package(&mut self, package: &[u8]) -> Result<(), DmxError<()>> {
+                self.sent_package = package.to_vec();
+                Ok(())
+            }
+        }
+
+        let mut receiver = build_receiver();
+        receiver
+            .get_socket()
+            .set_read_timeout(Some(Duration::from_secs(1)))
+            .unwrap();
+        let receiver_address = receiver.get_socket().local_addr().unwrap();
+
+        let sender = UdpSocket::bind("127.0.0.1:0").unwrap();
+        sender
+            .send_to(
+                &build_packet(0x01, 100, 0, 0, 1, &[1, 2, 3]),
+                receiver_address,
+            )
+            .unwrap();
+
+        let mut controller = DmxController::new(
+            DmxDriver {
+                sent_package: Vec::new(),
+            },
+            &DmxControllerConfig::default(),
+        );
+        assert!(receiver.receive_and_forward(&mut controller).unwrap());
+
+        let sent_package = &controller.get_driver().sent_package;
+        assert_eq!(sent_package.len(), 512);
+        assert_eq!(&sent_package[..4], &[1, 2, 3, 0]);
+    }
+}
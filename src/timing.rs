@@ -0,0 +1,167 @@
+use crate::consts::{BREAK_MICROS, MAB_MICROS, SLOT_MICROS};
+
+/// The maximum time a responder is allowed to wait before it starts sending its response.
+pub const RDM_RESPONDER_TURNAROUND_MICROS: u32 = 2_000;
+/// The minimum break a transmitter has to send according to E1.11.
+pub const MIN_BREAK_MICROS: u32 = 88;
+/// The minimum mark after break a transmitter has to send according to E1.11.
+pub const MIN_MAB_MICROS: u32 = 8;
+
+/// Raised by [DmxTiming::new] if the timing violates E1.11.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DmxTimingError {
+    /// The break is shorter than [MIN_BREAK_MICROS].
+    BreakTooShort,
+    /// The mark after break is shorter than [MIN_MAB_MICROS].
+    MarkAfterBreakTooShort,
+}
+
+impl core::fmt::Display for DmxTimingError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DmxTimingError::BreakTooShort => {
+                write!(f, "the break must be at least {MIN_BREAK_MICROS}µs")
+            },
+            DmxTimingError::MarkAfterBreakTooShort => {
+                write!(
+                    f,
+                    "the mark after break must be at least {MIN_MAB_MICROS}µs"
+                )
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DmxTimingError {}
+
+/// The timing of the bus.
+///
+/// The break and mark after break are generated by the uart driver, which can read them using
+/// [DmxUartDriver::get_timing](crate::dmx_uart_driver::DmxUartDriver::get_timing).
+/// The RDM read timeout is the time the controller waits for a response.
+/// Use [TimedUartDriver](crate::dmx_uart_driver::TimedUartDriver) to change the timing of a driver.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DmxTiming {
+    break_us: u32,
+    mab_us: u32,
+    rdm_read_timeout_us: u32,
+}
+
+impl DmxTiming {
+    /// A break of 200µs, a mark after break of 48µs and an RDM read timeout of 2.8ms.
+    pub const DEFAULT: Self = Self {
+        break_us: BREAK_MICROS as u32,
+        mab_us: MAB_MICROS as u32,
+        rdm_read_timeout_us: 2_800,
+    };
+
+    /// Creates a new timing. Raises an error if the break or the mark after break are shorter
+    /// than allowed by E1.11.
+    pub const fn new(
+        break_us: u32,
+        mab_us: u32,
+        rdm_read_timeout_us: u32,
+    ) -> Result<Self, DmxTimingError> {
+        if break_us < MIN_BREAK_MICROS {
+            return Err(DmxTimingError::BreakTooShort);
+        }
+
+        if mab_us < MIN_MAB_MICROS {
+            return Err(DmxTimingError::MarkAfterBreakTooShort);
+        }
+
+        Ok(Self {
+            break_us,
+            mab_us,
+            rdm_read_timeout_us,
+        })
+    }
+
+    /// The length of the break in microseconds.
+    pub const fn get_break_us(&self) -> u32 {
+        self.break_us
+    }
+
+    /// The length of the mark after break in microseconds.
+    pub const fn get_mab_us(&self) -> u32 {
+        self.mab_us
+    }
+
+    /// The time the controller waits for an RDM response in microseconds.
+    pub const fn get_rdm_read_timeout_us(&self) -> u32 {
+        self.rdm_read_timeout_us
+    }
+
+    /// Returns the time it takes to send a frame of the given length (start code included)
+    /// including the break and the mark after break.
+    pub const fn frame_us(&self, frame_len: usize) -> u32 {
+        self.break_us + self.mab_us + frame_len as u32 * SLOT_MICROS
+    }
+}
+
+impl Default for DmxTiming {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Returns the time it takes to send a frame of the given length (start code included)
+/// including the break and the mark after break of [DmxTiming::DEFAULT].
+pub const fn frame_us(frame_len: usize) -> u32 {
+    DmxTiming::DEFAULT.frame_us(frame_len)
+}
+
+/// Estimates the worst case time of an RDM transaction, which consists of sending the request,
+/// waiting for the responder and receiving the response.
+///
+/// The lengths are the lengths of the serialized packages (see [RdmData::serialized_len](crate::rdm_data::RdmData::serialized_len)).
+/// Use 0 as `expected_response_len` for requests that won't be answered, like broadcasts.
+pub const fn estimate_transaction_us(request_len: usize, expected_response_len: usize) -> u32 {
+    if expected_response_len == 0 {
+        return frame_us(request_len);
+    }
+
+    frame_us(request_len) + RDM_RESPONDER_TURNAROUND_MICROS + frame_us(expected_response_len)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::timing::{estimate_transaction_us, DmxTiming, DmxTimingError};
+
+    #[test]
+    fn test_estimate_transaction_us() {
+        // get IDENTIFY_DEVICE: 26 bytes request, 27 bytes response
+        // (200 + 48 + 26 * 44) + 2000 + (200 + 48 + 27 * 44)
+        assert_eq!(estimate_transaction_us(26, 27), 4828);
+
+        // get with a full response: 26 bytes request, 257 bytes response
+        // (200 + 48 + 26 * 44) + 2000 + (200 + 48 + 257 * 44)
+        assert_eq!(estimate_transaction_us(26, 257), 14948);
+
+        // broadcast set with one byte of parameter data
+        assert_eq!(estimate_transaction_us(27, 0), 1436);
+    }
+
+    #[test]
+    fn test_dmx_timing() {
+        assert_eq!(DmxTiming::default(), DmxTiming::new(200, 48, 2800).unwrap());
+
+        let timing = DmxTiming::new(88, 8, 5000).unwrap();
+        assert_eq!(timing.get_break_us(), 88);
+        assert_eq!(timing.get_mab_us(), 8);
+        assert_eq!(timing.get_rdm_read_timeout_us(), 5000);
+        assert_eq!(timing.frame_us(1), 88 + 8 + 44);
+
+        assert_eq!(
+            DmxTiming::new(87, 48, 2800),
+            Err(DmxTimingError::BreakTooShort)
+        );
+        assert_eq!(
+            DmxTiming::new(200, 7, 2800),
+            Err(DmxTimingError::MarkAfterBreakTooShort)
+        );
+    }
+}
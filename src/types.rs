@@ -33,26 +33,75 @@ impl TryFrom<u8> for ResponseType {
     }
 }
 
+/// The reason of a [ResponseType::ResponseTypeNackReason] response.
+/// Use `u16::from` to get the value of the nack reason.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-#[repr(u16)]
 pub enum NackReason {
-    UnknownPid = 0x0000,
-    FormatError = 0x0001,
-    HardwareFault = 0x0002,
-    ProxyReject = 0x0003,
-    WriteProtect = 0x0004,
-    UnsupportedCommandClass = 0x0005,
-    DataOutOfRange = 0x0006,
-    BufferFull = 0x0007,
-    PacketSizeUnsupported = 0x0008,
-    SubDeviceOutOfRange = 0x0009,
-    ProxyBufferFull = 0x000A,
+    UnknownPid,
+    FormatError,
+    HardwareFault,
+    ProxyReject,
+    WriteProtect,
+    UnsupportedCommandClass,
+    DataOutOfRange,
+    BufferFull,
+    PacketSizeUnsupported,
+    SubDeviceOutOfRange,
+    ProxyBufferFull,
+    /// Defined by E1.37-7.
+    ActionNotSupported,
+    /// Defined by E1.37-7.
+    EndpointNumberInvalid,
+    /// Defined by E1.37-7.
+    InvalidEndpointMode,
+    /// Defined by E1.37-7.
+    UnknownUid,
+    /// Defined by E1.33.
+    UnknownScope,
+    /// Defined by E1.33.
+    InvalidStaticConfigType,
+    /// Defined by E1.33.
+    InvalidIpv4Address,
+    /// Defined by E1.33.
+    InvalidIpv6Address,
+    /// Defined by E1.33.
+    InvalidPort,
+    /// A manufacturer specific nack reason (0x8000-0xFFDF).
+    ManufacturerSpecific(u16),
 }
 
 impl NackReason {
     pub fn serialize(&self) -> DataPack {
-        DataPack::from_slice(&(*self as u16).to_be_bytes()).unwrap()
+        DataPack::from_slice(&u16::from(*self).to_be_bytes()).unwrap()
+    }
+}
+
+impl From<NackReason> for u16 {
+    fn from(value: NackReason) -> Self {
+        match value {
+            NackReason::UnknownPid => 0x0000,
+            NackReason::FormatError => 0x0001,
+            NackReason::HardwareFault => 0x0002,
+            NackReason::ProxyReject => 0x0003,
+            NackReason::WriteProtect => 0x0004,
+            NackReason::UnsupportedCommandClass => 0x0005,
+            NackReason::DataOutOfRange => 0x0006,
+            NackReason::BufferFull => 0x0007,
+            NackReason::PacketSizeUnsupported => 0x0008,
+            NackReason::SubDeviceOutOfRange => 0x0009,
+            NackReason::ProxyBufferFull => 0x000A,
+            NackReason::ActionNotSupported => 0x000B,
+            NackReason::EndpointNumberInvalid => 0x000C,
+            NackReason::InvalidEndpointMode => 0x000D,
+            NackReason::UnknownUid => 0x000E,
+            NackReason::UnknownScope => 0x000F,
+            NackReason::InvalidStaticConfigType => 0x0010,
+            NackReason::InvalidIpv4Address => 0x0011,
+            NackReason::InvalidIpv6Address => 0x0012,
+            NackReason::InvalidPort => 0x0013,
+            NackReason::ManufacturerSpecific(nack_reason) => nack_reason,
+        }
     }
 }
 
@@ -72,6 +121,16 @@ impl TryFrom<u16> for NackReason {
             0x0008 => Ok(Self::PacketSizeUnsupported),
             0x0009 => Ok(Self::SubDeviceOutOfRange),
             0x000A => Ok(Self::ProxyBufferFull),
+            0x000B => Ok(Self::ActionNotSupported),
+            0x000C => Ok(Self::EndpointNumberInvalid),
+            0x000D => Ok(Self::InvalidEndpointMode),
+            0x000E => Ok(Self::UnknownUid),
+            0x000F => Ok(Self::UnknownScope),
+            0x0010 => Ok(Self::InvalidStaticConfigType),
+            0x0011 => Ok(Self::InvalidIpv4Address),
+            0x0012 => Ok(Self::InvalidIpv6Address),
+            0x0013 => Ok(Self::InvalidPort),
+            0x8000..=0xFFDF => Ok(Self::ManufacturerSpecific(value)),
             _ => Err(()),
         }
     }
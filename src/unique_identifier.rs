@@ -18,6 +18,38 @@ impl core::fmt::Display for UniqueIdentifier {
     }
 }
 
+/// Parses the manufacturer and device id from the "MMMM:DDDDDDDD" format.
+fn parse_uid_parts(value: &str) -> Result<(u16, u32), DeserializationError> {
+    fn is_hex(part: &str, max_len: usize) -> bool {
+        !part.is_empty() && part.len() <= max_len && part.bytes().all(|c| c.is_ascii_hexdigit())
+    }
+
+    let (manufacturer_part, device_part) = value
+        .split_once(':')
+        .ok_or(DeserializationError::InvalidUid)?;
+    if !is_hex(manufacturer_part, 4) || !is_hex(device_part, 8) {
+        return Err(DeserializationError::InvalidUid);
+    }
+
+    let manufacturer_uid =
+        u16::from_str_radix(manufacturer_part, 16).map_err(|_| DeserializationError::InvalidUid)?;
+    let device_uid =
+        u32::from_str_radix(device_part, 16).map_err(|_| DeserializationError::InvalidUid)?;
+
+    Ok((manufacturer_uid, device_uid))
+}
+
+impl core::str::FromStr for UniqueIdentifier {
+    type Err = DeserializationError;
+
+    /// Parses a uid in the "MMMM:DDDDDDDD" format that is also used by [core::fmt::Display].
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (manufacturer_uid, device_uid) = parse_uid_parts(value)?;
+
+        Self::new(manufacturer_uid, device_uid)
+    }
+}
+
 #[cfg(feature = "defmt")]
 impl defmt::Format for UniqueIdentifier {
     fn format(&self, fmt: defmt::Formatter) {
@@ -28,7 +60,7 @@ impl defmt::Format for UniqueIdentifier {
 impl UniqueIdentifier {
     pub const fn new(manufacturer_uid: u16, device_uid: u32) -> Result<Self, DeserializationError> {
         if device_uid == u32::MAX || manufacturer_uid == u16::MAX {
-            return Err(DeserializationError);
+            return Err(DeserializationError::InvalidUid);
         }
 
         Ok(UniqueIdentifier {
@@ -50,7 +82,7 @@ impl UniqueIdentifier {
         manufacturer_uid: u16,
     ) -> Result<(), DeserializationError> {
         if manufacturer_uid == u16::MAX {
-            return Err(DeserializationError);
+            return Err(DeserializationError::InvalidUid);
         }
 
         self.manufacturer_uid = manufacturer_uid;
@@ -59,7 +91,7 @@ impl UniqueIdentifier {
 
     pub fn set_device_uid(&mut self, device_uid: u32) -> Result<(), DeserializationError> {
         if device_uid == u32::MAX {
-            return Err(DeserializationError);
+            return Err(DeserializationError::InvalidUid);
         }
 
         self.device_uid = device_uid;
@@ -84,7 +116,7 @@ impl TryFrom<u64> for UniqueIdentifier {
         let device_uid = (value & u32::MAX as u64) as u32;
 
         if device_uid == u32::MAX {
-            return Err(DeserializationError);
+            return Err(DeserializationError::InvalidUid);
         }
 
         Ok(Self {
@@ -100,6 +132,66 @@ impl From<UniqueIdentifier> for u64 {
     }
 }
 
+/// A [PackageAddress] that is guaranteed to address a single device.
+///
+/// Get requests are never answered if they are sent to a broadcast address,
+/// so the get methods of the [crate::dmx_controller::DmxController] only accept this type.
+///
+/// ```
+/// use dmx_rdm::dmx_controller::DmxController;
+/// use dmx_rdm::dmx_driver::RdmControllerDriver;
+/// use dmx_rdm::unique_identifier::UniqueIdentifier;
+///
+/// fn get_identify<D: RdmControllerDriver>(controller: &mut DmxController<D>) {
+///     let uid = UniqueIdentifier::new(0x7FF0, 1).unwrap();
+///     let _ = controller.rdm_get_identify(uid);
+/// }
+/// ```
+///
+/// A broadcast can't be passed to a get method:
+///
+/// ```compile_fail
+/// use dmx_rdm::dmx_controller::DmxController;
+/// use dmx_rdm::dmx_driver::RdmControllerDriver;
+/// use dmx_rdm::unique_identifier::PackageAddress;
+///
+/// fn get_identify<D: RdmControllerDriver>(controller: &mut DmxController<D>) {
+///     let _ = controller.rdm_get_identify(PackageAddress::Broadcast);
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct UnicastAddress(pub UniqueIdentifier);
+
+impl From<UniqueIdentifier> for UnicastAddress {
+    fn from(value: UniqueIdentifier) -> Self {
+        Self(value)
+    }
+}
+
+impl From<UnicastAddress> for UniqueIdentifier {
+    fn from(value: UnicastAddress) -> Self {
+        value.0
+    }
+}
+
+impl From<UnicastAddress> for PackageAddress {
+    fn from(value: UnicastAddress) -> Self {
+        Self::Device(value.0)
+    }
+}
+
+impl TryFrom<PackageAddress> for UnicastAddress {
+    type Error = DeserializationError;
+
+    fn try_from(value: PackageAddress) -> Result<Self, Self::Error> {
+        match value {
+            PackageAddress::Device(uid) => Ok(Self(uid)),
+            _ => Err(DeserializationError::InvalidUid),
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum PackageAddress {
@@ -153,12 +245,28 @@ impl PackageAddress {
     }
 }
 
+impl core::str::FromStr for PackageAddress {
+    type Err = DeserializationError;
+
+    /// Parses an address in the "MMMM:DDDDDDDD" format.
+    /// "FFFF:FFFFFFFF" is a broadcast and "MMMM:FFFFFFFF" is a manufacturer broadcast.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (manufacturer_uid, device_uid) = parse_uid_parts(value)?;
+
+        let mut buffer = [0u8; 6];
+        buffer[..2].copy_from_slice(&manufacturer_uid.to_be_bytes());
+        buffer[2..].copy_from_slice(&device_uid.to_be_bytes());
+
+        Ok(Self::from_bytes(&buffer))
+    }
+}
+
 impl TryFrom<u64> for PackageAddress {
     type Error = DeserializationError;
 
     fn try_from(value: u64) -> Result<Self, Self::Error> {
         if value >> 6 > 0 {
-            return Err(DeserializationError);
+            return Err(DeserializationError::InvalidUid);
         }
 
         let manufacturer_uid = (value >> u32::BITS) as u16;
@@ -190,3 +298,42 @@ impl From<PackageAddress> for u64 {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::unique_identifier::{PackageAddress, UniqueIdentifier};
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_uid_from_str() {
+        let uid = UniqueIdentifier::new(0x7FF0, 0x1234ABCD).unwrap();
+        assert_eq!(uid.to_string().parse::<UniqueIdentifier>().ok(), Some(uid));
+        assert_eq!("7ff0:1234abcd".parse::<UniqueIdentifier>().ok(), Some(uid));
+
+        assert!("12345:1".parse::<UniqueIdentifier>().is_err());
+        assert!("FFFF:1".parse::<UniqueIdentifier>().is_err());
+        assert!("1:FFFFFFFF".parse::<UniqueIdentifier>().is_err());
+        assert!("1:123456789".parse::<UniqueIdentifier>().is_err());
+        assert!("1:+1".parse::<UniqueIdentifier>().is_err());
+        assert!("1".parse::<UniqueIdentifier>().is_err());
+        assert!(":1".parse::<UniqueIdentifier>().is_err());
+    }
+
+    #[test]
+    fn test_package_address_from_str() {
+        let uid = UniqueIdentifier::new(0x7FF0, 1).unwrap();
+        assert_eq!(
+            "7FF0:00000001".parse::<PackageAddress>().ok(),
+            Some(PackageAddress::Device(uid))
+        );
+        assert_eq!(
+            "7FF0:FFFFFFFF".parse::<PackageAddress>().ok(),
+            Some(PackageAddress::ManufacturerBroadcast(0x7FF0))
+        );
+        assert_eq!(
+            "FFFF:FFFFFFFF".parse::<PackageAddress>().ok(),
+            Some(PackageAddress::Broadcast)
+        );
+        assert!("12345:1".parse::<PackageAddress>().is_err());
+    }
+}
@@ -1,5 +1,7 @@
 use crate::dmx_controller::{DmxController, RdmResponseError};
 use crate::dmx_driver::{DiscoveryOption, DmxError, RdmControllerDriver};
+use crate::pids;
+use crate::rdm_types::DeserializationError;
 use crate::unique_identifier::{PackageAddress, UniqueIdentifier};
 
 /// Blocking recursive discovery.
@@ -18,11 +20,14 @@ use crate::unique_identifier::{PackageAddress, UniqueIdentifier};
 /// <div class="warning">Since this function is blocking and does not make polled approaches
 /// possible, it is not suitable for embedded. Use this function as a starting point only and create
 /// a custom solution that fits your platform and use-case best. Refer to Section 7 of the
-/// ANSI E1.20 specifications for this.</div>
+/// ANSI E1.20 specifications for this. For a non-recursive alternative see [DiscoveryState].</div>
 pub fn run_full_discovery<Driver: RdmControllerDriver>(
     manager: &mut DmxController<Driver>,
     uid_array: &mut [UniqueIdentifier],
 ) -> Result<usize, RdmResponseError<Driver::DriverError>> {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::info_span!("run_full_discovery").entered();
+
     let addresses_found = discover_range(manager, 0x00000001, 0xFFFFFFFFFFFE, uid_array)?;
 
     Ok(addresses_found)
@@ -34,6 +39,9 @@ fn discover_range<Driver: RdmControllerDriver>(
     upper_bound: u64,
     uid_array: &mut [UniqueIdentifier],
 ) -> Result<usize, RdmResponseError<Driver::DriverError>> {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!("discover_range", lower_bound, upper_bound).entered();
+
     let discovery_option = manager.rdm_discover(lower_bound, upper_bound)?;
 
     if uid_array.is_empty() {
@@ -41,35 +49,25 @@ fn discover_range<Driver: RdmControllerDriver>(
     }
 
     match discovery_option {
-        DiscoveryOption::Collision => {
-            if upper_bound - lower_bound <= 1 {
+        DiscoveryOption::Collision => discover_halves(manager, lower_bound, upper_bound, uid_array),
+        DiscoveryOption::NoDevice => Ok(0),
+        DiscoveryOption::Found(uid) => {
+            if !mute_device(manager, uid)? {
                 return Ok(0);
             }
+            #[cfg(feature = "tracing")]
+            tracing::info!(%uid, "found device");
+            uid_array[0] = uid;
 
-            let first_lower_bound = lower_bound;
-            let first_upper_bound = (upper_bound + lower_bound) / 2;
-
-            let second_lower_bound = first_upper_bound + 1;
-            let second_upper_bound = upper_bound;
-
-            let upper_addresses_found =
-                discover_range(manager, second_lower_bound, second_upper_bound, uid_array)?;
-
-            let lower_address_found = discover_range(
-                manager,
-                first_lower_bound,
-                first_upper_bound,
-                &mut uid_array[upper_addresses_found..],
-            )?;
-
-            Ok(upper_addresses_found + lower_address_found)
+            Ok(1)
         },
-        DiscoveryOption::NoDevice => Ok(0),
-        DiscoveryOption::Found(uid) => {
-            match manager.rdm_disc_mute(PackageAddress::Device(uid)) {
-                Err(RdmResponseError::DmxError(DmxError::TimeoutError)) => return Ok(0),
-                result => result,
-            }?;
+        DiscoveryOption::FoundWithErrors(uid) => {
+            // no answer means the uid was the result of a collision
+            if !mute_device(manager, uid)? {
+                return discover_halves(manager, lower_bound, upper_bound, uid_array);
+            }
+            #[cfg(feature = "tracing")]
+            tracing::info!(%uid, "found device");
             uid_array[0] = uid;
 
             Ok(1)
@@ -77,6 +75,295 @@ fn discover_range<Driver: RdmControllerDriver>(
     }
 }
 
+fn discover_halves<Driver: RdmControllerDriver>(
+    manager: &mut DmxController<Driver>,
+    lower_bound: u64,
+    upper_bound: u64,
+    uid_array: &mut [UniqueIdentifier],
+) -> Result<usize, RdmResponseError<Driver::DriverError>> {
+    if upper_bound - lower_bound <= 1 {
+        return Ok(0);
+    }
+
+    let first_lower_bound = lower_bound;
+    let first_upper_bound = (upper_bound + lower_bound) / 2;
+
+    let second_lower_bound = first_upper_bound + 1;
+    let second_upper_bound = upper_bound;
+
+    let upper_addresses_found =
+        discover_range(manager, second_lower_bound, second_upper_bound, uid_array)?;
+
+    let lower_address_found = discover_range(
+        manager,
+        first_lower_bound,
+        first_upper_bound,
+        &mut uid_array[upper_addresses_found..],
+    )?;
+
+    Ok(upper_addresses_found + lower_address_found)
+}
+
+/// Mutes a found device. Returns false if the device didn't answer.
+///
+/// The mute is retried as configured in
+/// [crate::dmx_controller::DmxControllerConfig::discovery_mute_retries], so a
+/// transient timeout doesn't drop a device from the results.
+fn mute_device<Driver: RdmControllerDriver>(
+    manager: &mut DmxController<Driver>,
+    uid: UniqueIdentifier,
+) -> Result<bool, RdmResponseError<Driver::DriverError>> {
+    for _ in 0..=manager.get_discovery_mute_retries() {
+        match manager.rdm_disc_mute(PackageAddress::Device(uid)) {
+            Err(RdmResponseError::DmxError(DmxError::TimeoutError)) => continue,
+            result => return result.map(|_| true),
+        }
+    }
+
+    Ok(false)
+}
+
+/// The maximum depth of the binary search through the unique id space.
+pub const DISCOVERY_STACK_DEPTH: usize = 48;
+
+/// The result of a single [DiscoveryState::step].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum DiscoveryProgress {
+    /// The discovery isn't finished yet. Call [DiscoveryState::step] again.
+    InProgress,
+    /// A device was found and muted.
+    Found(UniqueIdentifier),
+    /// The whole unique id space has been searched.
+    Done,
+}
+
+/// Non-recursive discovery that can be driven one branch at a time.
+///
+/// Every call of [DiscoveryState::step] sends exactly one DISC_UNIQUE_BRANCH request
+/// (and a DISC_MUTE if a device was found), so the discovery can be interleaved with other work
+/// in a bare-metal main loop.
+///
+/// Instead of recursing, the upper halves of the branches that still have to be searched are kept
+/// on a stack that is capped at [DISCOVERY_STACK_DEPTH] entries. Each entry holds two u64 bounds,
+/// so the state takes up about 800 bytes.
+///
+/// Like [run_full_discovery], all rdm responders should be unmuted using a DISC_UN_MUTE
+/// broadcast before starting the discovery.
+pub struct DiscoveryState {
+    current_branch: Option<(u64, u64)>,
+    branch_stack: heapless::Vec<(u64, u64), DISCOVERY_STACK_DEPTH>,
+}
+
+impl Default for DiscoveryState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DiscoveryState {
+    /// Creates a new [DiscoveryState] that searches the whole unique id space.
+    pub fn new() -> Self {
+        Self {
+            current_branch: Some((0x00000001, 0xFFFFFFFFFFFE)),
+            branch_stack: heapless::Vec::new(),
+        }
+    }
+
+    /// Returns true if the whole unique id space has been searched.
+    pub fn is_done(&self) -> bool {
+        self.current_branch.is_none()
+    }
+
+    /// Searches the next branch of the unique id space.
+    pub fn step<Driver: RdmControllerDriver>(
+        &mut self,
+        manager: &mut DmxController<Driver>,
+    ) -> Result<DiscoveryProgress, RdmResponseError<Driver::DriverError>> {
+        let (lower_bound, upper_bound) = match self.current_branch {
+            None => return Ok(DiscoveryProgress::Done),
+            Some(branch) => branch,
+        };
+
+        let progress = match manager.rdm_discover(lower_bound, upper_bound)? {
+            DiscoveryOption::Collision => return Ok(self.split_branch(lower_bound, upper_bound)),
+            DiscoveryOption::NoDevice => DiscoveryProgress::InProgress,
+            DiscoveryOption::Found(uid) => match mute_device(manager, uid)? {
+                true => DiscoveryProgress::Found(uid),
+                false => DiscoveryProgress::InProgress,
+            },
+            DiscoveryOption::FoundWithErrors(uid) => match mute_device(manager, uid)? {
+                true => DiscoveryProgress::Found(uid),
+                // no answer means the uid was the result of a collision
+                false => return Ok(self.split_branch(lower_bound, upper_bound)),
+            },
+        };
+
+        self.current_branch = self.branch_stack.pop();
+
+        Ok(progress)
+    }
+
+    /// Continues with the lower half of a branch with a collision.
+    fn split_branch(&mut self, lower_bound: u64, upper_bound: u64) -> DiscoveryProgress {
+        if lower_bound == upper_bound {
+            self.current_branch = self.branch_stack.pop();
+            return DiscoveryProgress::InProgress;
+        }
+
+        let middle = (upper_bound + lower_bound) / 2;
+
+        // Every entry of the stack is the upper half of a different ancestor of the current
+        // branch, so the stack holds at most as many entries as the current branch is deep.
+        // The whole unique id space holds less than 2^48 uids and every split halves the branch,
+        // so a branch that is 48 splits deep contains a single uid and isn't split anymore.
+        self.branch_stack.push((middle + 1, upper_bound)).unwrap();
+        self.current_branch = Some((lower_bound, middle));
+
+        DiscoveryProgress::InProgress
+    }
+}
+
+/// Lazily discovers the devices using a [DiscoveryState].
+///
+/// Every call of [Iterator::next] searches the unique id space until the next device has been
+/// found and muted. The iterator ends once the whole unique id space has been searched.
+/// If a request fails, the error is returned and the failed branch is searched again on the next
+/// call, so the discovery can be resumed.
+///
+/// Like [run_full_discovery], all rdm responders should be unmuted using a DISC_UN_MUTE
+/// broadcast before starting the discovery.
+pub fn iter_discovery<Driver: RdmControllerDriver>(
+    manager: &mut DmxController<Driver>,
+) -> DiscoveryIter<'_, Driver> {
+    DiscoveryIter::new(manager, DiscoveryState::new())
+}
+
+/// An iterator over the discovered devices. See [iter_discovery].
+pub struct DiscoveryIter<'a, Driver: RdmControllerDriver> {
+    manager: &'a mut DmxController<Driver>,
+    state: DiscoveryState,
+}
+
+impl<'a, Driver: RdmControllerDriver> DiscoveryIter<'a, Driver> {
+    /// Creates an iterator that continues the discovery at the state.
+    pub fn new(manager: &'a mut DmxController<Driver>, state: DiscoveryState) -> Self {
+        Self { manager, state }
+    }
+
+    /// Returns the state of the discovery, so it can be resumed later.
+    pub fn into_state(self) -> DiscoveryState {
+        self.state
+    }
+}
+
+impl<Driver: RdmControllerDriver> Iterator for DiscoveryIter<'_, Driver> {
+    type Item = Result<UniqueIdentifier, RdmResponseError<Driver::DriverError>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.state.step(self.manager) {
+                Ok(DiscoveryProgress::InProgress) => continue,
+                Ok(DiscoveryProgress::Found(uid)) => return Some(Ok(uid)),
+                Ok(DiscoveryProgress::Done) => return None,
+                Err(error) => return Some(Err(error)),
+            }
+        }
+    }
+}
+
+impl<Driver: RdmControllerDriver> core::iter::FusedIterator for DiscoveryIter<'_, Driver> {}
+
+/// Returns true if the response pid is a valid answer to a request with the request pid.
+///
+/// Most responses echo the pid of the request. A QUEUED_MESSAGE request however is answered
+/// with the pid of the queued message or STATUS_MESSAGES if there are no queued messages.
+pub fn pid_response_matches(request_pid: u16, response_pid: u16) -> bool {
+    request_pid == response_pid || request_pid == pids::QUEUED_MESSAGE
+}
+
+/// The size of the uid count that prefixes a serialized uid table.
+pub const UID_TABLE_HEADER_SIZE: usize = 2;
+/// The size of a single serialized uid in a uid table.
+pub const UID_TABLE_ENTRY_SIZE: usize = 6;
+
+/// Returns the amount of bytes a uid table with the given amount of uids serializes to.
+/// Use it to size the buffer of [serialize_uid_table].
+pub const fn uid_table_size(uid_count: usize) -> usize {
+    UID_TABLE_HEADER_SIZE + uid_count * UID_TABLE_ENTRY_SIZE
+}
+
+/// Raised if a uid table doesn't fit into the buffer of [serialize_uid_table].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct UidTableTooLargeError;
+
+impl core::fmt::Display for UidTableTooLargeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "The uid table doesn't fit into the buffer.")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UidTableTooLargeError {}
+
+/// Serializes a table of uids (e.g. the devices found by [run_full_discovery]) so it can be
+/// persisted to flash or a file.
+///
+/// The table is the uid count as a big endian u16 followed by the 6 byte uids.
+/// The buffer needs at least [uid_table_size] bytes.
+pub fn serialize_uid_table<const N: usize>(
+    uids: &[UniqueIdentifier],
+) -> Result<heapless::Vec<u8, N>, UidTableTooLargeError> {
+    let uid_count = u16::try_from(uids.len()).map_err(|_| UidTableTooLargeError)?;
+    if uid_table_size(uids.len()) > N {
+        return Err(UidTableTooLargeError);
+    }
+
+    let mut buffer = heapless::Vec::new();
+    // can't fail since the size was checked before
+    buffer.extend_from_slice(&uid_count.to_be_bytes()).unwrap();
+    for uid in uids {
+        buffer.extend_from_slice(&uid.to_bytes()).unwrap();
+    }
+
+    Ok(buffer)
+}
+
+/// Deserializes a uid table that was serialized using [serialize_uid_table].
+///
+/// Fails if the length doesn't match the uid count, a uid is invalid or the table
+/// contains more than N uids.
+pub fn deserialize_uid_table<const N: usize>(
+    buffer: &[u8],
+) -> Result<heapless::Vec<UniqueIdentifier, N>, DeserializationError> {
+    let (uid_count, entries) = match buffer {
+        [count_high, count_low, entries @ ..] => (
+            u16::from_be_bytes([*count_high, *count_low]) as usize,
+            entries,
+        ),
+        _ => return Err(DeserializationError::WrongLength),
+    };
+
+    if entries.len() != uid_count * UID_TABLE_ENTRY_SIZE {
+        return Err(DeserializationError::WrongLength);
+    }
+    if uid_count > N {
+        return Err(DeserializationError::OutOfRange);
+    }
+
+    let mut uids = heapless::Vec::new();
+    for entry in entries.chunks_exact(UID_TABLE_ENTRY_SIZE) {
+        let uid = UniqueIdentifier::new(
+            u16::from_be_bytes([entry[0], entry[1]]),
+            u32::from_be_bytes([entry[2], entry[3], entry[4], entry[5]]),
+        )?;
+        // can't fail since the uid count was checked before
+        uids.push(uid).unwrap();
+    }
+
+    Ok(uids)
+}
+
 #[inline]
 pub(crate) fn calculate_checksum(data: &[u8]) -> u16 {
     let mut checksum = 0u16;
@@ -99,3 +386,351 @@ pub(crate) fn encode_disc_unique(src: &[u8], dest: &mut [u8]) {
         dest[index * 2 + 1] = byte | 0x55;
     }
 }
+
+#[cfg(test)]
+#[cfg_attr(not(feature = "std"), allow(unused_imports))]
+mod tests {
+    use crate::dmx_controller::{DmxController, DmxControllerConfig};
+    use crate::dmx_driver::{
+        ControllerDriverErrorDef, DiscoveryOption, DmxError, RdmControllerDriver,
+    };
+    use crate::pids;
+    use crate::rdm_data::RdmData;
+    use crate::rdm_responder::{
+        RdmAnswer, RdmResponderConfig, RdmResponderHandlerFunc, RdmResponderPackageHandler,
+    };
+    use crate::rdm_types::DeserializationError;
+    use crate::unique_identifier::{PackageAddress, UniqueIdentifier};
+    use crate::utils::{
+        deserialize_uid_table, iter_discovery, pid_response_matches, serialize_uid_table,
+        uid_table_size, DiscoveryIter, DiscoveryProgress, DiscoveryState, UidTableTooLargeError,
+    };
+
+    #[cfg(feature = "std")]
+    struct EmptyHandler;
+
+    #[cfg(feature = "std")]
+    impl RdmResponderHandlerFunc for EmptyHandler {
+        type Error = ();
+    }
+
+    /// A bus with multiple responders. Discovery responses of multiple responders collide.
+    #[cfg(feature = "std")]
+    struct MultiResponderBus {
+        responders: Vec<RdmResponderPackageHandler<0>>,
+        responses: Vec<RdmData>,
+        discovery_responses: Vec<UniqueIdentifier>,
+    }
+
+    #[cfg(feature = "std")]
+    impl MultiResponderBus {
+        fn new(uids: &[UniqueIdentifier]) -> Self {
+            Self {
+                responders: uids
+                    .iter()
+                    .map(|&uid| {
+                        RdmResponderPackageHandler::new(RdmResponderConfig {
+                            uid,
+                            ..Default::default()
+                        })
+                    })
+                    .collect(),
+                responses: Vec::new(),
+                discovery_responses: Vec::new(),
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl ControllerDriverErrorDef for MultiResponderBus {
+        type DriverError = ();
+    }
+
+    #[cfg(feature = "std")]
+    impl RdmControllerDriver for MultiResponderBus {
+        fn send_rdm(&mut self, package: RdmData) -> Result<(), DmxError<()>> {
+            let request = match package {
+                RdmData::Request(request) => request,
+                RdmData::Response(_) => panic!("controller sent a response"),
+            };
+
+            self.responses.clear();
+            self.discovery_responses.clear();
+            for responder in &mut self.responders {
+                match responder.handle_rdm_request(request.clone(), &mut EmptyHandler) {
+                    Ok(RdmAnswer::Response(response)) => {
+                        self.responses.push(RdmData::Response(response))
+                    },
+                    Ok(RdmAnswer::DiscoveryResponse(uid)) => self.discovery_responses.push(uid),
+                    _ => {},
+                }
+            }
+
+            Ok(())
+        }
+
+        fn receive_rdm(&mut self) -> Result<RdmData, DmxError<()>> {
+            match self.responses.len() {
+                1 => Ok(self.responses.remove(0)),
+                _ => Err(DmxError::TimeoutError),
+            }
+        }
+
+        fn receive_rdm_discovery_response(&mut self) -> Result<DiscoveryOption, DmxError<()>> {
+            Ok(match self.discovery_responses[..] {
+                [] => DiscoveryOption::NoDevice,
+                [uid] => DiscoveryOption::Found(uid),
+                _ => DiscoveryOption::Collision,
+            })
+        }
+
+        fn send_rdm_discovery_response(&mut self, _: UniqueIdentifier) -> Result<(), DmxError<()>> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_iter_discovery() {
+        let uids = [
+            UniqueIdentifier::new(0x7FF0, 0x0001).unwrap(),
+            UniqueIdentifier::new(0x7FF0, 0x0002).unwrap(),
+            UniqueIdentifier::new(0x4144, 0x1234_5678).unwrap(),
+        ];
+        let mut controller = DmxController::new(
+            MultiResponderBus::new(&uids),
+            &DmxControllerConfig::default(),
+        );
+        controller
+            .rdm_disc_un_mute(PackageAddress::Broadcast)
+            .unwrap();
+
+        // stop after the first device and resume the discovery afterward
+        let mut discovery = iter_discovery(&mut controller);
+        let mut found_uids = vec![discovery.next().unwrap().unwrap()];
+        let state = discovery.into_state();
+
+        let mut discovery = DiscoveryIter::new(&mut controller, state);
+        found_uids.extend(discovery.by_ref().map(Result::unwrap));
+        assert!(discovery.next().is_none());
+
+        found_uids.sort_by_key(|uid| u64::from(*uid));
+        let mut expected_uids = uids.to_vec();
+        expected_uids.sort_by_key(|uid| u64::from(*uid));
+        assert_eq!(found_uids, expected_uids);
+
+        // every device is muted, so a new discovery doesn't find anything
+        assert_eq!(
+            DiscoveryIter::new(&mut controller, DiscoveryState::new()).count(),
+            0
+        );
+    }
+
+    /// A bus that answers discovery requests as scripted and never answers other requests.
+    #[cfg(feature = "std")]
+    struct ScriptedDiscoveryBus {
+        discovery_options: Vec<DiscoveryOption>,
+        branches: Vec<(u64, u64)>,
+    }
+
+    #[cfg(feature = "std")]
+    impl ControllerDriverErrorDef for ScriptedDiscoveryBus {
+        type DriverError = ();
+    }
+
+    #[cfg(feature = "std")]
+    impl RdmControllerDriver for ScriptedDiscoveryBus {
+        fn send_rdm(&mut self, package: RdmData) -> Result<(), DmxError<()>> {
+            let request = match package {
+                RdmData::Request(request) => request,
+                RdmData::Response(_) => panic!("controller sent a response"),
+            };
+
+            if request.parameter_id == pids::DISC_UNIQUE_BRANCH {
+                let read_bound = |bytes: &[u8]| {
+                    let mut bound = [0u8; 8];
+                    bound[2..].copy_from_slice(bytes);
+                    u64::from_be_bytes(bound)
+                };
+                self.branches.push((
+                    read_bound(&request.parameter_data[..6]),
+                    read_bound(&request.parameter_data[6..]),
+                ));
+            }
+
+            Ok(())
+        }
+
+        fn receive_rdm(&mut self) -> Result<RdmData, DmxError<()>> {
+            Err(DmxError::TimeoutError)
+        }
+
+        fn receive_rdm_discovery_response(&mut self) -> Result<DiscoveryOption, DmxError<()>> {
+            Ok(self.discovery_options.remove(0))
+        }
+
+        fn send_rdm_discovery_response(&mut self, _: UniqueIdentifier) -> Result<(), DmxError<()>> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_discovery_state_splits_collision() {
+        let uids = [
+            UniqueIdentifier::new(0x7FF0, 0x0002).unwrap(),
+            UniqueIdentifier::new(0x7FF0, 0x0003).unwrap(),
+        ];
+        let mut controller = DmxController::new(
+            MultiResponderBus::new(&uids),
+            &DmxControllerConfig::default(),
+        );
+
+        // a branch that only contains the two colliding devices
+        let mut state = DiscoveryState {
+            current_branch: Some((u64::from(uids[0]), u64::from(uids[1]))),
+            branch_stack: heapless::Vec::new(),
+        };
+        let progress: Vec<_> = (0..4)
+            .map(|_| state.step(&mut controller).unwrap())
+            .collect();
+        assert_eq!(
+            progress,
+            [
+                DiscoveryProgress::InProgress,
+                DiscoveryProgress::Found(uids[0]),
+                DiscoveryProgress::Found(uids[1]),
+                DiscoveryProgress::Done,
+            ]
+        );
+        assert!(state.is_done());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_discovery_state_splits_unmutable_uid() {
+        let mut controller = DmxController::new(
+            ScriptedDiscoveryBus {
+                discovery_options: vec![
+                    // a uid that was made up by a collision doesn't answer the mute
+                    DiscoveryOption::FoundWithErrors(
+                        UniqueIdentifier::new(0x7FF0, 0x0002).unwrap(),
+                    ),
+                    DiscoveryOption::NoDevice,
+                    DiscoveryOption::NoDevice,
+                ],
+                branches: Vec::new(),
+            },
+            &DmxControllerConfig {
+                accept_discovery_responses_with_errors: true,
+                ..Default::default()
+            },
+        );
+
+        let mut state = DiscoveryState {
+            current_branch: Some((0x10, 0x13)),
+            branch_stack: heapless::Vec::new(),
+        };
+        for _ in 0..3 {
+            assert!(!state.is_done());
+            assert_eq!(
+                state.step(&mut controller).unwrap(),
+                DiscoveryProgress::InProgress
+            );
+        }
+        assert!(state.is_done());
+        assert_eq!(
+            state.step(&mut controller).unwrap(),
+            DiscoveryProgress::Done
+        );
+
+        assert_eq!(
+            controller.get_driver().branches,
+            [(0x10, 0x13), (0x10, 0x11), (0x12, 0x13)]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_discovery_state_done() {
+        let mut controller =
+            DmxController::new(MultiResponderBus::new(&[]), &DmxControllerConfig::default());
+
+        let mut state = DiscoveryState::new();
+        assert!(!state.is_done());
+        assert_eq!(
+            state.step(&mut controller).unwrap(),
+            DiscoveryProgress::InProgress
+        );
+
+        // the empty unique id space has been searched with a single request
+        assert!(state.is_done());
+        for _ in 0..2 {
+            assert_eq!(
+                state.step(&mut controller).unwrap(),
+                DiscoveryProgress::Done
+            );
+        }
+    }
+
+    #[test]
+    fn test_pid_response_matches() {
+        assert!(pid_response_matches(pids::DEVICE_INFO, pids::DEVICE_INFO));
+        assert!(pid_response_matches(
+            pids::QUEUED_MESSAGE,
+            pids::STATUS_MESSAGES
+        ));
+        assert!(!pid_response_matches(
+            pids::DEVICE_INFO,
+            pids::DMX_START_ADDRESS
+        ));
+    }
+
+    #[test]
+    fn test_uid_table() {
+        let uids = [
+            UniqueIdentifier::new(0x7FF0, 0x00000001).unwrap(),
+            UniqueIdentifier::new(0x4144, 0x12345678).unwrap(),
+            UniqueIdentifier::new(0x0000, 0x00000000).unwrap(),
+            UniqueIdentifier::new(0xFFFE, 0xFFFFFFFE).unwrap(),
+        ];
+
+        let table = serialize_uid_table::<{ uid_table_size(4) }>(&uids).unwrap();
+        assert_eq!(table.len(), 26);
+        assert_eq!(
+            &table[..8],
+            &[0x00, 0x04, 0x7F, 0xF0, 0x00, 0x00, 0x00, 0x01]
+        );
+        assert_eq!(&deserialize_uid_table::<4>(&table).unwrap()[..], &uids);
+
+        let table = serialize_uid_table::<{ uid_table_size(0) }>(&[]).unwrap();
+        assert_eq!(&table[..], &[0x00, 0x00]);
+        assert!(deserialize_uid_table::<4>(&table).unwrap().is_empty());
+
+        assert_eq!(
+            serialize_uid_table::<{ uid_table_size(3) }>(&uids),
+            Err(UidTableTooLargeError)
+        );
+
+        let table = serialize_uid_table::<32>(&uids).unwrap();
+        // more uids than fit into the vector
+        assert_eq!(
+            deserialize_uid_table::<3>(&table),
+            Err(DeserializationError::OutOfRange)
+        );
+        // truncated table
+        assert_eq!(
+            deserialize_uid_table::<4>(&table[..table.len() - 1]),
+            Err(DeserializationError::WrongLength)
+        );
+        assert_eq!(
+            deserialize_uid_table::<4>(&[0x00]),
+            Err(DeserializationError::WrongLength)
+        );
+        // broadcast uids aren't valid device uids
+        assert_eq!(
+            deserialize_uid_table::<1>(&[0x00, 0x01, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]),
+            Err(DeserializationError::InvalidUid)
+        );
+    }
+}